@@ -38,15 +38,10 @@ export const handleSubmitHeadersCmd = async (
 
     spinner.start(`Submitting headers for ${gatewayId}`)
 
-    const bridge = getBridge(circuit, gatewayId)
     const tx = sdk.circuit.tx.createBatch(
-      transactionArguments.map((args) => {
-        return bridge.submitHeaders(
-          args.range,
-          args.signed_header,
-          args.justification,
-        )
-      }),
+      transactionArguments.map((args) =>
+        createPortalSubmission(circuit, gatewayId, args),
+      ),
     )
     await sdk.circuit.tx.signAndSendSafe(tx)
 
@@ -81,28 +76,24 @@ export const getHeaders = async (circuit: Circuit, gateway: Gateway) => {
   }
 }
 
-export const getBridge = (circuit: Circuit, gatewayId: string) => {
-  const config = getConfig()
-  if (!config) {
-    return
-  }
-
-  const gateway = config.gateways.find((g) => g.id === gatewayId)
-  if (!gateway) {
-    return
-  }
+// Relayers submit headers through the Portal, the light clients only accept them from root
+export const createPortalSubmission = (
+  circuit: Circuit,
+  gatewayId: string,
+  args: any,
+) => {
+  const encodedHeaderData = circuit
+    .createType(
+      '(SpRuntimeHeader, Vec<SpRuntimeHeader>, PalletGrandpaFinalityVerifierBridgesHeaderChainJustificationGrandpaJustification)',
+      [args.signed_header, args.range, args.justification],
+    )
+    .toHex()
 
-  const verificationVendor = gateway.registrationData.verificationVendor
-  switch (verificationVendor) {
-    case 'Kusama':
-      return circuit.tx.kusamaBridge
-    case 'Rococo':
-      return circuit.tx.rococoBridge
-    case 'Polkadot':
-      return circuit.tx.polkadotBridge
-    case 'Sepolia':
-      return circuit.tx.sepoliaBridge
-  }
+  return circuit.tx.portal.submitHeaders(
+    gatewayId,
+    parseInt(args.signed_header.number),
+    encodedHeaderData,
+  )
 }
 
 const getRelayChainHeaders = async (
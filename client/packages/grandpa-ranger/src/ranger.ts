@@ -172,29 +172,12 @@ export class GrandpaRanger {
   private createTxBatch(range: any[]) {
     let tx
     tx = this.circuit.sdk?.circuit.tx.createBatch(
-      range.map(args => {
-        let submit
-        // select the correct submit function based on the targetGatewayId
-        if (this.config.targetGatewayId === 'roco') {
-          submit = this.circuit.client.tx.rococoBridge.submitHeaders
-        } else if (this.config.targetGatewayId === 'kusm') {
-          submit = this.circuit.client.tx.kusamaBridge.submitHeaders
-        } else if (this.config.targetGatewayId === 'pdot') {
-          submit = this.circuit.client.tx.polkadotBridge.submitHeaders
-        } else {
-          throw new Error(
-            `Unknown targetGatewayId: ${this.config.targetGatewayId}`
-          )
-        }
-
-        return submit(args.range, args.signed_header, args.justification)
-      })
+      range.map(args => this.createPortalSubmission(args))
     )
     return tx
   }
 
   private createTx(range: any[]) {
-    let tx
     logger.debug('Batches disabled')
     logger.debug(
       `Size of range: ${Math.floor(
@@ -211,28 +194,27 @@ export class GrandpaRanger {
         Buffer.from(JSON.stringify(range[0].justification)).length / 1024
       )}kB`
     )
-    if (this.config.targetGatewayId === 'roco') {
-      tx = this.circuit.client.tx.rococoBridge.submitHeaders(
-        range[0].range,
-        range[0].signed_header,
-        range[0].justification
-      )
-    } else if (this.config.targetGatewayId === 'kusm') {
-      tx = this.circuit.client.tx.kusamaBridge.submitHeaders(
-        range[0].range,
-        range[0].signed_header,
-        range[0].justification
-      )
-    } else if (this.config.targetGatewayId === 'pdot') {
-      tx = this.circuit.client.tx.polkadotBridge.submitHeaders(
-        range[0].range,
-        range[0].signed_header,
-        range[0].justification
-      )
-    } else {
+    return this.createPortalSubmission(range[0])
+  }
+
+  // Relayers submit headers through the Portal, the light clients only accept them from root
+  private createPortalSubmission(args: any) {
+    if (!['roco', 'kusm', 'pdot'].includes(this.config.targetGatewayId)) {
       throw new Error(`Unknown targetGatewayId: ${this.config.targetGatewayId}`)
     }
-    return tx
+
+    const encodedHeaderData = this.circuit.client
+      .createType(
+        '(SpRuntimeHeader, Vec<SpRuntimeHeader>, PalletGrandpaFinalityVerifierBridgesHeaderChainJustificationGrandpaJustification)',
+        [args.signed_header, args.range, args.justification]
+      )
+      .toHex()
+
+    return this.circuit.client.tx.portal.submitHeaders(
+      this.config.targetGatewayId,
+      parseInt(args.signed_header.number),
+      encodedHeaderData
+    )
   }
 
   async scheduleHeightMonitoring() {
@@ -49,11 +49,11 @@ use bridges::{
 };
 use finality_grandpa::voter_set::VoterSet;
 use frame_support::{ensure, pallet_prelude::*, transactional, StorageHasher};
-use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor, RawOrigin};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 
 use sp_consensus_grandpa::{ConsensusLog, GRANDPA_ENGINE_ID};
 use sp_core::crypto::ByteArray;
-use sp_runtime::traits::{BadOrigin, Header as HeaderT, Zero};
+use sp_runtime::traits::{BadOrigin, Header as HeaderT, One, Saturating, Zero};
 use t3rn_primitives::light_client::LightClientAsyncAPI;
 
 use sp_std::{convert::TryInto, vec, vec::Vec};
@@ -125,6 +125,9 @@ pub mod pallet {
         /// The setting is there to prevent growing the on-chain state indefinitely. Note
         /// the setting does not relate to block numbers - we will simply keep as much items
         /// in the storage, so it doesn't guarantee any fixed timeframe for finality headers.
+        ///
+        /// Used as the retention window unless overridden via `set_retention_window`, and as
+        /// the least number of pruned header hashes remembered to report `HeaderPruned` errors.
         #[pallet::constant]
         type HeadersToStore: Get<u32>;
 
@@ -151,13 +154,49 @@ pub mod pallet {
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config<I>, I: 'static = ()> {
         HeadersAdded(BridgedBlockNumber<T, I>),
+        /// Headers which fell out of the retention window were pruned. [count]
+        HeadersPruned(u32),
+        /// The retention window was updated. [window]
+        RetentionWindowSet(u32),
     }
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T, I = ()>(pub PhantomData<(T, I)>);
 
     #[pallet::hooks]
-    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Pallet::<T, I>::prune_headers(n, remaining_weight)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            // Define the current storage migration version.
+            const CURRENT_STORAGE_VERSION: u32 = 1;
+            if <StorageMigrations<T, I>>::get() >= CURRENT_STORAGE_VERSION {
+                return T::DbWeight::get().reads(1)
+            }
+            // Storage Migration: lay the imported hashes ring buffer out as a queue, oldest first,
+            // so that on_idle prunes them in import order. A queue nothing was pruned from yet
+            // starts at zero and keeps its order.
+            let mut migrated: u64 = 0;
+            if <OldestImportedHashPointer<T, I>>::get() == 0 {
+                let head = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
+                let mut hashes = <ImportedHashes<T, I>>::drain().collect::<Vec<_>>();
+                // The ring buffer overwrote its oldest entry next, at the pointer.
+                hashes.sort_by_key(|(index, _hash)| (*index < head, *index));
+                for (hash, index) in hashes.into_iter().map(|(_, hash)| hash).zip(0u32..) {
+                    <ImportedHashes<T, I>>::insert(index, hash);
+                    <ImportedHashesPointer<T, I>>::put(index.wrapping_add(1));
+                    migrated += 1;
+                }
+            }
+            <StorageMigrations<T, I>>::put(CURRENT_STORAGE_VERSION);
+            T::DbWeight::get().reads_writes(
+                migrated.saturating_add(3),
+                migrated.saturating_mul(3).saturating_add(1),
+            )
+        }
+    }
 
     #[pallet::error]
     pub enum Error<T, I = ()> {
@@ -211,6 +250,10 @@ pub mod pallet {
         InvalidPayloadSource,
         /// The payload source format is invalid
         InvalidSourceFormat,
+        /// The header was imported, but has since been pruned out of the retention window
+        HeaderPruned,
+        /// The retention window must keep at least one header
+        InvalidRetentionWindow,
     }
 
     /// Hash of the header used to bootstrap the pallet.
@@ -225,12 +268,68 @@ pub mod pallet {
     pub(super) type BestFinalizedHash<T: Config<I>, I: 'static = ()> =
         StorageValue<_, BridgedBlockHash<T, I>, OptionQuery>;
 
-    /// A ring buffer of imported hashes. Ordered by the insertion time.
+    /// A queue of imported hashes. Ordered by the insertion time.
     #[pallet::storage]
     #[pallet::getter(fn get_imported_hashes)]
     pub(super) type ImportedHashes<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Identity, u32, BridgedBlockHash<T, I>>;
 
+    /// Queue position of the oldest imported hash which hasn't been pruned yet.
+    #[pallet::storage]
+    #[pallet::getter(fn get_oldest_imported_hash_pointer)]
+    pub(super) type OldestImportedHashPointer<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
+    /// Number of the latest finalized headers to retain. Falls back to `HeadersToStore` if unset.
+    #[pallet::storage]
+    #[pallet::getter(fn get_retention_window)]
+    pub(super) type RetentionWindow<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, OptionQuery>;
+
+    /// Headers referenced by in-flight xtxs, pinned until the given local block number.
+    #[pallet::storage]
+    #[pallet::getter(fn get_pinned_headers)]
+    pub(super) type PinnedHeaders<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, BridgedBlockHash<T, I>, BlockNumberFor<T>>;
+
+    /// Pinned headers which already left the retention window. Pruned once their pin expires.
+    #[pallet::storage]
+    #[pallet::getter(fn get_evicted_pinned_headers)]
+    pub(super) type EvictedPinnedHeaders<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, BridgedBlockHash<T, I>, BlockNumberFor<T>>;
+
+    /// Hashes of the evicted pinned headers, keyed by the local block number their pin expires at.
+    #[pallet::storage]
+    pub(super) type EvictedPinExpiries<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<BridgedBlockHash<T, I>>, ValueQuery>;
+
+    /// Earliest local block number whose expired pins haven't been pruned yet.
+    #[pallet::storage]
+    #[pallet::getter(fn get_next_pin_expiry)]
+    pub(super) type NextPinExpiry<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// A queue of recently pruned hashes. Ordered by the pruning time.
+    #[pallet::storage]
+    pub(super) type PrunedHashes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, u32, BridgedBlockHash<T, I>>;
+
+    /// Queue position of the next pruned hash.
+    #[pallet::storage]
+    pub(super) type PrunedHashesPointer<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
+    /// Queue position of the oldest pruned hash which is still remembered.
+    #[pallet::storage]
+    pub(super) type OldestPrunedHashPointer<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
+    /// Numbers of recently pruned headers, telling pruned headers apart from unknown ones.
+    #[pallet::storage]
+    #[pallet::getter(fn get_pruned_headers)]
+    pub(super) type PrunedHeaders<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, BridgedBlockHash<T, I>, BridgedBlockNumber<T, I>>;
+
     /// Count successful submissions.
     #[pallet::storage]
     #[pallet::getter(fn get_submissions_counter)]
@@ -253,6 +352,11 @@ pub mod pallet {
     pub(super) type RelayChainId<T: Config<I>, I: 'static = ()> =
         StorageValue<_, ChainId, OptionQuery>;
 
+    /// Version of the last storage migration applied to the light client.
+    #[pallet::storage]
+    pub(super) type StorageMigrations<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
     /// The current GRANDPA Authority set.
     #[pallet::storage]
     pub(super) type CurrentAuthoritySet<T: Config<I>, I: 'static = ()> =
@@ -295,7 +399,8 @@ pub mod pallet {
         /// If successful in verification, it will write the target range to the underlying storage
         /// pallet.
         ///
-        /// If the new range was accepted, pays no fee.
+        /// Restricted to root, relayers submit headers through the Portal, which bounds, weighs
+        /// and rewards their submissions.
         #[pallet::weight(Weight::from_parts(10_000, 0u64) + T::DbWeight::get().writes(1))]
         pub fn submit_headers(
             origin: OriginFor<T>,
@@ -306,7 +411,7 @@ pub mod pallet {
             // GrandpaJustification for the signed_header
             justification: GrandpaJustification<BridgedHeader<T, I>>,
         ) -> DispatchResultWithPostInfo {
-            let _ = ensure_signed(origin)?;
+            ensure_root(origin)?;
 
             let pointer_prior = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
             Pallet::<T, I>::verify_and_store_headers(range, signed_header, justification)?;
@@ -331,11 +436,9 @@ pub mod pallet {
                     counter
                         .saturating_add(frame_system::pallet_prelude::BlockNumberFor::<T>::one()),
                 );
-
-                Ok(Pays::No.into())
-            } else {
-                Ok(Pays::Yes.into())
             }
+
+            Ok(().into())
         }
 
         #[pallet::weight(100_000)]
@@ -346,14 +449,40 @@ pub mod pallet {
             for _ in <ParachainIdMap<T, I>>::drain() {}
             for _ in <ImportedHashes<T, I>>::drain() {}
             for _ in <ImportedHeaders<T, I>>::drain() {}
+            for _ in <PinnedHeaders<T, I>>::drain() {}
+            for _ in <EvictedPinnedHeaders<T, I>>::drain() {}
+            for _ in <EvictedPinExpiries<T, I>>::drain() {}
+            for _ in <PrunedHashes<T, I>>::drain() {}
+            for _ in <PrunedHeaders<T, I>>::drain() {}
             <InitialHash<T, I>>::kill();
             <ImportedHashesPointer<T, I>>::kill(); // one ahead of first value
+            <OldestImportedHashPointer<T, I>>::kill();
+            <PrunedHashesPointer<T, I>>::kill();
+            <OldestPrunedHashPointer<T, I>>::kill();
+            <NextPinExpiry<T, I>>::kill();
+            <RetentionWindow<T, I>>::kill();
             <RelayChainId<T, I>>::kill();
             <CurrentAuthoritySet<T, I>>::kill();
             <IsHalted<T, I>>::kill();
             <PalletOwner<T, I>>::kill();
             Ok(().into())
         }
+
+        /// Set the number of latest finalized headers kept by the light client.
+        ///
+        /// Headers falling out of the window are pruned in `on_idle`, unless pinned by an
+        /// in-flight xtx. May only be called either by root, or by `PalletOwner`.
+        #[pallet::weight(T::WeightInfo::set_retention_window())]
+        pub fn set_retention_window(
+            origin: OriginFor<T>,
+            window: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_owner_or_root_single::<T, I>(origin)?;
+            ensure!(window > 0, Error::<T, I>::InvalidRetentionWindow);
+            <RetentionWindow<T, I>>::put(window);
+            Self::deposit_event(Event::RetentionWindowSet(window));
+            Ok(().into())
+        }
     }
 
     /// Check the given header for a GRANDPA scheduled authority set change. If a change
@@ -466,21 +595,14 @@ pub mod pallet {
         Ok(())
     }
 
-    /// removes old header data based on ring buffer logic. Adds new header data, updates the ring buffer entry and increments buffer index
-    pub(crate) fn write_and_clean_header_data<T: Config<I>, I: 'static>(
+    /// Adds new header data, appends its hash to the imported hashes queue and increments the queue index.
+    /// Old headers are removed later on, in `on_idle`, once they fall out of the retention window.
+    pub(crate) fn write_header_data<T: Config<I>, I: 'static>(
         buffer_index: &mut u32,
         header: &BridgedHeader<T, I>,
         hash: BridgedBlockHash<T, I>,
         is_signed_header: bool,
     ) -> Result<(), &'static str> {
-        // If we find an entry to overwrite, do so.
-        if let Ok(hash) = <ImportedHashes<T, I>>::try_get(
-            *buffer_index, // can't overflow because of incrementation logic
-        ) {
-            <ImportedHeaders<T, I>>::remove(hash);
-        }
-
-        // Once deleted, we add the new header
         <ImportedHeaders<T, I>>::insert(hash, header.clone());
         <ImportedHashes<T, I>>::insert(*buffer_index, hash);
 
@@ -489,7 +611,7 @@ pub mod pallet {
             <BestFinalizedHash<T, I>>::put(hash);
         }
 
-        *buffer_index = (*buffer_index + 1) % T::HeadersToStore::get(); // prevents overflows
+        *buffer_index = buffer_index.wrapping_add(1); // queue positions are compared with wrapping_sub
         Ok(())
     }
 
@@ -513,10 +635,6 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // GrandpaJustification for the signed_header
         justification: GrandpaJustification<BridgedHeader<T, I>>,
     ) -> DispatchResult {
-        // °°°°° Implicit Check: °°°°°
-        // range.len() < T::HeadersToStore::get() - ensures that we don't mess up our ring buffer
-        // Since polkadot updates its authority set every 24h, this is implicitly ensured => Justification check would fail after 1/7th of max len
-
         // we get the latest header from storage
         let mut best_finalized_hash =
             <BestFinalizedHash<T, I>>::get().ok_or(Error::<T, I>::NoFinalizedHeader)?;
@@ -552,12 +670,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         for header in range {
             if best_finalized_hash == *header.parent_hash() {
                 // write header to storage if correct
-                write_and_clean_header_data::<T, I>(
-                    &mut buffer_index,
-                    &header,
-                    header.hash(),
-                    false,
-                )?;
+                write_header_data::<T, I>(&mut buffer_index, &header, header.hash(), false)?;
 
                 best_finalized_hash = header.hash();
             } else {
@@ -570,12 +683,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // °°°°° Begin Check: #3 °°°°°
         if best_finalized_hash == *signed_header.parent_hash() {
             // write header to storage if correct
-            write_and_clean_header_data::<T, I>(
-                &mut buffer_index,
-                &signed_header,
-                signed_hash,
-                true,
-            )?;
+            write_header_data::<T, I>(&mut buffer_index, &signed_header, signed_hash, true)?;
         } else {
             return Err(Error::<T, I>::InvalidJustificationLinkage.into())
         }
@@ -611,6 +719,164 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         <ImportedHeaders<T, I>>::contains_key(hash)
     }
 
+    /// Get an imported header, telling apart headers pruned out of the retention window
+    /// from the ones never imported.
+    pub fn get_imported_header(
+        hash: BridgedBlockHash<T, I>,
+    ) -> Result<BridgedHeader<T, I>, Error<T, I>> {
+        <ImportedHeaders<T, I>>::get(hash).ok_or_else(|| {
+            if <PrunedHeaders<T, I>>::contains_key(hash) {
+                Error::<T, I>::HeaderPruned
+            } else {
+                Error::<T, I>::UnknownHeader
+            }
+        })
+    }
+
+    /// Number of the latest finalized headers kept before pruning.
+    pub fn retention_window() -> u32 {
+        <RetentionWindow<T, I>>::get().unwrap_or_else(T::HeadersToStore::get)
+    }
+
+    /// Keep an imported header from being pruned until the given local block number.
+    /// Used for headers referenced by in-flight xtxs, as their inclusion proofs may need re-checking.
+    pub fn pin_header(
+        encoded_header_hash: Vec<u8>,
+        until: BlockNumberFor<T>,
+    ) -> Result<(), DispatchError> {
+        let hash: BridgedBlockHash<T, I> = Decode::decode(&mut &*encoded_header_hash)
+            .map_err(|_| Error::<T, I>::HeaderDecodingError)?;
+        Self::get_imported_header(hash)?;
+
+        if let Some(pinned_until) = <EvictedPinnedHeaders<T, I>>::get(hash) {
+            if until > pinned_until {
+                Self::evict_pinned_header(hash, until);
+            }
+        } else {
+            <PinnedHeaders<T, I>>::mutate(hash, |pinned_until| {
+                *pinned_until = Some(pinned_until.map_or(until, |current| current.max(until)));
+            });
+        }
+        Ok(())
+    }
+
+    /// Prune headers which fell out of the retention window and the evicted headers whose pins expired.
+    /// Stops once the next step would exceed the given weight budget.
+    pub(crate) fn prune_headers(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // Dequeue a hash, take its pin and remove the header, forgetting up to two tombstones.
+        let step_weight = db_weight.reads_writes(7, 11);
+        // Take the hashes of the evicted headers whose pins expire at the next block.
+        let expiry_weight = db_weight.reads_writes(1, 1);
+        // Check the evicted header's pin is still expired and remove the header.
+        let expired_pin_weight = db_weight.reads_writes(6, 10);
+        let mut consumed_weight = db_weight.reads_writes(5, 3);
+        if consumed_weight.any_gt(max_weight) {
+            return Weight::zero()
+        }
+        let fits = |consumed_weight: Weight, step_weight: Weight| {
+            !consumed_weight
+                .saturating_add(step_weight)
+                .any_gt(max_weight)
+        };
+
+        let window = Self::retention_window();
+        let tombstones_to_keep = window.max(T::HeadersToStore::get());
+        let head = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
+        let mut oldest = <OldestImportedHashPointer<T, I>>::get();
+        let mut pruned: u32 = 0;
+
+        while head.wrapping_sub(oldest) > window {
+            if !fits(consumed_weight, step_weight) {
+                break
+            }
+            consumed_weight = consumed_weight.saturating_add(step_weight);
+
+            if let Some(hash) = <ImportedHashes<T, I>>::take(oldest) {
+                match <PinnedHeaders<T, I>>::take(hash) {
+                    Some(until) if until >= now => Self::evict_pinned_header(hash, until),
+                    _ => {
+                        Self::prune_header(hash, tombstones_to_keep);
+                        pruned = pruned.saturating_add(1);
+                    },
+                }
+            }
+            oldest = oldest.wrapping_add(1);
+        }
+        <OldestImportedHashPointer<T, I>>::put(oldest);
+
+        // Walk the pin expiries in order, so unexpired pins never hold up the expired ones.
+        let mut next_expiry = <NextPinExpiry<T, I>>::get();
+        'expiries: while let Some(expiry) = next_expiry.filter(|expiry| *expiry < now) {
+            if !fits(consumed_weight, expiry_weight) {
+                break
+            }
+            consumed_weight = consumed_weight.saturating_add(expiry_weight);
+
+            let mut hashes = <EvictedPinExpiries<T, I>>::take(expiry);
+            while let Some(hash) = hashes.pop() {
+                if !fits(consumed_weight, expired_pin_weight) {
+                    hashes.push(hash);
+                    <EvictedPinExpiries<T, I>>::insert(expiry, hashes);
+                    break 'expiries
+                }
+                consumed_weight = consumed_weight.saturating_add(expired_pin_weight);
+
+                // Pins extended after the eviction are indexed again under their later expiry.
+                if <EvictedPinnedHeaders<T, I>>::get(hash).map_or(false, |until| until < now) {
+                    <EvictedPinnedHeaders<T, I>>::remove(hash);
+                    Self::prune_header(hash, tombstones_to_keep);
+                    pruned = pruned.saturating_add(1);
+                }
+            }
+            next_expiry = Some(expiry.saturating_add(One::one()));
+        }
+        // Stop walking the expiries once there are no evicted headers left.
+        if next_expiry == Some(now) && <EvictedPinnedHeaders<T, I>>::iter_keys().next().is_none() {
+            next_expiry = None;
+        }
+        <NextPinExpiry<T, I>>::set(next_expiry);
+
+        if pruned > 0 {
+            Self::deposit_event(Event::HeadersPruned(pruned));
+        }
+
+        consumed_weight
+    }
+
+    /// Keep a pinned header which left the retention window, indexed by its pin expiry.
+    fn evict_pinned_header(hash: BridgedBlockHash<T, I>, until: BlockNumberFor<T>) {
+        <EvictedPinnedHeaders<T, I>>::insert(hash, until);
+        <EvictedPinExpiries<T, I>>::append(until, hash);
+        <NextPinExpiry<T, I>>::mutate(|next_expiry| {
+            *next_expiry = Some(next_expiry.map_or(until, |next_expiry| next_expiry.min(until)));
+        });
+    }
+
+    /// Remove the header and remember its hash in the pruned hashes queue. Forgets the oldest
+    /// pruned hashes beyond `tombstones_to_keep`, at most two per call to catch up on a shrunk window.
+    fn prune_header(hash: BridgedBlockHash<T, I>, tombstones_to_keep: u32) {
+        if let Some(header) = <ImportedHeaders<T, I>>::take(hash) {
+            let head = <PrunedHashesPointer<T, I>>::get();
+            <PrunedHashes<T, I>>::insert(head, hash);
+            <PrunedHeaders<T, I>>::insert(hash, *header.number());
+            let head = head.wrapping_add(1);
+            <PrunedHashesPointer<T, I>>::put(head);
+
+            let mut oldest = <OldestPrunedHashPointer<T, I>>::get();
+            for _ in 0..2 {
+                if head.wrapping_sub(oldest) <= tombstones_to_keep {
+                    break
+                }
+                if let Some(forgotten_hash) = <PrunedHashes<T, I>>::take(oldest) {
+                    <PrunedHeaders<T, I>>::remove(forgotten_hash);
+                }
+                oldest = oldest.wrapping_add(1);
+            }
+            <OldestPrunedHashPointer<T, I>>::put(oldest);
+        }
+    }
+
     /// Verify that the passed storage proof is valid, given it is crafted using
     /// known finalized header. If the proof is valid, then the `parse` callback
     /// is called and the function returns its result.
@@ -619,7 +885,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         storage_proof: sp_trie::StorageProof,
         parse: impl FnOnce(bp_runtime::StorageProofChecker<BridgedBlockHasher<T, I>>) -> R,
     ) -> Result<R, DispatchError> {
-        let header = <ImportedHeaders<T, I>>::get(hash).ok_or(Error::<T, I>::UnknownHeader)?;
+        let header = Self::get_imported_header(hash)?;
         let storage_proof_checker =
             bp_runtime::StorageProofChecker::new(*header.state_root(), storage_proof)
                 .map_err(|_| Error::<T, I>::StorageRootMismatch)?;
@@ -785,8 +1051,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 Decode::decode(&mut &*encoded_inclusion_proof)
                     .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
 
-            let header = <ImportedHeaders<T, I>>::get(proof.block_hash)
-                .ok_or(Error::<T, I>::UnknownHeader)?;
+            let header = Self::get_imported_header(proof.block_hash)?;
 
             (
                 proof.payload_proof,
@@ -976,8 +1241,7 @@ pub(crate) fn verify_header_storage_proof<T: Config<I>, I: 'static>(
     proof: StorageProof,
     parachain: ParachainRegistrationData,
 ) -> Result<BridgedHeader<T, I>, DispatchError> {
-    let relay_header =
-        <ImportedHeaders<T, I>>::get(relay_block_hash).ok_or(Error::<T, I>::UnknownHeader)?;
+    let relay_header = Pallet::<T, I>::get_imported_header(relay_block_hash)?;
 
     // partial StorageKey for Paras_Heads. We now need to append the parachain_id as LE-u32 to generate the parachains StorageKey
     // This is a bit unclean, but it makes no sense to hash the StorageKey for each exec
@@ -1129,6 +1393,13 @@ pub mod tests {
         Ok(data)
     }
 
+    fn prune_on_idle() {
+        use frame_support::traits::OnIdle;
+
+        let current_number = frame_system::Pallet::<TestRuntime>::block_number();
+        let _ = <Pallet<TestRuntime> as OnIdle<u32>>::on_idle(current_number, Weight::MAX);
+    }
+
     fn next_block() {
         use frame_support::traits::OnInitialize;
 
@@ -1460,6 +1731,34 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn only_root_submits_headers_directly() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let data = produce_mock_headers_range(1, 3);
+
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_headers(
+                    Origin::signed(1),
+                    data.range.clone(),
+                    data.signed_header.clone(),
+                    data.justification.clone(),
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Pallet::<TestRuntime>::submit_headers(
+                Origin::root(),
+                data.range,
+                data.signed_header.clone(),
+                data.justification,
+            ));
+            assert_eq!(
+                <BestFinalizedHash<TestRuntime>>::get(),
+                Some(data.signed_header.hash())
+            );
+        })
+    }
+
     #[test]
     fn reject_header_range_gap() {
         run_test(|| {
@@ -1862,6 +2161,7 @@ pub mod tests {
 
             //°°°°°°°°°°°ACHTUNG!!!°°°°°°°°°°°°
             assert_ok!(submit_headers(1, 5));
+            prune_on_idle();
             // MultiImportedHashes: [1, 2, 3, 4, 5] in MultiImportedHashes
             // Pointer               ^
             assert_eq!(
@@ -1870,6 +2170,7 @@ pub mod tests {
             );
             // contains added header
             assert_ok!(submit_headers(6, 7));
+            prune_on_idle();
             // [6, 7, 3, 4, 5]
             //        ^
 
@@ -1889,6 +2190,7 @@ pub mod tests {
             ); // overwritten by buffer
 
             assert_ok!(submit_headers(8, 10));
+            prune_on_idle();
             // [6, 7, 8, 9, 10]
             //  ^
 
@@ -1913,6 +2215,7 @@ pub mod tests {
             );
 
             assert_ok!(submit_headers(11, 15));
+            prune_on_idle();
             assert_eq!(
                 <ImportedHeaders<TestRuntime>>::contains_key(headers[10].hash(),),
                 false
@@ -1929,4 +2232,294 @@ pub mod tests {
             );
         })
     }
+
+    #[test]
+    fn should_not_prune_headers_before_on_idle() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(10);
+
+            assert_ok!(submit_headers(1, 10));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[1].hash()
+            ));
+
+            prune_on_idle();
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[5].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[6].hash()
+            ));
+            assert_eq!(<OldestImportedHashPointer<TestRuntime>>::get(), 5);
+        })
+    }
+
+    #[test]
+    fn should_prune_headers_over_configured_retention_window() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(10);
+
+            assert_noop!(
+                Pallet::<TestRuntime>::set_retention_window(Origin::root(), 0),
+                Error::<TestRuntime>::InvalidRetentionWindow
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::set_retention_window(Origin::signed(2), 2),
+                "Bad origin"
+            );
+            assert_ok!(Pallet::<TestRuntime>::set_retention_window(
+                Origin::root(),
+                2
+            ));
+            assert_eq!(Pallet::<TestRuntime>::retention_window(), 2);
+
+            assert_ok!(submit_headers(1, 5));
+            prune_on_idle();
+
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[3].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[4].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[5].hash()
+            ));
+        })
+    }
+
+    #[test]
+    fn should_keep_pinned_headers_until_pin_expires() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(10);
+            frame_system::Pallet::<TestRuntime>::set_block_number(1);
+
+            assert_ok!(submit_headers(1, 5));
+            assert_ok!(Pallet::<TestRuntime>::pin_header(
+                headers[2].hash().encode(),
+                3
+            ));
+
+            assert_ok!(submit_headers(6, 10));
+            prune_on_idle();
+
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[1].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[2].hash()
+            ));
+            assert_eq!(
+                <EvictedPinnedHeaders<TestRuntime>>::get(headers[2].hash()),
+                Some(3)
+            );
+
+            frame_system::Pallet::<TestRuntime>::set_block_number(4);
+            prune_on_idle();
+
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[2].hash()
+            ));
+            assert_eq!(
+                <EvictedPinnedHeaders<TestRuntime>>::get(headers[2].hash()),
+                None
+            );
+        })
+    }
+
+    #[test]
+    fn should_report_pruned_header_in_storage_proofs_and_pins() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(10);
+
+            assert_ok!(submit_headers(1, 10));
+            prune_on_idle();
+
+            assert_eq!(
+                <PrunedHeaders<TestRuntime>>::get(headers[1].hash()),
+                Some(1)
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::parse_finalized_storage_proof(
+                    headers[1].hash(),
+                    sp_trie::StorageProof::new(vec![]),
+                    |_| (),
+                ),
+                Error::<TestRuntime>::HeaderPruned
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(headers[1].hash().encode(), 100),
+                Error::<TestRuntime>::HeaderPruned
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(H256::repeat_byte(7).encode(), 100),
+                Error::<TestRuntime>::UnknownHeader
+            );
+        })
+    }
+
+    #[test]
+    fn should_prune_evicted_headers_in_pin_expiry_order() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(10);
+            frame_system::Pallet::<TestRuntime>::set_block_number(1);
+
+            assert_ok!(submit_headers(1, 5));
+            assert_ok!(Pallet::<TestRuntime>::pin_header(
+                headers[1].hash().encode(),
+                100
+            ));
+            assert_ok!(Pallet::<TestRuntime>::pin_header(
+                headers[2].hash().encode(),
+                3
+            ));
+            assert_ok!(Pallet::<TestRuntime>::pin_header(
+                headers[3].hash().encode(),
+                3
+            ));
+
+            assert_ok!(submit_headers(6, 10));
+            prune_on_idle();
+            assert_eq!(<NextPinExpiry<TestRuntime>>::get(), Some(3));
+
+            // Extending the pin of an evicted header indexes it again under its later expiry.
+            assert_ok!(Pallet::<TestRuntime>::pin_header(
+                headers[3].hash().encode(),
+                50
+            ));
+            assert_eq!(
+                <EvictedPinExpiries<TestRuntime>>::get(50),
+                vec![headers[3].hash()]
+            );
+
+            frame_system::Pallet::<TestRuntime>::set_block_number(4);
+            prune_on_idle();
+
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(headers[2].hash().encode(), 100),
+                Error::<TestRuntime>::HeaderPruned
+            );
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[1].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[3].hash()
+            ));
+            assert!(<EvictedPinExpiries<TestRuntime>>::get(3).is_empty());
+            assert_eq!(<NextPinExpiry<TestRuntime>>::get(), Some(4));
+
+            frame_system::Pallet::<TestRuntime>::set_block_number(51);
+            prune_on_idle();
+
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[3].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[1].hash()
+            ));
+            assert_eq!(<NextPinExpiry<TestRuntime>>::get(), Some(51));
+
+            frame_system::Pallet::<TestRuntime>::set_block_number(101);
+            prune_on_idle();
+
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[1].hash()
+            ));
+            assert_eq!(<NextPinExpiry<TestRuntime>>::get(), None);
+        })
+    }
+
+    #[test]
+    fn should_remember_pruned_headers_over_retention_window_larger_than_headers_to_store() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(15);
+            assert_ok!(Pallet::<TestRuntime>::set_retention_window(
+                Origin::root(),
+                7
+            ));
+
+            assert_ok!(submit_headers(1, 5));
+            prune_on_idle();
+            assert_ok!(submit_headers(6, 10));
+            prune_on_idle();
+            assert_ok!(submit_headers(11, 15));
+            prune_on_idle();
+
+            // Headers 1 to 8 were pruned, the tombstones of the latest 7 are remembered.
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(headers[2].hash().encode(), 100),
+                Error::<TestRuntime>::HeaderPruned
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(headers[8].hash().encode(), 100),
+                Error::<TestRuntime>::HeaderPruned
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(headers[1].hash().encode(), 100),
+                Error::<TestRuntime>::UnknownHeader
+            );
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[9].hash()
+            ));
+        })
+    }
+
+    #[test]
+    fn should_migrate_imported_hashes_ring_buffer_to_queue() {
+        use frame_support::traits::OnRuntimeUpgrade;
+
+        run_test(|| {
+            let headers = test_header_range(7);
+            // Ring buffer of 5 which wrapped around: headers 6 and 7 overwrote 1 and 2.
+            for (index, number) in [6usize, 7, 3, 4, 5].into_iter().enumerate() {
+                <ImportedHeaders<TestRuntime>>::insert(
+                    headers[number].hash(),
+                    headers[number].clone(),
+                );
+                <ImportedHashes<TestRuntime>>::insert(index as u32, headers[number].hash());
+            }
+            <ImportedHashesPointer<TestRuntime>>::put(2);
+
+            let _ = <Pallet<TestRuntime> as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+            for (index, number) in (3usize..=7).enumerate() {
+                assert_eq!(
+                    <ImportedHashes<TestRuntime>>::get(index as u32),
+                    Some(headers[number].hash())
+                );
+            }
+            assert_eq!(<ImportedHashesPointer<TestRuntime>>::get(), Some(5));
+            assert_eq!(<StorageMigrations<TestRuntime>>::get(), 1);
+
+            assert_ok!(Pallet::<TestRuntime>::set_retention_window(
+                Origin::root(),
+                2
+            ));
+            prune_on_idle();
+
+            assert_noop!(
+                Pallet::<TestRuntime>::pin_header(headers[5].hash().encode(), 100),
+                Error::<TestRuntime>::HeaderPruned
+            );
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[6].hash()
+            ));
+            assert!(<ImportedHeaders<TestRuntime>>::contains_key(
+                headers[7].hash()
+            ));
+
+            // Already migrated, nothing is moved again.
+            assert_eq!(
+                <Pallet<TestRuntime> as OnRuntimeUpgrade>::on_runtime_upgrade(),
+                <TestRuntime as frame_system::Config>::DbWeight::get().reads(1)
+            );
+            assert_eq!(<OldestImportedHashPointer<TestRuntime>>::get(), 3);
+        })
+    }
 }
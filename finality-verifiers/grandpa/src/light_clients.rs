@@ -287,6 +287,19 @@ where
             PalletInstance::Phantom(_) => unreachable!("Phantom variant should not be used"),
         }
     }
+
+    fn pin_header(
+        &self,
+        including_header: Bytes,
+        until: BlockNumberFor<T>,
+    ) -> Result<(), DispatchError> {
+        match self {
+            PalletInstance::Rococo(pallet) => pallet.pin_header(including_header, until),
+            PalletInstance::Kusama(pallet) => pallet.pin_header(including_header, until),
+            PalletInstance::Polkadot(pallet) => pallet.pin_header(including_header, until),
+            PalletInstance::Phantom(_) => unreachable!("Phantom variant should not be used"),
+        }
+    }
 }
 
 impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
@@ -444,6 +457,14 @@ impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
     ) -> Result<Bytes, DispatchError> {
         Err("GrandpaFV::verify_tx_inclusion not implemented yet".into())
     }
+
+    fn pin_header(
+        &self,
+        including_header: Bytes,
+        until: BlockNumberFor<T>,
+    ) -> Result<(), DispatchError> {
+        Pallet::<T, I>::pin_header(including_header, until)
+    }
 }
 
 #[cfg(all(feature = "testing"))]
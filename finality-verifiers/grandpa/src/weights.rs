@@ -62,6 +62,7 @@ pub trait WeightInfo {
     fn submit_finality_proof_on_many_forks(p: u32) -> Weight;
     fn find_scheduled_change(n: u32) -> Weight;
     fn read_write_authority_sets(n: u32) -> Weight;
+    fn set_retention_window() -> Weight;
 }
 
 /// Weights for pallet_bridge_grandpa using the Rialto node and recommended hardware.
@@ -106,6 +107,16 @@ impl<T: frame_system::Config> WeightInfo for GatewayWeight<T> {
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    // Estimated, not benchmarked: the pallet has no benchmarks yet.
+    // Storage: GRANDPA PalletOwner (r:1 w:0)
+    // Storage: GRANDPA RetentionWindow (r:0 w:1)
+    // Storage: System Events (r:0 w:1)
+    fn set_retention_window() -> Weight {
+        Weight::from_parts(14_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
 }
 
 // For backwards compatibility and tests
@@ -149,4 +160,14 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    // Estimated, not benchmarked: the pallet has no benchmarks yet.
+    // Storage: GRANDPA PalletOwner (r:1 w:0)
+    // Storage: GRANDPA RetentionWindow (r:0 w:1)
+    // Storage: System Events (r:0 w:1)
+    fn set_retention_window() -> Weight {
+        Weight::from_parts(14_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
 }
@@ -0,0 +1,105 @@
+//! Benchmarking setup for pallet-standalone-finality-verifier
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as StandaloneFV;
+
+use frame_benchmarking::{account, benchmarks_instance_pallet};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{Digest, DigestItem};
+use sp_std::vec;
+
+const BENCHMARK_GATEWAY_ID: ChainId = *b"bnch";
+const AURA_KEY_TYPE: KeyTypeId = KeyTypeId(AURA_ENGINE_ID);
+/// Upper bound of the benchmarked range length, capped by `HeadersToStore`.
+const MAX_RANGE_LEN: u32 = 100;
+
+fn genesis_header() -> StandaloneHeader {
+    StandaloneHeader::new(
+        0,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+}
+
+/// Initialize the light client with a single Aura authority generated in the keystore.
+fn initialize_aura<T: Config<I>, I: 'static>() -> (AuthorityId, StandaloneHeader) {
+    let authority = sp_io::crypto::sr25519_generate(AURA_KEY_TYPE, None);
+    let genesis = genesis_header();
+    let registration_data = StandaloneRegistrationData::<T::AccountId> {
+        first_header: genesis.encode(),
+        engine: SealEngine::Aura,
+        authorities: vec![authority],
+        authority_set_id: 0,
+        confirmation_depths: Default::default(),
+        owner: account("owner", 0, 0),
+    };
+    StandaloneFV::<T, I>::initialize(
+        RawOrigin::Root.into(),
+        BENCHMARK_GATEWAY_ID,
+        registration_data.encode(),
+    )
+    .expect("initializes the light client");
+    (authority, genesis)
+}
+
+/// Build `count` headers on top of `parent`, each sealed by the single Aura authority.
+fn aura_chain(
+    authority: &AuthorityId,
+    parent: &StandaloneHeader,
+    count: u32,
+) -> Vec<StandaloneHeader> {
+    let mut parent = parent.clone();
+    (1..=count as u64)
+        .map(|slot| {
+            let mut header = StandaloneHeader::new(
+                parent.number + 1,
+                Default::default(),
+                Default::default(),
+                parent.hash(),
+                Digest {
+                    logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())],
+                },
+            );
+            let signature =
+                sp_io::crypto::sr25519_sign(AURA_KEY_TYPE, authority, header.hash().as_ref())
+                    .expect("signs with the generated authority");
+            header
+                .digest_mut()
+                .push(DigestItem::Seal(AURA_ENGINE_ID, signature.encode()));
+            parent = header.clone();
+            header
+        })
+        .collect()
+}
+
+benchmarks_instance_pallet! {
+    submit_headers {
+        let n in 1 .. MAX_RANGE_LEN.min(T::HeadersToStore::get());
+        let (authority, genesis) = initialize_aura::<T, I>();
+        let range = aura_chain(&authority, &genesis, n);
+        let caller: T::AccountId = account("relayer", 0, 0);
+    }: _(RawOrigin::Signed(caller), range)
+    verify {
+        assert_eq!(<BestNumber<T, I>>::get(), n);
+    }
+
+    set_confirmation_depths {
+        initialize_aura::<T, I>();
+    }: _(RawOrigin::Root, 1, 2)
+    verify {
+        assert_eq!(<Depths<T, I>>::get().finalized, 2);
+    }
+
+    reset {
+        let h in 1 .. MAX_RANGE_LEN.min(T::HeadersToStore::get());
+        let (authority, genesis) = initialize_aura::<T, I>();
+        StandaloneFV::<T, I>::verify_and_store_headers(aura_chain(&authority, &genesis, h))?;
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(!<EverInitialized<T, I>>::get());
+        assert_eq!(<ImportedHeaders<T, I>>::iter().count(), 0);
+    }
+}
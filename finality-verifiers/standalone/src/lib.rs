@@ -0,0 +1,769 @@
+//! Standalone Finality Verifier Pallet
+//!
+//! This pallet is an on-chain light client for standalone Substrate chains which produce blocks
+//! with Aura, but don't run GRANDPA. Without finality proofs to verify, every submitted header is
+//! checked to be sealed by the expected block author of the registered authority set, and to
+//! extend the best known header.
+//!
+//! BABE chains aren't supported. The author of a BABE primary slot is only established by its VRF
+//! output, and without verifying it any authority could seal headers for any slot.
+//!
+//! Finality is approximated with confirmation depths: a header is considered confirmed for
+//! `SpeedMode::Rational` or `SpeedMode::Finalized` once the configured number of headers have
+//! been imported on top of it. `SpeedMode::Fast` and `SpeedMode::Instant` accept the best header.
+//!
+//! The pallet tracks authority set hand-offs announced by Aura `AuthoritiesChange` logs, which are
+//! enacted right after the announcing header.
+//!
+//! Inclusion of events, storage entries and extrinsics is proven against the state and extrinsics
+//! roots of confirmed headers.
+//!
+//! An instance follows a single standalone chain, registered as its gateway at initialization.
+//! Following several standalone chains requires one pallet instance per chain.
+//!
+//! Since only seals are verified, the pallet doesn't deal with forks. Shall the authorities of
+//! the standalone chain equivocate, governance intervention is required to re-initialize the
+//! light client on the right fork.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// Runtime-generated enums
+#![allow(clippy::large_enum_variant)]
+
+// Re-export in crate namespace for `construct_runtime!`
+pub use pallet::*;
+
+use codec::{Compact, Decode, Encode};
+use frame_support::{ensure, pallet_prelude::*, transactional};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use sp_core::{sr25519, H256};
+use sp_runtime::{
+    generic::OpaqueDigestItemId,
+    traits::{BadOrigin, BlakeTwo256, Header as HeaderT, One, Saturating},
+    DigestItem,
+};
+use sp_std::vec::Vec;
+use sp_trie::{read_trie_value, LayoutV0, LayoutV1, StorageProof};
+use t3rn_primitives::{
+    light_client::{InclusionReceipt, LightClient, LightClientAsyncAPI},
+    ExecutionSource, GatewayVendor, SpeedMode,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod light_clients;
+pub mod types;
+pub mod weights;
+
+use crate::{
+    types::{
+        AuraConsensusLog, AuthorityId, ChainId, ConfirmationDepths, SealEngine, StandaloneHeader,
+        StandaloneHeaderData, StandaloneInclusionProof, StandaloneRegistrationData,
+        StandaloneStateInclusionProof, StandaloneTxInclusionProof, AURA_ENGINE_ID,
+    },
+    weights::WeightInfo,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config<I: 'static = ()>: frame_system::Config {
+        /// Maximal number of headers to keep in the storage.
+        ///
+        /// Confirmation depths have to stay below this bound, so that confirmed headers
+        /// are still available for inclusion proofs.
+        #[pallet::constant]
+        type HeadersToStore: Get<u32>;
+
+        type LightClientAsyncAPI: LightClientAsyncAPI<Self>;
+
+        type MyVendor: Get<GatewayVendor>;
+
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config<I>, I: 'static = ()> {
+        /// Headers were imported on top of the best header. [best_number]
+        HeadersAdded(u32),
+        /// A new authority set became active. [authority_set_id, authorities_count]
+        AuthoritySetChanged(u64, u32),
+        /// The confirmation depths were updated. [rational, finalized]
+        ConfirmationDepthsSet(u32, u32),
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T, I = ()>(pub PhantomData<(T, I)>);
+
+    #[pallet::error]
+    pub enum Error<T, I = ()> {
+        /// The submitted range is empty
+        EmptyRangeSubmitted,
+        /// The submitted range is larger the HeadersToStore, which is not permitted
+        RangeToLarge,
+        /// The light client hasn't been initialized yet
+        NoBestHeader,
+        /// The header range doesn't extend the best header
+        InvalidRangeLinkage,
+        /// The header carries no seal of the registered engine
+        MissingSeal,
+        /// The header carries no pre-runtime digest of the registered engine
+        MissingPreDigest,
+        /// The header was sealed by someone else than the expected author
+        InvalidSeal,
+        /// The expected author isn't part of the authority set
+        UnknownAuthority,
+        /// The authority set is empty
+        InvalidAuthoritySet,
+        /// The header's slot doesn't advance over its parent
+        SlotNotIncreasing,
+        /// The confirmation depths are out of bounds
+        InvalidConfirmationDepths,
+        /// The pallet is currently halted
+        Halted,
+        /// The given bytes couldn't be decoded as header data
+        HeaderDataDecodingError,
+        /// The inclusion data couldn't be decoded
+        InclusionDataDecodeError,
+        /// The header couldn't be found in storage
+        UnknownHeader,
+        /// The header isn't deep enough for the requested speed mode
+        HeaderNotConfirmed,
+        /// The submitted storage proof is invalid
+        InvalidStorageProof,
+        /// The event was not found in the specified block
+        EventNotIncluded,
+        /// The gateway isn't the one registered with this light client
+        UnknownGateway,
+        /// The extrinsic was not found in the specified block
+        TxNotIncluded,
+        /// The event is shorter than its expected source
+        UnexpectedEventLength,
+        /// The event wasn't emitted by the expected source
+        UnexpectedSource,
+    }
+
+    /// Id of the gateway registered with this light client.
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_id)]
+    pub(super) type GatewayId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, ChainId, OptionQuery>;
+
+    /// Block production engine sealing the headers.
+    #[pallet::storage]
+    #[pallet::getter(fn seal_engine)]
+    pub(super) type Engine<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, SealEngine, OptionQuery>;
+
+    /// Authorities expected to seal the next headers.
+    #[pallet::storage]
+    #[pallet::getter(fn authorities)]
+    pub(super) type Authorities<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, Vec<AuthorityId>, ValueQuery>;
+
+    /// Id of the active authority set, incremented on every hand-off.
+    #[pallet::storage]
+    #[pallet::getter(fn authority_set_id)]
+    pub(super) type AuthoritySetId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u64, ValueQuery>;
+
+    /// Slot of the best header.
+    #[pallet::storage]
+    #[pallet::getter(fn last_slot)]
+    pub(super) type LastSlot<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+    /// Hash of the best header.
+    #[pallet::storage]
+    #[pallet::getter(fn get_best_block_hash)]
+    pub(super) type BestHash<T: Config<I>, I: 'static = ()> = StorageValue<_, H256, OptionQuery>;
+
+    /// Number of the best header.
+    #[pallet::storage]
+    #[pallet::getter(fn best_number)]
+    pub(super) type BestNumber<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of the oldest header still kept in storage.
+    #[pallet::storage]
+    pub(super) type OldestImportedNumber<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
+    /// Imported headers by number.
+    #[pallet::storage]
+    #[pallet::getter(fn canonical_hash)]
+    pub(super) type CanonicalHashes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, u32, H256>;
+
+    /// Imported headers by hash.
+    #[pallet::storage]
+    pub(super) type ImportedHeaders<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, H256, StandaloneHeader>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn confirmation_depths)]
+    pub(super) type Depths<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, ConfirmationDepths, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn submissions_counter)]
+    pub(super) type SubmissionsCounter<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Optional pallet owner.
+    ///
+    /// Pallet owner has a right to halt all pallet operations and then resume them. If it is
+    /// `None`, then there are no direct ways to halt/resume pallet operations, but other
+    /// runtime methods may still be used to do that (i.e. democracy::referendum to update halt
+    /// flag directly or call the `halt_operations`).
+    #[pallet::storage]
+    #[pallet::getter(fn get_pallet_owner)]
+    pub(super) type PalletOwner<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// If true, all pallet transactions are failed immediately.
+    #[pallet::storage]
+    #[pallet::getter(fn is_halted)]
+    pub(super) type IsHalted<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+    /// If true, the light client has been initialized at least once.
+    #[pallet::storage]
+    #[pallet::getter(fn ever_initialized)]
+    pub(super) type EverInitialized<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Add a header range extending the best known header.
+        ///
+        /// Every header must be sealed by its expected author of the active authority set.
+        ///
+        /// Submitters pay for the seal verifications. Relayers registered with the Portal
+        /// submit their ranges through it instead.
+        #[pallet::weight(T::WeightInfo::submit_headers(range.len() as u32))]
+        pub fn submit_headers(
+            origin: OriginFor<T>,
+            // seq vector of headers to be added.
+            range: Vec<StandaloneHeader>,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            Pallet::<T, I>::verify_and_store_headers(range)?;
+            Ok(().into())
+        }
+
+        /// Set the confirmation depths required by `SpeedMode::Rational` and
+        /// `SpeedMode::Finalized`.
+        ///
+        /// May only be called either by root, or by `PalletOwner`.
+        #[pallet::weight(T::WeightInfo::set_confirmation_depths())]
+        pub fn set_confirmation_depths(
+            origin: OriginFor<T>,
+            rational: u32,
+            finalized: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_owner_or_root_single::<T, I>(origin)?;
+            let depths = ConfirmationDepths {
+                rational,
+                finalized,
+            };
+            ensure_valid_depths::<T, I>(&depths)?;
+            <Depths<T, I>>::put(depths);
+            Self::deposit_event(Event::ConfirmationDepthsSet(rational, finalized));
+            Ok(().into())
+        }
+
+        #[pallet::weight(T::WeightInfo::reset(T::HeadersToStore::get()))]
+        pub fn reset(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            <EverInitialized<T, I>>::kill();
+            <GatewayId<T, I>>::kill();
+            <Engine<T, I>>::kill();
+            <Authorities<T, I>>::kill();
+            <AuthoritySetId<T, I>>::kill();
+            <LastSlot<T, I>>::kill();
+            <BestHash<T, I>>::kill();
+            <BestNumber<T, I>>::kill();
+            <OldestImportedNumber<T, I>>::kill();
+            for _ in <CanonicalHashes<T, I>>::drain() {}
+            for _ in <ImportedHeaders<T, I>>::drain() {}
+            <Depths<T, I>>::kill();
+            <IsHalted<T, I>>::kill();
+            <PalletOwner<T, I>>::kill();
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    #[transactional]
+    pub(crate) fn verify_and_store_headers(range: Vec<StandaloneHeader>) -> DispatchResult {
+        ensure_operational_single::<T, I>()?;
+        ensure!(!range.is_empty(), Error::<T, I>::EmptyRangeSubmitted);
+        ensure!(
+            range.len() as u32 <= T::HeadersToStore::get(),
+            Error::<T, I>::RangeToLarge
+        );
+
+        let mut best_hash = <BestHash<T, I>>::get().ok_or(Error::<T, I>::NoBestHeader)?;
+        let mut best_number = <BestNumber<T, I>>::get();
+
+        for header in range {
+            ensure!(
+                header.parent_hash == best_hash && header.number == best_number.saturating_add(1),
+                Error::<T, I>::InvalidRangeLinkage
+            );
+            best_hash = Self::import_header(header)?;
+            best_number = best_number.saturating_add(1);
+        }
+
+        Self::deposit_event(Event::HeadersAdded(best_number));
+
+        let counter = <SubmissionsCounter<T, I>>::get();
+        match Pallet::<T, I>(PhantomData).get_latest_heartbeat() {
+            Ok(heartbeat) =>
+                T::LightClientAsyncAPI::on_new_epoch(T::MyVendor::get(), counter, heartbeat),
+            Err(e) => log::error!(
+                "Failed to get latest heartbeat after submit_headers: {:?}",
+                e
+            ),
+        }
+        <SubmissionsCounter<T, I>>::put(counter.saturating_add(BlockNumberFor::<T>::one()));
+
+        Ok(())
+    }
+
+    /// Verify the seal of a header extending the best header, enact the authority set changes
+    /// it carries and store it as the new best header.
+    fn import_header(header: StandaloneHeader) -> Result<H256, DispatchError> {
+        let engine = <Engine<T, I>>::get().ok_or(Error::<T, I>::NoBestHeader)?;
+        let slot = verify_seal::<T, I>(&header, engine, &<Authorities<T, I>>::get())?;
+        ensure!(
+            slot > <LastSlot<T, I>>::get(),
+            Error::<T, I>::SlotNotIncreasing
+        );
+        <LastSlot<T, I>>::put(slot);
+
+        if let Some(authorities) = find_aura_authorities_change(&header) {
+            ensure!(!authorities.is_empty(), Error::<T, I>::InvalidAuthoritySet);
+            Self::enact_authority_set(authorities);
+        }
+
+        let hash = header.hash();
+        let number = header.number;
+        <CanonicalHashes<T, I>>::insert(number, hash);
+        <ImportedHeaders<T, I>>::insert(hash, header);
+        <BestHash<T, I>>::put(hash);
+        <BestNumber<T, I>>::put(number);
+
+        // Each import adds a single header, so at most a single one falls out of storage
+        let oldest = <OldestImportedNumber<T, I>>::get();
+        if number.saturating_sub(oldest) >= T::HeadersToStore::get() {
+            if let Some(pruned_hash) = <CanonicalHashes<T, I>>::take(oldest) {
+                <ImportedHeaders<T, I>>::remove(pruned_hash);
+            }
+            <OldestImportedNumber<T, I>>::put(oldest.saturating_add(1));
+        }
+
+        Ok(hash)
+    }
+
+    fn enact_authority_set(authorities: Vec<AuthorityId>) {
+        let set_id = <AuthoritySetId<T, I>>::get().saturating_add(1);
+        let authorities_count = authorities.len() as u32;
+        <Authorities<T, I>>::put(authorities);
+        <AuthoritySetId<T, I>>::put(set_id);
+        Self::deposit_event(Event::AuthoritySetChanged(set_id, authorities_count));
+    }
+
+    /// Returns the height of the best header confirmed for the given speed mode.
+    pub fn confirmed_height(speed_mode: &SpeedMode) -> u32 {
+        let best_number = <BestNumber<T, I>>::get();
+        let depths = <Depths<T, I>>::get();
+        match speed_mode {
+            SpeedMode::Fast | SpeedMode::Instant => best_number,
+            SpeedMode::Rational => best_number.saturating_sub(depths.rational),
+            SpeedMode::Finalized => best_number.saturating_sub(depths.finalized),
+        }
+    }
+
+    pub fn get_imported_header(hash: H256) -> Option<StandaloneHeader> {
+        <ImportedHeaders<T, I>>::get(hash)
+    }
+
+    pub fn initialize(
+        origin: T::RuntimeOrigin,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), &'static str> {
+        ensure_owner_or_root_single::<T, I>(origin)?;
+        ensure!(
+            !<BestHash<T, I>>::exists(),
+            "StandaloneFV::initialize -- chain_id already initialized"
+        );
+
+        let registration_data: StandaloneRegistrationData<T::AccountId> =
+            Decode::decode(&mut &*encoded_registration_data).map_err(|_| "Decoding Error")?;
+        let header: StandaloneHeader = Decode::decode(&mut &registration_data.first_header[..])
+            .map_err(|_| "header decoding error")?;
+
+        ensure!(
+            !registration_data.authorities.is_empty(),
+            Error::<T, I>::InvalidAuthoritySet
+        );
+        ensure_valid_depths::<T, I>(&registration_data.confirmation_depths)?;
+
+        // Genesis carries no pre-runtime digest, all other headers announce their slot
+        let slot = if header.number == 0 {
+            0
+        } else {
+            find_author_and_slot::<T, I>(
+                &header,
+                registration_data.engine,
+                registration_data.authorities.len(),
+            )
+            .map(|(_, slot)| slot)?
+        };
+
+        let hash = header.hash();
+        let number = header.number;
+        <CanonicalHashes<T, I>>::insert(number, hash);
+        <ImportedHeaders<T, I>>::insert(hash, header);
+        <BestHash<T, I>>::put(hash);
+        <BestNumber<T, I>>::put(number);
+        <OldestImportedNumber<T, I>>::put(number);
+        <LastSlot<T, I>>::put(slot);
+
+        <GatewayId<T, I>>::put(gateway_id);
+        <Engine<T, I>>::put(registration_data.engine);
+        <Authorities<T, I>>::put(registration_data.authorities);
+        <AuthoritySetId<T, I>>::put(registration_data.authority_set_id);
+        <Depths<T, I>>::put(registration_data.confirmation_depths);
+        <PalletOwner<T, I>>::put(registration_data.owner);
+        <IsHalted<T, I>>::put(false);
+        <EverInitialized<T, I>>::put(true);
+
+        Ok(())
+    }
+
+    /// Halt or resume all pallet operations.
+    ///
+    /// May only be called either by root, or by `PalletOwner`.
+    pub fn set_operational(origin: OriginFor<T>, operational: bool) -> Result<(), &'static str> {
+        ensure_owner_or_root_single::<T, I>(origin)?;
+        <IsHalted<T, I>>::put(!operational); // inverted because operational vs halted are opposite
+        Ok(())
+    }
+
+    pub fn submit_encoded_headers(encoded_header_data: Vec<u8>) -> Result<(), DispatchError> {
+        let data: StandaloneHeaderData = Decode::decode(&mut &*encoded_header_data)
+            .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
+
+        Pallet::<T, I>::verify_and_store_headers(data.range)
+    }
+
+    /// Ensure the gateway is the one this light client was initialized for, so proofs of
+    /// one standalone chain can't confirm the inclusion on another.
+    pub fn ensure_registered_gateway(gateway_id: ChainId) -> Result<(), DispatchError> {
+        ensure!(
+            <GatewayId<T, I>>::get() == Some(gateway_id),
+            Error::<T, I>::UnknownGateway
+        );
+        Ok(())
+    }
+
+    /// Returns the imported header, ensuring it's deep enough for the given speed mode.
+    fn confirmed_header(
+        block_hash: H256,
+        speed_mode: &SpeedMode,
+    ) -> Result<StandaloneHeader, DispatchError> {
+        let header =
+            <ImportedHeaders<T, I>>::get(block_hash).ok_or(Error::<T, I>::UnknownHeader)?;
+        ensure!(
+            header.number <= Self::confirmed_height(speed_mode),
+            Error::<T, I>::HeaderNotConfirmed
+        );
+        Ok(header)
+    }
+
+    pub fn confirm_event_inclusion(
+        gateway_id: ChainId,
+        speed_mode: SpeedMode,
+        maybe_source: Option<ExecutionSource>,
+        encoded_inclusion_proof: Vec<u8>,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::ensure_registered_gateway(gateway_id)?;
+
+        let proof: StandaloneInclusionProof = Decode::decode(&mut &*encoded_inclusion_proof)
+            .map_err(|_| Error::<T, I>::InclusionDataDecodeError)?;
+        let header = Self::confirmed_header(proof.block_hash, &speed_mode)?;
+
+        let message = verify_event_storage_proof::<T, I>(
+            proof.payload_proof,
+            &header,
+            proof.encoded_payload,
+        )?;
+
+        if let Some(source) = maybe_source {
+            check_vm_source::<T, I>(source, &message)?;
+        }
+
+        Ok(InclusionReceipt::<BlockNumberFor<T>> {
+            height: header.number.into(),
+            including_header: proof.block_hash.encode(),
+            message,
+        })
+    }
+
+    /// Confirm a storage entry of the standalone chain. The receipt's message is the proven
+    /// value stored under the key.
+    pub fn confirm_state_inclusion(
+        gateway_id: ChainId,
+        speed_mode: SpeedMode,
+        encoded_inclusion_proof: Vec<u8>,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::ensure_registered_gateway(gateway_id)?;
+
+        let proof: StandaloneStateInclusionProof =
+            Decode::decode(&mut &*encoded_inclusion_proof)
+                .map_err(|_| Error::<T, I>::InclusionDataDecodeError)?;
+        let header = Self::confirmed_header(proof.block_hash, &speed_mode)?;
+
+        let db = proof.storage_proof.into_memory_db::<BlakeTwo256>();
+        let message = match read_trie_value::<LayoutV1<BlakeTwo256>, _>(
+            &db,
+            header.state_root(),
+            proof.storage_key.as_ref(),
+            None,
+            None,
+        ) {
+            Ok(Some(value)) => value,
+            _ => return Err(Error::<T, I>::InvalidStorageProof.into()),
+        };
+
+        Ok(InclusionReceipt::<BlockNumberFor<T>> {
+            height: header.number.into(),
+            including_header: proof.block_hash.encode(),
+            message,
+        })
+    }
+
+    /// Confirm an extrinsic of the standalone chain. The receipt's message is the encoded
+    /// extrinsic.
+    pub fn confirm_tx_inclusion(
+        gateway_id: ChainId,
+        speed_mode: SpeedMode,
+        encoded_inclusion_proof: Vec<u8>,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::ensure_registered_gateway(gateway_id)?;
+
+        let proof: StandaloneTxInclusionProof = Decode::decode(&mut &*encoded_inclusion_proof)
+            .map_err(|_| Error::<T, I>::InclusionDataDecodeError)?;
+        let header = Self::confirmed_header(proof.block_hash, &speed_mode)?;
+
+        // The extrinsics root is the ordered trie root of the block's extrinsics, keyed by their
+        // compact encoded indexes and always built with the V0 state version.
+        let db = proof.tx_proof.into_memory_db::<BlakeTwo256>();
+        let included_tx = match read_trie_value::<LayoutV0<BlakeTwo256>, _>(
+            &db,
+            header.extrinsics_root(),
+            Compact(proof.tx_index).encode().as_ref(),
+            None,
+            None,
+        ) {
+            Ok(Some(value)) => value,
+            _ => return Err(Error::<T, I>::InvalidStorageProof.into()),
+        };
+        ensure!(
+            included_tx == proof.encoded_tx,
+            Error::<T, I>::TxNotIncluded
+        );
+
+        Ok(InclusionReceipt::<BlockNumberFor<T>> {
+            height: header.number.into(),
+            including_header: proof.block_hash.encode(),
+            message: proof.encoded_tx,
+        })
+    }
+}
+
+/// Find the expected author of the header and the slot it was authored at, as announced by the
+/// engine's pre-runtime digest.
+pub(crate) fn find_author_and_slot<T: Config<I>, I: 'static>(
+    header: &StandaloneHeader,
+    engine: SealEngine,
+    authorities_count: usize,
+) -> Result<(usize, u64), DispatchError> {
+    ensure!(authorities_count > 0, Error::<T, I>::InvalidAuthoritySet);
+    let engine_id = engine.engine_id();
+    let pre_digest = header
+        .digest()
+        .logs()
+        .iter()
+        .find_map(|log| match log {
+            DigestItem::PreRuntime(id, data) if *id == engine_id => Some(data.as_slice()),
+            _ => None,
+        })
+        .ok_or(Error::<T, I>::MissingPreDigest)?;
+
+    match engine {
+        // Aura authors take turns in slot order
+        SealEngine::Aura => {
+            let slot =
+                u64::decode(&mut &*pre_digest).map_err(|_| Error::<T, I>::MissingPreDigest)?;
+            Ok(((slot % authorities_count as u64) as usize, slot))
+        },
+    }
+}
+
+/// Verify the header is sealed by its expected author. Returns the slot of the header.
+pub(crate) fn verify_seal<T: Config<I>, I: 'static>(
+    header: &StandaloneHeader,
+    engine: SealEngine,
+    authorities: &[AuthorityId],
+) -> Result<u64, DispatchError> {
+    // The seal is the last digest item and isn't part of the signed pre-hash
+    let mut unsealed = header.clone();
+    let seal = match unsealed.digest.pop() {
+        Some(DigestItem::Seal(id, seal)) if id == engine.engine_id() => seal,
+        _ => return Err(Error::<T, I>::MissingSeal.into()),
+    };
+    let signature =
+        sr25519::Signature::decode(&mut &*seal).map_err(|_| Error::<T, I>::InvalidSeal)?;
+
+    let (author_index, slot) = find_author_and_slot::<T, I>(&unsealed, engine, authorities.len())?;
+    let author = authorities
+        .get(author_index)
+        .ok_or(Error::<T, I>::UnknownAuthority)?;
+
+    ensure!(
+        sp_io::crypto::sr25519_verify(&signature, unsealed.hash().as_ref(), author),
+        Error::<T, I>::InvalidSeal
+    );
+
+    Ok(slot)
+}
+
+pub(crate) fn find_aura_authorities_change(header: &StandaloneHeader) -> Option<Vec<AuthorityId>> {
+    let id = OpaqueDigestItemId::Consensus(&AURA_ENGINE_ID);
+
+    let filter_log = |log: AuraConsensusLog| match log {
+        AuraConsensusLog::AuthoritiesChange(authorities) => Some(authorities),
+        _ => None,
+    };
+
+    header
+        .digest()
+        .convert_first(|l| l.try_to(id).and_then(filter_log))
+}
+
+pub(crate) fn verify_event_storage_proof<T: Config<I>, I: 'static>(
+    storage_proof: StorageProof,
+    header: &StandaloneHeader,
+    encoded_payload: Vec<u8>,
+) -> Result<Vec<u8>, DispatchError> {
+    // storage key for System_Events
+    let key: Vec<u8> = [
+        38, 170, 57, 78, 234, 86, 48, 224, 124, 72, 174, 12, 149, 88, 206, 247, 128, 212, 30, 94,
+        22, 5, 103, 101, 188, 132, 97, 133, 16, 114, 201, 215,
+    ]
+    .to_vec();
+    let db = storage_proof.into_memory_db::<BlakeTwo256>();
+    let verified_block_events = match read_trie_value::<LayoutV1<BlakeTwo256>, _>(
+        &db,
+        header.state_root(),
+        key.as_ref(),
+        None,
+        None,
+    ) {
+        Ok(Some(value)) => value,
+        _ => return Err(Error::<T, I>::InvalidStorageProof.into()),
+    };
+
+    // in substrates current design its not possible to prove the inclusion of a single event, only all events of a block
+    ensure!(
+        is_sub(verified_block_events.as_slice(), encoded_payload.as_slice()),
+        Error::<T, I>::EventNotIncluded
+    );
+
+    Ok(encoded_payload)
+}
+
+/// Ensure the event was emitted by the given source, following the layout conventions of the
+/// GRANDPA light client:
+/// - source preceded with 12 bytes of 0x00 is an EVM address, emitted as the first field of the
+///   EVM::Log event (index=0)
+/// - otherwise it's a WASM contract, emitted as the first field of the
+///   Contracts::ContractEmitted event (index=3)
+pub(crate) fn check_vm_source<T: Config<I>, I: 'static>(
+    source: ExecutionSource,
+    message: &[u8],
+) -> Result<(), DispatchError> {
+    // The first 2 bytes of the message are the pallet and event indexes on the target
+    let (event_index, source_bytes) = if source[0..12] == [0u8; 12] {
+        (0u8, &source[12..])
+    } else {
+        (3u8, &source[..])
+    };
+    ensure!(
+        message.len() >= 2 + source_bytes.len(),
+        Error::<T, I>::UnexpectedEventLength
+    );
+    ensure!(
+        message[1] == event_index && &message[2..2 + source_bytes.len()] == source_bytes,
+        Error::<T, I>::UnexpectedSource
+    );
+    Ok(())
+}
+
+pub(crate) fn is_sub<T: PartialEq>(mut haystack: &[T], needle: &[T]) -> bool {
+    while !haystack.is_empty() {
+        if haystack.starts_with(needle) {
+            return true
+        }
+        haystack = &haystack[1..];
+    }
+    false
+}
+
+/// Ensure the confirmation depths are ordered and leave confirmed headers in storage.
+fn ensure_valid_depths<T: Config<I>, I: 'static>(
+    depths: &ConfirmationDepths,
+) -> Result<(), Error<T, I>> {
+    ensure!(
+        depths.rational <= depths.finalized && depths.finalized < T::HeadersToStore::get(),
+        Error::<T, I>::InvalidConfirmationDepths
+    );
+    Ok(())
+}
+
+/// Ensure that the pallet is in operational mode (not halted).
+pub fn ensure_operational_single<T: Config<I>, I: 'static>() -> Result<(), Error<T, I>> {
+    if <IsHalted<T, I>>::get() {
+        Err(<Error<T, I>>::Halted)
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensure that the origin is either root, or `PalletOwner`.
+fn ensure_owner_or_root_single<T: Config<I>, I: 'static>(
+    origin: OriginFor<T>,
+) -> Result<(), &'static str> {
+    match origin.into() {
+        Ok(RawOrigin::Root) => Ok(()),
+        Ok(RawOrigin::Signed(ref signer))
+            if <PalletOwner<T, I>>::exists()
+                && Some(signer) == <PalletOwner<T, I>>::get().as_ref() =>
+            Ok(()),
+        _ => Err(BadOrigin.into()),
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{Config, Pallet};
+use codec::Encode;
+
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use sp_runtime::DispatchError;
+use sp_std::vec;
+use t3rn_abi::types::Bytes;
+pub use t3rn_primitives::light_client::{LightClient, LightClientHeartbeat};
+use t3rn_primitives::{
+    execution_source_to_option,
+    light_client::{HeaderResult, HeightResult, InclusionReceipt},
+    ExecutionSource, SpeedMode,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    fn height_for(speed_mode: &SpeedMode) -> HeightResult<BlockNumberFor<T>> {
+        if !Pallet::<T, I>::ever_initialized() {
+            return HeightResult::NotActive
+        }
+        HeightResult::Height(Pallet::<T, I>::confirmed_height(speed_mode).into())
+    }
+}
+
+impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
+    fn get_latest_finalized_header(&self) -> HeaderResult {
+        if !Pallet::<T, I>::ever_initialized() {
+            return HeaderResult::NotActive
+        }
+        let finalized_height = Pallet::<T, I>::confirmed_height(&SpeedMode::Finalized);
+        match Pallet::<T, I>::canonical_hash(finalized_height) {
+            Some(hash) => HeaderResult::Header(hash.encode()),
+            None => HeaderResult::NotActive,
+        }
+    }
+
+    fn get_fast_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        Pallet::<T, I>::height_for(&SpeedMode::Fast)
+    }
+
+    fn get_rational_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        Pallet::<T, I>::height_for(&SpeedMode::Rational)
+    }
+
+    fn get_finalized_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        Pallet::<T, I>::height_for(&SpeedMode::Finalized)
+    }
+
+    fn get_latest_finalized_header_precompile(&self) -> Bytes {
+        match self.get_latest_finalized_header() {
+            HeaderResult::Header(header) => header,
+            HeaderResult::NotActive => vec![],
+        }
+    }
+
+    fn get_fast_height_precompile(&self) -> BlockNumberFor<T> {
+        Pallet::<T, I>::confirmed_height(&SpeedMode::Fast).into()
+    }
+
+    fn get_rational_height_precompile(&self) -> BlockNumberFor<T> {
+        Pallet::<T, I>::confirmed_height(&SpeedMode::Rational).into()
+    }
+
+    fn get_finalized_height_precompile(&self) -> BlockNumberFor<T> {
+        Pallet::<T, I>::confirmed_height(&SpeedMode::Finalized).into()
+    }
+
+    fn get_latest_heartbeat(&self) -> Result<LightClientHeartbeat<T>, DispatchError> {
+        Ok(LightClientHeartbeat {
+            last_heartbeat: frame_system::Pallet::<T>::block_number(),
+            last_finalized_height: Pallet::<T, I>::confirmed_height(&SpeedMode::Finalized).into(),
+            last_rational_height: Pallet::<T, I>::confirmed_height(&SpeedMode::Rational).into(),
+            last_fast_height: Pallet::<T, I>::confirmed_height(&SpeedMode::Fast).into(),
+            is_halted: Pallet::<T, I>::is_halted(),
+            ever_initialized: Pallet::<T, I>::ever_initialized(),
+        })
+    }
+
+    fn initialize(
+        &self,
+        origin: OriginFor<T>,
+        gateway_id: [u8; 4],
+        encoded_registration_data: Bytes,
+    ) -> Result<(), DispatchError> {
+        Pallet::<T, I>::initialize(origin, gateway_id, encoded_registration_data)
+            .map_err(|str_err| str_err.into())
+    }
+
+    fn turn_on(&self, origin: OriginFor<T>) -> Result<bool, DispatchError> {
+        Pallet::<T, I>::set_operational(origin, true)?;
+        Ok(!Pallet::<T, I>::is_halted())
+    }
+
+    fn turn_off(&self, origin: OriginFor<T>) -> Result<bool, DispatchError> {
+        Pallet::<T, I>::set_operational(origin, false)?;
+        Ok(!Pallet::<T, I>::is_halted())
+    }
+
+    fn submit_encoded_headers(&self, headers: Bytes) -> Result<bool, DispatchError> {
+        Pallet::<T, I>::submit_encoded_headers(headers)?;
+        Ok(true)
+    }
+
+    fn verify_event_inclusion(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Pallet::<T, I>::confirm_event_inclusion(gateway_id, speed_mode, source, message)
+    }
+
+    fn verify_state_inclusion(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Pallet::<T, I>::confirm_state_inclusion(gateway_id, speed_mode, message)
+    }
+
+    fn verify_tx_inclusion(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Pallet::<T, I>::confirm_tx_inclusion(gateway_id, speed_mode, message)
+    }
+
+    fn verify_event_inclusion_precompile(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        source: ExecutionSource,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        Pallet::<T, I>::confirm_event_inclusion(
+            gateway_id,
+            speed_mode,
+            execution_source_to_option(source),
+            message,
+        )
+        .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_state_inclusion_precompile(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        Pallet::<T, I>::confirm_state_inclusion(gateway_id, speed_mode, message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_tx_inclusion_precompile(
+        &self,
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        Pallet::<T, I>::confirm_tx_inclusion(gateway_id, speed_mode, message)
+            .map(|receipt| receipt.message.encode())
+    }
+}
@@ -0,0 +1,161 @@
+// From construct_runtime macro
+#![allow(clippy::from_over_into)]
+
+use codec::Encode;
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_system::mocking::MockUncheckedExtrinsic;
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{
+    generic,
+    traits::{BlakeTwo256, Header as HeaderT, IdentityLookup},
+    Digest, DigestItem,
+};
+use t3rn_primitives::{light_client::LightClientAsyncAPIEmptyMock, GatewayVendor};
+
+use crate::{
+    types::{
+        ConfirmationDepths, SealEngine, StandaloneHeader, StandaloneRegistrationData,
+        AURA_ENGINE_ID,
+    },
+    Config,
+};
+
+pub type AccountId = u64;
+type Header = generic::Header<u32, BlakeTwo256>;
+pub type Block = sp_runtime::generic::Block<Header, MockUncheckedExtrinsic<TestRuntime>>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
+
+construct_runtime! {
+    pub enum TestRuntime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        StandaloneFinalityVerifier: crate,
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+}
+
+impl frame_system::Config for TestRuntime {
+    type AccountData = ();
+    type AccountId = AccountId;
+    type BaseCallFilter = Everything;
+    /// The block type.
+    type Block = Block;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    /// The index type for storing how many extrinsics an account has signed.
+    type Nonce = u32;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+parameter_types! {
+    pub const HeadersToStore: u32 = 5;
+    pub const StandaloneVendor: GatewayVendor = GatewayVendor::Standalone;
+}
+
+impl Config for TestRuntime {
+    type HeadersToStore = HeadersToStore;
+    type LightClientAsyncAPI = LightClientAsyncAPIEmptyMock<TestRuntime>;
+    type MyVendor = StandaloneVendor;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+}
+
+pub const GATEWAY_ID: [u8; 4] = *b"stnd";
+
+pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
+    sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+        System::set_block_number(1);
+        test()
+    })
+}
+
+pub fn authority_pairs(seeds: &[&str]) -> Vec<sr25519::Pair> {
+    seeds
+        .iter()
+        .map(|seed| sr25519::Pair::from_string(&format!("//{seed}"), None).unwrap())
+        .collect()
+}
+
+pub fn registration_data(
+    engine: SealEngine,
+    authorities: &[sr25519::Pair],
+    first_header: &StandaloneHeader,
+) -> StandaloneRegistrationData<AccountId> {
+    StandaloneRegistrationData {
+        first_header: first_header.encode(),
+        engine,
+        authorities: authorities.iter().map(|pair| pair.public()).collect(),
+        authority_set_id: 0,
+        confirmation_depths: ConfirmationDepths {
+            rational: 1,
+            finalized: 2,
+        },
+        owner: 1,
+    }
+}
+
+pub fn genesis_header() -> StandaloneHeader {
+    StandaloneHeader::new(
+        0,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+}
+
+/// Build the pre-runtime digest announcing the header's slot.
+pub fn pre_digest(engine: SealEngine, slot: u64) -> DigestItem {
+    match engine {
+        SealEngine::Aura => DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode()),
+    }
+}
+
+/// Build a header on top of `parent` carrying the given digests, sealed by `author`.
+pub fn sealed_header(
+    engine: SealEngine,
+    parent: &StandaloneHeader,
+    slot: u64,
+    extra_logs: Vec<DigestItem>,
+    author: &sr25519::Pair,
+) -> StandaloneHeader {
+    let mut logs = vec![pre_digest(engine, slot)];
+    logs.extend(extra_logs);
+    let mut header = StandaloneHeader::new(
+        parent.number + 1,
+        Default::default(),
+        Default::default(),
+        parent.hash(),
+        Digest { logs },
+    );
+    seal(engine, &mut header, author);
+    header
+}
+
+pub fn seal(engine: SealEngine, header: &mut StandaloneHeader, author: &sr25519::Pair) {
+    let signature = author.sign(header.hash().as_ref());
+    header
+        .digest_mut()
+        .push(DigestItem::Seal(engine.engine_id(), signature.encode()));
+}
@@ -0,0 +1,611 @@
+use crate::{
+    mock::*,
+    types::{
+        SealEngine, StandaloneHeader, StandaloneHeaderData, StandaloneInclusionProof,
+        StandaloneStateInclusionProof, StandaloneTxInclusionProof,
+    },
+    Error, Event as StandaloneEvent, Pallet,
+};
+use codec::{Compact, Encode};
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{
+    traits::{Hash, Header as HeaderT},
+    Digest, DigestItem, StateVersion,
+};
+use sp_std::marker::PhantomData;
+use t3rn_primitives::{
+    light_client::{HeightResult, LightClient},
+    SpeedMode,
+};
+
+type StandaloneFV = Pallet<TestRuntime>;
+
+fn light_client() -> StandaloneFV {
+    Pallet(PhantomData)
+}
+
+fn initialize(engine: SealEngine, authorities: &[sr25519::Pair]) -> StandaloneHeader {
+    let genesis = genesis_header();
+    assert_ok!(StandaloneFV::initialize(
+        RuntimeOrigin::root(),
+        GATEWAY_ID,
+        registration_data(engine, authorities, &genesis).encode(),
+    ));
+    genesis
+}
+
+/// Aura authors take turns in slot order.
+fn aura_header(
+    parent: &StandaloneHeader,
+    slot: u64,
+    authorities: &[sr25519::Pair],
+    extra_logs: Vec<DigestItem>,
+) -> StandaloneHeader {
+    let author = &authorities[(slot % authorities.len() as u64) as usize];
+    sealed_header(SealEngine::Aura, parent, slot, extra_logs, author)
+}
+
+fn aura_chain(
+    parent: &StandaloneHeader,
+    first_slot: u64,
+    count: u64,
+    authorities: &[sr25519::Pair],
+) -> Vec<StandaloneHeader> {
+    let mut parent = parent.clone();
+    (first_slot..first_slot + count)
+        .map(|slot| {
+            parent = aura_header(&parent, slot, authorities, vec![]);
+            parent.clone()
+        })
+        .collect()
+}
+
+fn submit(range: Vec<StandaloneHeader>) -> sp_runtime::DispatchResult {
+    StandaloneFV::submit_headers(RuntimeOrigin::signed(2), range)
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+#[test]
+fn initializes_with_genesis_as_best_header() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice", "Bob"]);
+        let light_client = light_client();
+        assert_eq!(light_client.get_fast_height(), HeightResult::NotActive);
+
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        assert_eq!(StandaloneFV::get_best_block_hash(), Some(genesis.hash()));
+        assert_eq!(light_client.get_fast_height(), HeightResult::Height(0));
+        assert!(
+            light_client
+                .get_latest_heartbeat()
+                .unwrap()
+                .ever_initialized
+        );
+        assert_eq!(
+            StandaloneFV::initialize(
+                RuntimeOrigin::root(),
+                GATEWAY_ID,
+                registration_data(SealEngine::Aura, &authorities, &genesis).encode(),
+            ),
+            Err("StandaloneFV::initialize -- chain_id already initialized")
+        );
+    });
+}
+
+#[test]
+fn imports_aura_sealed_headers_and_tracks_heights_by_confirmation_depth() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice", "Bob", "Charlie"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let range = aura_chain(&genesis, 1, 3, &authorities);
+        assert_ok!(submit(range.clone()));
+
+        let light_client = light_client();
+        assert_eq!(light_client.get_fast_height(), HeightResult::Height(3));
+        assert_eq!(light_client.get_rational_height(), HeightResult::Height(2));
+        assert_eq!(light_client.get_finalized_height(), HeightResult::Height(1));
+        assert_eq!(StandaloneFV::last_slot(), 3);
+        assert_eq!(StandaloneFV::get_best_block_hash(), Some(range[2].hash()));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::StandaloneFinalityVerifier(StandaloneEvent::HeadersAdded(3))));
+    });
+}
+
+#[test]
+fn rejects_headers_sealed_by_unexpected_author() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice", "Bob", "Charlie"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        // slot 1 belongs to Bob
+        let header = sealed_header(SealEngine::Aura, &genesis, 1, vec![], &authorities[0]);
+        assert_noop!(submit(vec![header]), Error::<TestRuntime>::InvalidSeal);
+
+        let outsider = authority_pairs(&["Mallory"]);
+        let header = sealed_header(SealEngine::Aura, &genesis, 1, vec![], &outsider[0]);
+        assert_noop!(submit(vec![header]), Error::<TestRuntime>::InvalidSeal);
+
+        let mut unsealed = aura_header(&genesis, 1, &authorities, vec![]);
+        unsealed.digest_mut().pop();
+        assert_noop!(submit(vec![unsealed]), Error::<TestRuntime>::MissingSeal);
+    });
+}
+
+#[test]
+fn rejects_headers_not_extending_best_header_or_reusing_slots() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice", "Bob"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let range = aura_chain(&genesis, 1, 2, &authorities);
+        assert_noop!(
+            submit(vec![range[1].clone()]),
+            Error::<TestRuntime>::InvalidRangeLinkage
+        );
+        assert_noop!(submit(vec![]), Error::<TestRuntime>::EmptyRangeSubmitted);
+
+        assert_ok!(submit(range.clone()));
+        let same_slot = aura_header(&range[1], 2, &authorities, vec![]);
+        assert_noop!(
+            submit(vec![same_slot]),
+            Error::<TestRuntime>::SlotNotIncreasing
+        );
+    });
+}
+
+#[test]
+fn enacts_aura_authorities_change_after_announcing_header() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice", "Bob"]);
+        let new_authorities = authority_pairs(&["Dave", "Eve"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let change = DigestItem::Consensus(
+            *b"aura",
+            (
+                1u8,
+                new_authorities
+                    .iter()
+                    .map(|pair| pair.public())
+                    .collect::<Vec<_>>(),
+            )
+                .encode(),
+        );
+        // the announcing header is still sealed by the previous set
+        let announcing = aura_header(&genesis, 1, &authorities, vec![change]);
+        assert_ok!(submit(vec![announcing.clone()]));
+
+        assert_eq!(StandaloneFV::authority_set_id(), 1);
+        assert_eq!(
+            StandaloneFV::authorities(),
+            new_authorities
+                .iter()
+                .map(|pair| pair.public())
+                .collect::<Vec<_>>()
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::StandaloneFinalityVerifier(StandaloneEvent::AuthoritySetChanged(
+                1, 2
+            ))));
+
+        let by_old_set = aura_header(&announcing, 2, &authorities, vec![]);
+        assert_noop!(submit(vec![by_old_set]), Error::<TestRuntime>::InvalidSeal);
+        assert_ok!(submit(vec![aura_header(
+            &announcing,
+            2,
+            &new_authorities,
+            vec![]
+        )]));
+    });
+}
+
+#[test]
+fn prunes_headers_beyond_headers_to_store() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let range = aura_chain(&genesis, 1, 5, &authorities);
+        assert_ok!(StandaloneFV::submit_encoded_headers(
+            StandaloneHeaderData {
+                range: range.clone()
+            }
+            .encode()
+        ));
+
+        assert_eq!(StandaloneFV::get_imported_header(genesis.hash()), None);
+        assert_eq!(StandaloneFV::canonical_hash(0), None);
+        assert_eq!(StandaloneFV::canonical_hash(1), Some(range[0].hash()));
+        assert_eq!(StandaloneFV::canonical_hash(5), Some(range[4].hash()));
+    });
+}
+
+#[test]
+fn confirms_event_inclusion_once_header_is_deep_enough() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let events_key = hex_events_key();
+        let encoded_event = b"transfer event bytes".to_vec();
+        let events = [b"other event".to_vec(), encoded_event.clone()].concat();
+        let backend = sp_state_machine::InMemoryBackend::<sp_runtime::traits::BlakeTwo256>::from((
+            vec![(None, vec![(events_key.clone(), Some(events))])],
+            StateVersion::V1,
+        ));
+        let state_root = *backend.root();
+        let payload_proof = sp_state_machine::prove_read(backend, &[events_key]).unwrap();
+
+        let mut header = StandaloneHeader::new(
+            1,
+            Default::default(),
+            state_root,
+            genesis.hash(),
+            Digest {
+                logs: vec![pre_digest(SealEngine::Aura, 1)],
+            },
+        );
+        seal(SealEngine::Aura, &mut header, &authorities[0]);
+        assert_ok!(submit(vec![header.clone()]));
+
+        let proof = StandaloneInclusionProof {
+            encoded_payload: encoded_event.clone(),
+            payload_proof,
+            block_hash: header.hash(),
+        }
+        .encode();
+        let light_client = light_client();
+
+        let receipt = light_client
+            .verify_event_inclusion(GATEWAY_ID, SpeedMode::Fast, None, proof.clone())
+            .unwrap();
+        assert_eq!(receipt.height, 1);
+        assert_eq!(receipt.message, encoded_event);
+        assert_eq!(receipt.including_header, header.hash().encode());
+
+        assert_eq!(
+            light_client.verify_event_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Rational,
+                None,
+                proof.clone()
+            ),
+            Err(Error::<TestRuntime>::HeaderNotConfirmed.into())
+        );
+
+        assert_ok!(submit(aura_chain(&header, 2, 1, &authorities)));
+        assert_ok!(light_client.verify_event_inclusion(
+            GATEWAY_ID,
+            SpeedMode::Rational,
+            None,
+            proof
+        ));
+
+        let unknown = StandaloneInclusionProof {
+            encoded_payload: encoded_event,
+            payload_proof: Default::default(),
+            block_hash: H256::repeat_byte(7),
+        }
+        .encode();
+        assert_eq!(
+            light_client.verify_event_inclusion(GATEWAY_ID, SpeedMode::Fast, None, unknown),
+            Err(Error::<TestRuntime>::UnknownHeader.into())
+        );
+    });
+}
+
+#[test]
+fn rejects_inclusion_proofs_for_other_gateways() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+        let proof = StandaloneInclusionProof {
+            encoded_payload: b"transfer event bytes".to_vec(),
+            payload_proof: Default::default(),
+            block_hash: genesis.hash(),
+        }
+        .encode();
+        let light_client = light_client();
+
+        assert_eq!(
+            light_client.verify_event_inclusion(*b"othr", SpeedMode::Fast, None, proof.clone()),
+            Err(Error::<TestRuntime>::UnknownGateway.into())
+        );
+        assert_eq!(
+            light_client.verify_state_inclusion(*b"othr", SpeedMode::Fast, proof.clone()),
+            Err(Error::<TestRuntime>::UnknownGateway.into())
+        );
+        assert_eq!(
+            light_client.verify_tx_inclusion(*b"othr", SpeedMode::Fast, proof),
+            Err(Error::<TestRuntime>::UnknownGateway.into())
+        );
+    });
+}
+
+#[test]
+fn only_owner_or_root_sets_confirmation_depths_within_bounds() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        initialize(SealEngine::Aura, &authorities);
+
+        assert_noop!(
+            StandaloneFV::set_confirmation_depths(RuntimeOrigin::signed(2), 1, 1),
+            "Bad origin"
+        );
+        assert_noop!(
+            StandaloneFV::set_confirmation_depths(RuntimeOrigin::signed(1), 2, 1),
+            Error::<TestRuntime>::InvalidConfirmationDepths
+        );
+        assert_noop!(
+            StandaloneFV::set_confirmation_depths(RuntimeOrigin::root(), 1, 5),
+            Error::<TestRuntime>::InvalidConfirmationDepths
+        );
+
+        assert_ok!(StandaloneFV::set_confirmation_depths(
+            RuntimeOrigin::signed(1),
+            0,
+            4
+        ));
+        assert_eq!(StandaloneFV::confirmation_depths().rational, 0);
+        assert_eq!(StandaloneFV::confirmation_depths().finalized, 4);
+    });
+}
+
+#[test]
+fn charges_fees_for_headers_submitted_directly() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let post_info = StandaloneFV::submit_headers(
+            RuntimeOrigin::signed(2),
+            aura_chain(&genesis, 1, 2, &authorities),
+        )
+        .unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+    });
+}
+
+#[test]
+fn initializes_from_header_announcing_its_slot() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice", "Bob"]);
+        let first_header = aura_header(&genesis_header(), 7, &authorities, vec![]);
+
+        let mut unannounced = first_header.clone();
+        unannounced.digest_mut().logs.clear();
+        assert_eq!(
+            StandaloneFV::initialize(
+                RuntimeOrigin::root(),
+                GATEWAY_ID,
+                registration_data(SealEngine::Aura, &authorities, &unannounced).encode(),
+            ),
+            Err("MissingPreDigest")
+        );
+
+        assert_ok!(StandaloneFV::initialize(
+            RuntimeOrigin::root(),
+            GATEWAY_ID,
+            registration_data(SealEngine::Aura, &authorities, &first_header).encode(),
+        ));
+        assert_eq!(StandaloneFV::last_slot(), 7);
+        assert_noop!(
+            submit(vec![aura_header(&first_header, 7, &authorities, vec![])]),
+            Error::<TestRuntime>::SlotNotIncreasing
+        );
+    });
+}
+
+#[test]
+fn confirms_event_inclusion_from_expected_source_only() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let evm_address = [5u8; 20];
+        // EVM::Log event of the pallet at index 10, emitted by the contract
+        let encoded_event = [vec![10u8, 0u8], evm_address.to_vec(), b"log data".to_vec()].concat();
+        let (state_root, payload_proof) = prove_entry(hex_events_key(), encoded_event.clone());
+        let header = header_with_roots(&genesis, state_root, Default::default(), &authorities[0]);
+        assert_ok!(submit(vec![header.clone()]));
+
+        let proof = StandaloneInclusionProof {
+            encoded_payload: encoded_event.clone(),
+            payload_proof,
+            block_hash: header.hash(),
+        }
+        .encode();
+        let light_client = light_client();
+
+        let mut source = [0u8; 32];
+        source[12..].copy_from_slice(&evm_address);
+        let receipt = light_client
+            .verify_event_inclusion(GATEWAY_ID, SpeedMode::Fast, Some(source), proof.clone())
+            .unwrap();
+        assert_eq!(receipt.message, encoded_event);
+
+        let mut other_source = [0u8; 32];
+        other_source[12..].copy_from_slice(&[6u8; 20]);
+        assert_eq!(
+            light_client.verify_event_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Fast,
+                Some(other_source),
+                proof
+            ),
+            Err(Error::<TestRuntime>::UnexpectedSource.into())
+        );
+    });
+}
+
+#[test]
+fn confirms_state_inclusion_of_storage_entry() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let storage_key = b"some storage key".to_vec();
+        let value = b"some storage value".to_vec();
+        let (state_root, storage_proof) = prove_entry(storage_key.clone(), value.clone());
+        let header = header_with_roots(&genesis, state_root, Default::default(), &authorities[0]);
+        assert_ok!(submit(vec![header.clone()]));
+
+        let light_client = light_client();
+        let receipt = light_client
+            .verify_state_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Fast,
+                StandaloneStateInclusionProof {
+                    storage_key: storage_key.clone(),
+                    storage_proof: storage_proof.clone(),
+                    block_hash: header.hash(),
+                }
+                .encode(),
+            )
+            .unwrap();
+        assert_eq!(receipt.height, 1);
+        assert_eq!(receipt.message, value);
+        assert_eq!(receipt.including_header, header.hash().encode());
+
+        assert_eq!(
+            light_client.verify_state_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Fast,
+                StandaloneStateInclusionProof {
+                    storage_key: b"other storage key".to_vec(),
+                    storage_proof: storage_proof.clone(),
+                    block_hash: header.hash(),
+                }
+                .encode(),
+            ),
+            Err(Error::<TestRuntime>::InvalidStorageProof.into())
+        );
+        assert_eq!(
+            light_client.verify_state_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Rational,
+                StandaloneStateInclusionProof {
+                    storage_key,
+                    storage_proof,
+                    block_hash: header.hash(),
+                }
+                .encode(),
+            ),
+            Err(Error::<TestRuntime>::HeaderNotConfirmed.into())
+        );
+    });
+}
+
+#[test]
+fn confirms_tx_inclusion_against_extrinsics_root() {
+    run_test(|| {
+        let authorities = authority_pairs(&["Alice"]);
+        let genesis = initialize(SealEngine::Aura, &authorities);
+
+        let extrinsics = vec![b"timestamp set".to_vec(), b"balances transfer".to_vec()];
+        let extrinsics_root = sp_runtime::traits::BlakeTwo256::ordered_trie_root(
+            extrinsics.clone(),
+            StateVersion::V0,
+        );
+        // The extrinsics trie is keyed by the compact encoded extrinsic indexes
+        let backend = sp_state_machine::InMemoryBackend::<sp_runtime::traits::BlakeTwo256>::from((
+            vec![(
+                None,
+                extrinsics
+                    .iter()
+                    .enumerate()
+                    .map(|(index, tx)| (Compact(index as u32).encode(), Some(tx.clone())))
+                    .collect(),
+            )],
+            StateVersion::V0,
+        ));
+        assert_eq!(*backend.root(), extrinsics_root);
+        let tx_proof = sp_state_machine::prove_read(backend, &[Compact(1u32).encode()]).unwrap();
+
+        let header = header_with_roots(
+            &genesis,
+            Default::default(),
+            extrinsics_root,
+            &authorities[0],
+        );
+        assert_ok!(submit(vec![header.clone()]));
+
+        let light_client = light_client();
+        let receipt = light_client
+            .verify_tx_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Fast,
+                StandaloneTxInclusionProof {
+                    encoded_tx: extrinsics[1].clone(),
+                    tx_index: 1,
+                    tx_proof: tx_proof.clone(),
+                    block_hash: header.hash(),
+                }
+                .encode(),
+            )
+            .unwrap();
+        assert_eq!(receipt.height, 1);
+        assert_eq!(receipt.message, extrinsics[1]);
+
+        assert_eq!(
+            light_client.verify_tx_inclusion(
+                GATEWAY_ID,
+                SpeedMode::Fast,
+                StandaloneTxInclusionProof {
+                    encoded_tx: b"forged transfer".to_vec(),
+                    tx_index: 1,
+                    tx_proof,
+                    block_hash: header.hash(),
+                }
+                .encode(),
+            ),
+            Err(Error::<TestRuntime>::TxNotIncluded.into())
+        );
+    });
+}
+
+/// Build the state holding a single entry, returning its root and the read proof of the entry.
+fn prove_entry(key: Vec<u8>, value: Vec<u8>) -> (H256, sp_trie::StorageProof) {
+    let backend = sp_state_machine::InMemoryBackend::<sp_runtime::traits::BlakeTwo256>::from((
+        vec![(None, vec![(key.clone(), Some(value))])],
+        StateVersion::V1,
+    ));
+    let state_root = *backend.root();
+    (
+        state_root,
+        sp_state_machine::prove_read(backend, &[key]).unwrap(),
+    )
+}
+
+/// Build the header on top of `parent` committing to the given roots, at the slot of its number.
+fn header_with_roots(
+    parent: &StandaloneHeader,
+    state_root: H256,
+    extrinsics_root: H256,
+    author: &sr25519::Pair,
+) -> StandaloneHeader {
+    let mut header = StandaloneHeader::new(
+        parent.number + 1,
+        extrinsics_root,
+        state_root,
+        parent.hash(),
+        Digest {
+            logs: vec![pre_digest(SealEngine::Aura, parent.number as u64 + 1)],
+        },
+    );
+    seal(SealEngine::Aura, &mut header, author);
+    header
+}
+
+fn hex_events_key() -> Vec<u8> {
+    // storage key for System_Events
+    [
+        38, 170, 57, 78, 234, 86, 48, 224, 124, 72, 174, 12, 149, 88, 206, 247, 128, 212, 30, 94,
+        22, 5, 103, 101, 188, 132, 97, 133, 16, 114, 201, 215,
+    ]
+    .to_vec()
+}
@@ -0,0 +1,97 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{sr25519, H256};
+use sp_runtime::{generic, traits::BlakeTwo256, ConsensusEngineId};
+use sp_std::vec::Vec;
+use sp_trie::StorageProof;
+
+pub type ChainId = [u8; 4];
+
+/// Header of the standalone chain. Standalone Substrate chains are expected to use the default
+/// `u32` block number and `BlakeTwo256` hashing.
+pub type StandaloneHeader = generic::Header<u32, BlakeTwo256>;
+
+/// Block authoring keys used to seal standalone chain headers.
+pub type AuthorityId = sr25519::Public;
+
+/// Engine id of the Aura consensus digests.
+pub const AURA_ENGINE_ID: ConsensusEngineId = *b"aura";
+
+/// Block production engine sealing the headers of the standalone chain.
+///
+/// BABE isn't supported: the authors of its primary slots are only known through VRF outputs,
+/// which the pallet can't verify, so any authority could claim any slot.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum SealEngine {
+    Aura,
+}
+
+impl SealEngine {
+    pub fn engine_id(&self) -> ConsensusEngineId {
+        match self {
+            SealEngine::Aura => AURA_ENGINE_ID,
+        }
+    }
+}
+
+/// Number of headers that have to be built on top of a header before it is considered
+/// confirmed for the given speed mode.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfirmationDepths {
+    pub rational: u32,
+    pub finalized: u32,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct StandaloneRegistrationData<T> {
+    pub first_header: Vec<u8>,
+    pub engine: SealEngine,
+    pub authorities: Vec<AuthorityId>,
+    pub authority_set_id: u64,
+    pub confirmation_depths: ConfirmationDepths,
+    pub owner: T,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct StandaloneHeaderData {
+    /// Headers extending the best known header, in ascending order
+    pub range: Vec<StandaloneHeader>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct StandaloneInclusionProof {
+    /// this is the item we're proving to be included in a specfic block (e.g. event, storage entry, etc)
+    pub encoded_payload: Vec<u8>,
+    pub payload_proof: StorageProof,
+    pub block_hash: H256,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct StandaloneStateInclusionProof {
+    /// Storage key of the entry, whose value is proven against the state root of the block
+    pub storage_key: Vec<u8>,
+    pub storage_proof: StorageProof,
+    pub block_hash: H256,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct StandaloneTxInclusionProof {
+    /// Encoded extrinsic, proven against the extrinsics root of the block
+    pub encoded_tx: Vec<u8>,
+    /// Index of the extrinsic within the block, keying the extrinsics trie
+    pub tx_index: u32,
+    pub tx_proof: StorageProof,
+    pub block_hash: H256,
+}
+
+/// Mirrors `sp_consensus_aura::ConsensusLog` for sr25519 authorities.
+#[derive(Decode, Encode)]
+pub(crate) enum AuraConsensusLog {
+    #[codec(index = 1)]
+    AuthoritiesChange(Vec<AuthorityId>),
+    #[codec(index = 2)]
+    OnDisabled(u32),
+}
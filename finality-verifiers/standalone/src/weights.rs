@@ -0,0 +1,90 @@
+//! Weights for pallet_standalone_finality_verifier
+//!
+//! Estimated from the storage accessed by each call and the cost of an sr25519 seal verification
+//! per imported header, until regenerated from the benchmarks with:
+//! `sh node/run-benchmark.sh pallet_standalone_finality_verifier ./finality-verifiers/standalone/src/weights.rs`
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_standalone_finality_verifier.
+pub trait WeightInfo {
+    fn submit_headers(n: u32) -> Weight;
+    fn set_confirmation_depths() -> Weight;
+    fn reset(h: u32) -> Weight;
+}
+
+/// Weights for pallet_standalone_finality_verifier using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: StandaloneBridge IsHalted (r:1 w:0)
+    // Storage: StandaloneBridge BestHash (r:1 w:1)
+    // Storage: StandaloneBridge BestNumber (r:1 w:1)
+    // Storage: StandaloneBridge Engine (r:1 w:0)
+    // Storage: StandaloneBridge Authorities (r:1 w:0)
+    // Storage: StandaloneBridge LastSlot (r:1 w:1)
+    // Storage: StandaloneBridge OldestImportedNumber (r:1 w:1)
+    // Storage: StandaloneBridge CanonicalHashes (r:1 w:2)
+    // Storage: StandaloneBridge ImportedHeaders (r:0 w:2)
+    // Storage: StandaloneBridge Depths (r:1 w:0)
+    // Storage: StandaloneBridge EverInitialized (r:1 w:0)
+    // Storage: StandaloneBridge SubmissionsCounter (r:1 w:1)
+    fn submit_headers(n: u32) -> Weight {
+        Weight::from_parts(31_000_000_u64, 2_500u64)
+            .saturating_add(Weight::from_parts(68_000_000_u64, 1_200u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n as u64)))
+    }
+
+    // Storage: StandaloneBridge PalletOwner (r:1 w:0)
+    // Storage: StandaloneBridge Depths (r:0 w:1)
+    fn set_confirmation_depths() -> Weight {
+        Weight::from_parts(19_000_000_u64, 1_600u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    // Storage: StandaloneBridge CanonicalHashes (r:h w:h)
+    // Storage: StandaloneBridge ImportedHeaders (r:h w:h)
+    fn reset(h: u32) -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(2_400_000_u64, 1_300u64).saturating_mul(h as u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(h as u64)))
+            .saturating_add(T::DbWeight::get().writes(13_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(h as u64)))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn submit_headers(n: u32) -> Weight {
+        Weight::from_parts(31_000_000_u64, 2_500u64)
+            .saturating_add(Weight::from_parts(68_000_000_u64, 1_200u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(n as u64)))
+    }
+
+    fn set_confirmation_depths() -> Weight {
+        Weight::from_parts(19_000_000_u64, 1_600u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn reset(h: u32) -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(2_400_000_u64, 1_300u64).saturating_mul(h as u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(h as u64)))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(h as u64)))
+    }
+}
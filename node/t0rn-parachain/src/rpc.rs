@@ -20,9 +20,12 @@ use sp_core::H256;
 use sp_runtime::{traits::BlakeTwo256, OpaqueExtrinsic};
 use std::{collections::BTreeMap, sync::Arc};
 
-use parachain_runtime::{opaque::Block, AccountId, Balance, Hash, Nonce};
+use parachain_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Hash, Nonce};
 
+use pallet_account_manager_rpc::{AccountManager, AccountManagerApiServer};
+use pallet_clock_rpc::{Clock, ClockApiServer};
 use pallet_portal_rpc::{Portal, PortalApiServer};
+use pallet_rewards_rpc::{Rewards, RewardsApiServer};
 
 use pallet_xdns_rpc::{Xdns, XdnsApiServer};
 use sp_api::CallApiAt;
@@ -111,8 +114,18 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId>,
+    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: pallet_portal_rpc::PortalRuntimeApi<Block, AccountId, Balance, Hash>,
+    C::Api: pallet_rewards_rpc::RewardsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_account_manager_rpc::AccountManagerRuntimeApi<
+        Block,
+        AccountId,
+        Balance,
+        Hash,
+        AssetId,
+        BlockNumber,
+    >,
+    C::Api: pallet_clock_rpc::ClockRuntimeApi<Block, BlockNumber>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
     C::Api: BlockBuilder<Block>,
@@ -148,6 +161,9 @@ where
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
     module.merge(Xdns::new(client.clone()).into_rpc())?;
+    module.merge(Rewards::new(client.clone()).into_rpc())?;
+    module.merge(AccountManager::new(client.clone()).into_rpc())?;
+    module.merge(Clock::new(client.clone()).into_rpc())?;
     module.merge(Portal::new(client.clone()).into_rpc())?;
 
     // Ethereum  modules
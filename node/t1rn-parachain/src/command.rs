@@ -4,9 +4,10 @@ use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
 use log::{info, warn};
 
-use parachain_runtime::{Block, MILLISECS_PER_BLOCK};
+use parachain_runtime::{AccountId, Block, MILLISECS_PER_BLOCK};
 const COLLATOR_NAME: &str = "t0rn collator";
 
+use pallet_xdns_cli::XdnsTopologyCmd;
 use sc_cli::{
     ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams,
     NetworkParams, Result, SharedParams, SubstrateCli,
@@ -142,6 +143,17 @@ pub fn run() -> Result<()> {
                 Ok(cmd.run(components.client, config.chain_spec))
             })
         },
+        Some(Subcommand::Xdns(cmd)) => match cmd {
+            XdnsTopologyCmd::Export(cmd) if cmd.from_spec.is_some() =>
+                cmd.run_from_spec::<AccountId>(),
+            XdnsTopologyCmd::Export(cmd) => {
+                construct_async_run!(|components, cli, cmd, config| {
+                    Ok(cmd.run::<AccountId, _, _, _>(components.client))
+                })
+            },
+            XdnsTopologyCmd::Validate(cmd) => cmd.run::<AccountId>(),
+            XdnsTopologyCmd::Import(cmd) => cmd.run::<AccountId>(),
+        },
         Some(Subcommand::ImportBlocks(cmd)) => {
             construct_async_run!(|components, cli, cmd, config| {
                 Ok(cmd.run(components.client, components.import_queue))
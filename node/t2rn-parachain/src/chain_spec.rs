@@ -230,6 +230,12 @@ fn testnet_genesis(
         xdns: XDNSConfig {
             known_gateway_records: vec![],
             standard_sfx_abi: t3rn_abi::standard::standard_sfx_abi().encode(),
+            known_tokens: vec![],
+            remote_order_addresses: vec![],
+            remote_bidding_addresses: vec![],
+            gateway_sfx_abis: vec![],
+            gateway_abis: vec![],
+            gateway_versions: vec![],
             _marker: Default::default(),
         },
         contracts_registry: Default::default(),
@@ -27,6 +27,10 @@ pub enum Subcommand {
     /// Export the state of a given block into a chain spec.
     ExportState(sc_cli::ExportStateCmd),
 
+    /// Export, validate and import the XDNS topology as a JSON/TOML file.
+    #[command(subcommand)]
+    Xdns(pallet_xdns_cli::XdnsTopologyCmd),
+
     /// Import blocks.
     ImportBlocks(sc_cli::ImportBlocksCmd),
 
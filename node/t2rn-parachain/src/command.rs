@@ -3,8 +3,9 @@ use crate::{
     cli::{Cli, Subcommand},
     service,
 };
-use t2rn_parachain_runtime::Block;
+use t2rn_parachain_runtime::{AccountId, Block};
 
+use pallet_xdns_cli::XdnsTopologyCmd;
 use sc_cli::{ChainSpec, SubstrateCli};
 use sc_service::PartialComponents;
 
@@ -90,6 +91,23 @@ pub fn run() -> sc_cli::Result<()> {
                 Ok((cmd.run(client, config.chain_spec), task_manager))
             })
         },
+        Some(Subcommand::Xdns(cmd)) => match cmd {
+            XdnsTopologyCmd::Export(cmd) if cmd.from_spec.is_some() =>
+                cmd.run_from_spec::<AccountId>(),
+            XdnsTopologyCmd::Export(cmd) => {
+                let runner = cli.create_runner(cmd)?;
+                runner.async_run(|config| {
+                    let PartialComponents {
+                        client,
+                        task_manager,
+                        ..
+                    } = service::new_partial(&config)?;
+                    Ok((cmd.run::<AccountId, _, _, _>(client), task_manager))
+                })
+            },
+            XdnsTopologyCmd::Validate(cmd) => cmd.run::<AccountId>(),
+            XdnsTopologyCmd::Import(cmd) => cmd.run::<AccountId>(),
+        },
         Some(Subcommand::ImportBlocks(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|config| {
@@ -4,10 +4,11 @@ use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::BenchmarkCmd;
 use log::{info, warn};
 
-use parachain_runtime::Block;
+use parachain_runtime::{AccountId, Block};
 
 const COLLATOR_NAME: &str = "t3rn collator";
 
+use pallet_xdns_cli::XdnsTopologyCmd;
 use sc_cli::{
     ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams,
     NetworkParams, Result, SharedParams, SubstrateCli,
@@ -143,6 +144,17 @@ pub fn run() -> Result<()> {
                 Ok(cmd.run(components.client, config.chain_spec))
             })
         },
+        Some(Subcommand::Xdns(cmd)) => match cmd {
+            XdnsTopologyCmd::Export(cmd) if cmd.from_spec.is_some() =>
+                cmd.run_from_spec::<AccountId>(),
+            XdnsTopologyCmd::Export(cmd) => {
+                construct_async_run!(|components, cli, cmd, config| {
+                    Ok(cmd.run::<AccountId, _, _, _>(components.client))
+                })
+            },
+            XdnsTopologyCmd::Validate(cmd) => cmd.run::<AccountId>(),
+            XdnsTopologyCmd::Import(cmd) => cmd.run::<AccountId>(),
+        },
         Some(Subcommand::ImportBlocks(cmd)) => {
             construct_async_run!(|components, cli, cmd, config| {
                 Ok(cmd.run(components.client, components.import_queue))
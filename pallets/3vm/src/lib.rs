@@ -55,7 +55,7 @@ pub mod pallet {
         account_manager::AccountManager,
         circuit::OnLocalTrigger,
         contract_metadata::ContractType,
-        contracts_registry::ContractsRegistry,
+        contracts_registry::{AuthorInfo, ContractsRegistry},
         portal::Portal,
         threevm::{AddressMapping, VacuumAccess},
         ChainId,
@@ -122,6 +122,12 @@ pub mod pallet {
     #[pallet::getter(fn author_of)]
     pub(crate) type AuthorOf<T: Config> = StorageMap<_, Identity, T::AccountId, T::AccountId>;
 
+    /// A mapping of a contract's address to its author's pricing and revenue splits, charged on
+    /// every call to the contract.
+    #[pallet::storage]
+    pub(crate) type AuthorInfoOf<T: Config> =
+        StorageMap<_, Identity, T::AccountId, AuthorInfo<T::AccountId, BalanceOf<T>>>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -278,9 +284,10 @@ impl<T: Config> Signaller<<T as frame_system::Config>::Hash> for Pallet<T> {
 impl<T: Config> Remuneration<T, BalanceOf<T>> for Pallet<T> {
     fn try_remunerate<Module: ModuleOperations<T, BalanceOf<T>>>(
         payee: &T::AccountId,
+        value: BalanceOf<T>,
         module: &Module,
     ) -> Result<Remunerated<T::Hash>, DispatchError> {
-        remuneration::try_remunerate::<T, Module>(payee, module)
+        remuneration::try_remunerate::<T, Module>(payee, value, module)
     }
 
     fn try_remunerate_exact<Module: ModuleOperations<T, BalanceOf<T>>>(
@@ -291,6 +298,14 @@ impl<T: Config> Remuneration<T, BalanceOf<T>> for Pallet<T> {
         remuneration::try_remunerate_exact::<T, Module>(payee, amount, module)
     }
 
+    fn try_remunerate_call(
+        payee: &T::AccountId,
+        contract: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<Remunerated<T::Hash>, DispatchError> {
+        remuneration::try_remunerate_call::<T>(payee, contract, value)
+    }
+
     fn try_finalize(ledger_id: T::Hash, outcome: Outcome) -> DispatchResult {
         remuneration::try_finalize::<T>(ledger_id, outcome)
     }
@@ -364,6 +379,7 @@ impl<T: Config> ThreeVm<T, BalanceOf<T>> for Pallet<T> {
         if let Some(author) = author {
             if !AuthorOf::<T>::contains_key(contract) {
                 AuthorOf::<T>::insert(contract, author.account.clone());
+                AuthorInfoOf::<T>::insert(contract, author.clone());
                 Self::deposit_event(Event::<T>::AuthorStored((
                     contract.clone(),
                     author.account.clone(),
@@ -376,6 +392,7 @@ impl<T: Config> ThreeVm<T, BalanceOf<T>> for Pallet<T> {
     fn try_remove_author(contract: &T::AccountId) -> Result<(), DispatchError> {
         if AuthorOf::<T>::contains_key(contract) {
             AuthorOf::<T>::remove(contract);
+            AuthorInfoOf::<T>::remove(contract);
             Self::deposit_event(Event::<T>::AuthorRemoved(contract.clone()))
         }
 
@@ -257,6 +257,15 @@ impl t3rn_primitives::rewards::RewardsWriteApi<AccountId32, u64, u32> for Reward
     ) -> bool {
         true
     }
+
+    fn add_pending_claim(
+        _beneficiary: &AccountId,
+        _role: t3rn_primitives::claimable::CircuitRole,
+        _amount: Balance,
+        _benefit_source: t3rn_primitives::claimable::BenefitSource,
+        _non_native_asset_id: Option<u32>,
+    ) {
+    }
 }
 
 impl pallet_circuit_vacuum::Config for Test {
@@ -283,9 +292,12 @@ impl pallet_account_manager::Config for Test {
     type Assets = Assets;
     type Clock = t3rn_primitives::clock::ClockMock<Self>;
     type Currency = Balances;
+    type DisputeOrigin = frame_system::EnsureRoot<Self::AccountId>;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type ReconciliationReportsToKeep = ConstU32<64>;
     type RuntimeEvent = RuntimeEvent;
+    type StuckChargeRounds = ConstU32<10>;
     type Time = Timestamp;
     type WeightInfo = ();
 }
@@ -296,6 +308,7 @@ parameter_types! {
 
 impl pallet_circuit::Config for Test {
     type AccountManager = AccountManager;
+    type Ambassadors = t3rn_primitives::rewards::AmbassadorsEmptyMock<AccountId>;
     type Attesters =
         t3rn_primitives::attesters::AttestersReadApiEmptyMock<AccountId, Balance, DispatchError>;
     type Balances = Balances;
@@ -361,6 +374,11 @@ impl PalletAssetsOverlay<Test, Balance> for Test {
     }
 }
 
+parameter_types! {
+    pub const GatewayRegistrationBond: Balance = 10_000;
+    pub const PendingGatewayMaxOrderValue: Balance = 1_000;
+}
+
 impl pallet_xdns::Config for Test {
     type AssetsOverlay = Test;
     type AttestersRead =
@@ -368,7 +386,14 @@ impl pallet_xdns::Config for Test {
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type GatewayRegistrationBond = GatewayRegistrationBond;
+    type MaxGatewayHistory = ConstU32<4>;
+    type MaxPriceFeeders = ConstU32<8>;
+    type MaxScheduledGatewayChangesPerBlock = ConstU32<4>;
+    type MinPriceFeeds = ConstU32<1>;
+    type PendingGatewayMaxOrderValue = PendingGatewayMaxOrderValue;
     type Portal = Portal;
+    type PriceFeedMaxAge = ConstU32<600>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = CircuitTargetId;
     type SelfTokenId = ConstU32<3333>;
@@ -468,8 +493,12 @@ impl pallet_portal::SelectLightClient<Test> for SelectLightClientRegistry {
 
 impl pallet_portal::Config for Test {
     type Currency = Balances;
+    type MaxRecentSubmissions = ConstU32<16>;
+    type MaxRelayersPerGateway = ConstU32<128>;
+    type Rewards = RewardsMockApi;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type TreasuryAccounts = Test;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Test>;
     type Xdns = Xdns;
 }
@@ -444,11 +444,11 @@ pub(crate) fn invoke<T: Config>(
             Ok(PrecompileInvocation::VacuumOrder(success))
         },
         PrecompileArgs::VacuumConfirm(origin, vacuum_order) => {
-            let success = true;
+            let success = <Pallet<T> as VacuumAccess<T>>::evm_confirm(&origin, vacuum_order)?;
             Ok(PrecompileInvocation::VacuumConfirm(success))
         },
         PrecompileArgs::Vacuum3DOrder(origin, vacuum_order) => {
-            let success = true;
+            let success = <Pallet<T> as VacuumAccess<T>>::evm_3d_order(&origin, vacuum_order)?;
             Ok(PrecompileInvocation::VacuumOrder(success))
         },
         PrecompileArgs::VacuumSubmitCorrectnessProof(origin, vacuum_proof) => {
@@ -456,11 +456,13 @@ pub(crate) fn invoke<T: Config>(
             Ok(PrecompileInvocation::VacuumSubmitCorrectnessProof(success))
         },
         PrecompileArgs::VacuumSubmitFaultProof(origin, vacuum_proof) => {
-            let success = true;
+            let success =
+                <Pallet<T> as VacuumAccess<T>>::evm_submit_fault_proof(&origin, vacuum_proof)?;
             Ok(PrecompileInvocation::VacuumSubmitFaultProof(success))
         },
         PrecompileArgs::VacuumTeleportOrder(origin, vacuum_order) => {
-            let success = true;
+            let success =
+                <Pallet<T> as VacuumAccess<T>>::evm_teleport_order(&origin, vacuum_order)?;
             Ok(PrecompileInvocation::VacuumTeleportOrder(success))
         },
         PrecompileArgs::Signal(origin, signal) => {
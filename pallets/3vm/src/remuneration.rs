@@ -1,5 +1,6 @@
-use crate::{BalanceOf, Config, Error};
+use crate::{AuthorInfoOf, BalanceOf, Config, Error};
 use frame_support::{dispatch::DispatchResult, sp_runtime::traits::Zero};
+use sp_std::vec::Vec;
 use t3rn_primitives::{
     account_manager::{AccountManager, Outcome, RequestCharge},
     claimable::{BenefitSource, CircuitRole},
@@ -11,10 +12,11 @@ const LOG_TARGET: &str = "3vm::remuneration";
 
 pub(crate) fn try_remunerate<T: Config, Module: ModuleOperations<T, BalanceOf<T>>>(
     payee: &T::AccountId,
+    value: BalanceOf<T>,
     module: &Module,
 ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
     if let Some(author) = module.get_author() {
-        let amount = author.fees_per_single_use.unwrap_or_default();
+        let amount = author.fee_for(payee, value);
         handle_remuneration(payee, module, author, amount)
     } else {
         Ok(Remunerated::default())
@@ -33,6 +35,20 @@ pub(crate) fn try_remunerate_exact<T: Config, Module: ModuleOperations<T, Balanc
     }
 }
 
+pub(crate) fn try_remunerate_call<T: Config>(
+    payee: &T::AccountId,
+    contract: &T::AccountId,
+    value: BalanceOf<T>,
+) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
+    // Authors are persisted only for remunerable modules, so the kind is not checked again
+    if let Some(author) = AuthorInfoOf::<T>::get(contract) {
+        let amount = author.fee_for(payee, value);
+        deposit_revenue_shares::<T>(payee, &author, amount)
+    } else {
+        Ok(Remunerated::default())
+    }
+}
+
 pub(crate) fn try_finalize<T: Config>(ledger_id: T::Hash, outcome: Outcome) -> DispatchResult {
     T::AccountManager::finalize(ledger_id, outcome, None, Option::<BalanceOf<T>>::None)
 }
@@ -52,21 +68,7 @@ fn handle_remuneration<T: Config, Module: ModuleOperations<T, BalanceOf<T>>>(
             author.clone()
         );
         if kind.can_remunerate() {
-            let next_charge_id = T::AccountManager::bump_contracts_registry_nonce()?;
-            T::AccountManager::deposit(
-                next_charge_id,
-                RequestCharge {
-                    payee: payee.clone(),
-                    offered_reward: amount,
-                    charge_fee: Zero::zero(),
-                    source: BenefitSource::TrafficRewards,
-                    role: CircuitRole::ContractAuthor,
-                    recipient: Some(author.account.clone()),
-                    maybe_asset_id: None,
-                },
-            )?;
-
-            Ok(Remunerated::<T::Hash>::new(Some(next_charge_id)))
+            deposit_revenue_shares::<T>(payee, author, amount)
         } else {
             Err(Error::<T>::ContractCannotRemunerate.into())
         }
@@ -74,3 +76,30 @@ fn handle_remuneration<T: Config, Module: ModuleOperations<T, BalanceOf<T>>>(
         Ok(Remunerated::<T::Hash>::default())
     }
 }
+
+fn deposit_revenue_shares<T: Config>(
+    payee: &T::AccountId,
+    author: &AuthorInfo<T::AccountId, BalanceOf<T>>,
+    amount: BalanceOf<T>,
+) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
+    // Each author and co-author gets a ledger entry of their own share
+    let mut charge_ids = Vec::new();
+    for (recipient, share) in author.revenue_shares(amount) {
+        let next_charge_id = T::AccountManager::bump_contracts_registry_nonce()?;
+        T::AccountManager::deposit(
+            next_charge_id,
+            RequestCharge {
+                payee: payee.clone(),
+                offered_reward: share,
+                charge_fee: Zero::zero(),
+                source: BenefitSource::TrafficRewards,
+                role: CircuitRole::ContractAuthor,
+                recipient: Some(recipient),
+                maybe_asset_id: None,
+            },
+        )?;
+        charge_ids.push(next_charge_id);
+    }
+
+    Ok(Remunerated::<T::Hash>::new(charge_ids))
+}
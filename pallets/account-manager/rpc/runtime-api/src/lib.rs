@@ -0,0 +1,35 @@
+//! Runtime API definition required by Account Manager RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding access to the state of escrowed deposits and their reconciliation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::sp_std;
+use sp_std::prelude::*;
+pub use t3rn_primitives::{
+    account_manager::{DepositInfo, DepositState, ReconciliationReport},
+    common::RoundIndex,
+};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Account Manager
+    pub trait AccountManagerRuntimeApi<AccountId, Balance, Hash, AssetId, BlockNumber> where
+        AccountId: Codec,
+        Balance: Codec,
+        Hash: Codec,
+        AssetId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the pending deposit of the given charge id, if any
+        fn fetch_deposit(charge_id: Hash) -> Option<DepositInfo<AccountId, Balance, AssetId, BlockNumber>>;
+
+        /// Returns all deposits currently on hold
+        fn fetch_held_deposits() -> Vec<(Hash, DepositInfo<AccountId, Balance, AssetId, BlockNumber>)>;
+
+        /// Returns the escrow reconciliation report of the given round, if still kept
+        fn fetch_reconciliation_report(round: RoundIndex) -> Option<ReconciliationReport<AssetId, Balance>>;
+    }
+}
@@ -0,0 +1,121 @@
+//! RPC interface for the Account Manager pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+pub use pallet_account_manager_rpc_runtime_api::AccountManagerRuntimeApi;
+use pallet_account_manager_rpc_runtime_api::{DepositInfo, ReconciliationReport, RoundIndex};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::sp_std;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+use sp_std::prelude::*;
+use std::sync::Arc;
+
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc(client, server)]
+pub trait AccountManagerApi<AccountId, Balance, Hash, AssetId, BlockNumber> {
+    /// Returns the pending deposit of the given charge id, if any
+    #[method(name = "accountManager_fetchDeposit")]
+    fn fetch_deposit(
+        &self,
+        charge_id: Hash,
+    ) -> RpcResult<Option<DepositInfo<AccountId, Balance, AssetId, BlockNumber>>>;
+
+    /// Returns all deposits currently on hold
+    #[method(name = "accountManager_fetchHeldDeposits")]
+    fn fetch_held_deposits(
+        &self,
+    ) -> RpcResult<Vec<(Hash, DepositInfo<AccountId, Balance, AssetId, BlockNumber>)>>;
+
+    /// Returns the escrow reconciliation report of the given round, if still kept
+    #[method(name = "accountManager_fetchReconciliationReport")]
+    fn fetch_reconciliation_report(
+        &self,
+        round: RoundIndex,
+    ) -> RpcResult<Option<ReconciliationReport<AssetId, Balance>>>;
+}
+
+/// A struct that implements the [`AccountManagerApiServer`].
+pub struct AccountManager<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> AccountManager<C, P> {
+    /// Create new `AccountManager` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, Hash, AssetId, BlockNumber>
+    AccountManagerApiServer<AccountId, Balance, Hash, AssetId, BlockNumber>
+    for AccountManager<C, Block>
+where
+    AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
+    Hash: Codec + MaybeDisplay,
+    AssetId: Codec + MaybeDisplay,
+    BlockNumber: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: AccountManagerRuntimeApi<Block, AccountId, Balance, Hash, AssetId, BlockNumber>,
+{
+    fn fetch_deposit(
+        &self,
+        charge_id: Hash,
+    ) -> RpcResult<Option<DepositInfo<AccountId, Balance, AssetId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_deposit(at, charge_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_held_deposits(
+        &self,
+    ) -> RpcResult<Vec<(Hash, DepositInfo<AccountId, Balance, AssetId, BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_held_deposits(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_reconciliation_report(
+        &self,
+        round: RoundIndex,
+    ) -> RpcResult<Option<ReconciliationReport<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_reconciliation_report(at, round)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(jsonrpsee::types::ErrorObject::owned(
+        RUNTIME_ERROR as i32,
+        "Runtime Error - Account Manager RPC",
+        Some(format!("{err:?}")),
+    )))
+}
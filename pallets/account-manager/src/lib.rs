@@ -9,20 +9,30 @@
 
 pub use crate::pallet::*;
 use frame_support::{
+    dispatch::DispatchResult,
     pallet_prelude::Weight,
-    traits::{fungibles::Inspect, Currency, Get},
+    traits::{fungibles::Inspect, Currency, EnsureOrigin, Get},
+    PalletId,
 };
-use sp_runtime::traits::Convert;
+use frame_system::{ensure_root, pallet_prelude::BlockNumberFor};
+use sp_runtime::traits::{AccountIdConversion, Convert, Saturating, Zero};
+use sp_std::prelude::*;
 
 use t3rn_primitives::{
-    account_manager::{AccountManager, Outcome},
+    account_manager::{
+        AccountManager, AssetReconciliation, DepositInfo, DepositState, EscrowTotals,
+        HeldDepositResolution, Outcome, PendingTotals, ReconciliationReport,
+    },
+    circuit::OrderOrigin,
     claimable::{BenefitSource, CircuitRole},
-    clock::Clock,
-    common::RoundInfo,
+    clock::{Clock, OnNewRound, RoundHandlerId},
+    common::{RoundIndex, RoundInfo},
     executors::Executors,
     reexport_currency_types,
 };
 
+use crate::{monetary::Monetary, weights::WeightInfo};
+
 #[cfg(test)]
 mod tests;
 
@@ -65,6 +75,9 @@ pub mod pallet {
 
         type Currency: ReservableCurrency<Self::AccountId>;
 
+        /// Origin allowed to hold and release deposits under dispute, besides root
+        type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type Assets: Unbalanced<Self::AccountId>;
 
         type Clock: Clock<Self>;
@@ -80,6 +93,14 @@ pub mod pallet {
         type AssetBalanceOf: Convert<BalanceOf<Self>, AssetsBalanceOf<Self>>;
 
         type AssetId: FullCodec + Copy + MaybeSerializeDeserialize + Debug + Default + Eq + TypeInfo;
+
+        /// Pending charges deposited this many rounds ago are reported as stuck
+        #[pallet::constant]
+        type StuckChargeRounds: Get<u32>;
+
+        /// Number of most recent reconciliation reports kept in storage
+        #[pallet::constant]
+        type ReconciliationReportsToKeep: Get<u32>;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -91,6 +112,10 @@ pub mod pallet {
     #[pallet::storage]
     pub type ContractsRegistryExecutionNonce<T: Config> = StorageValue<_, ExecutionId, ValueQuery>;
 
+    /// Version of the last storage migration applied to the account manager.
+    #[pallet::storage]
+    pub type StorageMigrations<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn pending_charges_per_round)]
     pub type PendingCharges<T: Config> = StorageMap<
@@ -119,6 +144,98 @@ pub mod pallet {
         >,
     >;
 
+    /// Deposits frozen pending a dispute, with the block the hold was placed at
+    #[pallet::storage]
+    #[pallet::getter(fn held_charges)]
+    pub type HeldCharges<T: Config> =
+        StorageMap<_, Identity, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+    /// Resolutions requested for deposits on hold, applied once the hold is released
+    #[pallet::storage]
+    #[pallet::getter(fn held_resolutions)]
+    pub type HeldResolutions<T: Config> =
+        StorageMap<_, Identity, T::Hash, HeldDepositResolution, OptionQuery>;
+
+    /// Portion of the offered reward of pending deposits already released by partial finalize
+    #[pallet::storage]
+    #[pallet::getter(fn released_per_charge)]
+    pub type ReleasedPerCharge<T: Config> =
+        StorageMap<_, Identity, T::Hash, BalanceOf<T>, ValueQuery>;
+
+    /// Round index each pending charge was deposited at
+    #[pallet::storage]
+    pub type ChargeDepositRounds<T: Config> = StorageMap<_, Identity, T::Hash, RoundIndex>;
+
+    /// Running totals of the escrow ledger per asset, `None` standing for the native currency
+    #[pallet::storage]
+    #[pallet::getter(fn escrow_totals)]
+    pub type EscrowLedger<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        EscrowTotals<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Running totals of the pending charges per asset
+    #[pallet::storage]
+    #[pallet::getter(fn pending_totals)]
+    pub type PendingLedger<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        PendingTotals<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Running totals of the pending charges per asset paid by remote origins, whose funds
+    /// are held by the remote origin rather than by the escrow holding account
+    #[pallet::storage]
+    #[pallet::getter(fn remote_pending)]
+    pub type RemotePendingLedger<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Running totals of the pending charges per asset deposited at or before `StuckUpToRound`
+    #[pallet::storage]
+    #[pallet::getter(fn stuck_totals)]
+    pub type StuckLedger<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        PendingTotals<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Totals of the pending charges per deposit round and asset not yet counted as stuck
+    #[pallet::storage]
+    pub type PendingPerDepositRound<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Blake2_128Concat,
+        Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        PendingTotals<BalanceOf<T>>,
+    >;
+
+    /// Most recent deposit round whose pending charges are counted in `StuckLedger`
+    #[pallet::storage]
+    pub type StuckUpToRound<T: Config> = StorageValue<_, RoundIndex>;
+
+    /// Reconciliation reports of the most recent rounds
+    #[pallet::storage]
+    #[pallet::getter(fn reconciliation_report)]
+    pub type ReconciliationReports<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        ReconciliationReport<<T::Assets as Inspect<T::AccountId>>::AssetId, BalanceOf<T>>,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(T::DbWeight::get().reads(2) + T::DbWeight::get().writes(1))]
@@ -156,7 +273,7 @@ pub mod pallet {
             .map(|_| ())
         }
 
-        #[pallet::weight(T::DbWeight::get().reads(1) + T::DbWeight::get().writes(1))]
+        #[pallet::weight(T::WeightInfo::finalize())]
         pub fn finalize(
             origin: OriginFor<T>,
             charge_id: T::Hash,
@@ -174,6 +291,56 @@ pub mod pallet {
                 <T::Assets as Inspect<T::AccountId>>::AssetId,
             >>::finalize(charge_id, outcome, maybe_recipient, maybe_actual_fees)
         }
+
+        #[pallet::weight(T::DbWeight::get().reads(3) + T::DbWeight::get().writes(3))]
+        pub fn finalize_partial(
+            origin: OriginFor<T>,
+            charge_id: T::Hash,
+            amount: BalanceOf<T>,
+            outcome: Outcome,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            <Self as AccountManager<
+                T::AccountId,
+                BalanceOf<T>,
+                T::Hash,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >>::finalize_partial(charge_id, amount, outcome)
+        }
+
+        #[pallet::weight(T::DbWeight::get().reads(2) + T::DbWeight::get().writes(1))]
+        pub fn hold_deposit(origin: OriginFor<T>, charge_id: T::Hash) -> DispatchResult {
+            Self::ensure_root_or_dispute_origin(origin)?;
+
+            <Self as AccountManager<
+                T::AccountId,
+                BalanceOf<T>,
+                T::Hash,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >>::hold(charge_id)
+        }
+
+        /// Release the hold of a deposit. The dispute may settle on a resolution overriding the
+        /// one requested while the deposit was on hold, which is applied right away.
+        #[pallet::weight(T::WeightInfo::release_deposit_hold())]
+        pub fn release_deposit_hold(
+            origin: OriginFor<T>,
+            charge_id: T::Hash,
+            maybe_resolution: Option<HeldDepositResolution>,
+        ) -> DispatchResult {
+            Self::ensure_root_or_dispute_origin(origin)?;
+
+            <Self as AccountManager<
+                T::AccountId,
+                BalanceOf<T>,
+                T::Hash,
+                frame_system::pallet_prelude::BlockNumberFor<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >>::release_hold(charge_id, maybe_resolution)
+        }
     }
 
     // Pallet implements [`Hooks`] trait to define some logic to execute in some context.
@@ -194,6 +361,94 @@ pub mod pallet {
             Weight::zero()
         }
 
+        fn on_runtime_upgrade() -> Weight {
+            // Define the current storage migration version.
+            const CURRENT_STORAGE_VERSION: u32 = 2;
+            let version = StorageMigrations::<T>::get();
+            if version >= CURRENT_STORAGE_VERSION {
+                return T::DbWeight::get().reads(1)
+            }
+            let mut reads: u64 = 1;
+            let mut writes: u64 = 1;
+            if version < 1 {
+                // Storage Migration: seed the escrow ledger, the pending totals and the deposit
+                // rounds of the charges pending before they were tracked, as if deposited in the
+                // current round.
+                let round = T::Clock::current_round().index;
+                let mut seeded: u64 = 0;
+                let mut charges: u64 = 0;
+                for (charge_id, charge) in PendingCharges::<T>::iter() {
+                    charges += 1;
+                    if ChargeDepositRounds::<T>::contains_key(charge_id) {
+                        continue
+                    }
+                    let amount = charge.offered_reward.saturating_add(charge.charge_fee);
+                    ChargeDepositRounds::<T>::insert(charge_id, round);
+                    Self::note_in_escrow_ledger(&charge.maybe_asset_id, |totals| {
+                        totals.reserved = totals.reserved.saturating_add(amount)
+                    });
+                    Self::note_pending_deposited(round, &charge.maybe_asset_id, amount);
+                    seeded += 1;
+                }
+                // Seeded charges become stuck `StuckChargeRounds` after the current round, rounds
+                // before it hold no tracked charges and need no catching up on by reconcile.
+                if StuckUpToRound::<T>::get().is_none() {
+                    if let Some(up_to) =
+                        round.checked_sub(T::StuckChargeRounds::get().saturating_add(1))
+                    {
+                        StuckUpToRound::<T>::put(up_to);
+                    }
+                }
+                reads = reads
+                    .saturating_add(charges)
+                    .saturating_add(seeded.saturating_mul(3))
+                    .saturating_add(2);
+                writes = writes
+                    .saturating_add(seeded.saturating_mul(4))
+                    .saturating_add(1);
+            }
+            if version < 2 {
+                // Storage Migration: funds of pending charges were burnt at deposit, so fund the
+                // escrow holding account with what the charges of local payees still hold and note
+                // what the charges of remote payees hold with their remote origin.
+                let mut charges: u64 = 0;
+                for (_charge_id, charge) in PendingCharges::<T>::iter() {
+                    charges += 1;
+                    if OrderOrigin::<T::AccountId>::new(&charge.payee).is_remote() {
+                        RemotePendingLedger::<T>::mutate(&charge.maybe_asset_id, |remote| {
+                            *remote = remote
+                                .saturating_add(charge.offered_reward)
+                                .saturating_add(charge.charge_fee)
+                        });
+                        writes = writes.saturating_add(1);
+                    }
+                }
+                for (maybe_asset_id, pending) in PendingLedger::<T>::iter() {
+                    Self::ensure_escrow_holding_exists(&maybe_asset_id);
+                    let expected = pending
+                        .amount
+                        .saturating_sub(RemotePendingLedger::<T>::get(&maybe_asset_id));
+                    let shortfall =
+                        expected.saturating_sub(Self::escrowed_balance(&maybe_asset_id));
+                    if !shortfall.is_zero() {
+                        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+                            &Self::escrow_holding_account(),
+                            maybe_asset_id,
+                            shortfall,
+                        );
+                    }
+                    reads = reads.saturating_add(4);
+                    writes = writes.saturating_add(2);
+                }
+                // Reports stored before the migration lack the escrowed balances
+                let _ = ReconciliationReports::<T>::clear(u32::MAX, None);
+                reads = reads.saturating_add(charges);
+                writes = writes.saturating_add(T::ReconciliationReportsToKeep::get().into());
+            }
+            StorageMigrations::<T>::put(CURRENT_STORAGE_VERSION);
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
         // A runtime code run after every block and have access to extended set of APIs.
         //
         // For instance you can generate extrinsics for the upcoming produced block.
@@ -222,6 +477,29 @@ pub mod pallet {
             recipient: Option<T::AccountId>,
             amount: BalanceOf<T>,
         },
+        DepositPartiallyFinalized {
+            charge_id: T::Hash,
+            amount: BalanceOf<T>,
+            outcome: Outcome,
+        },
+        DepositHeld {
+            charge_id: T::Hash,
+        },
+        DepositHoldReleased {
+            charge_id: T::Hash,
+        },
+        ReconciliationMismatch {
+            round: RoundIndex,
+            maybe_asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+            reserved: BalanceOf<T>,
+            accounted: BalanceOf<T>,
+        },
+        EscrowBalanceMismatch {
+            round: RoundIndex,
+            maybe_asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+            escrowed: BalanceOf<T>,
+            expected: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -238,6 +516,10 @@ pub mod pallet {
         DecodingExecutionIDFailed,
         TransferDepositFailedOldChargeNotFound,
         TransferDepositFailedToReleasePreviousCharge,
+        ChargeOnHold,
+        ChargeAlreadyOnHold,
+        ChargeNotOnHold,
+        PartialFinalizeExceedsOfferedReward,
     }
 
     #[pallet::genesis_config]
@@ -255,6 +537,208 @@ pub mod pallet {
     }
 }
 
+impl<T: Config> Pallet<T> {
+    fn ensure_root_or_dispute_origin(origin: T::RuntimeOrigin) -> DispatchResult {
+        if T::DisputeOrigin::try_origin(origin.clone()).is_ok() {
+            return Ok(())
+        }
+        ensure_root(origin)?;
+        Ok(())
+    }
+
+    /// Current state of a pending deposit, if any
+    pub fn deposit_info(
+        charge_id: T::Hash,
+    ) -> Option<
+        DepositInfo<
+            T::AccountId,
+            BalanceOf<T>,
+            <T::Assets as Inspect<T::AccountId>>::AssetId,
+            BlockNumberFor<T>,
+        >,
+    > {
+        PendingCharges::<T>::get(charge_id).map(|charge| DepositInfo {
+            charge,
+            state: match HeldCharges::<T>::get(charge_id) {
+                Some(held_at) => DepositState::Held(held_at),
+                None => DepositState::Pending,
+            },
+            released: ReleasedPerCharge::<T>::get(charge_id),
+        })
+    }
+
+    /// All deposits currently on hold
+    pub fn held_deposits() -> Vec<(
+        T::Hash,
+        DepositInfo<
+            T::AccountId,
+            BalanceOf<T>,
+            <T::Assets as Inspect<T::AccountId>>::AssetId,
+            BlockNumberFor<T>,
+        >,
+    )> {
+        HeldCharges::<T>::iter_keys()
+            .filter_map(|charge_id| {
+                Self::deposit_info(charge_id).map(|deposit_info| (charge_id, deposit_info))
+            })
+            .collect()
+    }
+}
+
+/// Id of the account holding the funds of the pending charges.
+pub const ESCROW_HOLDING_ID: PalletId = PalletId(*b"trn/amhd");
+
+impl<T: Config> Pallet<T> {
+    /// Account holding the funds of the pending charges of local payees until they leave escrow
+    pub fn escrow_holding_account() -> T::AccountId {
+        ESCROW_HOLDING_ID.into_account_truncating()
+    }
+
+    /// Check that everything reserved into escrow per asset is accounted for as committed,
+    /// reverted, slashed, fees or still pending, that the escrow holding account actually
+    /// holds the pending funds of local payees, and store the report of the given round.
+    /// Reads the running totals only, so the cost grows with the number of assets rather
+    /// than with the number of pending charges.
+    pub fn reconcile(round: RoundIndex) -> Weight {
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        // Count the charges deposited at rounds that became stuck since the last reconciliation
+        if let Some(stuck_round) = round.checked_sub(T::StuckChargeRounds::get()) {
+            let from = StuckUpToRound::<T>::get().map_or(0, |up_to| up_to.saturating_add(1));
+            for deposit_round in from..=stuck_round {
+                for (maybe_asset_id, totals) in
+                    PendingPerDepositRound::<T>::drain_prefix(deposit_round)
+                {
+                    StuckLedger::<T>::mutate(maybe_asset_id, |stuck| {
+                        stuck.amount = stuck.amount.saturating_add(totals.amount);
+                        stuck.charges = stuck.charges.saturating_add(totals.charges);
+                    });
+                    reads = reads.saturating_add(2);
+                    writes = writes.saturating_add(2);
+                }
+                reads = reads.saturating_add(1);
+            }
+            if from <= stuck_round {
+                StuckUpToRound::<T>::put(stuck_round);
+                writes = writes.saturating_add(1);
+            }
+        }
+
+        let mut assets: Vec<
+            AssetReconciliation<<T::Assets as Inspect<T::AccountId>>::AssetId, BalanceOf<T>>,
+        > = EscrowLedger::<T>::iter()
+            .map(|(maybe_asset_id, totals)| AssetReconciliation {
+                maybe_asset_id,
+                totals,
+                pending: Zero::zero(),
+                stuck: Zero::zero(),
+                stuck_charges: 0,
+                escrowed: Zero::zero(),
+                remote_pending: Zero::zero(),
+                mismatch: false,
+            })
+            .collect();
+        reads = reads.saturating_add(assets.len() as u64);
+
+        for (maybe_asset_id, pending) in PendingLedger::<T>::iter() {
+            let stuck = StuckLedger::<T>::get(&maybe_asset_id);
+            reads = reads.saturating_add(2);
+            let position = match assets
+                .iter()
+                .position(|asset| asset.maybe_asset_id == maybe_asset_id)
+            {
+                Some(position) => position,
+                None => {
+                    assets.push(AssetReconciliation {
+                        maybe_asset_id,
+                        totals: Default::default(),
+                        pending: Zero::zero(),
+                        stuck: Zero::zero(),
+                        stuck_charges: 0,
+                        escrowed: Zero::zero(),
+                        remote_pending: Zero::zero(),
+                        mismatch: false,
+                    });
+                    assets.len() - 1
+                },
+            };
+            let asset = &mut assets[position];
+            asset.pending = pending.amount;
+            asset.stuck = stuck.amount;
+            asset.stuck_charges = stuck.charges;
+        }
+
+        for asset in assets.iter_mut() {
+            let accounted = asset
+                .totals
+                .committed
+                .saturating_add(asset.totals.reverted)
+                .saturating_add(asset.totals.slashed)
+                .saturating_add(asset.totals.fees)
+                .saturating_add(asset.pending);
+            if accounted != asset.totals.reserved {
+                asset.mismatch = true;
+                log::error!(
+                    "AccountManager::reconcile mismatch at round {:?} for asset {:?}: reserved {:?}, accounted {:?}",
+                    round,
+                    asset.maybe_asset_id,
+                    asset.totals.reserved,
+                    accounted
+                );
+                Self::deposit_event(Event::ReconciliationMismatch {
+                    round,
+                    maybe_asset_id: asset.maybe_asset_id.clone(),
+                    reserved: asset.totals.reserved,
+                    accounted,
+                });
+            }
+
+            asset.escrowed = Self::escrowed_balance(&asset.maybe_asset_id);
+            asset.remote_pending = RemotePendingLedger::<T>::get(&asset.maybe_asset_id);
+            reads = reads.saturating_add(3);
+            let expected = asset.pending.saturating_sub(asset.remote_pending);
+            if asset.escrowed != expected {
+                asset.mismatch = true;
+                log::error!(
+                    "AccountManager::reconcile escrow balance mismatch at round {:?} for asset {:?}: escrowed {:?}, expected {:?}",
+                    round,
+                    asset.maybe_asset_id,
+                    asset.escrowed,
+                    expected
+                );
+                Self::deposit_event(Event::EscrowBalanceMismatch {
+                    round,
+                    maybe_asset_id: asset.maybe_asset_id.clone(),
+                    escrowed: asset.escrowed,
+                    expected,
+                });
+            }
+        }
+
+        ReconciliationReports::<T>::insert(round, ReconciliationReport { round, assets });
+        writes = writes.saturating_add(1);
+        if let Some(expired) = round.checked_sub(T::ReconciliationReportsToKeep::get()) {
+            ReconciliationReports::<T>::remove(expired);
+            writes = writes.saturating_add(1);
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+/// Id under which the escrow reconciliation is registered as a round handler with the clock.
+pub const RECONCILIATION_ROUND_HANDLER: RoundHandlerId = *b"escrowrc";
+
+impl<T: Config> OnNewRound<T> for Pallet<T> {
+    fn on_new_round(
+        past_round: RoundInfo<BlockNumberFor<T>>,
+        _new_round: RoundInfo<BlockNumberFor<T>>,
+    ) -> Weight {
+        Self::reconcile(past_round.index)
+    }
+}
+
 impl<T: Config> Convert<Weight, BalanceOf<T>> for Pallet<T>
 where
     <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance:
@@ -1,23 +1,31 @@
 use crate::{
-    AccountManager as AccountManagerExt, BalanceOf, Config, ContractsRegistryExecutionNonce, Error,
-    Outcome, Pallet, PendingCharges, SettlementsPerRound,
+    AccountManager as AccountManagerExt, BalanceOf, ChargeDepositRounds, Config,
+    ContractsRegistryExecutionNonce, Error, EscrowLedger, HeldCharges, HeldDepositResolution,
+    HeldResolutions, Outcome, Pallet, PendingCharges, PendingLedger, PendingPerDepositRound,
+    ReleasedPerCharge, RemotePendingLedger, SettlementsPerRound, StuckLedger, StuckUpToRound,
 };
 
 use codec::{Decode, Encode};
 use frame_support::{
     dispatch::DispatchResult,
-    traits::{fungibles::Inspect, Get},
+    ensure,
+    traits::{
+        fungibles::{Inspect, Unbalanced},
+        tokens::Precision::Exact,
+        Currency, Get,
+    },
 };
 use sp_runtime::{
-    traits::{CheckedAdd, CheckedDiv, CheckedMul, Convert, Zero},
-    ArithmeticError, DispatchError,
+    traits::{CheckedAdd, CheckedDiv, CheckedMul, Convert, Saturating, Zero},
+    ArithmeticError, DispatchError, SaturatedConversion,
 };
 use sp_std::prelude::*;
 
 use t3rn_primitives::{
-    account_manager::{RequestCharge, Settlement},
+    account_manager::{EscrowTotals, PendingTotals, RequestCharge, Settlement},
     claimable::CircuitRole,
     clock::Clock,
+    common::{RoundIndex, RoundInfo},
 };
 
 use crate::monetary::Monetary;
@@ -34,6 +42,282 @@ pub fn percent_ratio<BalanceOf: Zero + CheckedDiv + CheckedMul + From<u8>>(
         .ok_or::<DispatchError>("PercentRatio::ChargeOrSettlementCalculationOverflow".into())
 }
 
+impl<T: Config> Pallet<T> {
+    /// Move the funds of a deposited charge from the payee into the escrow holding account.
+    /// Remote payees are settled by their remote origin, so only their pending amount is noted.
+    fn escrow_from(
+        payee: &T::AccountId,
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        if OrderOrigin::<T::AccountId>::new(payee).is_remote() {
+            RemotePendingLedger::<T>::mutate(maybe_asset_id, |remote| {
+                *remote = remote.saturating_add(amount)
+            });
+            return Ok(())
+        }
+        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::withdraw(
+            payee,
+            amount,
+            maybe_asset_id.clone(),
+        )?;
+        Self::ensure_escrow_holding_exists(maybe_asset_id);
+        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+            &Self::escrow_holding_account(),
+            maybe_asset_id.clone(),
+            amount,
+        );
+        Ok(())
+    }
+
+    /// Take funds of a pending charge out of the escrow holding account as they leave escrow.
+    /// A failure leaves the holding account short of the pending totals, which is reported
+    /// by `reconcile`.
+    pub(crate) fn release_from_escrow(
+        payee: &T::AccountId,
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) {
+        if amount.is_zero() {
+            return
+        }
+        if OrderOrigin::<T::AccountId>::new(payee).is_remote() {
+            RemotePendingLedger::<T>::mutate(maybe_asset_id, |remote| {
+                *remote = remote.saturating_sub(amount)
+            });
+            return
+        }
+        if let Err(e) =
+            Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::withdraw(
+                &Self::escrow_holding_account(),
+                amount,
+                maybe_asset_id.clone(),
+            )
+        {
+            log::error!(
+                "AccountManager::release_from_escrow failed for asset {:?} and amount {:?}: {:?}",
+                maybe_asset_id,
+                amount,
+                e
+            );
+        }
+    }
+
+    /// Pay funds of a pending charge out of the escrow holding account to the beneficiary.
+    fn pay_out_of_escrow(
+        payee: &T::AccountId,
+        beneficiary: &T::AccountId,
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) {
+        Self::release_from_escrow(payee, maybe_asset_id, amount);
+        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+            beneficiary,
+            maybe_asset_id.clone(),
+            amount,
+        );
+    }
+
+    /// Keep the escrow holding account alive at the minimum balance of the asset, so that
+    /// deposits below it are not lost and releases never reap the account.
+    pub(crate) fn ensure_escrow_holding_exists(
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+    ) {
+        let holding = Self::escrow_holding_account();
+        if T::Currency::total_balance(&holding).is_zero() {
+            let _ = T::Currency::deposit_creating(&holding, T::Currency::minimum_balance());
+        }
+        if let Some(asset_id) = maybe_asset_id {
+            if T::Assets::balance(asset_id.clone(), &holding).is_zero() {
+                let _ = T::Assets::increase_balance(
+                    asset_id.clone(),
+                    &holding,
+                    T::Assets::minimum_balance(asset_id.clone()),
+                    Exact,
+                );
+            }
+        }
+    }
+
+    /// Funds held in the escrow holding account in the given asset, above its minimum balance
+    pub fn escrowed_balance(
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+    ) -> BalanceOf<T> {
+        let holding = Self::escrow_holding_account();
+        match maybe_asset_id {
+            None =>
+                T::Currency::free_balance(&holding).saturating_sub(T::Currency::minimum_balance()),
+            Some(asset_id) => T::Assets::balance(asset_id.clone(), &holding)
+                .saturating_sub(T::Assets::minimum_balance(asset_id.clone()))
+                .saturated_into::<u128>()
+                .saturated_into(),
+        }
+    }
+
+    /// Pay out the charge fee of a pending charge to the recipient in the charged asset, returning
+    /// the reserved fees exceeding the actual fees to the payee. Without a recipient the fees
+    /// remain with the charge and land in Escrow on finalize.
+    fn settle_charge_fee(
+        charge_id: T::Hash,
+        maybe_recipient: Option<T::AccountId>,
+        maybe_actual_fees: Option<BalanceOf<T>>,
+    ) -> DispatchResult {
+        let mut charge =
+            PendingCharges::<T>::get(charge_id).ok_or(Error::<T>::PendingChargeNotFoundAtCommit)?;
+        let actual_fees = maybe_actual_fees.unwrap_or(charge.charge_fee);
+        if actual_fees > charge.charge_fee {
+            return Err(Error::<T>::ChargeOrSettlementActualFeesOutgrowReserved.into())
+        }
+
+        let excess_fees = charge.charge_fee - actual_fees;
+        if !excess_fees.is_zero() {
+            Self::pay_out_of_escrow(
+                &charge.payee,
+                &charge.payee,
+                &charge.maybe_asset_id,
+                excess_fees,
+            );
+            Self::note_in_escrow_ledger(&charge.maybe_asset_id, |totals| {
+                totals.reverted = totals.reverted.saturating_add(excess_fees)
+            });
+        }
+        charge.charge_fee = actual_fees;
+        Self::note_pending_released(charge_id, &charge.maybe_asset_id, excess_fees, false);
+
+        if let Some(recipient) = maybe_recipient {
+            if !actual_fees.is_zero() {
+                Self::pay_out_of_escrow(
+                    &charge.payee,
+                    &recipient,
+                    &charge.maybe_asset_id,
+                    actual_fees,
+                );
+                Self::note_in_escrow_ledger(&charge.maybe_asset_id, |totals| {
+                    totals.fees = totals.fees.saturating_add(actual_fees)
+                });
+            }
+            charge.charge_fee = Zero::zero();
+            Self::note_pending_released(charge_id, &charge.maybe_asset_id, actual_fees, false);
+        }
+
+        PendingCharges::<T>::insert(charge_id, charge);
+        Ok(())
+    }
+
+    /// Record a committed reward in the current round, adding up to the settlement of
+    /// the same charge committed earlier in the round by partial finalize.
+    fn accrue_settlement(
+        charge_id: T::Hash,
+        settlement: Settlement<
+            T::AccountId,
+            BalanceOf<T>,
+            <T::Assets as Inspect<T::AccountId>>::AssetId,
+        >,
+    ) {
+        SettlementsPerRound::<T>::mutate(
+            T::Clock::current_round(),
+            charge_id,
+            |maybe_settlement| match maybe_settlement {
+                Some(existing) => {
+                    existing.settlement_amount = existing
+                        .settlement_amount
+                        .saturating_add(settlement.settlement_amount);
+                },
+                None => *maybe_settlement = Some(settlement),
+            },
+        );
+    }
+
+    fn remove_charge(charge_id: T::Hash) {
+        if let Some(charge) = PendingCharges::<T>::take(charge_id) {
+            Self::note_pending_released(
+                charge_id,
+                &charge.maybe_asset_id,
+                charge.offered_reward.saturating_add(charge.charge_fee),
+                true,
+            );
+        }
+        ReleasedPerCharge::<T>::remove(charge_id);
+        ChargeDepositRounds::<T>::remove(charge_id);
+    }
+
+    /// Add a deposited charge to the running totals of pending charges
+    pub(crate) fn note_pending_deposited(
+        round: RoundIndex,
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) {
+        let add = |totals: &mut PendingTotals<BalanceOf<T>>| {
+            totals.amount = totals.amount.saturating_add(amount);
+            totals.charges = totals.charges.saturating_add(1);
+        };
+        PendingLedger::<T>::mutate(maybe_asset_id, add);
+        PendingPerDepositRound::<T>::mutate(round, maybe_asset_id, |maybe_totals| {
+            add(maybe_totals.get_or_insert_with(Default::default))
+        });
+    }
+
+    /// Subtract the amount leaving escrow of a pending charge from the running totals,
+    /// together with the charge itself once removed.
+    fn note_pending_released(
+        charge_id: T::Hash,
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+        is_removed: bool,
+    ) {
+        if amount.is_zero() && !is_removed {
+            return
+        }
+        let subtract = |totals: &mut PendingTotals<BalanceOf<T>>| {
+            totals.amount = totals.amount.saturating_sub(amount);
+            if is_removed {
+                totals.charges = totals.charges.saturating_sub(1);
+            }
+        };
+        PendingLedger::<T>::mutate(maybe_asset_id, subtract);
+        match ChargeDepositRounds::<T>::get(charge_id) {
+            Some(deposited)
+                if StuckUpToRound::<T>::get().map_or(false, |up_to| deposited <= up_to) =>
+                StuckLedger::<T>::mutate(maybe_asset_id, subtract),
+            Some(deposited) => PendingPerDepositRound::<T>::mutate_exists(
+                deposited,
+                maybe_asset_id,
+                |maybe_totals| {
+                    if let Some(totals) = maybe_totals {
+                        subtract(totals);
+                        if totals.charges == 0 {
+                            *maybe_totals = None;
+                        }
+                    }
+                },
+            ),
+            None => {},
+        }
+    }
+
+    /// Book a movement of escrowed funds in the ledger of the charged asset
+    pub(crate) fn note_in_escrow_ledger(
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        note: impl FnOnce(&mut EscrowTotals<BalanceOf<T>>),
+    ) {
+        EscrowLedger::<T>::mutate(maybe_asset_id, note)
+    }
+
+    /// Book a portion of the offered reward leaving escrow with the given outcome
+    fn note_reward_released(
+        maybe_asset_id: &Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        outcome: &Outcome,
+        amount: BalanceOf<T>,
+    ) {
+        Self::note_in_escrow_ledger(maybe_asset_id, |totals| match outcome {
+            Outcome::Commit => totals.committed = totals.committed.saturating_add(amount),
+            Outcome::Slash => totals.slashed = totals.slashed.saturating_add(amount),
+            Outcome::UnexpectedFailure | Outcome::Revert =>
+                totals.reverted = totals.reverted.saturating_add(amount),
+        })
+    }
+}
+
 impl<T: Config>
     AccountManagerExt<
         T::AccountId,
@@ -77,8 +361,17 @@ impl<T: Config>
         T::AccountId,
         Settlement<T::AccountId, BalanceOf<T>, <T::Assets as Inspect<T::AccountId>>::AssetId>,
     )> {
-        let settlements = SettlementsPerRound::<T>::iter_prefix_values(T::Clock::current_round());
-        settlements
+        Self::get_round_settlements_by_role(T::Clock::current_round(), role)
+    }
+
+    fn get_round_settlements_by_role(
+        round: RoundInfo<frame_system::pallet_prelude::BlockNumberFor<T>>,
+        role: CircuitRole,
+    ) -> Vec<(
+        T::AccountId,
+        Settlement<T::AccountId, BalanceOf<T>, <T::Assets as Inspect<T::AccountId>>::AssetId>,
+    )> {
+        SettlementsPerRound::<T>::iter_prefix_values(round)
             .filter(|settlement| settlement.role == role)
             .map(|settlement| (settlement.recipient.clone(), settlement))
             .collect()
@@ -153,12 +446,24 @@ impl<T: Config>
         }
 
         for (charge_id, request_charge, total_deposit) in validated_requests {
-            Self::withdraw_immediately(
+            Self::escrow_from(
                 &request_charge.payee,
+                &request_charge.maybe_asset_id,
                 total_deposit,
-                request_charge.maybe_asset_id.clone(),
             )?;
+            let round = T::Clock::current_round().index;
             PendingCharges::<T>::insert(charge_id, request_charge.clone());
+            ChargeDepositRounds::<T>::insert(charge_id, round);
+            Self::note_pending_deposited(
+                round,
+                &request_charge.maybe_asset_id,
+                request_charge
+                    .offered_reward
+                    .saturating_add(request_charge.charge_fee),
+            );
+            Self::note_in_escrow_ledger(&request_charge.maybe_asset_id, |totals| {
+                totals.reserved = totals.reserved.saturating_add(total_deposit)
+            });
             Self::deposit_event(crate::Event::DepositReceived {
                 charge_id,
                 payee: request_charge.payee.clone(),
@@ -190,14 +495,25 @@ impl<T: Config>
     fn finalize(
         charge_id: T::Hash,
         outcome: Outcome,
-        _maybe_recipient: Option<T::AccountId>,
-        _maybe_actual_fees: Option<BalanceOf<T>>,
+        maybe_recipient: Option<T::AccountId>,
+        maybe_actual_fees: Option<BalanceOf<T>>,
     ) -> DispatchResult {
+        ensure!(!Self::is_held(charge_id), Error::<T>::ChargeOnHold);
+        if maybe_recipient.is_some() || maybe_actual_fees.is_some() {
+            Self::settle_charge_fee(charge_id, maybe_recipient, maybe_actual_fees)?;
+        }
         let _ = Self::finalize_infallible(charge_id, outcome);
         Ok(())
     }
 
     fn finalize_infallible(charge_id: T::Hash, outcome: Outcome) -> bool {
+        if Self::is_held(charge_id) {
+            if !PendingCharges::<T>::contains_key(charge_id) {
+                return false
+            }
+            HeldResolutions::<T>::insert(charge_id, HeldDepositResolution::Finalize(outcome));
+            return true
+        }
         if let Some(charge) = PendingCharges::<T>::get(charge_id) {
             // Infallible recipient assignment to Escrow
             let recipient = match charge.recipient {
@@ -208,53 +524,69 @@ impl<T: Config>
             let maybe_asset_id = charge.maybe_asset_id.clone();
 
             if charge.offered_reward > Zero::zero() {
+                Self::note_reward_released(&maybe_asset_id, &outcome, charge.offered_reward);
                 match outcome {
                     Outcome::Commit => {
                         // Skip remote origin settlements - they are handled by the remote origin
                         if OrderOrigin::<T::AccountId>::new(&charge.payee).is_remote() {
-                            PendingCharges::<T>::remove(charge_id);
+                            Self::note_in_escrow_ledger(&maybe_asset_id, |totals| {
+                                totals.fees = totals.fees.saturating_add(charge.charge_fee)
+                            });
+                            Self::release_from_escrow(
+                                &charge.payee,
+                                &maybe_asset_id,
+                                charge.offered_reward.saturating_add(charge.charge_fee),
+                            );
+                            Self::remove_charge(charge_id);
                             return false
                         }
 
                         // Deposit funds out of Escrow executions to recipient account immediately
                         if charge.source == BenefitSource::EscrowUnlock {
-                            Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+                            Self::pay_out_of_escrow(
+                                &charge.payee,
                                 &recipient,
-                                maybe_asset_id,
+                                &maybe_asset_id,
                                 charge.offered_reward,
                             );
-                            return true
+                        } else {
+                            // Settlements are paid out on claim, so the reward leaves escrow now
+                            Self::release_from_escrow(
+                                &charge.payee,
+                                &maybe_asset_id,
+                                charge.offered_reward,
+                            );
+                            Self::accrue_settlement(
+                                charge_id,
+                                Settlement::<
+                                    T::AccountId,
+                                    BalanceOf<T>,
+                                    <T::Assets as Inspect<T::AccountId>>::AssetId,
+                                > {
+                                    requester: charge.payee,
+                                    recipient,
+                                    settlement_amount: charge.offered_reward,
+                                    outcome,
+                                    source: charge.source,
+                                    role: charge.role,
+                                    maybe_asset_id,
+                                },
+                            );
                         }
-
-                        SettlementsPerRound::<T>::insert(
-                            T::Clock::current_round(),
-                            charge_id,
-                            Settlement::<
-                                T::AccountId,
-                                BalanceOf<T>,
-                                <T::Assets as Inspect<T::AccountId>>::AssetId,
-                            > {
-                                requester: charge.payee,
-                                recipient,
-                                settlement_amount: charge.offered_reward,
-                                outcome,
-                                source: charge.source,
-                                role: charge.role,
-                                maybe_asset_id,
-                            },
-                        );
                     },
                     Outcome::Slash => {
-                        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+                        Self::pay_out_of_escrow(
+                            &charge.payee,
                             &T::EscrowAccount::get(),
-                            maybe_asset_id,
+                            &maybe_asset_id,
                             charge.offered_reward,
                         );
                     },
                     Outcome::UnexpectedFailure | Outcome::Revert => {
-                        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+                        Self::pay_out_of_escrow(
+                            &charge.payee,
                             &charge.payee,
-                            maybe_asset_id,
+                            &maybe_asset_id,
                             charge.offered_reward,
                         );
                     },
@@ -263,28 +595,191 @@ impl<T: Config>
 
             // Take charge fee to treasury
             if charge.charge_fee > Zero::zero() {
-                Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+                Self::note_in_escrow_ledger(&charge.maybe_asset_id, |totals| {
+                    totals.fees = totals.fees.saturating_add(charge.charge_fee)
+                });
+                Self::pay_out_of_escrow(
+                    &charge.payee,
                     &T::EscrowAccount::get(),
-                    charge.maybe_asset_id,
+                    &charge.maybe_asset_id,
                     charge.charge_fee,
                 );
             }
-            PendingCharges::<T>::remove(charge_id);
+            Self::remove_charge(charge_id);
             true
         } else {
             false
         }
     }
 
+    fn finalize_partial(
+        charge_id: T::Hash,
+        amount: BalanceOf<T>,
+        outcome: Outcome,
+    ) -> DispatchResult {
+        ensure!(!Self::is_held(charge_id), Error::<T>::ChargeOnHold);
+        ensure!(!amount.is_zero(), Error::<T>::SkippingEmptyCharges);
+        let mut charge =
+            PendingCharges::<T>::get(charge_id).ok_or(Error::<T>::NoChargeOfGivenIdRegistered)?;
+        ensure!(
+            amount <= charge.offered_reward,
+            Error::<T>::PartialFinalizeExceedsOfferedReward
+        );
+
+        let recipient = charge
+            .recipient
+            .clone()
+            .unwrap_or_else(T::EscrowAccount::get);
+        let maybe_asset_id = charge.maybe_asset_id.clone();
+
+        match outcome {
+            Outcome::Commit if OrderOrigin::<T::AccountId>::new(&charge.payee).is_remote() => {
+                // Skip remote origin settlements - they are handled by the remote origin
+                Self::release_from_escrow(&charge.payee, &maybe_asset_id, amount);
+            },
+            Outcome::Commit if charge.source == BenefitSource::EscrowUnlock => {
+                Self::pay_out_of_escrow(&charge.payee, &recipient, &maybe_asset_id, amount);
+            },
+            Outcome::Commit => {
+                Self::release_from_escrow(&charge.payee, &maybe_asset_id, amount);
+                Self::accrue_settlement(
+                    charge_id,
+                    Settlement {
+                        requester: charge.payee.clone(),
+                        recipient,
+                        settlement_amount: amount,
+                        outcome: outcome.clone(),
+                        source: charge.source.clone(),
+                        role: charge.role.clone(),
+                        maybe_asset_id,
+                    },
+                );
+            },
+            Outcome::Slash => {
+                Self::pay_out_of_escrow(
+                    &charge.payee,
+                    &T::EscrowAccount::get(),
+                    &maybe_asset_id,
+                    amount,
+                );
+            },
+            Outcome::UnexpectedFailure | Outcome::Revert => {
+                Self::pay_out_of_escrow(&charge.payee, &charge.payee, &maybe_asset_id, amount);
+            },
+        }
+
+        Self::note_reward_released(&charge.maybe_asset_id, &outcome, amount);
+        Self::note_pending_released(charge_id, &charge.maybe_asset_id, amount, false);
+        charge.offered_reward -= amount;
+        PendingCharges::<T>::insert(charge_id, charge);
+        ReleasedPerCharge::<T>::mutate(charge_id, |released| {
+            *released = released.saturating_add(amount)
+        });
+        Self::deposit_event(crate::Event::DepositPartiallyFinalized {
+            charge_id,
+            amount,
+            outcome,
+        });
+        Ok(())
+    }
+
+    fn hold(charge_id: T::Hash) -> DispatchResult {
+        ensure!(
+            PendingCharges::<T>::contains_key(charge_id),
+            Error::<T>::NoChargeOfGivenIdRegistered
+        );
+        ensure!(!Self::is_held(charge_id), Error::<T>::ChargeAlreadyOnHold);
+        HeldCharges::<T>::insert(charge_id, frame_system::Pallet::<T>::block_number());
+        Self::deposit_event(crate::Event::DepositHeld { charge_id });
+        Ok(())
+    }
+
+    fn release_hold(
+        charge_id: T::Hash,
+        maybe_resolution: Option<HeldDepositResolution>,
+    ) -> DispatchResult {
+        ensure!(Self::is_held(charge_id), Error::<T>::ChargeNotOnHold);
+        HeldCharges::<T>::remove(charge_id);
+        Self::deposit_event(crate::Event::DepositHoldReleased { charge_id });
+        // Apply the resolution the dispute settled on, or else the one requested while on hold
+        let requested = HeldResolutions::<T>::take(charge_id);
+        match maybe_resolution.or(requested) {
+            Some(HeldDepositResolution::Finalize(outcome)) => {
+                let _ = Self::finalize_infallible(charge_id, outcome);
+            },
+            Some(HeldDepositResolution::Cancel) => {
+                let _ = Self::cancel_deposit(charge_id);
+            },
+            None => {},
+        }
+        Ok(())
+    }
+
+    fn is_held(charge_id: T::Hash) -> bool {
+        HeldCharges::<T>::contains_key(charge_id)
+    }
+
     fn cancel_deposit(charge_id: T::Hash) -> bool {
+        if Self::is_held(charge_id) {
+            if !PendingCharges::<T>::contains_key(charge_id) {
+                return false
+            }
+            HeldResolutions::<T>::insert(charge_id, HeldDepositResolution::Cancel);
+            return true
+        }
         match PendingCharges::<T>::get(charge_id) {
             Some(charge) => {
-                Self::deposit_immediately(
+                // The charge fee is retained on cancellation
+                Self::note_in_escrow_ledger(&charge.maybe_asset_id, |totals| {
+                    totals.reverted = totals.reverted.saturating_add(charge.offered_reward);
+                    totals.fees = totals.fees.saturating_add(charge.charge_fee);
+                });
+                Self::pay_out_of_escrow(
+                    &charge.payee,
                     &charge.payee,
+                    &charge.maybe_asset_id,
                     charge.offered_reward,
-                    charge.maybe_asset_id,
                 );
-                PendingCharges::<T>::remove(charge_id);
+                Self::release_from_escrow(&charge.payee, &charge.maybe_asset_id, charge.charge_fee);
+                Self::remove_charge(charge_id);
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn split_settlement(
+        settlement_id: T::Hash,
+        new_settlement_id: T::Hash,
+        beneficiary: &T::AccountId,
+        role: CircuitRole,
+        amount: BalanceOf<T>,
+    ) -> bool {
+        let current_round = T::Clock::current_round();
+        if amount.is_zero()
+            || SettlementsPerRound::<T>::contains_key(current_round, new_settlement_id)
+        {
+            return false
+        }
+        let maybe_split =
+            SettlementsPerRound::<T>::mutate(current_round, settlement_id, |maybe_settlement| {
+                match maybe_settlement {
+                    Some(settlement) if settlement.settlement_amount >= amount => {
+                        settlement.settlement_amount -= amount;
+                        Some(Settlement {
+                            recipient: beneficiary.clone(),
+                            settlement_amount: amount,
+                            role: role.clone(),
+                            ..settlement.clone()
+                        })
+                    },
+                    _ => None,
+                }
+            });
+
+        match maybe_split {
+            Some(split) => {
+                SettlementsPerRound::<T>::insert(current_round, new_settlement_id, split);
                 true
             },
             None => false,
@@ -308,6 +803,7 @@ impl<T: Config>
         new_payee: Option<&T::AccountId>,
         new_recipient: Option<&T::AccountId>,
     ) -> DispatchResult {
+        ensure!(!Self::is_held(charge_id), Error::<T>::ChargeOnHold);
         match PendingCharges::<T>::get(charge_id) {
             Some(charge) => {
                 let offered_reward = if let Some(reward) = new_reward {
@@ -403,12 +899,13 @@ mod tests {
     use super::*;
     use circuit_mock_runtime::*;
 
-    use frame_support::{assert_err, assert_ok};
+    use frame_support::{assert_err, assert_ok, traits::Hooks};
 
     pub use frame_support::traits::Currency;
 
     use sp_core::H256;
     use t3rn_primitives::{
+        account_manager::{AssetReconciliation, DepositInfo, DepositState},
         claimable::{BenefitSource, CircuitRole},
         common::RoundInfo,
         Balance,
@@ -446,6 +943,10 @@ mod tests {
                 Balances::free_balance(&ALICE),
                 DEFAULT_BALANCE - DEPOSIT_AMOUNT
             );
+            assert_eq!(
+                AccountManager::escrowed_balance(&None::<AssetId>),
+                DEPOSIT_AMOUNT
+            );
 
             let charge_item =
                 AccountManager::pending_charges_per_round::<H256>(execution_id).unwrap();
@@ -487,6 +988,11 @@ mod tests {
             ));
 
             assert_eq!(Balances::free_balance(&remote_origin_account_here), 0);
+            assert_eq!(AccountManager::escrowed_balance(&None::<AssetId>), 0);
+            assert_eq!(
+                AccountManager::remote_pending(None::<AssetId>),
+                DEPOSIT_AMOUNT + 10
+            );
 
             let charge_item =
                 AccountManager::pending_charges_per_round::<H256>(execution_id).unwrap();
@@ -862,4 +1368,511 @@ mod tests {
             assert_eq!(settlement, None);
         });
     }
+
+    #[test]
+    fn test_finalize_pays_actual_fees_to_recipient_and_returns_excess_to_payee() {
+        ExtBuilder::default().build().execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let _ = Balances::deposit_creating(&CHARLIE, DEFAULT_BALANCE);
+            let reserved_fees = 100;
+            let actual_fees = 60;
+            let execution_id: H256 = H256::repeat_byte(0);
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(
+                execution_id,
+                RequestCharge {
+                    payee: ALICE,
+                    offered_reward: 0,
+                    charge_fee: reserved_fees,
+                    source: BenefitSource::TrafficFees,
+                    role: CircuitRole::Requester,
+                    recipient: None,
+                    maybe_asset_id: None
+                }
+            ));
+
+            assert_err!(
+                <AccountManager as AccountManagerExt<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::finalize(
+                    execution_id,
+                    Outcome::Commit,
+                    Some(CHARLIE),
+                    Some(reserved_fees + 1),
+                ),
+                pallet_account_manager::Error::<Runtime>::ChargeOrSettlementActualFeesOutgrowReserved
+            );
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize(
+                execution_id,
+                Outcome::Commit,
+                Some(CHARLIE),
+                Some(actual_fees),
+            ));
+
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE - actual_fees);
+            assert_eq!(Balances::free_balance(&CHARLIE), DEFAULT_BALANCE + actual_fees);
+            assert_eq!(
+                AccountManager::pending_charges_per_round::<H256>(execution_id,),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn test_finalize_partial_refunds_and_settles_portions_of_the_offered_reward() {
+        ExtBuilder::default().build().execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let _ = Balances::deposit_creating(&BOB, DEFAULT_BALANCE);
+            let charge_amt = 100;
+            let execution_id: H256 = H256::repeat_byte(0);
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(
+                execution_id,
+                RequestCharge {
+                    payee: ALICE,
+                    offered_reward: charge_amt,
+                    charge_fee: 0,
+                    source: BenefitSource::TrafficRewards,
+                    role: CircuitRole::Executor,
+                    recipient: Some(BOB),
+                    maybe_asset_id: None
+                }
+            ));
+
+            // Only part of the order executed - refund the rest to the requester
+            assert_ok!(AccountManager::finalize_partial(
+                RuntimeOrigin::root(),
+                execution_id,
+                30,
+                Outcome::Revert,
+            ));
+            assert_eq!(
+                Balances::free_balance(&ALICE),
+                DEFAULT_BALANCE - charge_amt + 30
+            );
+
+            assert_ok!(AccountManager::finalize_partial(
+                RuntimeOrigin::root(),
+                execution_id,
+                50,
+                Outcome::Commit,
+            ));
+            assert_err!(
+                AccountManager::finalize_partial(
+                    RuntimeOrigin::root(),
+                    execution_id,
+                    21,
+                    Outcome::Commit,
+                ),
+                pallet_account_manager::Error::<Runtime>::PartialFinalizeExceedsOfferedReward
+            );
+
+            let deposit_info = AccountManager::deposit_info(execution_id).unwrap();
+            assert_eq!(deposit_info.charge.offered_reward, 20);
+            assert_eq!(deposit_info.released, 80);
+            assert_eq!(deposit_info.state, DepositState::Pending);
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize(
+                execution_id, Outcome::Commit, None, None,
+            ));
+
+            let settlement = AccountManager::settlements_per_round::<RoundInfo<BlockNumber>, H256>(
+                Default::default(),
+                execution_id,
+            )
+            .unwrap();
+            assert_eq!(settlement.recipient, BOB);
+            assert_eq!(settlement.settlement_amount, 70);
+            assert_eq!(AccountManager::deposit_info(execution_id), None);
+            assert_eq!(AccountManager::released_per_charge(execution_id), 0);
+        });
+    }
+
+    #[test]
+    fn test_held_deposit_defers_finalization_until_released() {
+        ExtBuilder::default().build().execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let charge_amt = 100;
+            let execution_id: H256 = H256::repeat_byte(0);
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(
+                execution_id,
+                RequestCharge {
+                    payee: ALICE,
+                    offered_reward: charge_amt,
+                    charge_fee: 0,
+                    source: BenefitSource::TrafficRewards,
+                    role: CircuitRole::Executor,
+                    recipient: Some(BOB),
+                    maybe_asset_id: None
+                }
+            ));
+
+            assert_err!(
+                AccountManager::hold_deposit(RuntimeOrigin::signed(ALICE), execution_id),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(AccountManager::hold_deposit(
+                RuntimeOrigin::root(),
+                execution_id
+            ));
+            assert_err!(
+                AccountManager::hold_deposit(RuntimeOrigin::root(), execution_id),
+                pallet_account_manager::Error::<Runtime>::ChargeAlreadyOnHold
+            );
+
+            let held_at = frame_system::Pallet::<Runtime>::block_number();
+            assert_eq!(
+                AccountManager::held_deposits(),
+                vec![(
+                    execution_id,
+                    DepositInfo {
+                        charge: AccountManager::pending_charges_per_round::<H256>(execution_id)
+                            .unwrap(),
+                        state: DepositState::Held(held_at),
+                        released: 0,
+                    }
+                )]
+            );
+
+            assert_err!(
+                <AccountManager as AccountManagerExt<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::finalize(execution_id, Outcome::Commit, None, None),
+                pallet_account_manager::Error::<Runtime>::ChargeOnHold
+            );
+            assert!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::cancel_deposit(execution_id));
+            assert!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize_infallible(
+                execution_id, Outcome::Revert
+            ));
+            assert_eq!(
+                AccountManager::held_resolutions(execution_id),
+                Some(HeldDepositResolution::Finalize(Outcome::Revert))
+            );
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE - charge_amt);
+            assert!(AccountManager::pending_charges_per_round::<H256>(execution_id).is_some());
+
+            assert_ok!(AccountManager::release_deposit_hold(
+                RuntimeOrigin::root(),
+                execution_id,
+                None
+            ));
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE);
+            assert_eq!(
+                AccountManager::pending_charges_per_round::<H256>(execution_id),
+                None
+            );
+            assert_eq!(AccountManager::held_resolutions(execution_id), None);
+            assert!(AccountManager::held_deposits().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_released_hold_applies_resolution_settled_by_dispute() {
+        ExtBuilder::default().build().execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let charge_amt = 100;
+            let execution_id: H256 = H256::repeat_byte(0);
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(
+                execution_id,
+                RequestCharge {
+                    payee: ALICE,
+                    offered_reward: charge_amt,
+                    charge_fee: 0,
+                    source: BenefitSource::TrafficRewards,
+                    role: CircuitRole::Executor,
+                    recipient: Some(BOB),
+                    maybe_asset_id: None
+                }
+            ));
+            assert_ok!(AccountManager::hold_deposit(
+                RuntimeOrigin::root(),
+                execution_id
+            ));
+            // The commit requested while on hold is overruled by the dispute
+            assert!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize_infallible(
+                execution_id, Outcome::Commit
+            ));
+
+            assert_err!(
+                AccountManager::release_deposit_hold(
+                    RuntimeOrigin::signed(ALICE),
+                    execution_id,
+                    Some(HeldDepositResolution::Finalize(Outcome::Revert))
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(AccountManager::release_deposit_hold(
+                RuntimeOrigin::root(),
+                execution_id,
+                Some(HeldDepositResolution::Finalize(Outcome::Revert))
+            ));
+
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE);
+            assert_eq!(
+                <AccountManager as AccountManagerExt<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::get_settlement(execution_id),
+                None
+            );
+            assert_eq!(
+                AccountManager::pending_charges_per_round::<H256>(execution_id),
+                None
+            );
+            assert_eq!(AccountManager::held_resolutions(execution_id), None);
+        });
+    }
+
+    #[test]
+    fn test_reconcile_reports_per_asset_totals_and_alerts_on_mismatch() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let committed_id: H256 = H256::repeat_byte(0);
+            let pending_id: H256 = H256::repeat_byte(1);
+
+            for charge_id in [committed_id, pending_id] {
+                assert_ok!(<AccountManager as AccountManagerExt<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::deposit(
+                    charge_id,
+                    RequestCharge {
+                        payee: ALICE,
+                        offered_reward: 100,
+                        charge_fee: 10,
+                        source: BenefitSource::TrafficRewards,
+                        role: CircuitRole::Executor,
+                        recipient: Some(BOB),
+                        maybe_asset_id: None
+                    }
+                ));
+            }
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize(
+                committed_id, Outcome::Commit, None, None,
+            ));
+            assert_ok!(AccountManager::finalize_partial(
+                RuntimeOrigin::root(),
+                pending_id,
+                40,
+                Outcome::Revert,
+            ));
+
+            let stuck_after = ChargeDepositRounds::<Runtime>::get(pending_id).unwrap()
+                + <Runtime as pallet_account_manager::Config>::StuckChargeRounds::get();
+            AccountManager::reconcile(stuck_after);
+
+            let report = AccountManager::reconciliation_report(stuck_after).unwrap();
+            assert_eq!(
+                report.assets,
+                vec![AssetReconciliation {
+                    maybe_asset_id: None,
+                    totals: EscrowTotals {
+                        reserved: 220,
+                        committed: 100,
+                        reverted: 40,
+                        slashed: 0,
+                        fees: 10,
+                    },
+                    pending: 70,
+                    stuck: 70,
+                    stuck_charges: 1,
+                    escrowed: 70,
+                    remote_pending: 0,
+                    mismatch: false,
+                }]
+            );
+
+            // Funds leaving the escrow holding account without leaving escrow are flagged
+            let holding = AccountManager::escrow_holding_account();
+            let _ = Balances::slash(&holding, 5);
+            AccountManager::reconcile(stuck_after + 1);
+
+            assert!(
+                AccountManager::reconciliation_report(stuck_after + 1)
+                    .unwrap()
+                    .assets[0]
+                    .mismatch
+            );
+            System::assert_has_event(RuntimeEvent::AccountManager(
+                pallet_account_manager::Event::EscrowBalanceMismatch {
+                    round: stuck_after + 1,
+                    maybe_asset_id: None,
+                    escrowed: 65,
+                    expected: 70,
+                },
+            ));
+            let _ = Balances::deposit_creating(&holding, 5);
+
+            // Settling the stuck charge drops it from the running totals
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize(
+                pending_id, Outcome::Revert, None, None,
+            ));
+            assert_eq!(
+                AccountManager::pending_totals(None::<AssetId>),
+                Default::default()
+            );
+            assert_eq!(
+                AccountManager::stuck_totals(None::<AssetId>),
+                Default::default()
+            );
+
+            assert_eq!(AccountManager::escrowed_balance(&None::<AssetId>), 0);
+
+            // Funds leaving escrow without being booked are flagged
+            EscrowLedger::<Runtime>::mutate(None::<AssetId>, |totals| totals.reserved += 1);
+            AccountManager::reconcile(stuck_after + 2);
+
+            assert!(
+                AccountManager::reconciliation_report(stuck_after + 2)
+                    .unwrap()
+                    .assets[0]
+                    .mismatch
+            );
+            System::assert_has_event(RuntimeEvent::AccountManager(
+                pallet_account_manager::Event::ReconciliationMismatch {
+                    round: stuck_after + 2,
+                    maybe_asset_id: None,
+                    reserved: 221,
+                    accounted: 220,
+                },
+            ));
+        });
+    }
+
+    #[test]
+    fn test_runtime_upgrade_seeds_escrow_ledger_from_pending_charges() {
+        ExtBuilder::default().build().execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let legacy_id: H256 = H256::repeat_byte(0);
+            let tracked_id: H256 = H256::repeat_byte(1);
+            let charge = RequestCharge {
+                payee: ALICE,
+                offered_reward: 100,
+                charge_fee: 10,
+                source: BenefitSource::TrafficRewards,
+                role: CircuitRole::Executor,
+                recipient: Some(BOB),
+                maybe_asset_id: None,
+            };
+
+            // Charge pending from before the escrow ledger was introduced, its funds burnt
+            PendingCharges::<Runtime>::insert(legacy_id, charge.clone());
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(tracked_id, charge));
+
+            <AccountManager as Hooks<BlockNumber>>::on_runtime_upgrade();
+
+            let round = <Runtime as pallet_account_manager::Config>::Clock::current_round().index;
+            assert_eq!(ChargeDepositRounds::<Runtime>::get(legacy_id), Some(round));
+            assert_eq!(AccountManager::escrow_totals(None::<AssetId>).reserved, 220);
+            assert_eq!(
+                AccountManager::pending_totals(None::<AssetId>),
+                PendingTotals {
+                    amount: 220,
+                    charges: 2,
+                }
+            );
+            // The escrow holding account is funded for the legacy charge
+            assert_eq!(AccountManager::escrowed_balance(&None::<AssetId>), 220);
+            assert_eq!(crate::StorageMigrations::<Runtime>::get(), 2);
+
+            // Applied only once
+            <AccountManager as Hooks<BlockNumber>>::on_runtime_upgrade();
+            assert_eq!(AccountManager::escrow_totals(None::<AssetId>).reserved, 220);
+            assert_eq!(AccountManager::escrowed_balance(&None::<AssetId>), 220);
+
+            AccountManager::reconcile(round);
+            assert!(!AccountManager::reconciliation_report(round).unwrap().assets[0].mismatch);
+        });
+    }
 }
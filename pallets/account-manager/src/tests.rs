@@ -10,6 +10,10 @@ use frame_support::{
     weights::Weight,
 };
 use sp_runtime::{traits::SignedExtension, AccountId32};
+use t3rn_primitives::{
+    xdns::{Xdns, ASSET_PRICE_PRECISION},
+    SubstrateToken, TokenInfo,
+};
 
 pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
 pub const BOB: AccountId32 = AccountId32::new([1u8; 32]);
@@ -54,10 +58,34 @@ fn setup_asset() -> u32 {
     asset_id
 }
 
+fn setup_xdns_priced_asset() -> u32 {
+    let asset_id = setup_asset();
+    let feeder = ALICE;
+
+    assert_ok!(XDNS::override_token(
+        asset_id,
+        [3, 3, 3, 3],
+        TokenInfo::Substrate(SubstrateToken {
+            id: asset_id,
+            symbol: b"XYZ".to_vec(),
+            decimals: 12,
+        })
+    ));
+    assert_ok!(XDNS::add_price_feeder(Origin::root(), feeder.clone()));
+    // 1 XYZ is worth 2 TRN
+    assert_ok!(XDNS::submit_asset_price(
+        Origin::signed(feeder),
+        asset_id,
+        ASSET_PRICE_PRECISION * 2
+    ));
+
+    asset_id
+}
+
 #[test]
 fn transaction_payment_in_asset_possible() {
     ExtBuilder::default().build().execute_with(|| {
-        let asset_id = setup_asset();
+        let asset_id = setup_xdns_priced_asset();
         let caller = BOB;
         let weight = 5_u64;
         let len = 10;
@@ -76,9 +104,12 @@ fn transaction_payment_in_asset_possible() {
         // assert that native balance is not used
         assert_eq!(Balances::free_balance(caller.clone()), native_balance);
 
-        // check that fee was charged in the given asset
+        // check that fee was charged in the given asset at half the native amount
         let asset_balance = Assets::balance(asset_id, caller.clone());
         assert!(asset_balance < initial_balance);
+        let native_fee =
+            TransactionPayment::compute_fee(len as u32, &info_from_weight(weight.into()), 0);
+        assert_eq!(initial_balance - asset_balance, (native_fee / 2).max(1));
 
         assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
             Some(pre),
@@ -108,3 +139,33 @@ fn transaction_payment_in_asset_fails_if_insufficient_balance() {
             .is_err());
     });
 }
+
+#[test]
+fn transaction_payment_in_asset_without_xdns_price_estimate_falls_back_to_min_balance_ratio() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_id = setup_asset();
+        let caller = BOB;
+        let initial_balance = 10_000_000_000_000;
+        let weight = 5_u64;
+        let len = 10;
+
+        assert_ok!(Assets::mint_into(asset_id, &caller, initial_balance));
+        assert_eq!(XDNS::get_asset_price_in_native(asset_id), None);
+        assert_ok!(
+            ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id)).pre_dispatch(
+                &caller,
+                CALL,
+                &info_from_weight(weight.into()),
+                len
+            )
+        );
+
+        // asset min balance and native existential deposit are both 1, so the fee converts 1:1
+        let native_fee =
+            TransactionPayment::compute_fee(len as u32, &info_from_weight(weight.into()), 0);
+        assert_eq!(
+            initial_balance - Assets::balance(asset_id, caller),
+            native_fee.max(1)
+        );
+    });
+}
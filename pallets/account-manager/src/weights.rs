@@ -1,4 +1,6 @@
-// STUB
+//! Weights for pallet_account_manager
+//!
+//! Estimated from the storage accessed by each call, until regenerated from benchmarks.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -9,12 +11,62 @@ use frame_support::{
 };
 use sp_std::marker::PhantomData;
 
-/// Weight functions needed for pallet_xdns.
-pub trait WeightInfo {}
+/// Weight functions needed for pallet_account_manager.
+pub trait WeightInfo {
+    fn finalize() -> Weight;
+    fn release_deposit_hold() -> Weight;
+}
 
-/// Weights for pallet_xdns using the Substrate node and recommended hardware.
+/// Weights for pallet_account_manager using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
-impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {}
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: AccountManager HeldCharges (r:1 w:0)
+    // Storage: AccountManager PendingCharges (r:1 w:1)
+    // Storage: AccountManager ChargeDepositRounds (r:1 w:1)
+    // Storage: AccountManager ReleasedPerCharge (r:1 w:1)
+    // Storage: AccountManager StuckUpToRound (r:1 w:0)
+    // Storage: AccountManager PendingLedger (r:1 w:1)
+    // Storage: AccountManager PendingPerDepositRound (r:1 w:1)
+    // Storage: AccountManager EscrowLedger (r:1 w:1)
+    // Storage: AccountManager SettlementsPerRound (r:1 w:1)
+    // Storage: Clock CurrentRound (r:1 w:0)
+    // Storage: System Account (r:3 w:3)
+    fn finalize() -> Weight {
+        Weight::from_parts(95_000_000_u64, 8_500u64)
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+
+    // Storage: AccountManager HeldCharges (r:1 w:1)
+    // Storage: AccountManager HeldResolutions (r:1 w:1)
+    // Storage: AccountManager PendingCharges (r:1 w:1)
+    // Storage: AccountManager ChargeDepositRounds (r:1 w:1)
+    // Storage: AccountManager ReleasedPerCharge (r:1 w:1)
+    // Storage: AccountManager StuckUpToRound (r:1 w:0)
+    // Storage: AccountManager PendingLedger (r:1 w:1)
+    // Storage: AccountManager PendingPerDepositRound (r:1 w:1)
+    // Storage: AccountManager EscrowLedger (r:1 w:1)
+    // Storage: AccountManager SettlementsPerRound (r:1 w:1)
+    // Storage: Clock CurrentRound (r:1 w:0)
+    // Storage: System Account (r:3 w:3)
+    fn release_deposit_hold() -> Weight {
+        Weight::from_parts(103_000_000_u64, 8_700u64)
+            .saturating_add(T::DbWeight::get().reads(14_u64))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+    }
+}
 
 // For backwards compatibility and tests
-impl WeightInfo for () {}
+impl WeightInfo for () {
+    fn finalize() -> Weight {
+        Weight::from_parts(95_000_000_u64, 8_500u64)
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+
+    fn release_deposit_hold() -> Weight {
+        Weight::from_parts(103_000_000_u64, 8_700u64)
+            .saturating_add(RocksDbWeight::get().reads(14_u64))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+    }
+}
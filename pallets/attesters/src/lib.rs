@@ -4,6 +4,12 @@
 pub mod benchmarking;
 
 pub use crate::pallet::*;
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use t3rn_primitives::{
+    clock::{OnNewRound, RoundHandlerId},
+    common::RoundInfo,
+};
 
 pub type TargetId = [u8; 4];
 
@@ -20,6 +26,7 @@ pub mod pallet {
 
     use super::*;
     t3rn_primitives::reexport_currency_types!();
+    use circuit_runtime_types::AssetId;
     use t3rn_primitives::ExecutionSource;
     use tiny_keccak::{Hasher, Keccak};
 
@@ -230,6 +237,7 @@ pub mod pallet {
         type CommitteeSize: Get<u32>;
         type BatchingWindow: Get<BlockNumberFor<Self>>;
         type RepatriationPeriod: Get<BlockNumberFor<Self>>;
+        /// Period unnominations are locked for, twice over. The committee itself is shuffled once per clock round.
         type ShufflingFrequency: Get<BlockNumberFor<Self>>;
         type MaxBatchSize: Get<u32>;
         type RewardMultiplier: Get<BalanceOf<Self>>;
@@ -378,6 +386,7 @@ pub mod pallet {
         ),
         CollusionWithPermanentSlashDetected(TargetId, H256),
         UserFinalityFeeEstimated(TargetId, BalanceOf<T>),
+        UserFinalityFeeEstimatedInAsset(TargetId, AssetId, BalanceOf<T>),
         NewAttestationBatch(TargetId, BatchMessage<BlockNumberFor<T>>),
         NewAttestationMessageHash(TargetId, H256, ExecutionVendor),
         NewConfirmationBatch(TargetId, BatchMessage<BlockNumberFor<T>>, Vec<u8>, H256),
@@ -421,6 +430,7 @@ pub mod pallet {
         RejectingFromSlashedAttester,
         TargetAlreadyActive,
         TargetNotActive,
+        AssetPriceEstimateNotFound,
         XdnsTargetNotActive,
         XdnsGatewayDoesNotHaveEscrowAddressRegistered,
         SfxAlreadyRequested,
@@ -937,6 +947,40 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Estimates the finality fee of the target and converts it into the given asset using the XDNS price estimates.
+        #[pallet::weight(10_000)]
+        pub fn estimate_user_finality_fee_in_asset(
+            origin: OriginFor<T>,
+            target: TargetId,
+            asset_id: AssetId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            // Ensure target is active
+            ensure!(
+                AttestationTargets::<T>::get().contains(&target),
+                Error::<T>::TargetNotActive
+            );
+
+            let finality_fee = <Pallet<T> as AttestersReadApi<
+                T::AccountId,
+                BalanceOf<T>,
+                BlockNumberFor<T>,
+            >>::estimate_finality_fee(&target);
+
+            let finality_fee_in_asset =
+                <T as Config>::Xdns::convert_asset_amount(None, Some(asset_id), finality_fee)
+                    .ok_or(Error::<T>::AssetPriceEstimateNotFound)?;
+
+            Self::deposit_event(Event::UserFinalityFeeEstimatedInAsset(
+                target,
+                asset_id,
+                finality_fee_in_asset,
+            ));
+
+            Ok(())
+        }
+
         #[pallet::weight(10_000)]
         pub fn nominate(
             origin: OriginFor<T>,
@@ -1155,6 +1199,11 @@ pub mod pallet {
             CurrentCommittee::<T>::get()
         }
 
+        #[cfg(feature = "runtime-benchmarks")]
+        fn set_current_committee(committee: Vec<T::AccountId>) {
+            CurrentCommittee::<T>::put(committee);
+        }
+
         /// Getter for the active set. Returns a Vec of AccountIds.
         fn active_set() -> Vec<T::AccountId> {
             ActiveSet::<T>::get()
@@ -1979,6 +2028,45 @@ pub mod pallet {
             PermanentSlashes::<T>::get().contains(account)
         }
 
+        /// Select the active set and shuffle the committee, once the transition to the last
+        /// committee has been attested.
+        pub fn process_shuffling(n: BlockNumberFor<T>) -> Weight {
+            let mut aggregated_weight: Weight = T::DbWeight::get().reads(1);
+            if !Self::is_last_transition_attested() {
+                return aggregated_weight
+            }
+            // Process pending unnominations
+            aggregated_weight = Self::process_pending_unnominations(n, aggregated_weight);
+            // Update the active set of attesters
+            ActiveSet::<T>::put(
+                SortedNominatedAttesters::<T>::get()
+                    .iter()
+                    .filter(|(account_id, _)| !Self::is_permanently_slashed(account_id))
+                    .take(32)
+                    .cloned()
+                    .map(|(account_id, _balance)| account_id)
+                    .collect::<Vec<T::AccountId>>(),
+            );
+            aggregated_weight += T::DbWeight::get().reads_writes(1, 1);
+
+            // Call shuffle_committee
+            Self::shuffle_committee();
+            aggregated_weight += T::DbWeight::get().reads_writes(2, 2);
+
+            Self::deposit_event(Event::ShufflingCompleted(
+                CurrentCommittee::<T>::get(),
+                PreviousCommittee::<T>::get(),
+                NextCommittee::<T>::get(),
+            ));
+
+            for (target, batch_index) in Self::request_next_committee_attestation() {
+                CommitteeTransitionOn::<T>::insert(target, batch_index);
+            }
+            aggregated_weight += T::DbWeight::get().reads_writes(2, 3);
+
+            aggregated_weight
+        }
+
         pub fn process_pending_unnominations(
             n: BlockNumberFor<T>,
             mut aggregated_weight: Weight,
@@ -2072,40 +2160,7 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             let mut aggregated_weight: Weight = Zero::zero();
-            // Check if a shuffling round has passed
-            if (n % T::ShufflingFrequency::get()).is_zero() && Self::is_last_transition_attested() {
-                // Process pending unnominations
-                aggregated_weight = Self::process_pending_unnominations(n, aggregated_weight);
-                // Update the active set of attesters
-                ActiveSet::<T>::put(
-                    SortedNominatedAttesters::<T>::get()
-                        .iter()
-                        .filter(|(account_id, _)| !Self::is_permanently_slashed(account_id))
-                        .take(32)
-                        .cloned()
-                        .map(|(account_id, _balance)| account_id)
-                        .collect::<Vec<T::AccountId>>(),
-                );
-                aggregated_weight += T::DbWeight::get().reads_writes(1, 1);
-
-                // Call shuffle_committee
-                Self::shuffle_committee();
-                aggregated_weight += T::DbWeight::get().reads_writes(2, 2);
-
-                Self::deposit_event(Event::ShufflingCompleted(
-                    CurrentCommittee::<T>::get(),
-                    PreviousCommittee::<T>::get(),
-                    NextCommittee::<T>::get(),
-                ));
-
-                for (target, batch_index) in Self::request_next_committee_attestation() {
-                    CommitteeTransitionOn::<T>::insert(target, batch_index);
-                }
-                aggregated_weight += T::DbWeight::get().reads_writes(2, 3);
-
-                return aggregated_weight
-            }
-
+            // Committee shuffling runs once per round, see `OnNewRound`
             if (n % T::BatchingWindow::get()).is_zero() {
                 // Check if there any pending attestations to submit with the current batch
                 aggregated_weight = Self::process_next_batch_window(n, aggregated_weight);
@@ -2160,6 +2215,18 @@ pub mod pallet {
     }
 }
 
+/// Id under which the committee shuffling is registered as a round handler with the clock.
+pub const SHUFFLING_ROUND_HANDLER: RoundHandlerId = *b"attshufl";
+
+impl<T: Config> OnNewRound<T> for Pallet<T> {
+    fn on_new_round(
+        _past_round: RoundInfo<BlockNumberFor<T>>,
+        new_round: RoundInfo<BlockNumberFor<T>>,
+    ) -> Weight {
+        Self::process_shuffling(new_round.head)
+    }
+}
+
 #[cfg(test)]
 pub mod attesters_test {
     use super::{
@@ -2187,7 +2254,7 @@ pub mod attesters_test {
         NextBatch, NextCommitteeOnTarget, Nominations, PaidFinalityFees, PendingUnnominations,
         PermanentSlashes, PreviousCommittee, Rewards, RuntimeEvent as Event, RuntimeOrigin,
         SFX2XTXLinksMap, SortedNominatedAttesters, System, XExecSignals, ETHEREUM_TARGET,
-        POLKADOT_TARGET,
+        POLKADOT_TARGET, XDNS,
     };
     use t3rn_primitives::{
         attesters::{
@@ -2198,7 +2265,8 @@ pub mod attesters_test {
             AdaptiveTimeout, CircuitStatus, FullSideEffect, SecurityLvl, SideEffect, XExecSignal,
         },
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
-        TreasuryAccount, TreasuryAccountProvider,
+        xdns::ASSET_PRICE_PRECISION,
+        SubstrateToken, TokenInfo, TreasuryAccount, TreasuryAccountProvider,
     };
     use tiny_keccak::{Hasher, Keccak};
 
@@ -2229,6 +2297,63 @@ pub mod attesters_test {
         });
     }
 
+    #[test]
+    fn estimate_user_finality_fee_in_asset_converts_fee_with_xdns_price_estimates() {
+        let mut ext = ExtBuilder::default().build();
+
+        ext.execute_with(|| {
+            let target_id = ETHEREUM_TARGET;
+            AttestationTargets::<MiniRuntime>::append(&target_id);
+            let fee_asset: u32 = 1111;
+            let feeder = AccountId::from([1u8; 32]);
+            let estimated_fee_in_native: Balance = 13_200_000_000_000u128;
+
+            assert_noop!(
+                Attesters::estimate_user_finality_fee_in_asset(
+                    RuntimeOrigin::signed(feeder.clone()),
+                    target_id,
+                    fee_asset,
+                ),
+                AttestersError::<MiniRuntime>::AssetPriceEstimateNotFound
+            );
+
+            assert_ok!(XDNS::register_new_token(
+                &RuntimeOrigin::root(),
+                fee_asset,
+                TokenInfo::Substrate(SubstrateToken {
+                    id: fee_asset,
+                    symbol: b"fee".to_vec(),
+                    decimals: 12,
+                })
+            ));
+            assert_ok!(XDNS::add_price_feeder(
+                RuntimeOrigin::root(),
+                feeder.clone()
+            ));
+            assert_ok!(XDNS::submit_asset_price(
+                RuntimeOrigin::signed(feeder.clone()),
+                fee_asset,
+                ASSET_PRICE_PRECISION * 2,
+            ));
+
+            assert_ok!(Attesters::estimate_user_finality_fee_in_asset(
+                RuntimeOrigin::signed(feeder),
+                target_id,
+                fee_asset,
+            ));
+
+            let events = System::events();
+            assert_eq!(
+                events.last().unwrap().event,
+                Event::Attesters(AttestersEvent::UserFinalityFeeEstimatedInAsset(
+                    target_id,
+                    fee_asset,
+                    estimated_fee_in_native / 2,
+                ))
+            );
+        });
+    }
+
     #[test]
     fn estimate_finality_fee_1_delay() {
         let mut ext = ExtBuilder::default()
@@ -2318,7 +2443,7 @@ pub mod attesters_test {
         );
 
         // Run to active to unlock block = 2 x shuffling frequency + next window
-        Attesters::on_initialize(1200u32);
+        Attesters::process_shuffling(1200u32);
 
         // Assert that attester is deregistered
         assert!(AttestersStore::<MiniRuntime>::get(&attester).is_none(),);
@@ -2349,11 +2474,11 @@ pub mod attesters_test {
         let current_block = System::block_number();
 
         if current_block < shuffling_frequency {
-            Attesters::on_initialize(shuffling_frequency);
+            Attesters::process_shuffling(shuffling_frequency);
             System::set_block_number(shuffling_frequency);
         } else {
             let shuffling_multiplier = current_block / shuffling_frequency;
-            Attesters::on_initialize(shuffling_multiplier * shuffling_frequency);
+            Attesters::process_shuffling(shuffling_multiplier * shuffling_frequency);
             System::set_block_number(shuffling_multiplier * shuffling_frequency);
         }
         assert!(!ActiveSet::<MiniRuntime>::get().is_empty(),);
@@ -2380,7 +2505,7 @@ pub mod attesters_test {
         ));
 
         // Run to active set selection
-        Attesters::on_initialize(400u32);
+        Attesters::process_shuffling(400u32);
 
         assert_eq!(Attesters::invulnerable_attester(), None);
 
@@ -2416,7 +2541,7 @@ pub mod attesters_test {
         ));
 
         // Run to active set selection
-        Attesters::on_initialize(400u32);
+        Attesters::process_shuffling(400u32);
 
         assert_eq!(
             Attesters::invulnerable_attester(),
@@ -3860,7 +3985,7 @@ pub mod attesters_test {
             }
 
             // Trigger the first setup
-            Attesters::on_initialize(400u32);
+            Attesters::process_shuffling(400u32);
 
             // Check if the committee is set up and has the correct size
             let committee = CurrentCommittee::<MiniRuntime>::get();
@@ -3873,7 +3998,7 @@ pub mod attesters_test {
             }
 
             // Trigger the transition
-            Attesters::on_initialize(800u32);
+            Attesters::process_shuffling(800u32);
 
             // Check if the previous committee is now set to the old committee and the new committee is different
             let previous_committee = PreviousCommittee::<MiniRuntime>::get();
@@ -4243,7 +4368,7 @@ pub mod attesters_test {
                 ));
             }
 
-            Attesters::on_initialize(400);
+            Attesters::process_shuffling(400);
 
             // Check that the top 32 attesters are the ones with the most nominations
             let active_set = ActiveSet::<MiniRuntime>::get();
@@ -4289,7 +4414,7 @@ pub mod attesters_test {
                 ));
             }
 
-            Attesters::on_initialize(400);
+            Attesters::process_shuffling(400);
 
             // Trigger inflation rewards distribution
             let distribution_period =
@@ -4447,8 +4572,8 @@ pub mod attesters_test {
             let unlock_block: BlockNumber = 3 * 400; // 1 is the current block number, 5 is the ShufflingFrequency
             System::set_block_number(unlock_block);
 
-            // Call on_initialize
-            Attesters::on_initialize(unlock_block);
+            // Shuffle the committee at the round change
+            Attesters::process_shuffling(unlock_block);
 
             // Verify that the active set is updated correctly
             let active_set = ActiveSet::<MiniRuntime>::get();
@@ -55,6 +55,11 @@ impl<T: Config> Bids<T> {
             fsx.security_lvl.clone(),
             fsx.input.insurance,
         );
+        // Check if the target's verifier is healthy enough to finalize the execution in time
+        if T::Xdns::get_gateway_health(&fsx.input.target).map_or(false, |health| !health.is_healthy)
+        {
+            return Err(Error::<T>::BiddingRejectedGatewayUnhealthy)
+        }
         // Check if bid doesn't go below dust limit.
         if bid.amount < T::Currency::minimum_balance() {
             return Err(Error::<T>::BiddingRejectedBidBelowDust)
@@ -89,8 +94,18 @@ impl<T: Config> Bids<T> {
                 .filter(|&fsx| fsx.security_lvl == SecurityLvl::Optimistic)
                 // All FSX but the current one
                 .filter(|&fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) != sfx_id)
-                // Since we don't know the final bid amounts, sum up the max reward for each SFX
-                .map(|fsx| fsx.input.max_reward)
+                // Since we don't know the final bid amounts, sum up the max reward for each SFX,
+                // converted into the asset the bond is reserved in
+                .map(|fsx| {
+                    T::Xdns::convert_asset_amount(
+                        fsx.input.reward_asset_id,
+                        bid.reward_asset_id,
+                        fsx.input.max_reward,
+                    )
+                })
+                .collect::<Option<Vec<BalanceOf<T>>>>()
+                .ok_or(Error::<T>::BiddingRejectedMissingAssetPriceEstimate)?
+                .into_iter()
                 .reduce(|total_reserved, next_amount| {
                     total_reserved
                         .checked_add(&next_amount)
@@ -78,6 +78,7 @@ pub use t3rn_primitives::light_client::InclusionReceipt;
 use t3rn_primitives::{
     attesters::AttestersWriteApi,
     circuit::{CircuitSubmitAPI, ReadSFX},
+    rewards::AmbassadorsReadApi,
 };
 pub use t3rn_sdk_primitives::signal::{ExecutionSignal, SignalKind};
 use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
@@ -241,6 +242,17 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Ambassador that referred the Xtx, paid a share of the executor fees on successful finalize
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_referrer)]
+    pub type XtxReferrers<T> = StorageMap<
+        _,
+        Identity,
+        XExecSignalId<T>,
+        <T as frame_system::Config>::AccountId,
+        OptionQuery,
+    >;
+
     /// LocalXtxStates stores the map of LocalState - additional state to be used to communicate between SFX that belong to the same Xtx
     ///
     /// - @Circuit::Requested: create LocalXtxStates array without confirmations or bids
@@ -278,6 +290,19 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Versions of the XDNS gateway records each Xtx was validated against.
+    ///     Side effects are confirmed against the pinned versions, even if the gateways changed in the meantime.
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_gateway_versions)]
+    pub type XtxGatewayVersions<T> =
+        StorageMap<_, Identity, XExecSignalId<T>, Vec<(TargetId, u32)>, OptionQuery>;
+
+    /// Asset each account chose to pay its finality fees in, instead of the native currency.
+    #[pallet::storage]
+    #[pallet::getter(fn get_finality_fee_asset)]
+    pub type FinalityFeeAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, AssetId, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_dlq)]
     pub type DLQ<T> = StorageMap<
@@ -380,6 +405,9 @@ pub mod pallet {
 
         // Needed in square_up mod
         type TreasuryAccounts: TreasuryAccountProvider<Self::AccountId>;
+
+        /// A type that provides the registry of ambassadors allowed to refer orders
+        type Ambassadors: AmbassadorsReadApi<Self::AccountId>;
     }
 
     #[pallet::pallet]
@@ -931,6 +959,61 @@ pub mod pallet {
             Ok(().into())
         }
 
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger())]
+        pub fn on_extrinsic_trigger_with_referrer(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
+            speed_mode: SpeedMode,
+            preferred_security_level: SecurityLvl,
+            referrer: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            ensure!(
+                T::Ambassadors::is_ambassador(&referrer),
+                Error::<T>::ReferrerNotAmbassador
+            );
+
+            let local_ctx = Self::do_on_extrinsic_trigger(
+                requester,
+                side_effects,
+                speed_mode,
+                &preferred_security_level,
+                None,
+            )?;
+
+            <XtxReferrers<T>>::insert(local_ctx.xtx_id, referrer.clone());
+            Self::deposit_event(Event::XTransactionReferred(local_ctx.xtx_id, referrer));
+
+            Ok(().into())
+        }
+
+        /// Sets the asset the caller's finality fees are charged in, converted from the native
+        /// estimates at XDNS asset prices. The asset needs a fresh XDNS price; `None` goes back to
+        /// the native currency.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_finality_fee_asset())]
+        pub fn set_finality_fee_asset(
+            origin: OriginFor<T>,
+            maybe_asset_id: Option<AssetId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            match maybe_asset_id {
+                Some(asset_id) => {
+                    ensure!(
+                        T::Xdns::get_asset_price_in_native(asset_id).is_some(),
+                        Error::<T>::FinalityFeeAssetPriceEstimateMissing
+                    );
+                    <FinalityFeeAssets<T>>::insert(&who, asset_id);
+                },
+                None => <FinalityFeeAssets<T>>::remove(&who),
+            }
+            Self::deposit_event(Event::FinalityFeeAssetSet(who, maybe_asset_id));
+
+            Ok(())
+        }
+
         #[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_side_effect())]
         pub fn escrow(origin: OriginFor<T>, sfx_id: SideEffectId<T>) -> DispatchResultWithPostInfo {
             // Authorize: Retrieve sender of the transaction.
@@ -1220,6 +1303,10 @@ pub mod pallet {
         UnsuccessfulFSXCommitAttestationRequest(H256),
         SuccessfulFSXRevertAttestationRequest(H256),
         UnsuccessfulFSXRevertAttestationRequest(H256),
+        // Listeners - ambassadors to know their referral of the Xtx was accepted
+        XTransactionReferred(XExecSignalId<T>, T::AccountId),
+        // Listeners - requesters to know the asset their finality fees are charged in
+        FinalityFeeAssetSet(T::AccountId, Option<AssetId>),
     }
 
     #[pallet::error]
@@ -1253,6 +1340,7 @@ pub mod pallet {
         BiddingRejectedInsuranceTooLow,
         BiddingRejectedBetterBidFound,
         BiddingRejectedFailedToDepositBidderBond,
+        BiddingRejectedMissingAssetPriceEstimate,
         BiddingFailedExecutorsBalanceTooLowToReserve,
         InsuranceBondAlreadyDeposited,
         InvalidFTXStateEmptyBidForReadyXtx,
@@ -1310,6 +1398,10 @@ pub mod pallet {
         XtxChargeFailedOnEscrowFee,
         FailedToPerformDynamicDestinationDealHotSwap,
         NotImplemented,
+        PendingGatewayOrderValueExceeded,
+        BiddingRejectedGatewayUnhealthy,
+        ReferrerNotAmbassador,
+        FinalityFeeAssetPriceEstimateMissing,
     }
 }
 
@@ -1495,6 +1587,22 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::SideEffectsValidationFailedAgainstABI
             })?;
 
+            // Orders on gateways pending promotion are capped in value; rewards without a price estimate can't be shown to fit the cap
+            if let Some(max_order_value) =
+                <T as Config>::Xdns::get_pending_gateway_max_order_value(&sfx.target)
+            {
+                let order_value = <T as Config>::Xdns::convert_asset_amount(
+                    sfx.reward_asset_id,
+                    None,
+                    sfx.max_reward,
+                )
+                .ok_or(Error::<T>::PendingGatewayOrderValueExceeded)?;
+                ensure!(
+                    order_value <= max_order_value,
+                    Error::<T>::PendingGatewayOrderValueExceeded
+                );
+            }
+
             let submission_target_height = match T::Portal::get_finalized_height(sfx.target)
                 .map_err(|_| Error::<T>::TargetAppearsNotToBeActiveAndDoesntHaveFinalizedHeight)?
             {
@@ -1512,6 +1620,21 @@ impl<T: Config> Pallet<T> {
                 index: index as u32,
             });
         }
+        // Pin the gateway versions the side effects were validated against
+        let mut gateway_versions: Vec<(TargetId, u32)> = vec![];
+        for sfx in side_effects.iter() {
+            let version = <T as Config>::Xdns::get_gateway_version(&sfx.target);
+            if version > 0 && !gateway_versions.contains(&(sfx.target, version)) {
+                gateway_versions.push((sfx.target, version));
+            }
+        }
+        for (target, version) in gateway_versions.iter() {
+            <T as Config>::Xdns::pin_gateway_version(target, *version);
+        }
+        if !gateway_versions.is_empty() {
+            <XtxGatewayVersions<T>>::insert(local_ctx.xtx_id, gateway_versions);
+        }
+
         // Skip automatic ordering of SFX for now, allow user to decide - consult PR#https://github.com/t3rn/t3rn/pull/1489
         full_side_effects.sort_by(|a, b| a.index.partial_cmp(&b.index).unwrap());
 
@@ -1592,12 +1715,34 @@ impl<T: Config> Pallet<T> {
             inclusion_receipt
         );
 
+        // keep the including header from being pruned by the light client while the xtx is in-flight
+        #[cfg(not(feature = "test-skip-verification"))]
+        <T as Config>::Portal::pin_header(
+            fsx.input.target,
+            inclusion_receipt.including_header.clone(),
+            xtx.timeouts_at.emergency_timeout_here,
+        )?;
+
         log::debug!("Inclusion confirmed!");
 
-        let sfx_abi =
-            <T as Config>::Xdns::get_sfx_abi(&fsx.input.target, fsx.input.action).ok_or({
-                DispatchError::Other("Unable to find matching Side Effect descriptor in XDNS")
-            })?;
+        let pinned_gateway_version = Self::get_xtx_gateway_versions(xtx_id).and_then(|versions| {
+            versions
+                .iter()
+                .find(|(target, _)| *target == fsx.input.target)
+                .map(|(_, version)| *version)
+        });
+
+        let sfx_abi = match pinned_gateway_version {
+            Some(version) => <T as Config>::Xdns::get_sfx_abi_at_version(
+                &fsx.input.target,
+                fsx.input.action,
+                version,
+            ),
+            None => <T as Config>::Xdns::get_sfx_abi(&fsx.input.target, fsx.input.action),
+        }
+        .ok_or({
+            DispatchError::Other("Unable to find matching Side Effect descriptor in XDNS")
+        })?;
 
         #[cfg(feature = "test-skip-verification")]
         let inclusion_receipt = InclusionReceipt::<BlockNumberFor<T>> {
@@ -1619,13 +1764,40 @@ impl<T: Config> Pallet<T> {
             ))
         }
 
-        let payload_codec = <T as Config>::Xdns::get_target_codec(&fsx.input.target)?;
+        let payload_codec = match pinned_gateway_version {
+            Some(version) =>
+                <T as Config>::Xdns::get_target_codec_at_version(&fsx.input.target, version)?,
+            None => <T as Config>::Xdns::get_target_codec(&fsx.input.target)?,
+        };
+
+        // Amounts of assets are emitted in the decimals of the asset on the target,
+        // while ordered in the canonical decimals of the asset on the Circuit.
+        let maybe_asset_id: Option<AssetId> = sfx_abi
+            .get_args_names()
+            .iter()
+            .position(|(arg_name, _)| arg_name.as_slice() == b"asset_id")
+            .and_then(|index| fsx.input.encoded_args.get(index))
+            .and_then(|encoded_asset_id| AssetId::decode(&mut &encoded_asset_id[..]).ok());
 
-        fsx.input.confirm(
+        fsx.input.confirm_normalized(
             sfx_abi,
             inclusion_receipt.message,
             &Codec::Scale, // Assume SCALE codec for egress args for now
             &payload_codec,
+            |arg_name, recoded_arg| match maybe_asset_id {
+                Some(asset_id) if arg_name.as_slice() == b"amount" => {
+                    let amount = u128::decode(&mut &recoded_arg[..]).map_err(|_| {
+                        DispatchError::Other("SideEffect confirmation failed to decode amount")
+                    })?;
+                    <T as Config>::Xdns::normalize_asset_amount_to_canonical(
+                        asset_id,
+                        &fsx.input.target,
+                        amount,
+                    )
+                    .map(|normalized| normalized.encode())
+                },
+                _ => Ok(recoded_arg),
+            },
         )?;
 
         log::debug!("Confirmation success");
@@ -1633,6 +1805,15 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Releases the gateway versions the Xtx was pinned to once no more confirmations are expected.
+    pub fn release_xtx_gateway_versions(xtx_id: XExecSignalId<T>) {
+        if let Some(gateway_versions) = <XtxGatewayVersions<T>>::take(xtx_id) {
+            for (target, version) in gateway_versions.iter() {
+                <T as Config>::Xdns::unpin_gateway_version(target, *version);
+            }
+        }
+    }
+
     pub fn get_all_xtx_targets(xtx_id: XExecSignalId<T>) -> Vec<TargetId> {
         // Get FSX of XTX
         let fsx_of_xtx = match <Pallet<T>>::get_fsx_of_xtx(xtx_id) {
@@ -529,6 +529,8 @@ impl<T: Config> Machine<T> {
                 <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::FullSideEffects::remove(local_ctx.xtx_id);
+                <pallet::Pallet<T> as Store>::XtxReferrers::remove(local_ctx.xtx_id);
+                pallet::Pallet::<T>::release_xtx_gateway_versions(local_ctx.xtx_id);
 
                 let mut fsx_mut_arr = local_ctx.full_side_effects.clone();
                 for fsx_step in fsx_mut_arr.iter_mut() {
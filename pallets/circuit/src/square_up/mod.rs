@@ -1,6 +1,6 @@
 use crate::*;
-use frame_support::{ensure, traits::ExistenceRequirement};
-use sp_runtime::DispatchResult;
+use frame_support::ensure;
+use sp_runtime::{traits::Hash, DispatchResult};
 
 #[cfg(test)]
 pub mod test;
@@ -26,6 +26,10 @@ pub struct SquareUp<T: Config> {
 //
 // G) infallible rewards payouts via AccountManager::finalize and infallible unlock executor's bonds @CircuitStatus::Finalize
 impl<T: Config> SquareUp<T> {
+    /// Charges the payer the finality fees of all Escrow SFX of the current step, paid to the Fee treasury.
+    /// The fees are estimated in the native currency and charged in the asset the payer chose with
+    /// `set_finality_fee_asset`, converted at XDNS asset prices. Without a chosen asset they're charged
+    /// in the native currency, regardless of the reward assets of the SFX.
     pub fn charge_finality_fee(
         local_ctx: &LocalXtxCtx<T, BalanceOf<T>>,
         requester: &T::AccountId,
@@ -48,16 +52,45 @@ impl<T: Config> SquareUp<T> {
                 acc.checked_add(fee).unwrap_or_else(Zero::zero)
             });
 
-        // log requester balance
-        T::Currency::transfer(
-            &requester,
-            &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Fee),
-            finality_fees_sum,
-            ExistenceRequirement::KeepAlive,
+        let fee_asset_id = <FinalityFeeAssets<T>>::get(requester);
+        let finality_fees = match fee_asset_id {
+            Some(asset_id) =>
+                T::Xdns::convert_asset_amount(None, Some(asset_id), finality_fees_sum)
+                    .ok_or(Error::<T>::FinalityFeeAssetPriceEstimateMissing)?,
+            None => finality_fees_sum,
+        };
+        if finality_fees.is_zero() {
+            return Ok(finality_fees)
+        }
+
+        let fee_treasury = T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Fee);
+        let fee_charge_id = Self::finality_fee_charge_id(local_ctx.xtx_id);
+        <T as Config>::AccountManager::deposit(
+            fee_charge_id,
+            RequestCharge {
+                payee: requester.clone(),
+                offered_reward: Zero::zero(),
+                charge_fee: finality_fees,
+                source: BenefitSource::TrafficFees,
+                role: CircuitRole::Requester,
+                recipient: Some(fee_treasury.clone()),
+                maybe_asset_id: fee_asset_id,
+            },
         )
         .map_err(|_| Error::<T>::RequesterNotEnoughBalance)?;
+        <T as Config>::AccountManager::finalize(
+            fee_charge_id,
+            Outcome::Commit,
+            Some(fee_treasury),
+            None,
+        )?;
 
-        Ok(finality_fees_sum)
+        Ok(finality_fees)
+    }
+
+    /// Id of the charge collecting finality fees for Xtx.
+    pub fn finality_fee_charge_id(xtx_id: XExecSignalId<T>) -> T::Hash {
+        SystemHashing::<T>::hash_of(&(xtx_id, TreasuryAccount::Fee))
     }
 
     /// Fallible lock requester' max rewards for Xtx.
@@ -240,6 +273,38 @@ impl<T: Config> SquareUp<T> {
         killed
     }
 
+    /// Id of the settlement carrying the referral share of the SFX executor fee.
+    pub fn referral_settlement_id(sfx_id: T::Hash, referrer: &T::AccountId) -> T::Hash {
+        SystemHashing::<T>::hash_of(&(sfx_id, referrer))
+    }
+
+    /// Infallible split of the ambassador's share out of the executor's settlement for SFX.
+    pub fn accrue_referral_share(sfx_id: T::Hash, referrer: &T::AccountId) -> bool {
+        let settlement = match <T as Config>::AccountManager::get_settlement(sfx_id) {
+            Some(settlement) => settlement,
+            // Nothing to share out of for remote orders or zero rewards
+            None => return false,
+        };
+        let share = T::Ambassadors::ambassador_share().mul_floor(settlement.settlement_amount);
+        if share.is_zero() {
+            return false
+        }
+        if !<T as Config>::AccountManager::split_settlement(
+            sfx_id,
+            Self::referral_settlement_id(sfx_id, referrer),
+            referrer,
+            CircuitRole::Ambassador,
+            share,
+        ) {
+            log::error!(
+                "squareUp::finalize: expect split_settlement to succeed for referral of sfx_id: {:?}",
+                sfx_id
+            );
+            return false
+        }
+        true
+    }
+
     /// Finalize Xtx after successful run.
     pub fn finalize(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> bool {
         let mut finalized = true;
@@ -281,6 +346,9 @@ impl<T: Config> SquareUp<T> {
                 },
             }
         }
+        let maybe_referrer = <XtxReferrers<T>>::take(local_ctx.xtx_id);
+        // No more confirmations against the pinned gateway versions once the Xtx is finalized
+        Pallet::<T>::release_xtx_gateway_versions(local_ctx.xtx_id);
         // Finalize XTX for requester - charge all deposits or return all max_reward deposits back to requester.
         Machine::<T>::read_current_step_fsx(local_ctx)
             .iter()
@@ -295,6 +363,11 @@ impl<T: Config> SquareUp<T> {
                     finalized = false;
                 }
 
+                // Accrue the referral share of the executor fee to the ambassador who referred the XTX
+                if let (Some(referrer), Outcome::Commit) = (&maybe_referrer, &step_outcome) {
+                    Self::accrue_referral_share(sfx_id, referrer);
+                }
+
                 // Finalize Escrow settlements if associated with SFX
                 // Standardize escrow_account IDs as re-hash of sfx_id with 3333
                 if fsx.security_lvl == SecurityLvl::Escrow {
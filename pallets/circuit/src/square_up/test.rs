@@ -2,24 +2,31 @@
 pub mod test {
     use crate::{
         square_up::test_extra::*,
-        tests::{ALICE, BOB},
+        tests::{ALICE, BOB, CHARLIE},
         SFXBid,
     };
     use circuit_mock_runtime::{
-        AccountId, AccountManager, AssetId, Balance, Balances, BlockNumber, ExtBuilder, Hash,
-        Runtime, System,
+        AccountId, AccountManager, AssetId, Assets, Attesters, Balance, Balances, BlockNumber,
+        Circuit, ExtBuilder, Hash, Rewards, Runtime, RuntimeOrigin, System, XDNS,
     };
     use circuit_runtime_pallets::pallet_circuit::{
-        machine::Machine, square_up::SquareUp, state::LocalXtxCtx,
+        machine::Machine, square_up::SquareUp, state::LocalXtxCtx, Error as CircuitError,
+        FinalityFeeAssets, XtxGatewayVersions, XtxReferrers,
+    };
+    use frame_support::{
+        assert_err, assert_noop, assert_ok,
+        traits::{fungibles::Mutate, Currency},
     };
-    use frame_support::{assert_err, assert_ok, traits::Currency};
     use sp_core::H256;
     use sp_runtime::{DispatchError, ModuleError};
     use t3rn_primitives::{
         account_manager::{
             AccountManager as AccountManagerInterface, Outcome, RequestCharge, Settlement,
         },
+        attesters::AttestersReadApi,
         claimable::{BenefitSource, CircuitRole},
+        xdns::{Xdns, ASSET_PRICE_PRECISION},
+        SubstrateToken, TokenInfo, TreasuryAccount, TreasuryAccountProvider,
     };
     use t3rn_types::sfx::SecurityLvl;
 
@@ -29,6 +36,7 @@ pub mod test {
 
     const REQUESTER: AccountId = ALICE;
     const EXECUTOR: AccountId = BOB;
+    const AMBASSADOR: AccountId = CHARLIE;
     const INITIAL_BALANCE: Balance = 10;
 
     fn stage_single_sfx_xtx() -> (
@@ -134,6 +142,32 @@ pub mod test {
         Ok(())
     }
 
+    fn setup_xdns_priced_fee_asset(asset_id: AssetId) {
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            sp_runtime::MultiAddress::Id(REQUESTER),
+            true,
+            1
+        ));
+        assert_ok!(XDNS::override_token(
+            asset_id,
+            [3, 3, 3, 3],
+            TokenInfo::Substrate(SubstrateToken {
+                id: asset_id,
+                symbol: b"FEE".to_vec(),
+                decimals: 12,
+            })
+        ));
+        assert_ok!(XDNS::add_price_feeder(RuntimeOrigin::root(), REQUESTER));
+        // 1 FEE is worth 2 TRN
+        assert_ok!(XDNS::submit_asset_price(
+            RuntimeOrigin::signed(REQUESTER),
+            asset_id,
+            ASSET_PRICE_PRECISION * 2
+        ));
+    }
+
     pub fn assert_pending_charges_no_longer_exist(charged_ids: Vec<H256>) {
         for id in charged_ids {
             assert_err!(
@@ -348,6 +382,173 @@ pub mod test {
             });
     }
 
+    #[test]
+    fn square_up_finalize_of_held_sfx_deposit_commits_once_the_hold_is_released() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let (mut local_ctx, sfx_id, bid, bid_id) = stage_single_sfx_xtx();
+
+                assert_ok!(request_and_bid_single_sfx_xtx(&mut local_ctx, &bid));
+
+                local_ctx.full_side_effects[0][0].confirmed = Some(ConfirmedSideEffect {
+                    err: None,
+                    output: None,
+                    inclusion_data: vec![0, 1, 2, 3],
+                    executioner: EXECUTOR,
+                    received_at: 1 as BlockNumber,
+                    cost: None,
+                });
+
+                assert_ok!(AccountManager::hold_deposit(RuntimeOrigin::root(), sfx_id));
+
+                assert!(SquareUp::<Runtime>::bind_bidders(&mut local_ctx));
+                assert!(SquareUp::<Runtime>::finalize(&local_ctx));
+
+                assert_eq!(
+                    <AccountManager as AccountManagerInterface<
+                        AccountId,
+                        Balance,
+                        Hash,
+                        BlockNumber,
+                        AssetId,
+                    >>::get_settlement(sfx_id),
+                    None
+                );
+                assert!(AccountManager::pending_charges_per_round::<H256>(sfx_id).is_some());
+
+                assert_ok!(AccountManager::release_deposit_hold(
+                    RuntimeOrigin::root(),
+                    sfx_id,
+                    None
+                ));
+
+                assert_eq!(
+                    <AccountManager as AccountManagerInterface<
+                        AccountId,
+                        Balance,
+                        Hash,
+                        BlockNumber,
+                        AssetId,
+                    >>::get_settlement(sfx_id),
+                    Some(Settlement {
+                        requester: REQUESTER,
+                        recipient: EXECUTOR,
+                        settlement_amount: bid.amount,
+                        outcome: Outcome::Commit,
+                        source: BenefitSource::TrafficFees,
+                        role: CircuitRole::Executor,
+                        maybe_asset_id: None,
+                    })
+                );
+                assert_pending_charges_no_longer_exist(vec![sfx_id, bid_id]);
+                assert_eq!(
+                    Balances::free_balance(&REQUESTER),
+                    INITIAL_BALANCE - bid.amount
+                );
+            });
+    }
+
+    #[test]
+    fn square_up_finalize_drops_pinned_gateway_versions() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let (mut local_ctx, _sfx_id, bid, _bid_id) = stage_single_sfx_xtx();
+                XtxGatewayVersions::<Runtime>::insert(local_ctx.xtx_id, vec![(*b"pdot", 1u32)]);
+
+                assert_ok!(request_and_bid_single_sfx_xtx(&mut local_ctx, &bid));
+                assert!(SquareUp::<Runtime>::bind_bidders(&mut local_ctx));
+                SquareUp::<Runtime>::finalize(&local_ctx);
+
+                assert_eq!(XtxGatewayVersions::<Runtime>::get(local_ctx.xtx_id), None);
+            });
+    }
+
+    #[test]
+    fn square_up_finalize_splits_executor_fee_with_referring_ambassador() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let (mut local_ctx, sfx_id, bid, _bid_id) = stage_single_sfx_xtx();
+
+                assert_ok!(Rewards::register_ambassador(
+                    RuntimeOrigin::root(),
+                    AMBASSADOR
+                ));
+                assert_ok!(Rewards::set_ambassador_rewards_share(
+                    RuntimeOrigin::root(),
+                    sp_runtime::Percent::from_percent(50)
+                ));
+                XtxReferrers::<Runtime>::insert(local_ctx.xtx_id, AMBASSADOR);
+
+                assert_ok!(request_and_bid_single_sfx_xtx(&mut local_ctx, &bid));
+
+                local_ctx.full_side_effects[0][0].confirmed = Some(ConfirmedSideEffect {
+                    err: None,
+                    output: None,
+                    inclusion_data: vec![0, 1, 2, 3],
+                    executioner: EXECUTOR,
+                    received_at: 1 as BlockNumber,
+                    cost: None,
+                });
+
+                assert!(SquareUp::<Runtime>::bind_bidders(&mut local_ctx));
+                assert!(SquareUp::<Runtime>::finalize(&local_ctx));
+
+                let referral_share = bid.amount / 2;
+                assert_eq!(
+                    <AccountManager as AccountManagerInterface<
+                        AccountId,
+                        Balance,
+                        Hash,
+                        BlockNumber,
+                        AssetId,
+                    >>::get_settlement(sfx_id),
+                    Some(Settlement {
+                        requester: REQUESTER,
+                        recipient: EXECUTOR,
+                        settlement_amount: bid.amount - referral_share,
+                        outcome: Outcome::Commit,
+                        source: BenefitSource::TrafficFees,
+                        role: CircuitRole::Executor,
+                        maybe_asset_id: None,
+                    })
+                );
+                assert_eq!(
+                    <AccountManager as AccountManagerInterface<
+                        AccountId,
+                        Balance,
+                        Hash,
+                        BlockNumber,
+                        AssetId,
+                    >>::get_settlement(
+                        SquareUp::<Runtime>::referral_settlement_id(sfx_id, &AMBASSADOR)
+                    ),
+                    Some(Settlement {
+                        requester: REQUESTER,
+                        recipient: AMBASSADOR,
+                        settlement_amount: referral_share,
+                        outcome: Outcome::Commit,
+                        source: BenefitSource::TrafficFees,
+                        role: CircuitRole::Ambassador,
+                        maybe_asset_id: None,
+                    })
+                );
+                assert_eq!(XtxReferrers::<Runtime>::get(local_ctx.xtx_id), None);
+                assert_eq!(
+                    Balances::free_balance(&REQUESTER),
+                    INITIAL_BALANCE - bid.amount
+                );
+            });
+    }
+
     #[test]
     fn square_up_finalize_reverts_xtx_if_some_fsx_are_unconfirmed() {
         ExtBuilder::default()
@@ -418,4 +619,83 @@ pub mod test {
                 assert_eq!(Balances::free_balance(&ESCROW_ACCOUNT), bid.insurance);
             });
     }
+
+    #[test]
+    fn square_up_charges_finality_fee_in_asset_chosen_by_requester() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let fee_asset_id: AssetId = 100;
+                let initial_asset_balance: Balance = 1_000_000_000_000_000;
+                let (mut local_ctx, _sfx_id, _bid, _bid_id) = stage_single_sfx_xtx();
+                local_ctx.full_side_effects[0][0].security_lvl = SecurityLvl::Escrow;
+
+                let native_fee = SquareUp::<Runtime>::charge_finality_fee(&local_ctx, &REQUESTER);
+                // Requester holds only dust of native currency, so the native fee can't be paid
+                assert_err!(
+                    native_fee,
+                    CircuitError::<Runtime>::RequesterNotEnoughBalance
+                );
+
+                setup_xdns_priced_fee_asset(fee_asset_id);
+                assert_ok!(Assets::mint_into(
+                    fee_asset_id,
+                    &REQUESTER,
+                    initial_asset_balance
+                ));
+                assert_ok!(Circuit::set_finality_fee_asset(
+                    RuntimeOrigin::signed(REQUESTER),
+                    Some(fee_asset_id)
+                ));
+                assert_eq!(
+                    FinalityFeeAssets::<Runtime>::get(REQUESTER),
+                    Some(fee_asset_id)
+                );
+
+                let fee_treasury =
+                    <Runtime as TreasuryAccountProvider<AccountId>>::get_treasury_account(
+                        TreasuryAccount::Fee,
+                    );
+                let charged =
+                    SquareUp::<Runtime>::charge_finality_fee(&local_ctx, &REQUESTER).unwrap();
+
+                assert!(charged > 0);
+                assert_eq!(Balances::free_balance(&REQUESTER), INITIAL_BALANCE);
+                assert_eq!(
+                    Assets::balance(fee_asset_id, &REQUESTER),
+                    initial_asset_balance - charged
+                );
+                assert_eq!(Assets::balance(fee_asset_id, &fee_treasury), charged);
+                // Native finality fee estimate converted at 1 FEE = 2 TRN
+                let native_estimate = <Attesters as AttestersReadApi<
+                    AccountId,
+                    Balance,
+                    BlockNumber,
+                >>::estimate_finality_fee(
+                    &local_ctx.full_side_effects[0][0].input.target
+                );
+                assert_eq!(charged, native_estimate / 2);
+            });
+    }
+
+    #[test]
+    fn set_finality_fee_asset_rejects_asset_without_xdns_price_estimate() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                assert_noop!(
+                    Circuit::set_finality_fee_asset(RuntimeOrigin::signed(REQUESTER), Some(100)),
+                    CircuitError::<Runtime>::FinalityFeeAssetPriceEstimateMissing
+                );
+                assert_ok!(Circuit::set_finality_fee_asset(
+                    RuntimeOrigin::signed(REQUESTER),
+                    None
+                ));
+                assert_eq!(FinalityFeeAssets::<Runtime>::get(REQUESTER), None);
+            });
+    }
 }
@@ -1306,6 +1306,156 @@ fn successfully_confirm_optimistic_transfer() {
 //     assert_eq!(created_sfx_bid.executor, relayer);
 // }
 
+#[test]
+fn confirms_optimistic_transfer_against_pinned_gateway_version_evicted_from_history() {
+    use frame_support::traits::Get;
+    use t3rn_primitives::xdns::Xdns;
+
+    let origin = Origin::signed(ALICE);
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+    let target = valid_transfer_side_effect.target;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 3);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 2);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+            let pinned_version = XDNS::get_gateway_version(&target);
+            assert_eq!(
+                Circuit::get_xtx_gateway_versions(xtx_id),
+                Some(vec![(target, pinned_version)])
+            );
+
+            // The gateway changes enough times to push the pinned version out of the kept history
+            for _ in 0..<Runtime as pallet_xdns::Config>::MaxGatewayHistory::get() {
+                XDNS::snapshot_gateway_version(target);
+            }
+            assert!(XDNS::gateway_history(target, pinned_version).is_some());
+
+            place_winning_bid_and_advance_3_blocks(
+                BOB_RELAYER,
+                xtx_id,
+                side_effect_a_id,
+                1 as Balance,
+            );
+
+            let mut scale_encoded_transfer_event = pallet_balances::Event::<Runtime>::Transfer {
+                from: BOB,
+                to: AccountId32::new([9u8; 32]),
+                amount: 1,
+            }
+            .encode();
+            // append an extra pallet event index byte as the second byte
+            scale_encoded_transfer_event.insert(1, 4u8);
+
+            assert_ok!(Circuit::confirm_side_effect(
+                Origin::signed(BOB_RELAYER),
+                side_effect_a_id,
+                ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
+                    err: None,
+                    output: None,
+                    inclusion_data: scale_encoded_transfer_event,
+                    executioner: ALICE,
+                    received_at: System::block_number(),
+                    cost: None,
+                }
+            ));
+
+            // Once finalized, nothing pins the evicted version anymore
+            assert_eq!(Circuit::get_xtx_gateway_versions(xtx_id), None);
+            assert_eq!(XDNS::gateway_version_pins(target, pinned_version), 0);
+            assert!(XDNS::gateway_history(target, pinned_version).is_none());
+        })
+}
+
+#[test]
+fn orders_on_pending_gateway_are_capped_in_value() {
+    use frame_support::traits::Get;
+    use t3rn_primitives::{xdns::Xdns, ExecutionVendor, GatewayVendor};
+
+    let registrant = AccountId32::new([7u8; 32]);
+    let max_order_value = <Runtime as pallet_xdns::Config>::PendingGatewayMaxOrderValue::get();
+
+    let mut capped_transfer = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+    capped_transfer.target = *b"pndg";
+    let mut exceeding_transfer = capped_transfer.clone();
+    exceeding_transfer.max_reward = max_order_value + 1;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let bond = <Runtime as pallet_xdns::Config>::GatewayRegistrationBond::get();
+            let _ = Balances::deposit_creating(&registrant, bond * 2);
+            let _ = Balances::deposit_creating(&ALICE, max_order_value * 2);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            // Pending gateway verified by the already initialized Rococo light client
+            assert_ok!(XDNS::add_pending_gateway(
+                registrant.clone(),
+                *b"pndg",
+                GatewayVendor::Rococo,
+                ExecutionVendor::Substrate,
+                t3rn_abi::Codec::Scale,
+                None,
+                vec![(*b"tran", Some(2))],
+            ));
+            XDNS::process_all_verifier_overviews(System::block_number());
+            XDNS::process_overview(System::block_number());
+
+            assert_err!(
+                Circuit::on_extrinsic_trigger(
+                    Origin::signed(ALICE),
+                    vec![exceeding_transfer],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                ),
+                circuit_error::<Runtime>::PendingGatewayOrderValueExceeded,
+            );
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![capped_transfer],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+        })
+}
+
 #[test]
 fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_submitted() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -35,6 +35,7 @@ pub trait WeightInfo {
     fn cancel_xtx() -> Weight;
     fn confirm_side_effect() -> Weight;
     fn execute_side_effects_with_xbi() -> Weight;
+    fn set_finality_fee_asset() -> Weight;
 }
 
 /// Storage: `XDNS::Gateways` (r:2 w:0)
@@ -104,6 +105,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn execute_side_effects_with_xbi() -> Weight {
         single_order_weight::<T>()
     }
+
+    // Estimated until benchmarked, not measured.
+    // Storage: XDNS AssetEstimatesValidUntil (r:1 w:0)
+    // Storage: XDNS AssetEstimatesInNative (r:1 w:0)
+    // Storage: Circuit FinalityFeeAssets (r:0 w:1)
+    fn set_finality_fee_asset() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
 }
 
 // For backwards compatibility and tests
@@ -131,4 +142,10 @@ impl WeightInfo for () {
     fn execute_side_effects_with_xbi() -> Weight {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
+
+    fn set_finality_fee_asset() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
 }
@@ -100,7 +100,34 @@ impl RemoteEVMOrderLocalized {
             nonce: decoded_remote_order_log.nonce,
         };
 
-        Ok(decoded_remote_order)
+        decoded_remote_order.normalize_to_canonical::<T>(remote_target_id)
+    }
+
+    /// Normalises the amounts emitted in the decimals of the assets on the remote gateway
+    /// to the canonical decimals of the assets on the Circuit: the amount in the decimals
+    /// of the ordered asset, the insurance and max_reward in the decimals of the reward asset.
+    pub fn normalize_to_canonical<T: Config>(
+        mut self,
+        remote_target_id: TargetId,
+    ) -> Result<RemoteEVMOrderLocalized, DispatchError> {
+        let normalize = |asset: Asset, amount: U256| -> Result<U256, DispatchError> {
+            if amount > U256::from(u128::MAX) {
+                return Err(DispatchError::Other(
+                    "RemoteEVMOrderLocalized::normalize_to_canonical -- amount exceeds u128",
+                ))
+            }
+            T::Xdns::normalize_asset_amount_to_canonical(asset, &remote_target_id, amount.as_u128())
+                .map(U256::from)
+        };
+        self.amount = normalize(self.asset, self.amount)?;
+        // Reward assets unknown to XDNS have no canonical group, so their amounts stay as emitted.
+        if let Ok(reward_token) =
+            T::Xdns::get_token_by_eth_address(remote_target_id, self.reward_asset)
+        {
+            self.insurance = normalize(reward_token.token_id, self.insurance)?;
+            self.max_reward = normalize(reward_token.token_id, self.max_reward)?;
+        }
+        Ok(self)
     }
 }
 
@@ -300,6 +327,22 @@ pub mod pallet {
         // Define your errors here
         XdnsGatewayDoesNotHaveRemoteOrderAddressRegistered,
         XdnsGatewayDoesNotHaveEscrowAddressRegistered,
+        /// One of the gateways the fault proof is verified on isn't active
+        FaultProofGatewayNotActive,
+        /// The executor on the destination isn't the winner of the bid
+        FaultProofExecutorIsNotBidWinner,
+        /// The fault proof can only be submitted by the bid winner
+        FaultProofSubmitterIsNotExecutor,
+        /// The executed amount doesn't match the ordered amount
+        FaultProofAmountMismatch,
+        /// The executed asset doesn't match the ordered asset
+        FaultProofAssetMismatch,
+        /// The execution went to a different account than the order's target
+        FaultProofTargetAccountMismatch,
+        /// The attestation already released the order to the bid winner
+        FaultProofAttestationReleasedToBidWinner,
+        /// The execution, bid and attestation refer to different orders
+        FaultProofOrderIdMismatch,
     }
 
     #[pallet::hooks]
@@ -330,7 +373,7 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::dynamic_destination_deal())]
         pub fn dynamic_destination_deal(
             origin: OriginFor<T>,
             destination: TargetId,
@@ -665,7 +708,8 @@ pub mod pallet {
                 insurance: decoded_remote_order_log.insurance,
                 max_reward: decoded_remote_order_log.max_reward,
                 nonce: decoded_remote_order_log.nonce,
-            };
+            }
+            .normalize_to_canonical::<T>(gateway_id)?;
 
             let side_effect: SideEffect<T::AccountId, BalanceOf<T>> =
                 decoded_remote_order.clone().try_into()?;
@@ -702,20 +746,20 @@ pub mod pallet {
             let destination_gateway_id = vacuum_evm_proof.destination_gateway_id.clone();
 
             // Make sure all gateways are active
-            assert!(T::Xdns::is_target_active(
-                circuit_gateway_id.clone(),
-                &SecurityLvl::Optimistic
-            ));
+            ensure!(
+                T::Xdns::is_target_active(circuit_gateway_id.clone(), &SecurityLvl::Optimistic),
+                Error::<T>::FaultProofGatewayNotActive
+            );
 
-            assert!(T::Xdns::is_target_active(
-                source_gateway_id.clone(),
-                &SecurityLvl::Optimistic
-            ));
+            ensure!(
+                T::Xdns::is_target_active(source_gateway_id.clone(), &SecurityLvl::Optimistic),
+                Error::<T>::FaultProofGatewayNotActive
+            );
 
-            assert!(T::Xdns::is_target_active(
-                destination_gateway_id.clone(),
-                &SecurityLvl::Optimistic
-            ));
+            ensure!(
+                T::Xdns::is_target_active(destination_gateway_id.clone(), &SecurityLvl::Optimistic),
+                Error::<T>::FaultProofGatewayNotActive
+            );
 
             let circuit_bidding_address_generalized: ExecutionSource = ExecutionSource::decode(
                 &mut &T::Xdns::get_remote_bidding_contract_address(source_gateway_id.clone())?[..],
@@ -788,22 +832,38 @@ pub mod pallet {
                 )?;
 
             // Verify executor's address equals the winner's address
-            assert!(bid.winner == decoded_remote_commit.sender, "Vacuum::evm_submit_fault_proof -- executor's address does not match the winner's address");
+            ensure!(
+                bid.winner == decoded_remote_commit.sender,
+                Error::<T>::FaultProofExecutorIsNotBidWinner
+            );
 
             // Map bid.winner to local account id
             let executor = T::AddressMapping::into_account_id(&bid.winner);
 
             // Ensure the executor is the one who submitted the fault proof to avoid confusion
-            assert!(executor == who, "Vacuum::evm_submit_fault_proof -- executor's address does not match the fault proof submitter's address");
+            ensure!(
+                executor == who,
+                Error::<T>::FaultProofSubmitterIsNotExecutor
+            );
 
-            // Verify execution has been provided to correct target account with matching amount & asset
-            assert!(
-                decoded_remote_commit.amount == localized_order.amount,
-                "Vacuum::evm_submit_fault_proof -- amount does not match the execution amount"
+            // Verify execution has been provided to correct target account with matching amount & asset.
+            // The order amount is canonical already, the executed one is in the destination's decimals.
+            ensure!(
+                decoded_remote_commit.amount <= U256::from(u128::MAX),
+                Error::<T>::FaultProofAmountMismatch
             );
-            assert!(
+            let executed_amount = T::Xdns::normalize_asset_amount_to_canonical(
+                localized_order.asset,
+                &destination_gateway_id,
+                decoded_remote_commit.amount.as_u128(),
+            )?;
+            ensure!(
+                U256::from(executed_amount) == localized_order.amount,
+                Error::<T>::FaultProofAmountMismatch
+            );
+            ensure!(
                 decoded_remote_commit.asset == localized_order.reward_asset,
-                "Vacuum::evm_submit_fault_proof -- asset does not match the execution asset"
+                Error::<T>::FaultProofAssetMismatch
             );
             // Convert decoded_remote_commit.target from H160 to Localized AccountId32 by pre-pending 12 0-bytes
             let mut target_account_bytes = [0u8; 32];
@@ -815,7 +875,10 @@ pub mod pallet {
                     )
                 })?;
 
-            assert!(target_account == localized_order.target_account, "Vacuum::evm_submit_fault_proof -- target account does not match the execution target account");
+            ensure!(
+                target_account == localized_order.target_account,
+                Error::<T>::FaultProofTargetAccountMismatch
+            );
 
             let verified_attestation_bytes = T::CircuitSubmitAPI::verify_sfx_proof(
                 source_gateway_id,
@@ -830,17 +893,20 @@ pub mod pallet {
             )?;
 
             // Verify the fault proof - attestation hasn't been released to the bid winner
-            assert!(
+            ensure!(
                 attestation.executor != bid.winner,
-                "Vacuum::evm_submit_fault_proof -- attestation has been released to the bid winner"
+                Error::<T>::FaultProofAttestationReleasedToBidWinner
             );
 
             // Make sure order id matches
-            assert!(
+            ensure!(
                 decoded_remote_commit.sfx_id == bid.sfx_id,
-                "Vacuum::evm_submit_fault_proof -- order id does not match the execution order id"
+                Error::<T>::FaultProofOrderIdMismatch
+            );
+            ensure!(
+                decoded_remote_commit.sfx_id == attestation.sfx_id,
+                Error::<T>::FaultProofOrderIdMismatch
             );
-            assert!(decoded_remote_commit.sfx_id == attestation.sfx_id, "Vacuum::evm_submit_fault_proof -- order id does not match the attestation order id");
 
             let sfx_only_fsx = t3rn_types::fsx::FullSideEffect {
                 input: side_effect,
@@ -922,7 +988,7 @@ mod tests {
 
     use frame_support::{assert_err, assert_ok, traits::Hooks};
     use hex_literal::hex;
-    use sp_core::H256;
+    use sp_core::{H160, H256, U256};
     use sp_runtime::{traits::Keccak256, AccountId32};
     use sp_std::convert::TryInto;
     pub use t3rn_mini_mock_runtime::{
@@ -931,10 +997,12 @@ mod tests {
         Balance, Balances, BlockNumber, Circuit, CircuitError, CircuitEvent, Clock, ConfigVacuum,
         EthereumEventInclusionProof, GlobalOnInitQueues, Hash, MiniRuntime, MockedAssetEvent,
         OrderStatusRead, Portal, Rewards, RuntimeEvent as Event, RuntimeOrigin, System, Vacuum,
-        VacuumEvent, ASSET_ASTAR, ASSET_DOT, ASSET_ETH, ASSET_USDT, ASTAR_TARGET, ETHEREUM_TARGET,
-        POLKADOT_TARGET, XDNS,
+        VacuumEvent, ASSET_ASTAR, ASSET_DOT, ASSET_ETH, ASSET_TRN, ASSET_USDT, ASTAR_TARGET,
+        ETHEREUM_TARGET, POLKADOT_TARGET, XDNS,
     };
 
+    use super::RemoteEVMOrderLocalized;
+
     use t3rn_primitives::{
         circuit::{
             types::{OrderSFX, SFXAction},
@@ -2521,4 +2589,81 @@ mod tests {
             assert_eq!(Circuit::get_dlq(xtx_id), None);
         });
     }
+
+    #[test]
+    fn remote_order_normalizes_reward_amounts_with_the_reward_asset_decimals() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            let trn_address = H160::from(hex!("670B24610DF99b1685aEAC0dfD5307B92e0cF4d7"));
+            // USDT keeps 6 decimals on Ethereum, TRN is emitted with 18 instead of its canonical 12
+            assert_ok!(XDNS::register_canonical_asset(
+                RuntimeOrigin::root(),
+                ASSET_USDT,
+                b"USDT".to_vec(),
+                6
+            ));
+            assert_ok!(XDNS::register_canonical_asset(
+                RuntimeOrigin::root(),
+                ASSET_TRN,
+                b"TRN".to_vec(),
+                12
+            ));
+            assert_ok!(XDNS::add_canonical_asset_member(
+                RuntimeOrigin::root(),
+                ASSET_TRN,
+                ETHEREUM_TARGET,
+                TokenInfo::Ethereum(EthereumToken {
+                    symbol: b"TRN".to_vec(),
+                    decimals: 18,
+                    address: Some(trn_address.into()),
+                })
+            ));
+
+            let remote_order = RemoteEVMOrderLocalized {
+                from: H160::repeat_byte(1),
+                destination: ASTAR_TARGET,
+                asset: ASSET_USDT,
+                target_account: AccountId32::from([3u8; 32]),
+                reward_asset: trn_address,
+                amount: U256::from(5_000_000u128),
+                insurance: U256::from(1_000_000_000_000_000_000u128),
+                max_reward: U256::from(2_000_000_000_000_000_000u128),
+                nonce: 0,
+            };
+
+            let normalized = remote_order
+                .clone()
+                .normalize_to_canonical::<MiniRuntime>(ETHEREUM_TARGET)
+                .unwrap();
+
+            assert_eq!(normalized.amount, U256::from(5_000_000u128));
+            assert_eq!(normalized.insurance, U256::from(1_000_000_000_000u128));
+            assert_eq!(normalized.max_reward, U256::from(2_000_000_000_000u128));
+        });
+    }
+
+    #[test]
+    fn remote_order_keeps_reward_amounts_of_unregistered_reward_assets() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            let remote_order = RemoteEVMOrderLocalized {
+                from: H160::repeat_byte(1),
+                destination: ASTAR_TARGET,
+                asset: ASSET_USDT,
+                target_account: AccountId32::from([3u8; 32]),
+                reward_asset: H160::repeat_byte(9),
+                amount: U256::from(5_000_000u128),
+                insurance: U256::from(1_000_000_000_000_000_000u128),
+                max_reward: U256::from(2_000_000_000_000_000_000u128),
+                nonce: 0,
+            };
+
+            let normalized = remote_order
+                .clone()
+                .normalize_to_canonical::<MiniRuntime>(ETHEREUM_TARGET)
+                .unwrap();
+
+            assert_eq!(normalized, remote_order);
+        });
+    }
 }
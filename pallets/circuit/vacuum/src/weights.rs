@@ -36,6 +36,10 @@ use core::marker::PhantomData;
 
 pub trait WeightInfo {
     fn single_order() -> Weight;
+    fn dynamic_destination_deal() -> Weight;
+    fn confirm() -> Weight;
+    fn teleport_order(p: u32) -> Weight;
+    fn submit_fault_proof(p: u32) -> Weight;
 }
 pub struct SubstrateWeight<T>(PhantomData<T>);
 
@@ -81,4 +85,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(11))
             .saturating_add(T::DbWeight::get().writes(9))
     }
+
+    // The weights below are estimated from `single_order` and the Portal's inclusion
+    // verification until benchmarked.
+
+    /// Orders to the destination decided by the executors, settling like a single order
+    /// with the extra nonce-keyed remote origin lookup.
+    fn dynamic_destination_deal() -> Weight {
+        Weight::from_parts(1_492_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 7689))
+            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().writes(9))
+    }
+    /// Confirmations are settled by the circuit's own confirmation path, the precompile
+    /// only forwards them.
+    fn confirm() -> Weight {
+        Weight::from_parts(21_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 1024))
+    }
+    /// Verifies the remote order's inclusion proof of `p` bytes through the Portal,
+    /// then triggers the order on behalf of its remote origin.
+    fn teleport_order(p: u32, ) -> Weight {
+        Weight::from_parts(1_539_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 11_273))
+            .saturating_add(Weight::from_parts(6_000, 1).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(16))
+            .saturating_add(T::DbWeight::get().writes(9))
+    }
+    /// Verifies the order, bid, execution and attestation inclusion proofs of `p` bytes in total
+    /// through the Portal and repatriates the attested escrow to the executor.
+    fn submit_fault_proof(p: u32, ) -> Weight {
+        Weight::from_parts(585_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 19_464))
+            .saturating_add(Weight::from_parts(6_000, 1).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(30))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
 }
@@ -0,0 +1,25 @@
+//! Runtime API definition required by Clock RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding access to the round timings and the status of round handlers.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::sp_std;
+use sp_std::prelude::*;
+pub use t3rn_primitives::clock::{RoundHandlerId, RoundHandlerStatus, RoundTimings};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Clock
+    pub trait ClockRuntimeApi<BlockNumber> where
+        BlockNumber: Codec,
+    {
+        /// Returns the timings of the current round
+        fn fetch_round_timings() -> RoundTimings<BlockNumber>;
+
+        /// Returns the status of the latest run of each round handler
+        fn fetch_round_handler_statuses() -> Vec<(RoundHandlerId, RoundHandlerStatus<BlockNumber>)>;
+    }
+}
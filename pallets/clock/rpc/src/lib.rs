@@ -0,0 +1,88 @@
+//! RPC interface for the Clock pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+pub use pallet_clock_rpc_runtime_api::ClockRuntimeApi;
+use pallet_clock_rpc_runtime_api::{RoundHandlerId, RoundHandlerStatus, RoundTimings};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::sp_std;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+use sp_std::prelude::*;
+use std::sync::Arc;
+
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc(client, server)]
+pub trait ClockApi<BlockNumber> {
+    /// Returns the timings of the current round
+    #[method(name = "clock_fetchRoundTimings")]
+    fn fetch_round_timings(&self) -> RpcResult<RoundTimings<BlockNumber>>;
+
+    /// Returns the status of the latest run of each round handler
+    #[method(name = "clock_fetchRoundHandlerStatuses")]
+    fn fetch_round_handler_statuses(
+        &self,
+    ) -> RpcResult<Vec<(RoundHandlerId, RoundHandlerStatus<BlockNumber>)>>;
+}
+
+/// A struct that implements the [`ClockApiServer`].
+pub struct Clock<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Clock<C, P> {
+    /// Create new `Clock` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, BlockNumber> ClockApiServer<BlockNumber> for Clock<C, Block>
+where
+    BlockNumber: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ClockRuntimeApi<Block, BlockNumber>,
+{
+    fn fetch_round_timings(&self) -> RpcResult<RoundTimings<BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_round_timings(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_round_handler_statuses(
+        &self,
+    ) -> RpcResult<Vec<(RoundHandlerId, RoundHandlerStatus<BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_round_handler_statuses(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(jsonrpsee::types::ErrorObject::owned(
+        RUNTIME_ERROR as i32,
+        "Runtime Error - Clock RPC",
+        Some(format!("{err:?}")),
+    )))
+}
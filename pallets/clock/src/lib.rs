@@ -9,14 +9,31 @@
 #![allow(clippy::too_many_arguments)]
 
 pub use crate::pallet::*;
+use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::Weight, traits::Get};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
 
 pub use t3rn_primitives::{
-    account_manager::AccountManager, claimable::ClaimableArtifacts, clock::Clock,
-    common::RoundInfo, executors::Executors, gateway::GatewayABIConfig, ChainId, EscrowTrait,
-    GatewayGenesisConfig, GatewayType, GatewayVendor,
+    account_manager::AccountManager,
+    claimable::ClaimableArtifacts,
+    clock::{Clock, RoundHandler, RoundHandlerId, RoundHandlerStatus, RoundTimings},
+    common::RoundInfo,
+    executors::Executors,
+    gateway::GatewayABIConfig,
+    ChainId, EscrowTrait, GatewayGenesisConfig, GatewayType, GatewayVendor,
 };
 
+/// Run of a round handler scheduled by the round bump and awaiting execution.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ScheduledRoundRun<BlockNumber> {
+    pub id: RoundHandlerId,
+    pub past_round: RoundInfo<BlockNumber>,
+    pub new_round: RoundInfo<BlockNumber>,
+    pub scheduled_at: BlockNumber,
+    pub deferrals: u32,
+}
+
 #[cfg(test)]
 mod tests;
 
@@ -35,6 +52,8 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
+    use sp_runtime::traits::Saturating;
+    use sp_std::{vec, vec::Vec};
     use t3rn_primitives::clock::OnHookQueues;
 
     const FIVE: u64 = 5;
@@ -52,6 +71,13 @@ pub mod pallet {
         type OnInitializeQueues: OnHookQueues<Self>;
 
         type OnFinalizeQueues: OnHookQueues<Self>;
+
+        /// Handlers registered to run once the round is bumped, in the given order.
+        type RoundHandlers: Get<Vec<RoundHandler<Self>>>;
+
+        /// Max. weight spent on round handlers per block. Handlers over the limit are deferred to the next blocks.
+        #[pallet::constant]
+        type RoundHandlersWeightLimit: Get<Weight>;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -65,6 +91,18 @@ pub mod pallet {
     /// Information on the current round.
     pub type CurrentRound<T: Config> = StorageValue<_, RoundInfo<BlockNumberFor<T>>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_round_runs)]
+    /// Round handler runs awaiting execution, in order of execution.
+    pub type ScheduledRoundRuns<T: Config> =
+        StorageValue<_, Vec<ScheduledRoundRun<BlockNumberFor<T>>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn round_handler_status)]
+    /// Status of the latest run of each round handler.
+    pub type RoundHandlerRuns<T: Config> =
+        StorageMap<_, Twox64Concat, RoundHandlerId, RoundHandlerStatus<BlockNumberFor<T>>>;
+
     impl<T: Config> Pallet<T> {
         pub fn check_bump_round(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
             let past_round = <CurrentRound<T>>::get();
@@ -86,11 +124,134 @@ pub mod pallet {
                     head: new_round.head,
                     term: new_round.term,
                 });
-                T::DbWeight::get().reads_writes(2, 1)
+                let scheduled = Self::schedule_round_handlers(n, past_round, new_round);
+                T::DbWeight::get().reads_writes(3, 2u64.saturating_add(scheduled))
             } else {
                 T::DbWeight::get().reads(2)
             }
         }
+
+        fn schedule_round_handlers(
+            n: BlockNumberFor<T>,
+            past_round: RoundInfo<BlockNumberFor<T>>,
+            new_round: RoundInfo<BlockNumberFor<T>>,
+        ) -> u64 {
+            let handlers = T::RoundHandlers::get();
+            let mut scheduled_runs = <ScheduledRoundRuns<T>>::get();
+            for handler in handlers.iter() {
+                let run = ScheduledRoundRun {
+                    id: handler.id,
+                    past_round,
+                    new_round,
+                    scheduled_at: n,
+                    deferrals: 0,
+                };
+                Self::note_round_handler_status(&run, None, Weight::zero());
+                scheduled_runs.push(run);
+            }
+            <ScheduledRoundRuns<T>>::put(scheduled_runs);
+            handlers.len() as u64
+        }
+
+        fn note_round_handler_status(
+            run: &ScheduledRoundRun<BlockNumberFor<T>>,
+            ran_at: Option<BlockNumberFor<T>>,
+            weight_consumed: Weight,
+        ) {
+            <RoundHandlerRuns<T>>::insert(
+                run.id,
+                RoundHandlerStatus {
+                    round: run.new_round.index,
+                    scheduled_at: run.scheduled_at,
+                    ran_at,
+                    weight_consumed,
+                    deferrals: run.deferrals,
+                },
+            );
+        }
+
+        /// Run the scheduled round handlers in order, as long as their weight budgets fit within the limit.
+        /// At least one handler runs per block, so that a single budget over the limit can't stall the queue.
+        /// The remaining runs are deferred to the next blocks.
+        pub fn process_round_handlers(n: BlockNumberFor<T>, weight_limit: Weight) -> Weight {
+            let scheduled_runs = <ScheduledRoundRuns<T>>::get();
+            if scheduled_runs.is_empty() {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let handlers = T::RoundHandlers::get();
+            let mut total_consumed = T::DbWeight::get().reads_writes(1, 1);
+            let mut deferred_runs = vec![];
+            let mut executed: u32 = 0;
+
+            for mut run in scheduled_runs.into_iter() {
+                let handler = match handlers.iter().find(|handler| handler.id == run.id) {
+                    Some(handler) => handler,
+                    None => {
+                        log::warn!(
+                            "Clock::process_round_handlers dropping run of unregistered handler: {:?}",
+                            run.id
+                        );
+                        continue
+                    },
+                };
+
+                let fits_limit = total_consumed
+                    .saturating_add(handler.weight_budget)
+                    .all_lte(weight_limit);
+                if deferred_runs.is_empty() && (fits_limit || executed == 0) {
+                    let weight = (handler.on_new_round)(run.past_round, run.new_round);
+                    if weight.any_gt(handler.weight_budget) {
+                        log::warn!(
+                            "Clock::process_round_handlers handler {:?} consumed {:?} over its budget {:?}",
+                            run.id,
+                            weight,
+                            handler.weight_budget
+                        );
+                    }
+                    Self::note_round_handler_status(&run, Some(n), weight);
+                    Self::deposit_event(Event::RoundHandlerExecuted {
+                        id: run.id,
+                        round: run.new_round.index,
+                        weight,
+                    });
+                    total_consumed = total_consumed
+                        .saturating_add(weight)
+                        .saturating_add(T::DbWeight::get().writes(1));
+                    executed = executed.saturating_add(1);
+                } else {
+                    run.deferrals = run.deferrals.saturating_add(1);
+                    Self::note_round_handler_status(&run, None, Weight::zero());
+                    Self::deposit_event(Event::RoundHandlerDeferred {
+                        id: run.id,
+                        round: run.new_round.index,
+                        deferrals: run.deferrals,
+                    });
+                    total_consumed = total_consumed.saturating_add(T::DbWeight::get().writes(1));
+                    deferred_runs.push(run);
+                }
+            }
+
+            <ScheduledRoundRuns<T>>::put(deferred_runs);
+            total_consumed
+        }
+
+        /// Timings of the current round, relative to the given block.
+        pub fn round_timings(n: BlockNumberFor<T>) -> RoundTimings<BlockNumberFor<T>> {
+            let current = Self::current_round();
+            let next_round_at = current.head.saturating_add(current.term);
+            RoundTimings {
+                current,
+                next_round_at,
+                blocks_remaining: next_round_at.saturating_sub(n),
+            }
+        }
+
+        /// Status of the latest run of each round handler.
+        pub fn round_handler_statuses(
+        ) -> Vec<(RoundHandlerId, RoundHandlerStatus<BlockNumberFor<T>>)> {
+            <RoundHandlerRuns<T>>::iter().collect()
+        }
     }
 
     // Pallet implements [`Hooks`] trait to define some logic to execute in some context.
@@ -117,7 +278,21 @@ pub mod pallet {
                 max_on_initialize_weight,
                 n
             );
-            T::OnInitializeQueues::process(n, max_on_initialize_weight)
+            let consumed = T::OnInitializeQueues::process(n, max_on_initialize_weight);
+            let round_handlers_weight_limit = max_on_initialize_weight
+                .saturating_sub(consumed)
+                .min(T::RoundHandlersWeightLimit::get());
+            consumed.saturating_add(Self::process_round_handlers(n, round_handlers_weight_limit))
+        }
+
+        fn integrity_test() {
+            let handlers = T::RoundHandlers::get();
+            for (i, handler) in handlers.iter().enumerate() {
+                assert!(
+                    handlers[i + 1..].iter().all(|other| other.id != handler.id),
+                    "Clock::RoundHandlers must have unique ids"
+                );
+            }
         }
 
         // A runtime code run after every block and have access to extended set of APIs.
@@ -140,6 +315,16 @@ pub mod pallet {
             head: frame_system::pallet_prelude::BlockNumberFor<T>,
             term: frame_system::pallet_prelude::BlockNumberFor<T>,
         },
+        RoundHandlerExecuted {
+            id: RoundHandlerId,
+            round: u32,
+            weight: Weight,
+        },
+        RoundHandlerDeferred {
+            id: RoundHandlerId,
+            round: u32,
+            deferrals: u32,
+        },
     }
 
     #[pallet::error]
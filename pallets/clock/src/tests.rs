@@ -1,6 +1,14 @@
 #[cfg(test)]
 pub mod clock_test {
-    use t3rn_mini_mock_runtime::{BlockNumber, Clock, ExtBuilder, System};
+    use frame_support::weights::Weight;
+    use pallet_account_manager::RECONCILIATION_ROUND_HANDLER;
+    use pallet_attesters::SHUFFLING_ROUND_HANDLER;
+    use pallet_rewards::SETTLEMENTS_ROUND_HANDLER;
+    use pallet_xdns::VERIFIER_OVERVIEW_ROUND_HANDLER;
+    use t3rn_mini_mock_runtime::{
+        AccountManager, BlockNumber, Clock, ExtBuilder, RoundHandlers, RoundHandlersWeightLimit,
+        System,
+    };
     use t3rn_primitives::common::RoundInfo;
 
     #[test]
@@ -47,4 +55,77 @@ pub mod clock_test {
             assert_eq!(current_round, expected_round_next);
         });
     }
+
+    #[test]
+    fn round_handlers_over_the_weight_limit_are_deferred_to_next_blocks() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let handlers_count = RoundHandlers::get().len();
+            System::set_block_number(300u32);
+            Clock::check_bump_round(BlockNumber::from(300u32));
+            System::set_block_number(600u32);
+            Clock::check_bump_round(BlockNumber::from(600u32));
+
+            assert_eq!(Clock::scheduled_round_runs().len(), 2 * handlers_count);
+            let status = Clock::round_handler_status(RECONCILIATION_ROUND_HANDLER).unwrap();
+            assert_eq!(status.round, 3);
+            assert_eq!(status.scheduled_at, 600);
+            assert_eq!(status.ran_at, None);
+
+            // Only the first run is executed while the rest is over the limit
+            System::set_block_number(601u32);
+            Clock::process_round_handlers(BlockNumber::from(601u32), Weight::zero());
+
+            let scheduled_runs = Clock::scheduled_round_runs();
+            assert_eq!(scheduled_runs.len(), 2 * handlers_count - 1);
+            assert_eq!(scheduled_runs[0].new_round.index, 2);
+            assert!(scheduled_runs.iter().all(|run| run.deferrals == 1));
+            assert!(AccountManager::reconciliation_report(1).is_none());
+            let status = Clock::round_handler_status(RECONCILIATION_ROUND_HANDLER).unwrap();
+            assert_eq!(status.round, 3);
+            assert_eq!(status.ran_at, None);
+            assert_eq!(status.deferrals, 1);
+
+            System::set_block_number(602u32);
+            Clock::process_round_handlers(
+                BlockNumber::from(602u32),
+                RoundHandlersWeightLimit::get(),
+            );
+
+            assert!(AccountManager::reconciliation_report(1).is_some());
+            assert!(AccountManager::reconciliation_report(2).is_some());
+            assert!(Clock::scheduled_round_runs().is_empty());
+            let status = Clock::round_handler_status(RECONCILIATION_ROUND_HANDLER).unwrap();
+            assert_eq!(status.round, 3);
+            assert_eq!(status.ran_at, Some(602));
+            assert_eq!(status.deferrals, 1);
+
+            let statuses = Clock::round_handler_statuses();
+            assert_eq!(statuses.len(), handlers_count);
+            for id in [
+                VERIFIER_OVERVIEW_ROUND_HANDLER,
+                SETTLEMENTS_ROUND_HANDLER,
+                SHUFFLING_ROUND_HANDLER,
+                RECONCILIATION_ROUND_HANDLER,
+            ] {
+                assert!(statuses
+                    .iter()
+                    .any(|(handler, status)| *handler == id && status.ran_at == Some(602)));
+            }
+        });
+    }
+
+    #[test]
+    fn round_timings_count_blocks_remaining_to_next_round() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(300u32);
+            Clock::check_bump_round(BlockNumber::from(300u32));
+
+            let timings = Clock::round_timings(BlockNumber::from(350u32));
+            assert_eq!(timings.current, Clock::current_round());
+            assert_eq!(timings.next_round_at, 600);
+            assert_eq!(timings.blocks_remaining, 250);
+        });
+    }
 }
@@ -68,6 +68,9 @@ pub mod pallet {
 
     use frame_system::pallet_prelude::*;
 
+    /// The current storage migration version.
+    pub const CURRENT_STORAGE_VERSION: u32 = 1;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching event type.
@@ -91,6 +94,24 @@ pub mod pallet {
     // Pallet implements [`Hooks`] trait to define some logic to execute in some context.
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageMigrations::<T>::get() >= CURRENT_STORAGE_VERSION {
+                return T::DbWeight::get().reads(1)
+            }
+            // Storage Migration: AuthorInfo gains revenue_splits and pricing,
+            // existing authors keep a flat fee with no co-authors.
+            let mut translated: u64 = 0;
+            ContractsRegistry::<T>::translate::<
+                LegacyRegistryContract<T::Hash, T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+                _,
+            >(|_contract_id, legacy| {
+                translated += 1;
+                Some(legacy.into())
+            });
+            StorageMigrations::<T>::put(CURRENT_STORAGE_VERSION);
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+
         // `on_initialize` is executed at the beginning of the block before any extrinsic are
         // dispatched.
         //
@@ -136,6 +157,11 @@ pub mod pallet {
                 "only the first submitter of contract to registry can become the author",
             );
 
+            ensure!(
+                contract.author.has_valid_revenue_splits(),
+                Error::<T>::InvalidRevenueSplits
+            );
+
             if <ContractsRegistry<T>>::contains_key(contract_id) {
                 Err(Error::<T>::ContractAlreadyExists.into())
             } else {
@@ -181,6 +207,8 @@ pub mod pallet {
         ContractAlreadyExists,
         /// Access of unknown contract
         UnknownContract,
+        /// Co-author revenue shares are zero or add up to more than 100%
+        InvalidRevenueSplits,
     }
 
     /// The pre-validated composable contracts on-chain registry.
@@ -199,6 +227,10 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Version of the last storage migration applied to the registry.
+    #[pallet::storage]
+    pub type StorageMigrations<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     // The genesis config type.
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
@@ -210,7 +242,10 @@ pub mod pallet {
     // The build of genesis for the pallet.
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
-        fn build(&self) {}
+        fn build(&self) {
+            // Fresh chains start out with the current storage layout
+            StorageMigrations::<T>::put(CURRENT_STORAGE_VERSION);
+        }
     }
 }
 
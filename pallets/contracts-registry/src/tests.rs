@@ -18,18 +18,32 @@
 //! Unit tests for pallet contracts-registry.
 
 use circuit_mock_runtime::{
-    pallet_contracts_registry, pallet_contracts_registry::pallet::Error, ContractsRegistry,
-    ContractsRegistryStorage, ExtBuilder, Runtime, RuntimeEvent as Event, RuntimeOrigin as Origin,
-    ALICE, BOB,
+    pallet_3vm_contracts::{CollectEvents, DebugInfo, Determinism},
+    pallet_contracts_registry,
+    pallet_contracts_registry::{
+        pallet::Error, LegacyAuthorInfo, LegacyRegistryContract, StorageMigrations,
+        CURRENT_STORAGE_VERSION,
+    },
+    AccountId, AccountManager, AssetId, Balance, Balances, BlockNumber, Contracts,
+    ContractsRegistry, ContractsRegistryStorage, ExtBuilder, Hash, Runtime, RuntimeEvent as Event,
+    RuntimeOrigin as Origin, ALICE, BOB,
 };
 
-use frame_support::{assert_err, assert_ok};
+use frame_support::{
+    assert_err, assert_ok,
+    traits::{Currency, Hooks},
+    weights::Weight,
+};
+use pallet_3vm_contracts_primitives::Code;
 use sp_core::H256;
-use sp_runtime::DispatchError;
+use sp_runtime::{AccountId32, DispatchError, Percent};
 use t3rn_primitives::{
+    account_manager::AccountManager as AccountManagerExt,
+    claimable::CircuitRole,
     contract_metadata::{ContractMetadata, ContractType},
     contracts_registry::{
-        AuthorInfo, ContractsRegistry as ContractsRegistryExt, KindValidator, RegistryContract,
+        AuthorInfo, ContractsRegistry as ContractsRegistryExt, KindValidator, PricingModel,
+        RegistryContract,
     },
 };
 
@@ -677,3 +691,259 @@ fn test_kind_validator() {
             assert_eq!(test_contract.meta.get_contract_type().has_storage(), false);
         });
 }
+
+#[test]
+fn add_new_contract_fails_if_revenue_splits_exceed_hundred_percent() {
+    let author = AuthorInfo::new(ALICE, Some(100)).with_revenue_splits(
+        vec![
+            (BOB, Percent::from_percent(60)),
+            (AccountId32::new([3; 32]), Percent::from_percent(50)),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    let test_contract = RegistryContract {
+        code_txt: b"some_code".to_vec(),
+        bytes: vec![],
+        author,
+        abi: None,
+        action_descriptions: vec![],
+        info: None,
+        meta: Default::default(),
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            ContractsRegistry::add_new_contract(Origin::root(), ALICE, test_contract.clone()),
+            Error::<Runtime>::InvalidRevenueSplits
+        );
+        assert!(
+            ContractsRegistryStorage::<Runtime>::get(test_contract.generate_id::<Runtime>())
+                .is_none()
+        );
+    });
+}
+
+#[test]
+fn author_info_prices_calls_and_splits_revenue_between_co_authors() {
+    let charlie = AccountId32::new([3; 32]);
+    let author = AuthorInfo::<AccountId32, u128>::new(ALICE, Some(100))
+        .with_revenue_splits(vec![(BOB, Percent::from_percent(30))].try_into().unwrap());
+
+    // Flat pricing charges the single use fee to everyone
+    assert_eq!(author.fee_for(&charlie, 5_000), 100);
+
+    let percent_of_value = author
+        .clone()
+        .with_pricing(PricingModel::PercentOfValue(Percent::from_percent(2)));
+    assert_eq!(percent_of_value.fee_for(&charlie, 5_000), 100);
+    assert_eq!(percent_of_value.fee_for(&charlie, 0), 0);
+
+    let tiered = author.clone().with_pricing(PricingModel::TieredByCaller(
+        vec![(charlie.clone(), 10)].try_into().unwrap(),
+    ));
+    assert_eq!(tiered.fee_for(&charlie, 0), 10);
+    assert_eq!(tiered.fee_for(&BOB, 0), 100);
+
+    let whitelisted = author
+        .clone()
+        .with_pricing(PricingModel::FreeForWhitelisted(
+            vec![charlie.clone()].try_into().unwrap(),
+        ));
+    assert_eq!(whitelisted.fee_for(&charlie, 0), 0);
+    assert_eq!(whitelisted.fee_for(&BOB, 0), 100);
+
+    // The author keeps the remainder after co-author shares
+    assert_eq!(author.revenue_shares(101), vec![(ALICE, 71), (BOB, 30)]);
+    assert_eq!(
+        AuthorInfo::<AccountId32, u128>::new(ALICE, None).revenue_shares(100),
+        vec![(ALICE, 100)]
+    );
+}
+
+fn remunerable_registry_contract(
+    co_author: AccountId32,
+) -> RegistryContract<Hash, AccountId, Balance, BlockNumber> {
+    RegistryContract {
+        code_txt: vec![],
+        bytes: wat::parse_str(
+            r#"(module
+                (import "env" "memory" (memory 1 1))
+                (func (export "deploy"))
+                (func (export "call"))
+            )"#,
+        )
+        .unwrap(),
+        author: AuthorInfo::new(BOB, Some(100)).with_revenue_splits(
+            vec![(co_author, Percent::from_percent(30))]
+                .try_into()
+                .unwrap(),
+        ),
+        abi: None,
+        action_descriptions: vec![],
+        info: None,
+        meta: ContractMetadata::new(
+            vec![],
+            b"registry contract".to_vec(),
+            ContractType::VolatileWasm,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    }
+}
+
+fn contract_author_settlements(caller: &AccountId32) -> Vec<(AccountId32, Balance)> {
+    let mut remunerated = <AccountManager as AccountManagerExt<
+        AccountId,
+        Balance,
+        Hash,
+        BlockNumber,
+        AssetId,
+    >>::get_settlements_by_role(CircuitRole::ContractAuthor)
+    .into_iter()
+    .map(|(recipient, settlement)| {
+        assert_eq!(&settlement.requester, caller);
+        (recipient, settlement.settlement_amount)
+    })
+    .collect::<Vec<_>>();
+    remunerated.sort();
+    remunerated
+}
+
+fn instantiate_from_registry(
+    caller: &AccountId32,
+    test_contract: &RegistryContract<Hash, AccountId, Balance, BlockNumber>,
+) -> AccountId32 {
+    let result = Contracts::bare_instantiate(
+        caller.clone(),
+        0,
+        Weight::from_parts(100_000_000_000, 3 * 1024 * 1024),
+        None,
+        Code::Existing(test_contract.generate_id::<Runtime>()),
+        vec![],
+        vec![],
+        DebugInfo::Skip,
+        CollectEvents::Skip,
+    );
+    result
+        .result
+        .expect("instantiates from registry")
+        .account_id
+}
+
+#[test]
+fn instantiating_from_the_registry_remunerates_the_author_and_co_authors() {
+    let caller = AccountId32::new([3; 32]);
+    let co_author = AccountId32::new([4; 32]);
+    let test_contract = remunerable_registry_contract(co_author.clone());
+
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&caller, 1_000_000_000_000);
+        assert_ok!(ContractsRegistry::add_new_contract(
+            Origin::root(),
+            ALICE,
+            test_contract.clone()
+        ));
+
+        instantiate_from_registry(&caller, &test_contract);
+
+        assert_eq!(
+            contract_author_settlements(&caller),
+            vec![(BOB, 70), (co_author, 30)]
+        );
+    });
+}
+
+#[test]
+fn calling_a_contract_instantiated_from_the_registry_remunerates_the_author_per_call() {
+    let caller = AccountId32::new([3; 32]);
+    let co_author = AccountId32::new([4; 32]);
+    let test_contract = remunerable_registry_contract(co_author.clone());
+
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&caller, 1_000_000_000_000);
+        assert_ok!(ContractsRegistry::add_new_contract(
+            Origin::root(),
+            ALICE,
+            test_contract.clone()
+        ));
+        let contract = instantiate_from_registry(&caller, &test_contract);
+
+        for _ in 0..2 {
+            let result = Contracts::bare_call(
+                caller.clone(),
+                contract.clone(),
+                0,
+                Weight::from_parts(100_000_000_000, 3 * 1024 * 1024),
+                None,
+                vec![],
+                DebugInfo::Skip,
+                CollectEvents::Skip,
+                Determinism::Enforced,
+            );
+            assert_ok!(result.result);
+        }
+
+        // One instantiation and two calls, each split between the author and the co-author
+        assert_eq!(
+            contract_author_settlements(&caller),
+            vec![
+                (BOB, 70),
+                (BOB, 70),
+                (BOB, 70),
+                (co_author.clone(), 30),
+                (co_author.clone(), 30),
+                (co_author, 30)
+            ]
+        );
+    });
+}
+
+#[test]
+fn runtime_upgrade_translates_legacy_registry_contracts_to_flat_priced_authors() {
+    let legacy_contract = LegacyRegistryContract::<Hash, AccountId, Balance, BlockNumber> {
+        code_txt: b"legacy code".to_vec(),
+        bytes: vec![1, 2, 3],
+        author: LegacyAuthorInfo {
+            account: BOB,
+            fees_per_single_use: Some(100),
+        },
+        abi: None,
+        action_descriptions: vec![],
+        info: None,
+        meta: Default::default(),
+    };
+    let contract_id = H256::repeat_byte(7);
+
+    ExtBuilder::default().build().execute_with(|| {
+        frame_support::storage::unhashed::put(
+            &ContractsRegistryStorage::<Runtime>::hashed_key_for(contract_id),
+            &legacy_contract,
+        );
+        StorageMigrations::<Runtime>::put(0);
+
+        let weight = <ContractsRegistry as Hooks<BlockNumber>>::on_runtime_upgrade();
+
+        assert_ne!(weight, Weight::zero());
+        assert_eq!(StorageMigrations::<Runtime>::get(), CURRENT_STORAGE_VERSION);
+        let translated = ContractsRegistryStorage::<Runtime>::get(contract_id)
+            .expect("legacy contract is translated, not dropped");
+        assert_eq!(translated.author, AuthorInfo::new(BOB, Some(100)));
+        assert_eq!(translated.author.pricing, PricingModel::Flat);
+        assert!(translated.author.revenue_splits.is_empty());
+        assert_eq!(translated.code_txt, legacy_contract.code_txt);
+        assert_eq!(translated.bytes, legacy_contract.bytes);
+
+        // A second upgrade leaves the translated contract untouched
+        <ContractsRegistry as Hooks<BlockNumber>>::on_runtime_upgrade();
+        assert_eq!(
+            ContractsRegistryStorage::<Runtime>::get(contract_id),
+            Some(translated)
+        );
+    });
+}
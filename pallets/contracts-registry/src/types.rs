@@ -5,6 +5,10 @@ use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 pub use t3rn_primitives::contracts_registry::{RegistryContract, RegistryContractId};
+use t3rn_primitives::{
+    contract_metadata::ContractMetadata, contracts_registry::AuthorInfo,
+    gateway::ContractActionDesc, storage::RawAliveContractInfo, ChainId,
+};
 /// The possible errors that can happen querying the storage of a contract.
 #[derive(Eq, PartialEq, Encode, Decode, Debug, Clone, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -34,3 +38,39 @@ pub struct ContractsRegistryResult<T> {
 }
 
 pub type FetchContractsResult = ContractsRegistryResult<Result<Vec<u8>, ContractAccessError>>;
+
+/// `AuthorInfo` as stored before revenue splits and pricing models were introduced.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LegacyAuthorInfo<AccountId, BalanceOf> {
+    pub account: AccountId,
+    pub fees_per_single_use: Option<BalanceOf>,
+}
+
+/// `RegistryContract` as stored before revenue splits and pricing models were introduced.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LegacyRegistryContract<Hash, AccountId, BalanceOf, BlockNumber> {
+    pub code_txt: Vec<u8>,
+    pub bytes: Vec<u8>,
+    pub author: LegacyAuthorInfo<AccountId, BalanceOf>,
+    pub abi: Option<Vec<u8>>,
+    pub action_descriptions: Vec<ContractActionDesc<Hash, ChainId, AccountId>>,
+    pub info: Option<RawAliveContractInfo<Hash, BalanceOf, BlockNumber>>,
+    pub meta: ContractMetadata,
+}
+
+impl<Hash, AccountId, BalanceOf, BlockNumber>
+    From<LegacyRegistryContract<Hash, AccountId, BalanceOf, BlockNumber>>
+    for RegistryContract<Hash, AccountId, BalanceOf, BlockNumber>
+{
+    fn from(legacy: LegacyRegistryContract<Hash, AccountId, BalanceOf, BlockNumber>) -> Self {
+        RegistryContract {
+            code_txt: legacy.code_txt,
+            bytes: legacy.bytes,
+            author: AuthorInfo::new(legacy.author.account, legacy.author.fees_per_single_use),
+            abi: legacy.abi,
+            action_descriptions: legacy.action_descriptions,
+            info: legacy.info,
+            meta: legacy.meta,
+        }
+    }
+}
@@ -134,7 +134,7 @@ use scale_info::TypeInfo;
 use smallvec::Array;
 use sp_runtime::traits::{Convert, Hash, Saturating, StaticLookup, Zero};
 use sp_std::{fmt::Debug, prelude::*};
-use t3rn_primitives::threevm::ThreeVm;
+use t3rn_primitives::threevm::{Remunerated, ThreeVm};
 pub use weights::WeightInfo;
 
 pub use crate::{
@@ -1251,6 +1251,15 @@ impl<T: Config> Invokable<T> for CallInput<T> {
                         storage_deposit: Default::default(),
                     },
             };
+        let remunerated = match patch3vm::try_remunerate_call::<T>(&origin, &dest, value) {
+            Ok(remunerated) => remunerated,
+            Err(err) =>
+                return InternalOutput {
+                    result: Err(err.into()),
+                    gas_meter,
+                    storage_deposit: Default::default(),
+                },
+        };
         let schedule = T::Schedule::get();
         let result = ExecStack::<T, WasmBlob<T>>::run_call(
             origin.clone(),
@@ -1263,6 +1272,10 @@ impl<T: Config> Invokable<T> for CallInput<T> {
             debug_message,
             determinism,
         );
+        patch3vm::finalize_remuneration::<T>(
+            &remunerated,
+            result.as_ref().map_or(false, |retval| !retval.did_revert()),
+        );
 
         match storage_meter.try_into_deposit(&origin) {
             Ok(storage_deposit) => InternalOutput {
@@ -1466,6 +1479,8 @@ impl<T: Config> Pallet<T> {
             }
         };
 
+        let mut remunerated = Remunerated::default();
+        let mut registry_author = None;
         let (code, upload_deposit): (WasmCode<T>, BalanceOf<T>) = match code {
             Code::Upload(code) => {
                 let result = Self::try_upload_code(
@@ -1495,9 +1510,13 @@ impl<T: Config> Pallet<T> {
             Code::Existing(hash) => {
                 let schedule = T::Schedule::get();
                 match patch3vm::try_instantiate_from_contracts_registry::<T>(
-                    &origin, &hash, &schedule,
+                    &origin, &hash, value, &schedule,
                 ) {
-                    Ok((module, deposit)) => (WasmCode::Wasm(module), deposit),
+                    Ok((module, registry_remuneration)) => {
+                        remunerated = registry_remuneration;
+                        registry_author = patch3vm::remunerable_author::<T>(&module);
+                        (WasmCode::Wasm(module), Default::default())
+                    },
                     Err(_) => (WasmCode::CodeHash(hash), Default::default()),
                 }
             },
@@ -1513,6 +1532,15 @@ impl<T: Config> Pallet<T> {
         };
 
         let output = InstantiateInput::<T> { code, salt }.run_guarded(common);
+        let is_success = output
+            .result
+            .as_ref()
+            .map_or(false, |(_, result)| !result.did_revert());
+        patch3vm::finalize_remuneration::<T>(&remunerated, is_success);
+        if let (true, Ok((account_id, _))) = (is_success, &output.result) {
+            // Calls to the new contract remunerate the registry author from now on
+            patch3vm::persist_author::<T>(account_id, registry_author.as_ref());
+        }
         ContractInstantiateResult {
             result: output
                 .result
@@ -15,8 +15,11 @@ use sp_runtime::{
 
 use sp_std::vec::Vec;
 use t3rn_primitives::{
+    account_manager::Outcome,
+    contracts_registry::AuthorInfo,
     threevm::{
-        GetState, ModuleOperations, Precompile, PrecompileArgs, PrecompileInvocation, ThreeVm,
+        GetState, ModuleOperations, Precompile, PrecompileArgs, PrecompileInvocation, Remunerated,
+        Remuneration, ThreeVm,
     },
     SpeedMode,
 };
@@ -233,12 +236,14 @@ fn size_to_weight<T: Encode>(encodable: &T) -> Weight {
 pub fn try_instantiate_from_contracts_registry<T: Config>(
     origin: &T::AccountId,
     hash: &T::Hash,
+    value: BalanceOf<T>,
     schedule: &Schedule<T>,
-) -> Result<(WasmBlob<T>, BalanceOf<T>), DispatchError> {
+) -> Result<(WasmBlob<T>, Remunerated<T::Hash>), DispatchError> {
     // Use ThreeVm to try to retrieve a module from the registry.
-    // If found, attempt to construct a WasmBlob from it.
+    // If found, attempt to construct a WasmBlob from it. Only deterministic code can be
+    // instantiated, so the registry bytes are validated as such.
     let module = T::ThreeVm::from_registry::<WasmBlob<T>, _>(hash, |bytes| {
-        WasmBlob::from_code(bytes, schedule, origin.clone(), Determinism::Relaxed)
+        WasmBlob::from_code(bytes, schedule, origin.clone(), Determinism::Enforced)
             .unwrap_or(WasmBlob::<T>::new_empty())
     })?;
 
@@ -248,15 +253,69 @@ pub fn try_instantiate_from_contracts_registry<T: Config>(
 
     T::ThreeVm::instantiate_check(module.get_type())?;
 
-    // Retrieve the fee for using the module, or use a default if not specified
-    let fee = module
-        .get_author()
-        .as_ref()
-        .and_then(|author| author.fees_per_single_use)
-        .unwrap_or_default();
+    // Charge the use of the module with the author's pricing model, shared among the co-authors
+    let remunerated = T::ThreeVm::try_remunerate(origin, value, &module)?;
 
-    // Return the fee and the module itself
-    Ok((module, fee))
+    // Return the remuneration charges and the module itself
+    Ok((module, remunerated))
+}
+
+// Used in src/lib.rs
+pub fn remunerable_author<T: Config>(
+    module: &WasmBlob<T>,
+) -> Option<AuthorInfo<T::AccountId, BalanceOf<T>>> {
+    // Only authors of remunerable modules are charged for calls to their contracts
+    T::ThreeVm::remunerable_check(module.get_type())
+        .ok()
+        .and_then(|_| module.get_author().cloned())
+}
+
+// Used in src/lib.rs
+pub fn persist_author<T: Config>(
+    contract: &T::AccountId,
+    author: Option<&AuthorInfo<T::AccountId, BalanceOf<T>>>,
+) {
+    if let Err(e) = T::ThreeVm::try_persist_author(contract, author) {
+        log::error!(
+            target: CONTRACTS_LOG_TARGET,
+            "failed to persist author of contract {:?}: {:?}",
+            contract,
+            e
+        );
+    }
+}
+
+// Used in src/lib.rs
+pub fn try_remunerate_call<T: Config>(
+    origin: &Origin<T>,
+    dest: &T::AccountId,
+    value: BalanceOf<T>,
+) -> Result<Remunerated<T::Hash>, DispatchError> {
+    // Charge the caller with the author's pricing model, shared among the co-authors
+    match origin {
+        Origin::Signed(caller) => T::ThreeVm::try_remunerate_call(caller, dest, value),
+        Origin::Root => Ok(Remunerated::default()),
+    }
+}
+
+// Used in src/lib.rs
+pub fn finalize_remuneration<T: Config>(remunerated: &Remunerated<T::Hash>, is_success: bool) {
+    // Authors are paid only for successful instantiations, otherwise the payee is refunded
+    let outcome = if is_success {
+        Outcome::Commit
+    } else {
+        Outcome::Revert
+    };
+    for remuneration_id in remunerated.remuneration_ids.iter() {
+        if let Err(e) = T::ThreeVm::try_finalize(*remuneration_id, outcome.clone()) {
+            log::error!(
+                target: CONTRACTS_LOG_TARGET,
+                "failed to finalize remuneration {:?}: {:?}",
+                remuneration_id,
+                e
+            );
+        }
+    }
 }
 
 pub fn try_submit_side_effects<T: Config>(
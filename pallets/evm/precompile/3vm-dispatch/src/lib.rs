@@ -1,41 +1,228 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-extern crate alloc;
-
-use alloc::vec::Vec;
+use codec::Decode;
 use core::marker::PhantomData;
-use fp_evm::{
-    ExitError, ExitSucceed, Precompile as EvmPrecompile, PrecompileFailure, PrecompileHandle,
-    PrecompileOutput, PrecompileResult,
+use fp_evm::{Precompile as EvmPrecompile, PrecompileHandle, PrecompileResult};
+use frame_support::{
+    sp_runtime::traits::Saturating,
+    traits::{
+        fungible::Inspect,
+        tokens::{Fortitude::Polite, Preservation::Protect},
+        Currency, Get,
+    },
+    weights::Weight,
+};
+use pallet_3vm_contracts::{CollectEvents, DebugInfo, Determinism};
+use pallet_evm::GasWeightMapping;
+use precompile_util_solidity::{
+    data::{Bytes, EvmDataReader, EvmDataWriter},
+    handle::PrecompileHandleExt,
+    modifier::FunctionModifier,
+    revert, succeed, EvmResult,
 };
-use t3rn_primitives::threevm::Precompile;
+use sp_core::{H256, U256};
+use sp_std::vec::Vec;
+use t3rn_abi::recode::{recode_bytes_with_descriptor, Codec};
+use t3rn_primitives::threevm::AddressMapping;
+
+#[cfg(test)]
+mod tests;
+
+type ContractsBalanceOf<T> = <<T as pallet_3vm_contracts::Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// How many EVM -> WASM dispatches may be nested within a single call stack.
+/// A WASM contract reached through this precompile can not dispatch back through it.
+pub const MAX_DISPATCH_DEPTH: u32 = 1;
+
+/// Gas charged for every dispatch before the WASM contract runs,
+/// covering the account mapping and the contract lookup.
+pub const DISPATCH_BASE_GAS: u64 = 2_600;
+
+/// Gas charged per byte of the precompile input, covering its decoding and recoding.
+pub const DISPATCH_GAS_PER_BYTE: u64 = 16;
 
-// TODO: build this for the next phase of evm artifacts.
+environmental::environmental!(dispatch_depth: u32);
+
+#[precompile_util_macro::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Call = "call(bytes32,uint256,bytes)",
+    CallRecoded = "callRecoded(bytes32,uint256,bytes4,bytes,bytes,bytes)",
+}
+
+/// Dispatches calls from EVM contracts to WASM contracts deployed on pallet-contracts.
+/// The EVM caller is mapped to its substrate account via pallet-3vm-account-mapping,
+/// and pays for the WASM execution with the gas left to the precompile.
+/// The precompile can't be reached with DELEGATECALL or CALLCODE, as it would then dispatch
+/// on behalf of the caller of the delegating contract.
 pub struct ThreeVmDispatch<T> {
     _marker: PhantomData<T>,
 }
 
 impl<T> EvmPrecompile for ThreeVmDispatch<T>
 where
-    T: pallet_evm::Config,
+    T: pallet_evm::Config + pallet_3vm_contracts::Config + pallet_3vm_account_mapping::Config,
 {
     fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-        let input = handle.input();
-        let _target_gas = handle.gas_limit();
-        let _context = handle.context();
-
-        let mut output = Vec::new();
-        T::ThreeVm::invoke_raw(&55_u8, input, &mut output); // TODO: dummy ptr for now
-
-        if !output.is_empty() {
-            Ok(PrecompileOutput {
-                exit_status: ExitSucceed::Stopped,
-                output,
-            })
+        if handle.code_address() != handle.context().address {
+            return Err(revert(
+                "ThreeVmDispatch: cannot be called with DELEGATECALL or CALLCODE",
+            ))
+        }
+
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+        handle.record_cost(Self::input_cost(handle.input().len()))?;
+
+        match selector {
+            Action::Call => Self::call(handle),
+            Action::CallRecoded => Self::call_recoded(handle),
+        }
+    }
+}
+
+impl<T> ThreeVmDispatch<T>
+where
+    T: pallet_evm::Config + pallet_3vm_contracts::Config + pallet_3vm_account_mapping::Config,
+{
+    /// Call a WASM contract with SCALE-encoded input data, including the message selector,
+    /// and return its raw output.
+    fn call(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        let dest: H256 = input.read()?;
+        let value: U256 = input.read()?;
+        let data: Vec<u8> = input.read::<Bytes>()?.into();
+
+        let output = Self::dispatch(handle, dest, value, data)?;
+
+        Ok(succeed(EvmDataWriter::new().write(Bytes(output)).build()))
+    }
+
+    /// Call a WASM contract with RLP-encoded arguments recoded to SCALE by the given t3rn ABI
+    /// descriptor. The output is recoded back to RLP if an output descriptor is provided.
+    fn call_recoded(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(6)?;
+
+        let dest: H256 = input.read()?;
+        let value: U256 = input.read()?;
+        let selector = read_bytes4(&mut input)?;
+        let args: Vec<u8> = input.read::<Bytes>()?.into();
+        let args_descriptor: Vec<u8> = input.read::<Bytes>()?.into();
+        let output_descriptor: Vec<u8> = input.read::<Bytes>()?.into();
+
+        let mut data = selector.to_vec();
+        if !args.is_empty() {
+            data.extend(
+                recode_bytes_with_descriptor(args, args_descriptor, Codec::Rlp, Codec::Scale)
+                    .map_err(|_| revert("ThreeVmDispatch: failed to recode arguments"))?,
+            );
+        }
+
+        let output = Self::dispatch(handle, dest, value, data)?;
+
+        let output = if output_descriptor.is_empty() {
+            output
         } else {
-            Err(PrecompileFailure::Error {
-                exit_status: ExitError::Other("invalid output".into()),
-            })
+            recode_bytes_with_descriptor(output, output_descriptor, Codec::Scale, Codec::Rlp)
+                .map_err(|_| revert("ThreeVmDispatch: failed to recode output"))?
+        };
+
+        Ok(succeed(EvmDataWriter::new().write(Bytes(output)).build()))
+    }
+
+    /// Execute the WASM contract within the remaining gas and charge the weight it consumed.
+    /// A reverted WASM call reverts the precompile with the contract's output.
+    fn dispatch(
+        handle: &mut impl PrecompileHandle,
+        dest: H256,
+        value: U256,
+        data: Vec<u8>,
+    ) -> EvmResult<Vec<u8>> {
+        let depth = dispatch_depth::with(|depth| *depth).unwrap_or_default();
+        if depth >= MAX_DISPATCH_DEPTH {
+            return Err(revert("ThreeVmDispatch: re-entrancy limit reached"))
+        }
+
+        let origin = <T as pallet_3vm_account_mapping::Config>::AddressMapping::into_account_id(
+            &handle.context().caller,
+        );
+        let dest = T::AccountId::decode(&mut &dest.0[..])
+            .map_err(|_| revert("ThreeVmDispatch: invalid contract address"))?;
+        let value = Self::to_contracts_balance(value)?;
+        let storage_deposit_limit = Self::storage_deposit_limit(&origin, value);
+        let gas_limit = Self::gas_to_weight(handle.remaining_gas());
+
+        let result = dispatch_depth::using(&mut (depth + 1), || {
+            pallet_3vm_contracts::Pallet::<T>::bare_call(
+                origin,
+                dest,
+                value,
+                gas_limit,
+                Some(storage_deposit_limit),
+                data,
+                DebugInfo::Skip,
+                CollectEvents::Skip,
+                Determinism::Enforced,
+            )
+        });
+
+        handle.record_cost(Self::weight_to_gas(result.gas_consumed))?;
+
+        match result.result {
+            Ok(output) if output.did_revert() => Err(revert(output.data)),
+            Ok(output) => Ok(output.data),
+            Err(_) => Err(revert("ThreeVmDispatch: contract call failed")),
         }
     }
+
+    /// Gas charged before the input is decoded, growing with its length.
+    fn input_cost(input_len: usize) -> u64 {
+        DISPATCH_BASE_GAS.saturating_add(DISPATCH_GAS_PER_BYTE.saturating_mul(input_len as u64))
+    }
+
+    /// The storage deposit the WASM call may reserve from the caller. It is bounded by the
+    /// contracts' `DefaultDepositLimit` and by what the caller can spend besides `value`.
+    fn storage_deposit_limit(
+        origin: &T::AccountId,
+        value: ContractsBalanceOf<T>,
+    ) -> ContractsBalanceOf<T> {
+        let spendable = <T as pallet_3vm_contracts::Config>::Currency::reducible_balance(
+            origin, Protect, Polite,
+        )
+        .saturating_sub(value)
+        .saturating_sub(<<T as pallet_3vm_contracts::Config>::Currency as Inspect<
+            T::AccountId,
+        >>::minimum_balance());
+        spendable.min(<T as pallet_3vm_contracts::Config>::DefaultDepositLimit::get())
+    }
+
+    fn to_contracts_balance(value: U256) -> EvmResult<ContractsBalanceOf<T>> {
+        u128::try_from(value)
+            .ok()
+            .and_then(|value| ContractsBalanceOf::<T>::try_from(value).ok())
+            .ok_or_else(|| revert("ThreeVmDispatch: value is too large"))
+    }
+
+    fn gas_to_weight(gas: u64) -> Weight {
+        <T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas, false)
+    }
+
+    fn weight_to_gas(weight: Weight) -> u64 {
+        <T as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+    }
+}
+
+/// Read a Solidity `bytes4`, left-aligned within its 32 bytes word.
+fn read_bytes4(input: &mut EvmDataReader) -> EvmResult<[u8; 4]> {
+    let word: H256 = input.read()?;
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&word.as_bytes()[..4]);
+    Ok(bytes)
 }
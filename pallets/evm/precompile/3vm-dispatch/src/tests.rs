@@ -0,0 +1,264 @@
+use crate::{dispatch_depth, Action, ThreeVmDispatch, MAX_DISPATCH_DEPTH};
+use circuit_mock_runtime::{
+    contracts_config::{DefaultDepositLimit, PrecompilesValue},
+    evm_precompile_util::precompile_mock::MockPrecompileSet,
+    *,
+};
+use frame_support::{traits::Currency, weights::Weight};
+use pallet_3vm_contracts::{CollectEvents, DebugInfo, Determinism};
+use pallet_3vm_contracts_primitives::Code;
+use pallet_evm::GasWeightMapping;
+use precompile_util_solidity::{
+    data::{Bytes, EvmDataWriter},
+    testing::*,
+};
+use sp_core::{crypto::AccountId32, H160, H256, U256};
+use t3rn_primitives::threevm::AddressMapping;
+
+/// Returns its input after the first 4 bytes, using the first byte as the return flags.
+const RETURN_WITH_DATA: &str = r#"(module
+    (import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+    (import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+    (import "env" "memory" (memory 1 1))
+    (data (i32.const 128) "\80")
+    (func (export "deploy") (call $call))
+    (func $call (export "call")
+        (call $seal_input (i32.const 0) (i32.const 128))
+        (call $seal_return
+            (i32.load8_u (i32.const 0))
+            (i32.const 4)
+            (i32.sub (i32.load (i32.const 128)) (i32.const 4))
+        )
+        (unreachable)
+    )
+)"#;
+
+const GAS_LIMIT: Weight = Weight::from_parts(100_000_000_000, 3 * 1024 * 1024);
+
+fn precompiles() -> MockPrecompileSet<circuit_mock_runtime::Runtime> {
+    PrecompilesValue::get()
+}
+
+fn dispatch_evm_address() -> H160 {
+    H160([11u8; 20])
+}
+
+fn call_input(dest: H256) -> Vec<u8> {
+    call_input_with_data(dest, vec![0, 1, 2, 3])
+}
+
+fn call_input_with_data(dest: H256, data: Vec<u8>) -> Vec<u8> {
+    EvmDataWriter::new_with_selector(Action::Call)
+        .write(dest)
+        .write(U256::zero())
+        .write(Bytes(data))
+        .build()
+}
+
+fn input_cost(input: &[u8]) -> u64 {
+    ThreeVmDispatch::<Runtime>::input_cost(input.len())
+}
+
+fn deploy_return_with_data(deployer: &AccountId32) -> AccountId32 {
+    let result = Contracts::bare_instantiate(
+        deployer.clone(),
+        0,
+        GAS_LIMIT,
+        Some(1_000_000_000_000_000),
+        Code::Upload(wat::parse_str(RETURN_WITH_DATA).unwrap()),
+        vec![0, 0, 0, 0],
+        vec![],
+        DebugInfo::Skip,
+        CollectEvents::Skip,
+    );
+    result.result.unwrap().account_id
+}
+
+/// The gas the precompile charges for the WASM execution of `data` on `contract`.
+fn contract_gas_cost(caller: H160, contract: &AccountId32, data: Vec<u8>) -> u64 {
+    let result = Contracts::bare_call(
+        <Runtime as pallet_3vm_account_mapping::Config>::AddressMapping::into_account_id(&caller),
+        contract.clone(),
+        0,
+        GAS_LIMIT,
+        None,
+        data,
+        DebugInfo::Skip,
+        CollectEvents::Skip,
+        Determinism::Enforced,
+    );
+    assert!(!result.result.unwrap().did_revert());
+    <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(result.gas_consumed)
+}
+
+#[test]
+fn call_returns_contract_output_and_charges_its_weight() {
+    let (pairs, mut ext) = new_test_ext_with_initial_balance(1, 1_000_000_000_000_000_000);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        let contract = deploy_return_with_data(&sender.account_id);
+        let data = vec![0, 0, 0, 0, 0xaa, 0xbb, 0xcc];
+        let input = call_input_with_data(H256::from_slice(contract.as_ref()), data.clone());
+
+        precompiles()
+            .prepare_test(sender.address, dispatch_evm_address(), input.clone())
+            .expect_cost(input_cost(&input) + contract_gas_cost(sender.address, &contract, data))
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(Bytes(vec![0xaa, 0xbb, 0xcc]))
+                    .build(),
+            );
+    });
+}
+
+#[test]
+fn call_recoded_recodes_arguments_and_output_and_charges_contract_weight() {
+    let (pairs, mut ext) = new_test_ext_with_initial_balance(1, 1_000_000_000_000_000_000);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        let contract = deploy_return_with_data(&sender.account_id);
+        // 100_000u32 encoded in RLP
+        let rlp_amount = vec![0x83, 0x01, 0x86, 0xa0];
+        let input = EvmDataWriter::new_with_selector(Action::CallRecoded)
+            .write(H256::from_slice(contract.as_ref()))
+            .write(U256::zero())
+            .write(H256::zero())
+            .write(Bytes(rlp_amount.clone()))
+            .write(Bytes(b"amount:Value32,".to_vec()))
+            .write(Bytes(b"amount:Value32,".to_vec()))
+            .build();
+
+        // The contract receives the zero selector followed by the SCALE encoded amount
+        let scale_data = [vec![0, 0, 0, 0], 100_000u32.to_le_bytes().to_vec()].concat();
+
+        precompiles()
+            .prepare_test(sender.address, dispatch_evm_address(), input.clone())
+            .expect_cost(
+                input_cost(&input) + contract_gas_cost(sender.address, &contract, scale_data),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(Bytes(rlp_amount)).build());
+    });
+}
+
+#[test]
+fn delegate_call_is_rejected() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                dispatch_evm_address(),
+                call_input(H256::repeat_byte(1)),
+            )
+            .with_context_address(H160::repeat_byte(0xde))
+            .expect_cost(0)
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output == b"ThreeVmDispatch: cannot be called with DELEGATECALL or CALLCODE"
+            });
+    });
+}
+
+#[test]
+fn storage_deposit_is_bounded_by_default_limit_and_spendable_balance() {
+    let (pairs, mut ext) = new_test_ext(1);
+    ext.execute_with(|| {
+        let rich = AccountId32::new([1; 32]);
+        let _ = Balances::deposit_creating(&rich, 1_000_000_000_000_000_000);
+        assert_eq!(
+            ThreeVmDispatch::<Runtime>::storage_deposit_limit(&rich, 0),
+            DefaultDepositLimit::get()
+        );
+
+        let poor = AccountId32::new([2; 32]);
+        assert_eq!(
+            ThreeVmDispatch::<Runtime>::storage_deposit_limit(&poor, 0),
+            0
+        );
+
+        let sender = &pairs[0].account_id;
+        let balance = Balances::free_balance(sender);
+        assert_eq!(
+            ThreeVmDispatch::<Runtime>::storage_deposit_limit(sender, balance),
+            0
+        );
+    });
+}
+
+#[test]
+fn call_to_unknown_contract_reverts() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                dispatch_evm_address(),
+                call_input(H256::repeat_byte(1)),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"ThreeVmDispatch: contract call failed");
+    });
+}
+
+#[test]
+fn call_is_not_payable() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                dispatch_evm_address(),
+                call_input(H256::repeat_byte(1)),
+            )
+            .with_value(1)
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Function is not payable");
+    });
+}
+
+#[test]
+fn nested_dispatch_is_rejected() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        let mut depth = MAX_DISPATCH_DEPTH;
+        dispatch_depth::using(&mut depth, || {
+            precompiles()
+                .prepare_test(
+                    sender.address,
+                    dispatch_evm_address(),
+                    call_input(H256::repeat_byte(1)),
+                )
+                .expect_cost(input_cost(&call_input(H256::repeat_byte(1))))
+                .expect_no_logs()
+                .execute_reverts(|output| output == b"ThreeVmDispatch: re-entrancy limit reached");
+        });
+    });
+}
+
+#[test]
+fn call_recoded_rejects_malformed_arguments() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        let input = EvmDataWriter::new_with_selector(Action::CallRecoded)
+            .write(H256::repeat_byte(1))
+            .write(U256::zero())
+            .write(H256::zero())
+            .write(Bytes(vec![0xc1, 0x01]))
+            .write(Bytes(vec![0xff]))
+            .write(Bytes(vec![]))
+            .build();
+
+        precompiles()
+            .prepare_test(sender.address, dispatch_evm_address(), input.clone())
+            .expect_cost(input_cost(&input))
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"ThreeVmDispatch: failed to recode arguments");
+    });
+}
@@ -1,62 +1,263 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use fp_evm::{
-    ExitError, ExitSucceed, Precompile as EvmPrecompile, PrecompileFailure, PrecompileHandle,
-    PrecompileOutput, PrecompileResult,
+use fp_evm::{Precompile as EvmPrecompile, PrecompileHandle, PrecompileResult};
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::GasWeightMapping;
+use pallet_portal::weights::WeightInfo;
+use precompile_util_solidity::{
+    data::{Bytes, EvmDataReader, EvmDataWriter},
+    handle::PrecompileHandleExt,
+    modifier::FunctionModifier,
+    revert, succeed, EvmResult,
 };
-use frame_support::{sp_runtime::app_crypto::sp_core, traits::Currency};
+use sp_core::{H256, U256};
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
 use sp_std::{marker::PhantomData, vec::Vec};
 use t3rn_primitives::{
-    threevm::{Precompile, PORTAL},
-    T3rnCodec,
+    execution_source_to_option,
+    light_client::{HeaderResult, HeightResult, InclusionReceipt},
+    portal::Portal,
+    ChainId, SpeedMode,
 };
 
-use precompile_util_solidity::data::EvmData;
+#[cfg(test)]
+mod tests;
 
+#[precompile_util_macro::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    GetLatestFinalizedHeader = "getLatestFinalizedHeader(bytes4)",
+    GetFinalizedHeight = "getFinalizedHeight(bytes4)",
+    GetRationalHeight = "getRationalHeight(bytes4)",
+    GetFastHeight = "getFastHeight(bytes4)",
+    GetHeight = "getHeight(bytes4,uint8)",
+    GetLatestHeartbeat = "getLatestHeartbeat(bytes4)",
+    VerifyEventInclusion = "verifyEventInclusion(bytes4,uint8,bytes32,bytes)",
+    VerifyStateInclusion = "verifyStateInclusion(bytes4,uint8,bytes)",
+    VerifyTxInclusion = "verifyTxInclusion(bytes4,uint8,bytes)",
+}
+
+/// Solidity-facing interface to the Portal light clients.
+/// Speed modes are passed as `uint8` following `SpeedMode`'s SCALE indices:
+/// 0 - Fast, 1 - Rational, 2 - Finalized, 3 - Instant.
 pub struct PortalPrecompile<T>(PhantomData<T>);
 
 impl<T> EvmPrecompile for PortalPrecompile<T>
 where
-    T: pallet_evm::Config + pallet_assets::Config + frame_system::Config,
-    <T as pallet_assets::Config>::AssetId: From<u32>,
-    <T as pallet_assets::Config>::AssetIdParameter: From<u32>,
-    <T as pallet_assets::Config>::Balance: EvmData,
-    <<T as pallet_evm::Config>::Currency as Currency<
-        <T as frame_system::pallet::Config>::AccountId,
-    >>::Balance: EvmData,
-    sp_core::U256: From<<T as pallet_assets::Config>::Balance>,
-    sp_core::U256: From<
-        <<T as pallet_evm::Config>::Currency as Currency<
-            <T as frame_system::pallet::Config>::AccountId,
-        >>::Balance,
-    >,
+    T: pallet_evm::Config + pallet_portal::Config,
 {
     fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-        let input = handle.input();
-        let _target_gas = handle.gas_limit();
-        let _context = handle.context();
-        let mut output = Vec::new();
-        let callee = handle.context().caller;
-
-        let restructured_args = [&[T3rnCodec::Rlp.into()][..], callee.as_bytes(), input].concat();
-
-        T::ThreeVm::invoke_raw(&PORTAL, &restructured_args, &mut output);
-
-        if let Some(result_byte) = output.first() {
-            if *result_byte == 0 {
-                Ok(PrecompileOutput {
-                    exit_status: ExitSucceed::Returned,
-                    output,
-                })
-            } else {
-                Err(PrecompileFailure::Error {
-                    exit_status: ExitError::Other("invalid output".into()),
-                })
-            }
-        } else {
-            Err(PrecompileFailure::Error {
-                exit_status: ExitError::Other("Empty buffer".into()),
-            })
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::View)?;
+
+        match selector {
+            Action::GetLatestFinalizedHeader => Self::get_latest_finalized_header(handle),
+            Action::GetFinalizedHeight => Self::get_height(handle, Some(SpeedMode::Finalized)),
+            Action::GetRationalHeight => Self::get_height(handle, Some(SpeedMode::Rational)),
+            Action::GetFastHeight => Self::get_height(handle, Some(SpeedMode::Fast)),
+            Action::GetHeight => Self::get_height(handle, None),
+            Action::GetLatestHeartbeat => Self::get_latest_heartbeat(handle),
+            Action::VerifyEventInclusion => Self::verify_event_inclusion(handle),
+            Action::VerifyStateInclusion => Self::verify_state_inclusion(handle),
+            Action::VerifyTxInclusion => Self::verify_tx_inclusion(handle),
+        }
+    }
+}
+
+impl<T> PortalPrecompile<T>
+where
+    T: pallet_evm::Config + pallet_portal::Config,
+{
+    fn get_latest_finalized_header(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+        let gateway_id = read_bytes4(&mut input)?;
+
+        Self::record_weight(
+            handle,
+            <T as pallet_portal::Config>::WeightInfo::get_latest_finalized_header(),
+        )?;
+
+        match pallet_portal::Pallet::<T>::get_latest_finalized_header(gateway_id)
+            .map_err(dispatch_revert)?
+        {
+            HeaderResult::Header(header) =>
+                Ok(succeed(EvmDataWriter::new().write(Bytes(header)).build())),
+            HeaderResult::NotActive => Err(revert("Portal: light client is not active")),
+        }
+    }
+
+    /// Read the height of the given speed mode, or of the one passed as the second argument.
+    /// Instant confirmations are tracked against the fast height.
+    fn get_height(
+        handle: &mut impl PrecompileHandle,
+        speed_mode: Option<SpeedMode>,
+    ) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(if speed_mode.is_some() { 1 } else { 2 })?;
+        let gateway_id = read_bytes4(&mut input)?;
+        let speed_mode = match speed_mode {
+            Some(speed_mode) => speed_mode,
+            None => read_speed_mode(&mut input)?,
+        };
+
+        Self::record_weight(
+            handle,
+            <T as pallet_portal::Config>::WeightInfo::get_height(),
+        )?;
+
+        let height = match speed_mode {
+            SpeedMode::Finalized => pallet_portal::Pallet::<T>::get_finalized_height(gateway_id),
+            SpeedMode::Rational => pallet_portal::Pallet::<T>::get_rational_height(gateway_id),
+            SpeedMode::Fast | SpeedMode::Instant =>
+                pallet_portal::Pallet::<T>::get_fast_height(gateway_id),
         }
+        .map_err(dispatch_revert)?;
+
+        match height {
+            HeightResult::Height(height) => Ok(succeed(
+                EvmDataWriter::new()
+                    .write(block_number_to_u256::<T>(height))
+                    .build(),
+            )),
+            HeightResult::NotActive => Err(revert("Portal: light client is not active")),
+        }
+    }
+
+    /// Returns the heartbeat as the static Solidity struct
+    /// `(uint256 lastHeartbeat, uint256 lastFinalizedHeight, uint256 lastRationalHeight,
+    /// uint256 lastFastHeight, bool isHalted, bool everInitialized)`.
+    fn get_latest_heartbeat(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+        let gateway_id = read_bytes4(&mut input)?;
+
+        Self::record_weight(
+            handle,
+            <T as pallet_portal::Config>::WeightInfo::get_latest_heartbeat(),
+        )?;
+
+        let heartbeat = pallet_portal::Pallet::<T>::get_latest_heartbeat(&gateway_id)
+            .map_err(dispatch_revert)?;
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write(block_number_to_u256::<T>(heartbeat.last_heartbeat))
+                .write(block_number_to_u256::<T>(heartbeat.last_finalized_height))
+                .write(block_number_to_u256::<T>(heartbeat.last_rational_height))
+                .write(block_number_to_u256::<T>(heartbeat.last_fast_height))
+                .write(heartbeat.is_halted)
+                .write(heartbeat.ever_initialized)
+                .build(),
+        ))
+    }
+
+    fn verify_event_inclusion(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(4)?;
+        let gateway_id = read_bytes4(&mut input)?;
+        let speed_mode = read_speed_mode(&mut input)?;
+        let source: H256 = input.read()?;
+        let proof: Vec<u8> = input.read::<Bytes>()?.into();
+
+        Self::record_weight(
+            handle,
+            <T as pallet_portal::Config>::WeightInfo::verify_event_inclusion(proof.len() as u32),
+        )?;
+
+        let receipt = pallet_portal::Pallet::<T>::verify_event_inclusion(
+            gateway_id,
+            speed_mode,
+            execution_source_to_option(source.0),
+            proof,
+        )
+        .map_err(dispatch_revert)?;
+
+        Ok(succeed(Self::write_receipt(receipt)))
+    }
+
+    fn verify_state_inclusion(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+        let gateway_id = read_bytes4(&mut input)?;
+        let speed_mode = read_speed_mode(&mut input)?;
+        let proof: Vec<u8> = input.read::<Bytes>()?.into();
+
+        Self::record_weight(
+            handle,
+            <T as pallet_portal::Config>::WeightInfo::verify_state_inclusion(proof.len() as u32),
+        )?;
+
+        let receipt =
+            pallet_portal::Pallet::<T>::verify_state_inclusion(gateway_id, speed_mode, proof)
+                .map_err(dispatch_revert)?;
+
+        Ok(succeed(Self::write_receipt(receipt)))
+    }
+
+    fn verify_tx_inclusion(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+        let gateway_id = read_bytes4(&mut input)?;
+        let speed_mode = read_speed_mode(&mut input)?;
+        let proof: Vec<u8> = input.read::<Bytes>()?.into();
+
+        Self::record_weight(
+            handle,
+            <T as pallet_portal::Config>::WeightInfo::verify_tx_inclusion(proof.len() as u32),
+        )?;
+
+        let receipt =
+            pallet_portal::Pallet::<T>::verify_tx_inclusion(gateway_id, speed_mode, proof)
+                .map_err(dispatch_revert)?;
+
+        Ok(succeed(Self::write_receipt(receipt)))
+    }
+
+    /// Returns the receipt as `(uint256 height, bytes includingHeader, bytes message)`.
+    fn write_receipt(receipt: InclusionReceipt<BlockNumberFor<T>>) -> Vec<u8> {
+        EvmDataWriter::new()
+            .write(block_number_to_u256::<T>(receipt.height))
+            .write(Bytes(receipt.including_header))
+            .write(Bytes(receipt.message))
+            .build()
+    }
+
+    /// Charge the weight's ref time as gas and its proof size against the transaction's PoV.
+    fn record_weight(handle: &mut impl PrecompileHandle, weight: Weight) -> EvmResult {
+        handle.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()))?;
+        handle.record_cost(<T as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+            weight,
+        ))
+    }
+}
+
+fn block_number_to_u256<T: frame_system::Config>(block_number: BlockNumberFor<T>) -> U256 {
+    U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(
+        block_number,
+    ))
+}
+
+fn dispatch_revert(error: DispatchError) -> fp_evm::PrecompileFailure {
+    revert(<&'static str>::from(error))
+}
+
+/// Read a Solidity `bytes4`, left-aligned within its 32 bytes word.
+fn read_bytes4(input: &mut EvmDataReader) -> EvmResult<ChainId> {
+    let word: H256 = input.read()?;
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&word.as_bytes()[..4]);
+    Ok(bytes)
+}
+
+fn read_speed_mode(input: &mut EvmDataReader) -> EvmResult<SpeedMode> {
+    match input.read::<u8>()? {
+        0 => Ok(SpeedMode::Fast),
+        1 => Ok(SpeedMode::Rational),
+        2 => Ok(SpeedMode::Finalized),
+        3 => Ok(SpeedMode::Instant),
+        _ => Err(revert("Portal: unknown speed mode")),
     }
 }
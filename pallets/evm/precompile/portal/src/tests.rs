@@ -0,0 +1,325 @@
+use crate::Action;
+use circuit_mock_runtime::{
+    contracts_config::PrecompilesValue, evm_precompile_util::precompile_mock::MockPrecompileSet, *,
+};
+use codec::Encode;
+use frame_support::assert_ok;
+use pallet_evm::GasWeightMapping;
+use pallet_portal::weights::{SubstrateWeight, WeightInfo};
+use pallet_standalone_finality_verifier::types::{
+    ConfirmationDepths, SealEngine, StandaloneHeader, StandaloneRegistrationData,
+    StandaloneStateInclusionProof, AURA_ENGINE_ID,
+};
+use precompile_util_solidity::{
+    data::{Bytes, EvmDataWriter},
+    testing::*,
+};
+use sp_core::{sr25519, Pair, H160, H256, U256};
+use sp_runtime::{traits::Header as HeaderT, Digest, DigestItem, StateVersion};
+use t3rn_primitives::{ExecutionVendor, GatewayVendor, SubstrateToken, T3rnCodec, TokenInfo};
+
+const STANDALONE_GATEWAY: [u8; 4] = *b"stnd";
+const STORAGE_KEY: &[u8] = b"some storage key";
+const STORAGE_VALUE: &[u8] = b"some storage value";
+
+fn precompiles() -> MockPrecompileSet<circuit_mock_runtime::Runtime> {
+    PrecompilesValue::get()
+}
+
+fn portal_evm_address() -> H160 {
+    H160([8u8; 20])
+}
+
+fn unknown_gateway() -> H256 {
+    let mut gateway = [0u8; 32];
+    gateway[..4].copy_from_slice(b"none");
+    H256::from(gateway)
+}
+
+fn weight_to_gas(weight: frame_support::weights::Weight) -> u64 {
+    <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+}
+
+#[test]
+fn height_of_unknown_gateway_reverts_after_charging_the_read() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                portal_evm_address(),
+                EvmDataWriter::new_with_selector(Action::GetHeight)
+                    .write(unknown_gateway())
+                    .write(2u8)
+                    .build(),
+            )
+            .expect_cost(weight_to_gas(SubstrateWeight::<Runtime>::get_height()))
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"GatewayVendorNotFound");
+    });
+}
+
+#[test]
+fn unknown_speed_mode_is_rejected() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                portal_evm_address(),
+                EvmDataWriter::new_with_selector(Action::GetHeight)
+                    .write(unknown_gateway())
+                    .write(4u8)
+                    .build(),
+            )
+            .expect_cost(0)
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Portal: unknown speed mode");
+    });
+}
+
+#[test]
+fn event_inclusion_charges_gas_proportional_to_proof_length() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    let proof = vec![1u8; 256];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                portal_evm_address(),
+                EvmDataWriter::new_with_selector(Action::VerifyEventInclusion)
+                    .write(unknown_gateway())
+                    .write(0u8)
+                    .write(H256::zero())
+                    .write(Bytes(proof.clone()))
+                    .build(),
+            )
+            .expect_cost(weight_to_gas(
+                SubstrateWeight::<Runtime>::verify_event_inclusion(proof.len() as u32),
+            ))
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"GatewayVendorNotFound");
+    });
+}
+
+#[test]
+fn inclusion_checks_are_not_payable() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                portal_evm_address(),
+                EvmDataWriter::new_with_selector(Action::VerifyTxInclusion)
+                    .write(unknown_gateway())
+                    .write(2u8)
+                    .write(Bytes(vec![]))
+                    .build(),
+            )
+            .with_value(1)
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Function is not payable");
+    });
+}
+
+fn standalone_gateway() -> H256 {
+    let mut gateway = [0u8; 32];
+    gateway[..4].copy_from_slice(&STANDALONE_GATEWAY);
+    H256::from(gateway)
+}
+
+fn aura_authority() -> sr25519::Pair {
+    sr25519::Pair::from_string("//Alice", None).unwrap()
+}
+
+/// Build the header on top of `parent` at the slot of its number, sealed by the Aura authority.
+fn aura_header(parent: &StandaloneHeader, state_root: H256) -> StandaloneHeader {
+    let slot = parent.number as u64 + 1;
+    let mut header = StandaloneHeader::new(
+        parent.number + 1,
+        Default::default(),
+        state_root,
+        parent.hash(),
+        Digest {
+            logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())],
+        },
+    );
+    let signature = aura_authority().sign(header.hash().as_ref());
+    header
+        .digest_mut()
+        .push(DigestItem::Seal(AURA_ENGINE_ID, signature.encode()));
+    header
+}
+
+/// Register a standalone gateway confirming headers as rational one block and as finalized two
+/// blocks below the best header, and import three headers on top of its genesis. The first
+/// imported header commits to a state holding `STORAGE_KEY`, whose read proof is returned.
+fn register_standalone_gateway_with_three_headers(
+    owner: AccountId,
+) -> (StandaloneHeader, sp_state_machine::StorageProof) {
+    let genesis = StandaloneHeader::new(
+        0,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    assert_ok!(Portal::register_gateway(
+        RuntimeOrigin::root(),
+        STANDALONE_GATEWAY,
+        3333,
+        GatewayVendor::Standalone,
+        ExecutionVendor::Substrate,
+        T3rnCodec::Scale,
+        None,
+        None,
+        vec![],
+        TokenInfo::Substrate(SubstrateToken {
+            id: 3333,
+            symbol: b"STND".to_vec(),
+            decimals: 12,
+        }),
+        StandaloneRegistrationData::<AccountId> {
+            first_header: genesis.encode(),
+            engine: SealEngine::Aura,
+            authorities: vec![aura_authority().public()],
+            authority_set_id: 0,
+            confirmation_depths: ConfirmationDepths {
+                rational: 1,
+                finalized: 2,
+            },
+            owner: owner.clone(),
+        }
+        .encode(),
+    ));
+
+    let backend = sp_state_machine::InMemoryBackend::<sp_runtime::traits::BlakeTwo256>::from((
+        vec![(
+            None,
+            vec![(STORAGE_KEY.to_vec(), Some(STORAGE_VALUE.to_vec()))],
+        )],
+        StateVersion::V1,
+    ));
+    let state_root = *backend.root();
+    let storage_proof = sp_state_machine::prove_read(backend, &[STORAGE_KEY]).unwrap();
+
+    let first = aura_header(&genesis, state_root);
+    let second = aura_header(&first, Default::default());
+    let third = aura_header(&second, Default::default());
+    assert_ok!(StandaloneBridge::submit_headers(
+        RuntimeOrigin::signed(owner),
+        vec![first.clone(), second, third],
+    ));
+
+    (first, storage_proof)
+}
+
+#[test]
+fn latest_heartbeat_is_returned_as_static_struct() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        register_standalone_gateway_with_three_headers(sender.account_id.clone());
+
+        precompiles()
+            .prepare_test(
+                sender.address,
+                portal_evm_address(),
+                EvmDataWriter::new_with_selector(Action::GetLatestHeartbeat)
+                    .write(standalone_gateway())
+                    .build(),
+            )
+            .expect_cost(weight_to_gas(
+                SubstrateWeight::<Runtime>::get_latest_heartbeat(),
+            ))
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(U256::from(System::block_number()))
+                    .write(U256::from(1))
+                    .write(U256::from(2))
+                    .write(U256::from(3))
+                    .write(false)
+                    .write(true)
+                    .build(),
+            );
+    });
+}
+
+#[test]
+fn each_height_getter_reads_its_speed_mode() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        register_standalone_gateway_with_three_headers(sender.account_id.clone());
+
+        let expect_height = |input: Vec<u8>, height: u32| {
+            precompiles()
+                .prepare_test(sender.address, portal_evm_address(), input)
+                .expect_cost(weight_to_gas(SubstrateWeight::<Runtime>::get_height()))
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(U256::from(height)).build());
+        };
+        let getter = |action: Action| {
+            EvmDataWriter::new_with_selector(action)
+                .write(standalone_gateway())
+                .build()
+        };
+        let by_speed_mode = |speed_mode: u8| {
+            EvmDataWriter::new_with_selector(Action::GetHeight)
+                .write(standalone_gateway())
+                .write(speed_mode)
+                .build()
+        };
+
+        expect_height(getter(Action::GetFinalizedHeight), 1);
+        expect_height(getter(Action::GetRationalHeight), 2);
+        expect_height(getter(Action::GetFastHeight), 3);
+        expect_height(by_speed_mode(0), 3);
+        expect_height(by_speed_mode(1), 2);
+        expect_height(by_speed_mode(2), 1);
+        expect_height(by_speed_mode(3), 3);
+    });
+}
+
+#[test]
+fn state_inclusion_returns_receipt_of_finalized_header() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        let (header, storage_proof) =
+            register_standalone_gateway_with_three_headers(sender.account_id.clone());
+        let proof = StandaloneStateInclusionProof {
+            storage_key: STORAGE_KEY.to_vec(),
+            storage_proof,
+            block_hash: header.hash(),
+        }
+        .encode();
+
+        precompiles()
+            .prepare_test(
+                sender.address,
+                portal_evm_address(),
+                EvmDataWriter::new_with_selector(Action::VerifyStateInclusion)
+                    .write(standalone_gateway())
+                    .write(2u8)
+                    .write(Bytes(proof.clone()))
+                    .build(),
+            )
+            .expect_cost(weight_to_gas(
+                SubstrateWeight::<Runtime>::verify_state_inclusion(proof.len() as u32),
+            ))
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(U256::from(1))
+                    .write(Bytes(header.hash().encode()))
+                    .write(Bytes(STORAGE_VALUE.to_vec()))
+                    .build(),
+            );
+    });
+}
@@ -12,7 +12,7 @@ use frame_support::traits::{
         Inspect as IssuanceInspect,
     },
     tokens::currency::Currency,
-    ExistenceRequirement, OriginTrait,
+    ExistenceRequirement, Get, OriginTrait, Time,
 };
 use frame_system::RawOrigin;
 use pallet_evm::AddressMapping;
@@ -20,13 +20,15 @@ use precompile_util_solidity::{
     data::{Address, Bytes, EvmData, EvmDataReader, EvmDataWriter},
     error,
     handle::PrecompileHandleExt,
+    logs::{log3, LogExt},
     modifier::FunctionModifier,
     revert,
     substrate::RuntimeHelper,
     succeed, EvmResult,
 };
-use sp_core::{H160, U256};
-use sp_runtime::traits::{StaticLookup, Zero};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{StaticLookup, UniqueSaturatedInto, Zero};
 use sp_std::{marker::PhantomData, str::*, vec::Vec};
 use t3rn_primitives::{
     monetary::DECIMALS,
@@ -36,6 +38,39 @@ use t3rn_primitives::{
 #[cfg(test)]
 mod tests;
 
+/// Solidity selector of the ERC-20 Transfer log.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] =
+    precompile_util_macro::keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the ERC-20 Approval log.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] =
+    precompile_util_macro::keccak256!("Approval(address,address,uint256)");
+
+/// EIP-712 type hash of the domain every permit is signed for.
+pub const PERMIT_DOMAIN: [u8; 32] = precompile_util_macro::keccak256!(
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// EIP-712 type hash of the EIP-2612 permit.
+pub const PERMIT_TYPEHASH: [u8; 32] = precompile_util_macro::keccak256!(
+    "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+);
+
+/// Gas charged for recovering the permit signer, same as the ECRecover precompile.
+pub const PERMIT_ECRECOVER_GAS_COST: u64 = 3000;
+
+/// Next EIP-2612 permit nonce of an owner, per asset.
+#[frame_support::storage_alias]
+pub type PermitNonces = StorageDoubleMap<
+    TokensPrecompilePermit,
+    frame_support::Blake2_128Concat,
+    u32,
+    frame_support::Blake2_128Concat,
+    H160,
+    U256,
+    frame_support::pallet_prelude::ValueQuery,
+>;
+
 #[precompile_util_macro::generate_function_selector]
 #[derive(Debug, PartialEq)]
 pub enum Action {
@@ -48,6 +83,9 @@ pub enum Action {
     Name = "name()",
     Symbol = "symbol()",
     Decimals = "decimals()",
+    Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+    Nonces = "nonces(address)",
+    DomainSeparator = "DOMAIN_SEPARATOR()",
 }
 
 pub struct TokensPrecompile<T>(PhantomData<T>);
@@ -127,6 +165,7 @@ where
                 if let Err(err) = handle.check_function_modifier(match selector {
                     Action::Approve | Action::Transfer | Action::TransferFrom =>
                         FunctionModifier::Payable,
+                    Action::Permit => FunctionModifier::NonPayable,
                     _ => FunctionModifier::View,
                 }) {
                     return Err(err)
@@ -142,6 +181,9 @@ where
                     Action::Name => Self::name(token_id, handle),
                     Action::Symbol => Self::symbol(token_id, handle),
                     Action::Decimals => Self::decimals(token_id, handle),
+                    Action::Permit => Self::permit(token_id, handle),
+                    Action::Nonces => Self::nonces(token_id, handle),
+                    Action::DomainSeparator => Self::domain_separator(token_id, handle),
                 }
             };
             return result
@@ -317,43 +359,64 @@ where
                 .read::<<T as pallet_assets::Config>::Balance>()?
                 .into();
 
-            let owner =
-                <T as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
-            let spender = <T as pallet_evm::Config>::AddressMapping::into_account_id(spender);
+            let owner = handle.context().caller;
+            Self::set_approval(asset_id, handle, owner, spender, amount)?;
 
-            // Check if there is existing allowance for the same pair of owner and sender
-            // and cancel it if there is one
-            handle.record_cost(RuntimeHelper::<T>::db_read_gas_cost())?;
+            return Ok(succeed(EvmDataWriter::new().write(true).build()))
+        }
+        Err(PrecompileFailure::Error {
+            exit_status: pallet_evm::ExitError::Other("Not Supported".into()),
+        })
+    }
 
-            if pallet_assets::Pallet::<T>::allowance(asset_id.into(), &owner, &spender)
-                != Zero::zero()
-            {
-                pallet_assets::Pallet::<T>::cancel_approval(
-                    RawOrigin::Signed(owner.clone()).into(),
-                    asset_id.into(),
-                    <T as frame_system::Config>::Lookup::unlookup(spender.clone()),
-                )
-                .map_err(|e| PrecompileFailure::Revert {
-                    exit_status: ExitRevert::Reverted,
-                    output: Into::<&str>::into(e).as_bytes().to_vec(),
-                })?;
-            }
-            pallet_assets::Pallet::<T>::approve_transfer(
-                RawOrigin::Signed(owner).into(),
+    /// Replace the allowance of the owner and spender pair and emit the Approval log,
+    /// whose cost is expected to be recorded by the caller.
+    fn set_approval(
+        asset_id: u32,
+        handle: &mut impl PrecompileHandle,
+        owner_address: H160,
+        spender_address: H160,
+        amount: <T as pallet_assets::Config>::Balance,
+    ) -> EvmResult {
+        let owner = <T as pallet_evm::Config>::AddressMapping::into_account_id(owner_address);
+        let spender = <T as pallet_evm::Config>::AddressMapping::into_account_id(spender_address);
+        let value = U256::from(amount.clone());
+
+        // Check if there is existing allowance for the same pair of owner and sender
+        // and cancel it if there is one
+        handle.record_cost(RuntimeHelper::<T>::db_read_gas_cost())?;
+
+        if pallet_assets::Pallet::<T>::allowance(asset_id.into(), &owner, &spender) != Zero::zero()
+        {
+            pallet_assets::Pallet::<T>::cancel_approval(
+                RawOrigin::Signed(owner.clone()).into(),
                 asset_id.into(),
-                <T as frame_system::Config>::Lookup::unlookup(spender),
-                amount,
+                <T as frame_system::Config>::Lookup::unlookup(spender.clone()),
             )
             .map_err(|e| PrecompileFailure::Revert {
                 exit_status: ExitRevert::Reverted,
                 output: Into::<&str>::into(e).as_bytes().to_vec(),
             })?;
-
-            return Ok(succeed(EvmDataWriter::new().write(true).build()))
         }
-        Err(PrecompileFailure::Error {
-            exit_status: pallet_evm::ExitError::Other("Not Supported".into()),
-        })
+        pallet_assets::Pallet::<T>::approve_transfer(
+            RawOrigin::Signed(owner).into(),
+            asset_id.into(),
+            <T as frame_system::Config>::Lookup::unlookup(spender),
+            amount,
+        )
+        .map_err(|e| PrecompileFailure::Revert {
+            exit_status: ExitRevert::Reverted,
+            output: Into::<&str>::into(e).as_bytes().to_vec(),
+        })?;
+
+        log3(
+            handle.code_address(),
+            SELECTOR_LOG_APPROVAL,
+            owner_address,
+            spender_address,
+            EvmDataWriter::new().write(value).build(),
+        )
+        .record(handle)
     }
 
     fn transfer(token_id: TokenId, handle: &mut impl PrecompileHandle) -> PrecompileResult
@@ -367,16 +430,16 @@ where
         // Parse input
         let mut input = handle.read_input()?;
         input.expect_arguments(2)?;
-        let to: H160 = input.read::<Address>()?.into();
+        let to_address: H160 = input.read::<Address>()?.into();
+        let origin_address = handle.context().caller;
 
         // Convert EVM address to Substrate address
-        let origin =
-            <T as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
-        let to = <T as pallet_evm::Config>::AddressMapping::into_account_id(to);
+        let origin = <T as pallet_evm::Config>::AddressMapping::into_account_id(origin_address);
+        let to = <T as pallet_evm::Config>::AddressMapping::into_account_id(to_address);
 
         // Get transfer amount value from input and transfer assets using
         // either pallet_evm::Config::Currnecy::transfer or pallet_assets::transfer
-        match token_id {
+        let value = match token_id {
             TokenId::Native => {
                 let value: <<T as pallet_evm::Config>::Currency as Currency<
                     <T as frame_system::pallet::Config>::AccountId,
@@ -396,6 +459,7 @@ where
                     exit_status: ExitRevert::Reverted,
                     output: Into::<&str>::into(e).as_bytes().to_vec(),
                 })?;
+                U256::from(value)
             },
             TokenId::Asset(asset_id) => {
                 let value: <T as pallet_assets::Config>::Balance = input
@@ -406,14 +470,25 @@ where
                     RawOrigin::Signed(origin).into(),
                     asset_id.into(),
                     <T as frame_system::Config>::Lookup::unlookup(to),
-                    value,
+                    value.clone(),
                 )
                 .map_err(|e| PrecompileFailure::Revert {
                     exit_status: ExitRevert::Reverted,
                     output: Into::<&str>::into(e).as_bytes().to_vec(),
                 })?;
+                U256::from(value)
             },
         };
+
+        log3(
+            handle.code_address(),
+            SELECTOR_LOG_TRANSFER,
+            origin_address,
+            to_address,
+            EvmDataWriter::new().write(value).build(),
+        )
+        .record(handle)?;
+
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
 
@@ -428,16 +503,25 @@ where
             let mut input = handle.read_input()?;
             input.expect_arguments(3)?;
 
-            let from: H160 = input.read::<Address>()?.into();
-            let to: H160 = input.read::<Address>()?.into();
+            let from_address: H160 = input.read::<Address>()?.into();
+            let to_address: H160 = input.read::<Address>()?.into();
             let value: <T as pallet_assets::Config>::Balance = input
                 .read::<<T as pallet_assets::Config>::Balance>()?
                 .into();
+            let log = log3(
+                handle.code_address(),
+                SELECTOR_LOG_TRANSFER,
+                from_address,
+                to_address,
+                EvmDataWriter::new()
+                    .write(U256::from(value.clone()))
+                    .build(),
+            );
 
             let caller =
                 <T as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
-            let from = <T as pallet_evm::Config>::AddressMapping::into_account_id(from);
-            let to = <T as pallet_evm::Config>::AddressMapping::into_account_id(to);
+            let from = <T as pallet_evm::Config>::AddressMapping::into_account_id(from_address);
+            let to = <T as pallet_evm::Config>::AddressMapping::into_account_id(to_address);
 
             if caller != from {
                 pallet_assets::Pallet::<T>::transfer_approved(
@@ -463,6 +547,7 @@ where
                     output: Into::<&str>::into(e).as_bytes().to_vec(),
                 })?;
             }
+            log.record(handle)?;
 
             return Ok(succeed(EvmDataWriter::new().write(true).build()))
         }
@@ -470,4 +555,141 @@ where
             exit_status: pallet_evm::ExitError::Other("Not Supported".into()),
         })
     }
+
+    /// EIP-2612 approval of the spender by the owner's signature over the permit,
+    /// so the spender can submit it and pay for the approval.
+    fn permit(token_id: TokenId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        handle.record_log_costs_manual(3, 32)?;
+        handle.record_cost(
+            RuntimeHelper::<T>::db_read_gas_cost()
+                .saturating_mul(2)
+                .saturating_add(RuntimeHelper::<T>::db_write_gas_cost())
+                .saturating_add(PERMIT_ECRECOVER_GAS_COST),
+        )?;
+
+        if let TokenId::Asset(asset_id) = token_id {
+            let mut input = handle.read_input()?;
+            input.expect_arguments(7)?;
+
+            let owner: H160 = input.read::<Address>()?.into();
+            let spender: H160 = input.read::<Address>()?.into();
+            let amount: <T as pallet_assets::Config>::Balance = input
+                .read::<<T as pallet_assets::Config>::Balance>()?
+                .into();
+            let deadline: U256 = input.read()?;
+            let v: u8 = input.read()?;
+            let r: H256 = input.read()?;
+            let s: H256 = input.read()?;
+
+            let now: u64 =
+                <T as pallet_evm::Config>::Timestamp::now().unique_saturated_into() / 1000;
+            if deadline < U256::from(now) {
+                return Err(revert("Permit expired"))
+            }
+
+            let nonce = PermitNonces::get(asset_id, owner);
+            let digest = Self::permit_digest(
+                TokenId::Asset(asset_id),
+                handle.code_address(),
+                owner,
+                spender,
+                U256::from(amount.clone()),
+                nonce,
+                deadline,
+            );
+
+            let mut signature = [0u8; 65];
+            signature[..32].copy_from_slice(r.as_bytes());
+            signature[32..64].copy_from_slice(s.as_bytes());
+            signature[64] = v.checked_sub(27).ok_or_else(|| revert("Invalid permit"))?;
+
+            let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+                .map(|public_key| H160::from(H256::from(keccak_256(&public_key))))
+                .map_err(|_| revert("Invalid permit"))?;
+            if signer != owner {
+                return Err(revert("Invalid permit"))
+            }
+
+            PermitNonces::insert(asset_id, owner, nonce.saturating_add(U256::one()));
+            Self::set_approval(asset_id, handle, owner, spender, amount)?;
+
+            return Ok(succeed(Vec::new()))
+        }
+        Err(PrecompileFailure::Error {
+            exit_status: pallet_evm::ExitError::Other("Not Supported".into()),
+        })
+    }
+
+    fn nonces(token_id: TokenId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        handle.record_cost(RuntimeHelper::<T>::db_read_gas_cost())?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+        let owner: H160 = input.read::<Address>()?.into();
+
+        let nonce = match token_id {
+            TokenId::Native => U256::zero(),
+            TokenId::Asset(asset_id) => PermitNonces::get(asset_id, owner),
+        };
+        Ok(succeed(EvmDataWriter::new().write(nonce).build()))
+    }
+
+    fn domain_separator(token_id: TokenId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        handle.record_cost(RuntimeHelper::<T>::db_read_gas_cost())?;
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write(H256::from(Self::compute_domain_separator(
+                    token_id,
+                    handle.code_address(),
+                )))
+                .build(),
+        ))
+    }
+
+    fn compute_domain_separator(token_id: TokenId, address: H160) -> [u8; 32] {
+        // Same name as returned by `name()`
+        let name = match token_id {
+            TokenId::Native => "TRN".as_bytes().to_vec(),
+            TokenId::Asset(asset_id) => pallet_assets::Pallet::<T>::name(asset_id.into()),
+        };
+
+        keccak_256(
+            &EvmDataWriter::new()
+                .write(H256::from(PERMIT_DOMAIN))
+                .write(H256::from(keccak_256(&name)))
+                .write(H256::from(keccak_256(b"1")))
+                .write(U256::from(<T as pallet_evm::Config>::ChainId::get()))
+                .write(Address(address))
+                .build(),
+        )
+    }
+
+    /// EIP-712 digest the owner signs to permit the spender.
+    pub fn permit_digest(
+        token_id: TokenId,
+        address: H160,
+        owner: H160,
+        spender: H160,
+        value: U256,
+        nonce: U256,
+        deadline: U256,
+    ) -> [u8; 32] {
+        let permit = keccak_256(
+            &EvmDataWriter::new()
+                .write(H256::from(PERMIT_TYPEHASH))
+                .write(Address(owner))
+                .write(Address(spender))
+                .write(value)
+                .write(nonce)
+                .write(deadline)
+                .build(),
+        );
+
+        let mut message = [0u8; 66];
+        message[..2].copy_from_slice(b"\x19\x01");
+        message[2..34].copy_from_slice(&Self::compute_domain_separator(token_id, address));
+        message[34..].copy_from_slice(&permit);
+        keccak_256(&message)
+    }
 }
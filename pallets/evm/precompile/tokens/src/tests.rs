@@ -1,4 +1,4 @@
-use crate::Action;
+use crate::{Action, PermitNonces, TokensPrecompile, SELECTOR_LOG_APPROVAL, SELECTOR_LOG_TRANSFER};
 use circuit_mock_runtime::{
     contracts_config::PrecompilesValue, evm_precompile_util::precompile_mock::MockPrecompileSet, *,
 };
@@ -9,9 +9,10 @@ use frame_support::{
 use hex_literal::hex;
 use precompile_util_solidity::{
     data::{Address, Bytes, EvmDataWriter},
+    logs::log3,
     testing::*,
 };
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::traits::Zero;
 use sp_std::boxed::Box;
 
@@ -190,7 +191,13 @@ fn transfer_native_works() {
                     .build(),
             )
             .expect_cost(1756)
-            .expect_no_logs()
+            .expect_log(log3(
+                trn_evm_address(),
+                SELECTOR_LOG_TRANSFER,
+                sender.address,
+                receiver.address,
+                EvmDataWriter::new().write(U256::from(1_000_000u64)).build(),
+            ))
             .execute_returns(EvmDataWriter::new().write(1u64).build());
 
         assert_eq!(
@@ -347,7 +354,13 @@ fn approve_asset_works() {
                     .build(),
             )
             .expect_cost(3006)
-            .expect_no_logs()
+            .expect_log(log3(
+                tst_evm_address(),
+                SELECTOR_LOG_APPROVAL,
+                owner.address,
+                spender.address,
+                EvmDataWriter::new().write(U256::from(2000u64)).build(),
+            ))
             .execute_returns(EvmDataWriter::new().write(1u64).build());
 
         assert_eq!(
@@ -395,7 +408,13 @@ fn transfer_from_asset_works() {
                     .build(),
             )
             .expect_cost(1756)
-            .expect_no_logs()
+            .expect_log(log3(
+                tst_evm_address(),
+                SELECTOR_LOG_TRANSFER,
+                from.address,
+                to.address,
+                EvmDataWriter::new().write(U256::from(1500u64)).build(),
+            ))
             .execute_returns(EvmDataWriter::new().write(1u64).build());
 
         assert_eq!(
@@ -438,7 +457,13 @@ fn transfer_asset_works() {
                     .build(),
             )
             .expect_cost(1756)
-            .expect_no_logs()
+            .expect_log(log3(
+                tst_evm_address(),
+                SELECTOR_LOG_TRANSFER,
+                sender.address,
+                receiver.address,
+                EvmDataWriter::new().write(U256::from(1000u64)).build(),
+            ))
             .execute_returns(EvmDataWriter::new().write(1u64).build());
 
         assert_eq!(
@@ -451,3 +476,278 @@ fn transfer_asset_works() {
         );
     });
 }
+
+fn sign_permit(
+    owner: &AccountInfo,
+    spender: H160,
+    value: U256,
+    nonce: U256,
+    deadline: U256,
+) -> (u8, H256, H256) {
+    let digest = TokensPrecompile::<Runtime>::permit_digest(
+        TokenId::Asset(1),
+        tst_evm_address(),
+        owner.address,
+        spender,
+        value,
+        nonce,
+        deadline,
+    );
+    let secret_key = libsecp256k1::SecretKey::parse_slice(&owner.private_key[..]).unwrap();
+    let (signature, recovery_id) =
+        libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), &secret_key);
+    let signature = signature.serialize();
+
+    (
+        recovery_id.serialize() + 27,
+        H256::from_slice(&signature[..32]),
+        H256::from_slice(&signature[32..]),
+    )
+}
+
+fn permit_input(
+    owner: H160,
+    spender: H160,
+    value: U256,
+    deadline: U256,
+    (v, r, s): (u8, H256, H256),
+) -> Vec<u8> {
+    EvmDataWriter::new_with_selector(Action::Permit)
+        .write(Address::from(owner))
+        .write(Address::from(spender))
+        .write(value)
+        .write(deadline)
+        .write(v)
+        .write(r)
+        .write(s)
+        .build()
+}
+
+// EIP-712 vectors for the mock chain id 42, produced with ethers-rs `TypedData`, independently
+// of the precompile's encoding: owner and spender are the addresses of `new_test_ext` accounts
+// 0 and 1, permitting 2000 TST with nonce 0 and deadline `U256::MAX`.
+const TST_DOMAIN_SEPARATOR: [u8; 32] =
+    hex!("6efdd94d1b730610ba91931c903986fe35d123af7763ed661f5e448d81b9bb83");
+const TRN_DOMAIN_SEPARATOR: [u8; 32] =
+    hex!("b0d12e985e5829180c0cbe8e648bc86c569406a6fc38cd83911ea1694b6936f4");
+const TST_PERMIT_DIGEST: [u8; 32] =
+    hex!("b73a1271c32a4780117b8b2e79cc9e64ba68857cec3e78cbfc751a830c515623");
+
+#[test]
+fn domain_separator_matches_external_eip712_vector() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                tst_evm_address(),
+                EvmDataWriter::new_with_selector(Action::DomainSeparator).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(H256::from(TST_DOMAIN_SEPARATOR))
+                    .build(),
+            );
+
+        precompiles()
+            .prepare_test(
+                sender.address,
+                trn_evm_address(),
+                EvmDataWriter::new_with_selector(Action::DomainSeparator).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(H256::from(TRN_DOMAIN_SEPARATOR))
+                    .build(),
+            );
+    });
+}
+
+#[test]
+fn permit_digest_matches_external_eip712_vector() {
+    let (pairs, mut ext) = new_test_ext(2);
+    let owner = &pairs[0];
+    let spender = &pairs[1];
+    assert_eq!(
+        owner.address,
+        H160::from(hex!("1a642f0e3c3af545e7acbd38b07251b3990914f1"))
+    );
+    assert_eq!(
+        spender.address,
+        H160::from(hex!("5050a4f4b3f9338c3472dcc01a87c76a144b3c9c"))
+    );
+    ext.execute_with(|| {
+        assert_eq!(
+            TokensPrecompile::<Runtime>::permit_digest(
+                TokenId::Asset(1),
+                tst_evm_address(),
+                owner.address,
+                spender.address,
+                U256::from(2000u64),
+                U256::zero(),
+                U256::MAX,
+            ),
+            TST_PERMIT_DIGEST
+        );
+    });
+}
+
+#[test]
+fn permit_asset_works() {
+    let (pairs, mut ext) = new_test_ext(3);
+    let owner = &pairs[0];
+    let spender = &pairs[1];
+    let relayer = &pairs[2];
+    ext.execute_with(|| {
+        let signature = sign_permit(
+            owner,
+            spender.address,
+            U256::from(2000u64),
+            U256::zero(),
+            U256::MAX,
+        );
+
+        precompiles()
+            .prepare_test(
+                relayer.address,
+                tst_evm_address(),
+                permit_input(
+                    owner.address,
+                    spender.address,
+                    U256::from(2000u64),
+                    U256::MAX,
+                    signature,
+                ),
+            )
+            .expect_log(log3(
+                tst_evm_address(),
+                SELECTOR_LOG_APPROVAL,
+                owner.address,
+                spender.address,
+                EvmDataWriter::new().write(U256::from(2000u64)).build(),
+            ))
+            .execute_returns(vec![]);
+
+        assert_eq!(
+            circuit_mock_runtime::Assets::allowance(1u32, &owner.account_id, &spender.account_id),
+            2000
+        );
+        assert_eq!(PermitNonces::get(1, owner.address), U256::one());
+
+        precompiles()
+            .prepare_test(
+                relayer.address,
+                tst_evm_address(),
+                EvmDataWriter::new_with_selector(Action::Nonces)
+                    .write(Address::from(owner.address))
+                    .build(),
+            )
+            .expect_cost(1250)
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(U256::one()).build());
+    });
+}
+
+#[test]
+fn permit_cannot_be_replayed() {
+    let (pairs, mut ext) = new_test_ext(2);
+    let owner = &pairs[0];
+    let spender = &pairs[1];
+    ext.execute_with(|| {
+        let signature = sign_permit(
+            owner,
+            spender.address,
+            U256::from(2000u64),
+            U256::zero(),
+            U256::MAX,
+        );
+        let input = permit_input(
+            owner.address,
+            spender.address,
+            U256::from(2000u64),
+            U256::MAX,
+            signature,
+        );
+
+        precompiles()
+            .prepare_test(spender.address, tst_evm_address(), input.clone())
+            .execute_returns(vec![]);
+
+        precompiles()
+            .prepare_test(spender.address, tst_evm_address(), input)
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Invalid permit");
+    });
+}
+
+#[test]
+fn permit_signed_by_someone_else_is_rejected() {
+    let (pairs, mut ext) = new_test_ext(2);
+    let owner = &pairs[0];
+    let spender = &pairs[1];
+    ext.execute_with(|| {
+        let signature = sign_permit(
+            spender,
+            spender.address,
+            U256::from(2000u64),
+            U256::zero(),
+            U256::MAX,
+        );
+
+        precompiles()
+            .prepare_test(
+                spender.address,
+                tst_evm_address(),
+                permit_input(
+                    owner.address,
+                    spender.address,
+                    U256::from(2000u64),
+                    U256::MAX,
+                    signature,
+                ),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Invalid permit");
+
+        assert_eq!(
+            circuit_mock_runtime::Assets::allowance(1u32, &owner.account_id, &spender.account_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn expired_permit_is_rejected() {
+    let (pairs, mut ext) = new_test_ext(2);
+    let owner = &pairs[0];
+    let spender = &pairs[1];
+    ext.execute_with(|| {
+        circuit_mock_runtime::Timestamp::set_timestamp(10_000);
+        let deadline = U256::from(5u64);
+        let signature = sign_permit(
+            owner,
+            spender.address,
+            U256::from(2000u64),
+            U256::zero(),
+            deadline,
+        );
+
+        precompiles()
+            .prepare_test(
+                spender.address,
+                tst_evm_address(),
+                permit_input(
+                    owner.address,
+                    spender.address,
+                    U256::from(2000u64),
+                    deadline,
+                    signature,
+                ),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Permit expired");
+    });
+}
@@ -10,6 +10,7 @@ use sp_std::{borrow, borrow::ToOwned};
 pub mod costs;
 pub mod data;
 pub mod handle;
+pub mod logs;
 pub mod modifier;
 pub mod precompile_set;
 pub mod substrate;
@@ -0,0 +1,85 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part Utils package, originally developed by PureStake
+
+// Utils is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Utils is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Utils.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers to build and emit EVM logs from precompiles.
+
+use crate::EvmResult;
+use pallet_3vm_evm_primitives::{Log, PrecompileHandle};
+use sp_core::{H160, H256};
+use sp_std::{vec, vec::Vec};
+
+/// Create a log with 1 topic.
+#[must_use]
+pub fn log1(address: impl Into<H160>, topic0: impl Into<H256>, data: impl Into<Vec<u8>>) -> Log {
+    Log {
+        address: address.into(),
+        topics: vec![topic0.into()],
+        data: data.into(),
+    }
+}
+
+/// Create a log with 2 topics.
+#[must_use]
+pub fn log2(
+    address: impl Into<H160>,
+    topic0: impl Into<H256>,
+    topic1: impl Into<H256>,
+    data: impl Into<Vec<u8>>,
+) -> Log {
+    Log {
+        address: address.into(),
+        topics: vec![topic0.into(), topic1.into()],
+        data: data.into(),
+    }
+}
+
+/// Create a log with 3 topics.
+#[must_use]
+pub fn log3(
+    address: impl Into<H160>,
+    topic0: impl Into<H256>,
+    topic1: impl Into<H256>,
+    topic2: impl Into<H256>,
+    data: impl Into<Vec<u8>>,
+) -> Log {
+    Log {
+        address: address.into(),
+        topics: vec![topic0.into(), topic1.into(), topic2.into()],
+        data: data.into(),
+    }
+}
+
+pub trait LogExt {
+    /// Emit the log through the handle.
+    /// Its cost is expected to be recorded beforehand, see `compute_cost`.
+    #[must_use]
+    fn record(self, handle: &mut impl PrecompileHandle) -> EvmResult;
+
+    /// Cost of emitting the log in gas.
+    #[must_use]
+    fn compute_cost(&self) -> EvmResult<u64>;
+}
+
+impl LogExt for Log {
+    fn record(self, handle: &mut impl PrecompileHandle) -> EvmResult {
+        handle.log(self.address, self.topics, self.data)?;
+        Ok(())
+    }
+
+    fn compute_cost(&self) -> EvmResult<u64> {
+        crate::costs::log_costs(self.topics.len(), self.data.len())
+    }
+}
@@ -231,6 +231,12 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         self
     }
 
+    /// Execute in the context of another address, as DELEGATECALL or CALLCODE would.
+    pub fn with_context_address(mut self, address: impl Into<H160>) -> Self {
+        self.handle.context.address = address.into();
+        self
+    }
+
     pub fn with_subcall_handle(mut self, subcall_handle: impl SubcallTrait) -> Self {
         self.subcall_handle = Some(Box::new(subcall_handle));
         self
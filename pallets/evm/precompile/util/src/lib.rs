@@ -12,6 +12,7 @@ pub use pallet_evm_precompile_simple::{
     ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256,
 };
 
+use pallet_evm_precompile_3vm_dispatch::ThreeVmDispatch;
 use portal_precompile::PortalPrecompile;
 use precompile_util_solidity::data::EvmData;
 use sp_core::H160;
@@ -58,6 +59,7 @@ where
     TokensPrecompile<T>: Precompile,
     PortalPrecompile<T>: Precompile,
     VacuumPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         let address = handle.code_address();
@@ -83,6 +85,7 @@ where
             // t3rn precompiles
             a if H160([7u8; 20]) == a => Some(PortalPrecompile::<T>::execute(handle)),
             a if H160([8u8; 20]) == a => Some(VacuumPrecompile::<T>::execute(handle)),
+            a if H160([6u8; 20]) == a => Some(ThreeVmDispatch::<T>::execute(handle)),
             a if &a.to_fixed_bytes()[0..16] == TOKENS_PRECOMPILE_PREFIX =>
                 Some(TokensPrecompile::<T>::execute(handle)),
             // Default
@@ -2,6 +2,7 @@ use crate::{IsPrecompileResult, PrecompileHandle, PrecompileSet};
 use frame_support::traits::Currency;
 use pallet_3vm_evm::{ExitError, PrecompileFailure};
 use pallet_3vm_evm_primitives::{Precompile, PrecompileResult};
+use pallet_evm_precompile_3vm_dispatch::ThreeVmDispatch;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_sha3fips::{Sha3FIPS256, Sha3FIPS512};
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
@@ -26,6 +27,8 @@ where
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
 {
     // Ethereum precompiles:
     ECRecover,
@@ -41,6 +44,7 @@ where
     Portal,
     Tokens,
     Vacuum,
+    ThreeVmDispatch,
     Noop(T),
 }
 
@@ -58,6 +62,8 @@ where
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
 {
     pub fn execute(&self, handle: &mut impl PrecompileHandle) -> PrecompileResult {
         match self {
@@ -75,6 +81,7 @@ where
             KnownPrecompile::Portal => PortalPrecompile::<T>::execute(handle),
             KnownPrecompile::Tokens => TokensPrecompile::<T>::execute(handle),
             KnownPrecompile::Vacuum => VacuumPrecompile::<T>::execute(handle),
+            KnownPrecompile::ThreeVmDispatch => ThreeVmDispatch::<T>::execute(handle),
             KnownPrecompile::Noop(_) => PrecompileResult::Err(PrecompileFailure::from(
                 ExitError::Other("Noop precompile".into()),
             )),
@@ -100,6 +107,8 @@ pub struct MockPrecompileSet<
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
 {
     pub inner: BTreeMap<H160, KnownPrecompile<T>>,
     phantom: PhantomData<T>,
@@ -119,6 +128,8 @@ where
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
 {
     pub fn new(inner: BTreeMap<H160, KnownPrecompile<T>>) -> Self {
         Self {
@@ -147,6 +158,8 @@ where
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         self.inner
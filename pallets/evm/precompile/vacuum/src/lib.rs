@@ -1,29 +1,56 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use fp_evm::{
-    ExitError, ExitSucceed, Precompile as EvmPrecompile, PrecompileFailure, PrecompileHandle,
-    PrecompileOutput, PrecompileResult,
+use codec::Encode;
+use fp_evm::{Precompile as EvmPrecompile, PrecompileHandle, PrecompileResult};
+use frame_support::{traits::Currency, weights::Weight};
+use pallet_circuit_vacuum::weights::{SubstrateWeight as VacuumWeight, WeightInfo};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use precompile_util_solidity::{
+    data::{Address, Bytes, EvmData, EvmDataReader, EvmDataWriter},
+    handle::PrecompileHandleExt,
+    logs::{log3, LogExt},
+    modifier::FunctionModifier,
+    revert, succeed, EvmResult,
 };
-use frame_support::{sp_runtime::app_crypto::sp_core, traits::Currency};
+use sp_core::{crypto::AccountId32, H160, H256, U256};
 use sp_std::{marker::PhantomData, vec::Vec};
 use t3rn_primitives::{
-    threevm::{Precompile, PORTAL},
+    circuit::{VacuumEVM3DOrder, VacuumEVMOrder, VacuumEVMProof, VacuumEVMTeleportOrder},
+    threevm::{
+        Precompile, VACUUM_3D_ORDER, VACUUM_CONFIRM, VACUUM_ORDER, VACUUM_SUBMIT_CORRECTNESS_PROOF,
+        VACUUM_SUBMIT_FAULT_PROOF, VACUUM_TELEPORT_ORDER,
+    },
     T3rnCodec,
 };
 
-use precompile_util_solidity::data::EvmData;
+#[cfg(test)]
+mod tests;
 
-pub struct VacuumPrecompile<T>(PhantomData<T>);
+/// Solidity selector of the OrderCreated log,
+/// emitted with the requester and destination as indexed topics.
+pub const SELECTOR_LOG_ORDER_CREATED: [u8; 32] = precompile_util_macro::keccak256!(
+    "OrderCreated(address,bytes4,uint32,uint256,address,uint256,uint256,bytes32)"
+);
+
+/// Solidity selector of the OrderConfirmed log,
+/// emitted with the executor and destination as indexed topics.
+pub const SELECTOR_LOG_ORDER_CONFIRMED: [u8; 32] = precompile_util_macro::keccak256!(
+    "OrderConfirmed(address,bytes4,uint32,uint256,address,uint256,uint256,bytes32)"
+);
 
-pub enum VacuumAction {
-    VacuumOrder = 90,
-    Vacuum3DOrder = 91,
-    VacuumConfirm = 92,
-    VacuumSubmitCorrectnessProof = 93,
-    VacuumSubmitFaultProof = 94,
-    VacuumTeleportOrder = 96,
+#[precompile_util_macro::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Order = "order(bytes4,uint32,uint256,address,uint256,uint256,bytes32)",
+    Order3D = "order3D(bytes4,uint32,uint256,address,uint256,uint256,bytes32,uint32)",
+    Confirm = "confirm(bytes4,uint32,uint256,address,uint256,uint256,bytes32)",
+    SubmitCorrectnessProof = "submitCorrectnessProof(bytes4,bytes4,bytes4,bytes,bytes,bytes,bytes)",
+    SubmitFaultProof = "submitFaultProof(bytes4,bytes4,bytes4,bytes,bytes,bytes,bytes)",
+    TeleportOrder = "teleportOrder(bytes4,bytes)",
 }
 
+pub struct VacuumPrecompile<T>(PhantomData<T>);
+
 impl<T> EvmPrecompile for VacuumPrecompile<T>
 where
     T: pallet_evm::Config + pallet_assets::Config + frame_system::Config,
@@ -41,51 +68,207 @@ where
     >,
 {
     fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-        let input = handle.input();
-        let _target_gas = handle.gas_limit();
-        let _context = handle.context();
-        let mut output = Vec::new();
-        let callee = handle.context().caller;
-
-        let restructured_args = [&[T3rnCodec::Rlp.into()][..], callee.as_bytes(), input].concat();
-
-        // Assume first byte of input is the action selector
-        let action = match restructured_args.first() {
-            Some(byte) => match byte {
-                0 => VacuumAction::VacuumOrder,
-                1 => VacuumAction::Vacuum3DOrder,
-                2 => VacuumAction::VacuumConfirm,
-                3 => VacuumAction::VacuumSubmitCorrectnessProof,
-                4 => VacuumAction::VacuumSubmitFaultProof,
-                5 => VacuumAction::VacuumTeleportOrder,
-                _ =>
-                    return Err(PrecompileFailure::Error {
-                        exit_status: ExitError::Other("Invalid action selector".into()),
-                    }),
-            },
-            None =>
-                return Err(PrecompileFailure::Error {
-                    exit_status: ExitError::Other("Empty buffer".into()),
-                }),
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+        // Proofs are verified by the Portal at a cost growing with their length, which the
+        // calldata bounds before it's decoded
+        let proof_len = handle.input().len() as u32;
+        let weight = match selector {
+            Action::Order => VacuumWeight::<T>::single_order(),
+            Action::Order3D => VacuumWeight::<T>::dynamic_destination_deal(),
+            Action::Confirm => VacuumWeight::<T>::confirm(),
+            Action::SubmitCorrectnessProof | Action::SubmitFaultProof =>
+                VacuumWeight::<T>::submit_fault_proof(proof_len),
+            Action::TeleportOrder => VacuumWeight::<T>::teleport_order(proof_len),
         };
+        handle.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()))?;
+        handle.record_cost(Self::weight_to_gas(weight))?;
 
-        T::ThreeVm::invoke_raw(&(action as u8), &restructured_args, &mut output);
-
-        if let Some(result_byte) = output.first() {
-            if *result_byte == 0 {
-                Ok(PrecompileOutput {
-                    exit_status: ExitSucceed::Returned,
-                    output,
-                })
-            } else {
-                Err(PrecompileFailure::Error {
-                    exit_status: ExitError::Other("invalid output".into()),
-                })
-            }
-        } else {
-            Err(PrecompileFailure::Error {
-                exit_status: ExitError::Other("Empty buffer".into()),
-            })
+        match selector {
+            Action::Order => Self::order(handle),
+            Action::Order3D => Self::order_3d(handle),
+            Action::Confirm => Self::confirm(handle),
+            Action::SubmitCorrectnessProof =>
+                Self::submit_proof(handle, VACUUM_SUBMIT_CORRECTNESS_PROOF),
+            Action::SubmitFaultProof => Self::submit_proof(handle, VACUUM_SUBMIT_FAULT_PROOF),
+            Action::TeleportOrder => Self::teleport_order(handle),
         }
     }
 }
+
+impl<T> VacuumPrecompile<T>
+where
+    T: pallet_evm::Config + frame_system::Config,
+{
+    fn order(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(7)?;
+        let order = Self::read_order(&mut input)?;
+
+        let log = Self::order_log(handle, SELECTOR_LOG_ORDER_CREATED, &order);
+        handle.record_cost(log.compute_cost()?)?;
+
+        Self::invoke_vacuum(handle, VACUUM_ORDER, order)?;
+        log.record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn order_3d(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(8)?;
+        let order = Self::read_order(&mut input)?;
+        let nonce: u32 = input.read()?;
+
+        let log = Self::order_log(handle, SELECTOR_LOG_ORDER_CREATED, &order);
+        handle.record_cost(log.compute_cost()?)?;
+
+        Self::invoke_vacuum(
+            handle,
+            VACUUM_3D_ORDER,
+            VacuumEVM3DOrder::new(
+                order.destination,
+                order.asset,
+                order.amount,
+                order.reward_asset,
+                order.max_reward,
+                order.insurance,
+                order.target_account,
+                nonce,
+            ),
+        )?;
+        log.record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn confirm(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(7)?;
+        let order = Self::read_order(&mut input)?;
+
+        let log = Self::order_log(handle, SELECTOR_LOG_ORDER_CONFIRMED, &order);
+        handle.record_cost(log.compute_cost()?)?;
+
+        Self::invoke_vacuum(handle, VACUUM_CONFIRM, order)?;
+        log.record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn submit_proof(handle: &mut impl PrecompileHandle, precompile: u8) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(7)?;
+
+        let proof = VacuumEVMProof::new(
+            read_bytes4(&mut input)?,
+            read_bytes4(&mut input)?,
+            read_bytes4(&mut input)?,
+            input.read::<Bytes>()?.into(),
+            input.read::<Bytes>()?.into(),
+            input.read::<Bytes>()?.into(),
+            input.read::<Bytes>()?.into(),
+        );
+
+        Self::invoke_vacuum(handle, precompile, proof)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn teleport_order(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let order =
+            VacuumEVMTeleportOrder::new(read_bytes4(&mut input)?, input.read::<Bytes>()?.into());
+
+        Self::invoke_vacuum(handle, VACUUM_TELEPORT_ORDER, order)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn read_order(input: &mut EvmDataReader) -> EvmResult<VacuumEVMOrder> {
+        let destination = read_bytes4(input)?;
+        let asset: u32 = input.read()?;
+        let amount: U256 = input.read()?;
+        let reward_asset: H160 = input.read::<Address>()?.into();
+        let max_reward: U256 = input.read()?;
+        let insurance: U256 = input.read()?;
+        let target_account: H256 = input.read()?;
+
+        Ok(VacuumEVMOrder::new(
+            destination,
+            asset,
+            amount,
+            reward_asset,
+            max_reward,
+            insurance,
+            AccountId32::new(target_account.0),
+        ))
+    }
+
+    fn order_log(
+        handle: &impl PrecompileHandle,
+        selector: [u8; 32],
+        order: &VacuumEVMOrder,
+    ) -> fp_evm::Log {
+        let mut destination = [0u8; 32];
+        destination[..4].copy_from_slice(&order.destination);
+        let target_account: &[u8; 32] = order.target_account.as_ref();
+
+        log3(
+            handle.code_address(),
+            selector,
+            handle.context().caller,
+            destination,
+            EvmDataWriter::new()
+                .write(order.asset)
+                .write(order.amount)
+                .write(Address(order.reward_asset))
+                .write(order.max_reward)
+                .write(order.insurance)
+                .write(H256::from(*target_account))
+                .build(),
+        )
+    }
+
+    /// Forward the SCALE-encoded action on behalf of the mapped caller to 3VM,
+    /// which answers with a leading 0 byte on success.
+    fn invoke_vacuum(
+        handle: &mut impl PrecompileHandle,
+        precompile: u8,
+        args: impl Encode,
+    ) -> EvmResult {
+        let caller =
+            <T as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
+        let input = [
+            &[T3rnCodec::Scale.into()][..],
+            &caller.encode()[..],
+            &args.encode()[..],
+        ]
+        .concat();
+        let mut output = Vec::new();
+
+        T::ThreeVm::invoke_raw(&precompile, &input, &mut output);
+
+        match output.first() {
+            Some(0) => Ok(()),
+            Some(_) => Err(revert("Vacuum: action rejected by circuit")),
+            None => Err(revert("Vacuum: empty output")),
+        }
+    }
+
+    fn weight_to_gas(weight: Weight) -> u64 {
+        <T as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+    }
+}
+
+/// Read a Solidity `bytes4`, left-aligned within its 32 bytes word.
+fn read_bytes4(input: &mut EvmDataReader) -> EvmResult<[u8; 4]> {
+    let word: H256 = input.read()?;
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&word.as_bytes()[..4]);
+    Ok(bytes)
+}
@@ -0,0 +1,238 @@
+use crate::{Action, SELECTOR_LOG_ORDER_CONFIRMED};
+use circuit_mock_runtime::{
+    contracts_config::PrecompilesValue, evm_precompile_util::precompile_mock::MockPrecompileSet, *,
+};
+use frame_support::weights::Weight;
+use pallet_circuit_vacuum::weights::{SubstrateWeight, WeightInfo};
+use pallet_evm::GasWeightMapping;
+use precompile_util_solidity::{
+    costs::log_costs,
+    data::{Address, Bytes, EvmDataWriter},
+    logs::log3,
+    testing::*,
+};
+use sp_core::{H160, H256, U256};
+
+fn precompiles() -> MockPrecompileSet<circuit_mock_runtime::Runtime> {
+    PrecompilesValue::get()
+}
+
+fn vacuum_evm_address() -> H160 {
+    H160([10u8; 20])
+}
+
+fn gas_cost(weight: Weight) -> u64 {
+    <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+}
+
+fn destination(gateway_id: &[u8; 4]) -> H256 {
+    let mut destination = [0u8; 32];
+    destination[..4].copy_from_slice(gateway_id);
+    H256::from(destination)
+}
+
+fn write_order(writer: EvmDataWriter, destination: H256) -> EvmDataWriter {
+    writer
+        .write(destination)
+        .write(1u32)
+        .write(U256::from(100))
+        .write(Address(H160::repeat_byte(1)))
+        .write(U256::from(10))
+        .write(U256::from(1))
+        .write(H256::repeat_byte(2))
+}
+
+#[test]
+fn confirm_charges_confirm_weight_and_emits_order_confirmed_log() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    let mut destination = [0u8; 32];
+    destination[..4].copy_from_slice(b"sepl");
+
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                vacuum_evm_address(),
+                EvmDataWriter::new_with_selector(Action::Confirm)
+                    .write(H256::from(destination))
+                    .write(1u32)
+                    .write(U256::from(100))
+                    .write(Address(H160::repeat_byte(1)))
+                    .write(U256::from(10))
+                    .write(U256::from(1))
+                    .write(H256::repeat_byte(2))
+                    .build(),
+            )
+            .expect_cost(
+                gas_cost(SubstrateWeight::<Runtime>::confirm()) + log_costs(3, 192).unwrap(),
+            )
+            .expect_log(log3(
+                vacuum_evm_address(),
+                SELECTOR_LOG_ORDER_CONFIRMED,
+                sender.address,
+                destination,
+                EvmDataWriter::new()
+                    .write(1u32)
+                    .write(U256::from(100))
+                    .write(Address(H160::repeat_byte(1)))
+                    .write(U256::from(10))
+                    .write(U256::from(1))
+                    .write(H256::repeat_byte(2))
+                    .build(),
+            ))
+            .execute_returns(EvmDataWriter::new().write(true).build());
+    });
+}
+
+#[test]
+fn raw_action_byte_is_rejected_for_missing_selector() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(sender.address, vacuum_evm_address(), vec![0u8])
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"tried to parse selector out of bounds");
+    });
+}
+
+#[test]
+fn order_is_not_payable() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                vacuum_evm_address(),
+                EvmDataWriter::new_with_selector(Action::Order).build(),
+            )
+            .with_value(1)
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Function is not payable");
+    });
+}
+
+#[test]
+fn order_charges_single_order_weight_and_logs_nothing_when_rejected_by_circuit() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                vacuum_evm_address(),
+                write_order(
+                    EvmDataWriter::new_with_selector(Action::Order),
+                    destination(b"sepl"),
+                )
+                .build(),
+            )
+            .expect_cost(
+                gas_cost(SubstrateWeight::<Runtime>::single_order()) + log_costs(3, 192).unwrap(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Vacuum: action rejected by circuit");
+    });
+}
+
+#[test]
+fn order_3d_charges_dynamic_destination_deal_weight_and_logs_nothing_when_rejected_by_circuit() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                vacuum_evm_address(),
+                write_order(
+                    EvmDataWriter::new_with_selector(Action::Order3D),
+                    destination(b"sepl"),
+                )
+                .write(7u32)
+                .build(),
+            )
+            .expect_cost(
+                gas_cost(SubstrateWeight::<Runtime>::dynamic_destination_deal())
+                    + log_costs(3, 192).unwrap(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Vacuum: action rejected by circuit");
+    });
+}
+
+#[test]
+fn order_3d_expects_nonce_argument() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+
+    ext.execute_with(|| {
+        precompiles()
+            .prepare_test(
+                sender.address,
+                vacuum_evm_address(),
+                write_order(
+                    EvmDataWriter::new_with_selector(Action::Order3D),
+                    destination(b"sepl"),
+                )
+                .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"input doesn't match expected length");
+    });
+}
+
+#[test]
+fn teleport_order_charges_by_proof_length_when_proof_is_not_verified() {
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+
+    ext.execute_with(|| {
+        let input = EvmDataWriter::new_with_selector(Action::TeleportOrder)
+            .write(destination(b"sepl"))
+            .write(Bytes(vec![7u8; 1024]))
+            .build();
+
+        precompiles()
+            .prepare_test(sender.address, vacuum_evm_address(), input.clone())
+            .expect_cost(gas_cost(SubstrateWeight::<Runtime>::teleport_order(
+                input.len() as u32,
+            )))
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Vacuum: action rejected by circuit");
+    });
+}
+
+#[test]
+fn fault_proof_gas_grows_with_proof_length() {
+    let short_proof = SubstrateWeight::<Runtime>::submit_fault_proof(1024);
+    let long_proof = SubstrateWeight::<Runtime>::submit_fault_proof(64 * 1024);
+    assert!(long_proof.ref_time() > short_proof.ref_time());
+    assert!(long_proof.proof_size() > short_proof.proof_size());
+
+    let (pairs, mut ext) = new_test_ext(1);
+    let sender = &pairs[0];
+
+    ext.execute_with(|| {
+        let input = EvmDataWriter::new_with_selector(Action::SubmitFaultProof)
+            .write(destination(b"sepl"))
+            .write(destination(b"sepl"))
+            .write(destination(b"sepl"))
+            .write(Bytes(vec![1u8; 4096]))
+            .write(Bytes(vec![2u8; 4096]))
+            .write(Bytes(vec![3u8; 4096]))
+            .write(Bytes(vec![4u8; 4096]))
+            .build();
+
+        precompiles()
+            .prepare_test(sender.address, vacuum_evm_address(), input.clone())
+            .expect_cost(gas_cost(SubstrateWeight::<Runtime>::submit_fault_proof(
+                input.len() as u32,
+            )))
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Vacuum: action rejected by circuit");
+    });
+}
@@ -10,11 +10,17 @@ use codec::Codec;
 
 use sp_std::prelude::*;
 
-pub use t3rn_primitives::ChainId;
+pub use t3rn_primitives::{
+    portal::{GatewayHeartbeat, GatewayHeights, InclusionReceipt},
+    ChainId, ExecutionSource, SpeedMode,
+};
 use t3rn_types::sfx::SideEffect;
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
+    ///
+    /// Version 2 adds `fetch_heartbeat`, `fetch_heights` and `verify_event_inclusion`.
+    #[api_version(2)]
     pub trait PortalRuntimeApi<AccountId, Balance, Hash> where
         AccountId: Codec,
         Balance: Codec,
@@ -27,5 +33,17 @@ sp_api::decl_runtime_apis! {
             Vec<SideEffect<AccountId, Balance>>, // side_effects
             Vec<Hash>,                         // sfx_ids
         )>;
+        /// Returns the latest light client heartbeat of the given chain
+        fn fetch_heartbeat(chain_id: ChainId) -> Option<GatewayHeartbeat>;
+        /// Returns the finalized, rational and fast heights of the given chain
+        fn fetch_heights(chain_id: ChainId) -> Option<GatewayHeights>;
+        /// Dry-runs the event inclusion verification of the given proof,
+        /// returning the name of the error on failure
+        fn verify_event_inclusion(
+            chain_id: ChainId,
+            speed_mode: SpeedMode,
+            source: Option<ExecutionSource>,
+            message: Vec<u8>,
+        ) -> Result<InclusionReceipt<u128>, Vec<u8>>;
     }
 }
@@ -9,10 +9,13 @@ use jsonrpsee::{
 };
 use sp_std::vec::Vec;
 
-use pallet_portal_rpc_runtime_api::ChainId;
 pub use pallet_portal_rpc_runtime_api::PortalRuntimeApi;
+use pallet_portal_rpc_runtime_api::{
+    ChainId, ExecutionSource, GatewayHeartbeat, GatewayHeights, InclusionReceipt, SpeedMode,
+};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
 use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
 use t3rn_types::sfx::SideEffect;
 
@@ -35,6 +38,24 @@ pub trait PortalApi<AccountId, Balance, Hash> {
             Vec<Hash>,                           // sfx_ids
         )>,
     >;
+
+    /// Returns the latest light client heartbeat of a gateway
+    #[method(name = "portal_fetchHeartbeat")]
+    fn fetch_heartbeat(&self, chain_id: ChainId) -> RpcResult<GatewayHeartbeat>;
+
+    /// Returns the finalized, rational and fast heights of a gateway
+    #[method(name = "portal_fetchHeights")]
+    fn fetch_heights(&self, chain_id: ChainId) -> RpcResult<GatewayHeights>;
+
+    /// Dry-runs the inclusion verification of an event proof, as done on side effect confirmation
+    #[method(name = "portal_verifyEventInclusion")]
+    fn verify_event_inclusion(
+        &self,
+        chain_id: ChainId,
+        speed_mode: SpeedMode,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> RpcResult<InclusionReceipt<u128>>;
 }
 
 /// A struct that implements the [`PortalApi`].
@@ -101,6 +122,51 @@ where
 
         Ok(result)
     }
+
+    fn fetch_heartbeat(&self, chain_id: ChainId) -> RpcResult<GatewayHeartbeat> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<GatewayHeartbeat> = api
+            .fetch_heartbeat(at, chain_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(heartbeat) => Ok(heartbeat),
+            None => Err(runtime_error_into_rpc_err("Gateway not found")),
+        }
+    }
+
+    fn fetch_heights(&self, chain_id: ChainId) -> RpcResult<GatewayHeights> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<GatewayHeights> = api
+            .fetch_heights(at, chain_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(heights) => Ok(heights),
+            None => Err(runtime_error_into_rpc_err("Gateway not found")),
+        }
+    }
+
+    fn verify_event_inclusion(
+        &self,
+        chain_id: ChainId,
+        speed_mode: SpeedMode,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> RpcResult<InclusionReceipt<u128>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Result<InclusionReceipt<u128>, Vec<u8>> = api
+            .verify_event_inclusion(at, chain_id, speed_mode, source, message.to_vec())
+            .map_err(runtime_error_into_rpc_err)?;
+
+        result.map_err(|err| JsonRpseeError::Custom(String::from_utf8_lossy(&err).into_owned()))
+    }
 }
 
 fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
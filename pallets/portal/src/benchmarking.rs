@@ -0,0 +1,362 @@
+//! Benchmarking setup for pallet-portal
+//!
+//! Queries and event inclusion are benchmarked against a GRANDPA light client initialized with a
+//! single header, which is the deepest path the Portal resolves them through. State and tx
+//! inclusion, which GRANDPA doesn't verify, and header submissions are benchmarked against the
+//! standalone light client, whose Aura seals can be signed with keystore keys.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Portal;
+
+use codec::{Compact, Decode, Encode};
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{
+    assert_ok,
+    sp_runtime::{
+        generic,
+        traits::{BlakeTwo256, Bounded, Header as HeaderT},
+        Digest, DigestItem,
+    },
+    storage::storage_prefix,
+    traits::Currency,
+};
+use frame_system::RawOrigin;
+use pallet_grandpa_finality_verifier::types::{
+    RelaychainInclusionProof, RelaychainRegistrationData,
+};
+use pallet_standalone_finality_verifier::types::{
+    AuthorityId, SealEngine, StandaloneHeader, StandaloneHeaderData, StandaloneRegistrationData,
+    StandaloneStateInclusionProof, StandaloneTxInclusionProof, AURA_ENGINE_ID,
+};
+use sp_core::crypto::KeyTypeId;
+use sp_std::vec;
+use sp_trie::{LayoutV0, LayoutV1, MemoryDB, StorageProof, TrieDBMutBuilder, TrieMut};
+use t3rn_primitives::{portal::Portal as PortalT, ExecutionVendor, SubstrateToken};
+
+const USER_SEED: u32 = 999666;
+const BENCHMARK_GATEWAY_ID: ChainId = *b"bnch";
+const BENCHMARK_TOKEN_ID: u32 = 999666;
+/// Upper bound of the benchmarked inclusion payload, in bytes.
+const MAX_INCLUSION_PAYLOAD: u32 = 16 * 1024;
+/// Upper bound of the benchmarked number of side effects allowed on a gateway.
+const MAX_ALLOWED_SIDE_EFFECTS: u32 = 64;
+/// Upper bound of the benchmarked header submission, in headers. Within `HeadersToStore` of the
+/// benchmarked runtime's standalone light client.
+const MAX_SUBMITTED_HEADERS: u32 = 64;
+/// Encoded length, rounded up, of a header sealed by a single Aura authority by `aura_headers`.
+const AURA_SEALED_HEADER_LEN: u32 = 184;
+const AURA_KEY_TYPE: KeyTypeId = KeyTypeId(AURA_ENGINE_ID);
+
+type BridgedHeader = generic::Header<u32, BlakeTwo256>;
+
+fn benchmark_token() -> TokenInfo {
+    TokenInfo::Substrate(SubstrateToken {
+        id: BENCHMARK_TOKEN_ID,
+        symbol: b"BNCH".to_vec(),
+        decimals: 12,
+    })
+}
+
+fn grandpa_registration_data<T: Config>(
+    header: &BridgedHeader,
+) -> RelaychainRegistrationData<T::AccountId> {
+    RelaychainRegistrationData::<T::AccountId> {
+        first_header: header.encode(),
+        authorities: (1u8..=3)
+            .map(|i| Decode::decode(&mut &[i; 32][..]).expect("any 32 bytes are an authority"))
+            .collect(),
+        authority_set_id: 1,
+        owner: account("OWNER", 0, USER_SEED),
+    }
+}
+
+/// Register a gateway verified by GRANDPA, initialized at the header carrying the given state root.
+fn register_grandpa_gateway<T: Config>(
+    state_root: <BridgedHeader as HeaderT>::Hash,
+) -> BridgedHeader {
+    let header = BridgedHeader::new(
+        0,
+        Default::default(),
+        state_root,
+        Default::default(),
+        Default::default(),
+    );
+    let registration_data = grandpa_registration_data::<T>(&header);
+
+    assert_ok!(Portal::<T>::register_gateway(
+        RawOrigin::Root.into(),
+        BENCHMARK_GATEWAY_ID,
+        BENCHMARK_TOKEN_ID,
+        GatewayVendor::Polkadot,
+        ExecutionVendor::Substrate,
+        t3rn_abi::Codec::Scale,
+        None,
+        None,
+        vec![],
+        benchmark_token(),
+        registration_data.encode(),
+    ));
+
+    header
+}
+
+/// Register a gateway verified by the standalone light client, sealed by a single Aura authority
+/// generated in the keystore and initialized at the genesis carrying the given roots.
+fn register_standalone_gateway<T: Config>(
+    state_root: <BridgedHeader as HeaderT>::Hash,
+    extrinsics_root: <BridgedHeader as HeaderT>::Hash,
+) -> (AuthorityId, StandaloneHeader) {
+    let authority = sp_io::crypto::sr25519_generate(AURA_KEY_TYPE, None);
+    let genesis = StandaloneHeader::new(
+        0,
+        extrinsics_root,
+        state_root,
+        Default::default(),
+        Default::default(),
+    );
+    let registration_data = StandaloneRegistrationData::<T::AccountId> {
+        first_header: genesis.encode(),
+        engine: SealEngine::Aura,
+        authorities: vec![authority],
+        authority_set_id: 0,
+        confirmation_depths: Default::default(),
+        owner: account("OWNER", 0, USER_SEED),
+    };
+
+    assert_ok!(Portal::<T>::register_gateway(
+        RawOrigin::Root.into(),
+        BENCHMARK_GATEWAY_ID,
+        BENCHMARK_TOKEN_ID,
+        GatewayVendor::Standalone,
+        ExecutionVendor::Substrate,
+        t3rn_abi::Codec::Scale,
+        None,
+        None,
+        vec![],
+        benchmark_token(),
+        registration_data.encode(),
+    ));
+
+    (authority, genesis)
+}
+
+/// Build `count` headers on top of `parent`, each sealed by the single Aura authority.
+fn aura_headers(
+    authority: &AuthorityId,
+    parent: &StandaloneHeader,
+    count: u32,
+) -> Vec<StandaloneHeader> {
+    let mut parent = parent.clone();
+    (1..=count as u64)
+        .map(|slot| {
+            let mut header = StandaloneHeader::new(
+                parent.number + 1,
+                Default::default(),
+                Default::default(),
+                parent.hash(),
+                Digest {
+                    logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())],
+                },
+            );
+            let signature =
+                sp_io::crypto::sr25519_sign(AURA_KEY_TYPE, authority, header.hash().as_ref())
+                    .expect("signs with the generated authority");
+            header
+                .digest_mut()
+                .push(DigestItem::Seal(AURA_ENGINE_ID, signature.encode()));
+            parent = header.clone();
+            header
+        })
+        .collect()
+}
+
+fn fund_treasury<T: Config>() {
+    T::Currency::make_free_balance_be(
+        &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury),
+        BalanceOf::<T>::max_value() / 4u32.into(),
+    );
+}
+
+/// Build a state trie holding the payload as the block's `System::Events`.
+fn events_storage_proof(payload: &[u8]) -> (<BridgedHeader as HeaderT>::Hash, StorageProof) {
+    storage_proof(&storage_prefix(b"System", b"Events"), payload)
+}
+
+/// Build a state trie holding the single entry.
+fn storage_proof(key: &[u8], value: &[u8]) -> (<BridgedHeader as HeaderT>::Hash, StorageProof) {
+    let mut db = MemoryDB::<BlakeTwo256>::default();
+    let mut root = Default::default();
+    {
+        let mut trie = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+        trie.insert(key, value)
+            .expect("inserting into an empty trie succeeds");
+    }
+    let proof = StorageProof::new(db.drain().into_values().map(|(node, _)| node));
+    (root, proof)
+}
+
+/// Build the extrinsics trie of a block holding the single extrinsic.
+fn tx_proof(tx: &[u8]) -> (<BridgedHeader as HeaderT>::Hash, StorageProof) {
+    let mut db = MemoryDB::<BlakeTwo256>::default();
+    let mut root = Default::default();
+    {
+        let mut trie = TrieDBMutBuilder::<LayoutV0<BlakeTwo256>>::new(&mut db, &mut root).build();
+        trie.insert(&Compact(0u32).encode(), tx)
+            .expect("inserting into an empty trie succeeds");
+    }
+    let proof = StorageProof::new(db.drain().into_values().map(|(node, _)| node));
+    (root, proof)
+}
+
+benchmarks! {
+    get_latest_heartbeat {
+        register_grandpa_gateway::<T>(Default::default());
+    }: {
+        assert_ok!(<Portal<T> as PortalT<T>>::get_latest_heartbeat(&BENCHMARK_GATEWAY_ID));
+    }
+
+    get_latest_finalized_header {
+        register_grandpa_gateway::<T>(Default::default());
+    }: {
+        assert!(matches!(
+            <Portal<T> as PortalT<T>>::get_latest_finalized_header(BENCHMARK_GATEWAY_ID),
+            Ok(HeaderResult::Header(_))
+        ));
+    }
+
+    get_height {
+        register_grandpa_gateway::<T>(Default::default());
+    }: {
+        assert!(matches!(
+            <Portal<T> as PortalT<T>>::get_finalized_height(BENCHMARK_GATEWAY_ID),
+            Ok(HeightResult::Height(_))
+        ));
+    }
+
+    verify_event_inclusion {
+        let n in 1 .. MAX_INCLUSION_PAYLOAD;
+        let payload = vec![1u8; n as usize];
+        let (state_root, payload_proof) = events_storage_proof(&payload);
+        let header = register_grandpa_gateway::<T>(state_root);
+        let message = RelaychainInclusionProof::<BridgedHeader> {
+            encoded_payload: payload,
+            payload_proof,
+            block_hash: header.hash(),
+        }
+        .encode();
+    }: {
+        assert_ok!(<Portal<T> as PortalT<T>>::verify_event_inclusion(
+            BENCHMARK_GATEWAY_ID,
+            SpeedMode::Finalized,
+            None,
+            message,
+        ));
+    }
+
+    verify_state_inclusion {
+        let n in 1 .. MAX_INCLUSION_PAYLOAD;
+        let storage_key = storage_prefix(b"System", b"Events").to_vec();
+        let (state_root, storage_proof) = storage_proof(&storage_key, &vec![1u8; n as usize]);
+        let (_, genesis) = register_standalone_gateway::<T>(state_root, Default::default());
+        let message = StandaloneStateInclusionProof {
+            storage_key,
+            storage_proof,
+            block_hash: genesis.hash(),
+        }
+        .encode();
+    }: {
+        assert_ok!(<Portal<T> as PortalT<T>>::verify_state_inclusion(
+            BENCHMARK_GATEWAY_ID,
+            SpeedMode::Finalized,
+            message,
+        ));
+    }
+
+    verify_tx_inclusion {
+        let n in 1 .. MAX_INCLUSION_PAYLOAD;
+        let encoded_tx = vec![1u8; n as usize];
+        let (extrinsics_root, tx_proof) = tx_proof(&encoded_tx);
+        let (_, genesis) = register_standalone_gateway::<T>(Default::default(), extrinsics_root);
+        let message = StandaloneTxInclusionProof {
+            encoded_tx,
+            tx_index: 0,
+            tx_proof,
+            block_hash: genesis.hash(),
+        }
+        .encode();
+    }: {
+        assert_ok!(<Portal<T> as PortalT<T>>::verify_tx_inclusion(
+            BENCHMARK_GATEWAY_ID,
+            SpeedMode::Finalized,
+            message,
+        ));
+    }
+
+    register_gateway_permissionless {
+        let s in 1 .. MAX_ALLOWED_SIDE_EFFECTS;
+        let registrant: T::AccountId = account("REGISTRANT", 0, USER_SEED);
+        T::Currency::make_free_balance_be(&registrant, BalanceOf::<T>::max_value() / 4u32.into());
+        T::Xdns::register_new_token(&RawOrigin::Root.into(), BENCHMARK_TOKEN_ID, benchmark_token())?;
+        let header = BridgedHeader::new(
+            0,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let registration_data = grandpa_registration_data::<T>(&header).encode();
+    }: _(
+        RawOrigin::Signed(registrant),
+        BENCHMARK_GATEWAY_ID,
+        BENCHMARK_TOKEN_ID,
+        GatewayVendor::Polkadot,
+        ExecutionVendor::Substrate,
+        t3rn_abi::Codec::Scale,
+        None,
+        vec![(*b"tran", Some(2)); s as usize],
+        benchmark_token(),
+        registration_data
+    )
+    verify {
+        assert!(matches!(
+            <Portal<T> as PortalT<T>>::get_finalized_height(BENCHMARK_GATEWAY_ID),
+            Ok(HeightResult::Height(_))
+        ));
+    }
+
+    submit_headers {
+        let b in AURA_SEALED_HEADER_LEN .. MAX_SUBMITTED_HEADERS * AURA_SEALED_HEADER_LEN;
+        let headers = b / AURA_SEALED_HEADER_LEN;
+        let (authority, genesis) =
+            register_standalone_gateway::<T>(Default::default(), Default::default());
+        fund_treasury::<T>();
+        Portal::<T>::set_relayer_rewards_budget(
+            RawOrigin::Root.into(),
+            BENCHMARK_GATEWAY_ID,
+            1u32.into(),
+            1_000_000u32.into(),
+        )?;
+        let submission = StandaloneHeaderData {
+            range: aura_headers(&authority, &genesis, headers),
+        }
+        .encode();
+        let relayer: T::AccountId = account("RELAYER", 0, USER_SEED);
+    }: _(RawOrigin::Signed(relayer.clone()), BENCHMARK_GATEWAY_ID, headers.into(), submission)
+    verify {
+        assert_eq!(
+            Portal::<T>::relayers(BENCHMARK_GATEWAY_ID, relayer).map(|record| record.headers_advanced),
+            Some(headers)
+        );
+    }
+
+    set_relayer_rewards_budget {
+        fund_treasury::<T>();
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID, 10u32.into(), 1_000u32.into())
+    verify {
+        assert_eq!(
+            Portal::<T>::relayer_rewards_budget(BENCHMARK_GATEWAY_ID).map(|budget| budget.remaining),
+            Some(1_000u32.into())
+        );
+    }
+}
@@ -1,7 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-    sp_runtime::{traits::Zero, DispatchError},
+    sp_runtime::{
+        traits::{Hash, Saturating, Zero},
+        DispatchError, SaturatedConversion,
+    },
     traits::Get,
 };
 use frame_system::{
@@ -11,20 +14,30 @@ use frame_system::{
 
 pub use pallet::*;
 use sp_std::{boxed::Box, prelude::*};
-use t3rn_abi::recode::{recode_bytes_with_descriptor, Codec};
+use t3rn_abi::{recode::Codec, Abi, FilledAbi};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 
 use frame_support::transactional;
 use t3rn_abi::types::Bytes;
 use t3rn_primitives::{
-    self, execution_source_to_option,
+    self,
+    claimable::{BenefitSource, CircuitRole},
+    execution_source_to_option,
+    gateway::GatewayABIConfig,
     light_client::LightClient,
-    portal::{HeaderResult, HeightResult, Portal},
+    portal::{
+        GatewayHeartbeat, GatewayHeights, HeaderResult, HeightResult, Portal, RelayerRecord,
+        RelayerRewardsBudget,
+    },
     reexport_currency_types,
+    rewards::RewardsWriteApi,
     xdns::Xdns,
-    ChainId, ExecutionSource, GatewayVendor, SpeedMode, TokenInfo,
+    ChainId, ExecutionSource, GatewayVendor, SpeedMode, TokenInfo, TreasuryAccount,
+    TreasuryAccountProvider,
 };
 pub mod weights;
 pub trait SelectLightClient<T: frame_system::Config> {
@@ -38,7 +51,11 @@ reexport_currency_types!();
 pub mod pallet {
     use super::*;
     use core::convert::TryInto;
-    use frame_support::{pallet_prelude::*, traits::Currency};
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, WithdrawReasons},
+    };
+    use frame_system::ensure_signed;
 
     use sp_std::vec::Vec;
     use t3rn_primitives::{xdns::Xdns, ChainId, ExecutionVendor, GatewayVendor};
@@ -56,12 +73,51 @@ pub mod pallet {
         type WeightInfo: crate::weights::WeightInfo;
         /// Selects the light client implementation
         type SelectLightClient: SelectLightClient<Self>;
+        /// Records relayer rewards as claimable
+        type Rewards: RewardsWriteApi<Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
+        /// Treasury funding the relayer rewards budgets
+        type TreasuryAccounts: TreasuryAccountProvider<Self::AccountId>;
+        /// Maximum number of relayers recorded per gateway
+        #[pallet::constant]
+        type MaxRelayersPerGateway: Get<u32>;
+        /// Number of the most recently accepted submissions per gateway rejected as duplicates
+        #[pallet::constant]
+        type MaxRecentSubmissions: Get<u32>;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
+    /// Relayers which advanced the finalized head of each gateway.
+    #[pallet::storage]
+    #[pallet::getter(fn relayers)]
+    pub type Relayers<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        ChainId,
+        Blake2_128Concat,
+        T::AccountId,
+        RelayerRecord<BlockNumberFor<T>, BalanceOf<T>>,
+    >;
+
+    /// Number of relayers recorded per gateway, bounded by `MaxRelayersPerGateway`.
+    #[pallet::storage]
+    #[pallet::getter(fn relayers_count)]
+    pub type RelayersCount<T: Config> = StorageMap<_, Identity, ChainId, u32, ValueQuery>;
+
+    /// Budget paying relayers per useful header, per gateway.
+    #[pallet::storage]
+    #[pallet::getter(fn relayer_rewards_budget)]
+    pub type RelayerRewardsBudgets<T: Config> =
+        StorageMap<_, Identity, ChainId, RelayerRewardsBudget<BalanceOf<T>>>;
+
+    /// Hashes of the most recently accepted header submissions per gateway, oldest first,
+    /// rejecting duplicates before verification.
+    #[pallet::storage]
+    pub type RecentSubmissions<T: Config> =
+        StorageMap<_, Identity, ChainId, BoundedVec<T::Hash, T::MaxRecentSubmissions>, ValueQuery>;
+
     // Pallets use events to inform users when important changes are made.
     // https://docs.substrate.io/v3/runtime/events-and-errors
     #[pallet::event]
@@ -76,6 +132,12 @@ pub mod pallet {
         SetOperational(ChainId, bool),
         /// Header was successfully added
         HeaderSubmitted(GatewayVendor, Vec<u8>),
+        /// Relayer advanced the gateway's finalized head. [ChainId, AccountId, headers advanced, reward]
+        HeadersRelayed(ChainId, T::AccountId, u32, BalanceOf<T>),
+        /// Relayer rewards budget was set. [ChainId, reward per header, remaining budget]
+        RelayerRewardsBudgetSet(ChainId, BalanceOf<T>, BalanceOf<T>),
+        /// Gateway was registered pending promotion, bonded by the registrant. [ChainId, AccountId]
+        GatewayRegisteredPermissionless(ChainId, T::AccountId),
     }
 
     // Errors inform users that something went wrong.
@@ -103,6 +165,14 @@ pub mod pallet {
         SideEffectConfirmationFailed,
         /// Recoding failed
         SFXRecodeError,
+        /// The same header submission was already accepted
+        DuplicateHeaderSubmission,
+        /// The submission targets a height the gateway is already finalized at
+        StaleHeaderSubmission,
+        /// The submission didn't advance the finalized head up to the declared height
+        HeaderSubmissionBelowTargetHeight,
+        /// The gateway already records the maximum number of relayers
+        TooManyRelayers,
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -147,20 +217,283 @@ pub mod pallet {
             Self::deposit_event(Event::GatewayRegistered(gateway_id));
             Ok(())
         }
+
+        /// Register a gateway without governance, bonding the registrant's deposit.
+        ///
+        /// The gateway stays pending in XDNS with optimistic execution and capped order value,
+        /// until promoted by governance or an attesters vote. The gateway's token has to be registered already.
+        /// The light client is initialized with the registration data right away, so that orders within
+        /// the cap can be confirmed on the gateway while it's pending.
+        #[pallet::weight(T::WeightInfo::register_gateway_permissionless(
+            allowed_side_effects.len() as u32
+        ))]
+        #[transactional]
+        pub fn register_gateway_permissionless(
+            origin: OriginFor<T>,
+            gateway_id: [u8; 4],
+            token_id: u32,
+            verification_vendor: GatewayVendor,
+            execution_vendor: ExecutionVendor,
+            codec: t3rn_abi::Codec,
+            escrow_account: Option<T::AccountId>,
+            allowed_side_effects: Vec<([u8; 4], Option<u8>)>,
+            token_props: TokenInfo,
+            encoded_registration_data: Bytes,
+        ) -> DispatchResult {
+            let registrant = ensure_signed(origin)?;
+            <T as Config>::Xdns::add_pending_gateway(
+                registrant.clone(),
+                gateway_id,
+                verification_vendor,
+                execution_vendor,
+                codec,
+                escrow_account,
+                allowed_side_effects,
+            )?;
+            <T as Config>::Xdns::link_token_to_gateway(token_id, gateway_id, token_props)?;
+            if !encoded_registration_data.is_empty() {
+                <Pallet<T> as Portal<T>>::initialize(
+                    frame_system::RawOrigin::Root.into(),
+                    gateway_id,
+                    encoded_registration_data,
+                )?;
+            }
+            Self::deposit_event(Event::GatewayRegisteredPermissionless(
+                gateway_id, registrant,
+            ));
+            Ok(())
+        }
+
+        /// Submit headers to the gateway's light client on behalf of a relayer.
+        ///
+        /// The relayer declares the finalized height the submission advances the gateway to,
+        /// so stale and recently accepted submissions are rejected before the costly verification.
+        /// Each header advancing the finalized head is rewarded from the gateway's budget.
+        /// Only useful submissions are free, the rejected ones pay for the worst-case
+        /// verification of their size.
+        #[pallet::weight(T::WeightInfo::submit_headers(encoded_header_data.len() as u32))]
+        #[transactional]
+        pub fn submit_headers(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            target_height: BlockNumberFor<T>,
+            encoded_header_data: Bytes,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            ensure!(
+                <Relayers<T>>::contains_key(gateway_id, &relayer)
+                    || <RelayersCount<T>>::get(gateway_id) < T::MaxRelayersPerGateway::get(),
+                Error::<T>::TooManyRelayers
+            );
+
+            let submission_hash = T::Hashing::hash(&encoded_header_data);
+            let mut recent_submissions = <RecentSubmissions<T>>::get(gateway_id);
+            ensure!(
+                !recent_submissions.contains(&submission_hash),
+                Error::<T>::DuplicateHeaderSubmission
+            );
+
+            let height_before = Self::finalized_height_or_zero(gateway_id)?;
+            ensure!(
+                target_height > height_before,
+                Error::<T>::StaleHeaderSubmission
+            );
+
+            <Pallet<T> as Portal<T>>::submit_encoded_headers(gateway_id, encoded_header_data)?;
+
+            let height_after = Self::finalized_height_or_zero(gateway_id)?;
+            ensure!(
+                height_after >= target_height,
+                Error::<T>::HeaderSubmissionBelowTargetHeight
+            );
+
+            if recent_submissions.is_full() && !recent_submissions.is_empty() {
+                recent_submissions.remove(0);
+            }
+            // Only fails with no submissions remembered at all
+            let _ = recent_submissions.try_push(submission_hash);
+            <RecentSubmissions<T>>::insert(gateway_id, recent_submissions);
+
+            let headers_advanced: u32 = height_after.saturating_sub(height_before).saturated_into();
+            Self::reward_relayer(gateway_id, &relayer, headers_advanced);
+
+            Ok(Pays::No.into())
+        }
+
+        /// Set the reward per header advancing the gateway's finalized head and top up the
+        /// gateway's budget paying it.
+        ///
+        /// The top-up is withdrawn from the Treasury account, offsetting the rewards relayers
+        /// mint once they claim them.
+        #[pallet::weight(T::WeightInfo::set_relayer_rewards_budget())]
+        pub fn set_relayer_rewards_budget(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            reward_per_header: BalanceOf<T>,
+            top_up: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if !top_up.is_zero() {
+                let _burnt = T::Currency::withdraw(
+                    &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury),
+                    top_up,
+                    WithdrawReasons::TRANSFER,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+            }
+
+            let remaining = <RelayerRewardsBudgets<T>>::mutate(gateway_id, |maybe_budget| {
+                let budget = maybe_budget.get_or_insert_with(Default::default);
+                budget.reward_per_header = reward_per_header;
+                budget.remaining = budget.remaining.saturating_add(top_up);
+                budget.remaining
+            });
+
+            Self::deposit_event(Event::RelayerRewardsBudgetSet(
+                gateway_id,
+                reward_per_header,
+                remaining,
+            ));
+            Ok(())
+        }
     }
 }
 
-// ToDo: this should come from XDNS
-pub fn match_vendor_with_codec(vendor: GatewayVendor) -> Codec {
-    match vendor {
-        GatewayVendor::Rococo => Codec::Scale,
-        GatewayVendor::Kusama => Codec::Scale,
-        GatewayVendor::Polkadot => Codec::Scale,
-        GatewayVendor::Ethereum => Codec::Rlp,
-        GatewayVendor::Sepolia => Codec::Rlp,
-        GatewayVendor::XBI => Codec::Scale,
-        GatewayVendor::Attesters => Codec::Scale,
+impl<T: Config> Pallet<T> {
+    /// Latest light client heartbeat of the gateway, served over RPC.
+    pub fn fetch_heartbeat(gateway_id: ChainId) -> Option<GatewayHeartbeat> {
+        <Pallet<T> as Portal<T>>::get_latest_heartbeat(&gateway_id)
+            .ok()
+            .map(Into::into)
+    }
+
+    /// Finalized, rational and fast heights of the gateway, served over RPC.
+    pub fn fetch_heights(gateway_id: ChainId) -> Option<GatewayHeights> {
+        let light_client = match_light_client_by_gateway_id::<T>(gateway_id).ok()?;
+        let height_to_rpc = |height: HeightResult<BlockNumberFor<T>>| match height {
+            HeightResult::Height(height) => Some(height.saturated_into()),
+            HeightResult::NotActive => None,
+        };
+        Some(GatewayHeights {
+            finalized: height_to_rpc(light_client.get_finalized_height()),
+            rational: height_to_rpc(light_client.get_rational_height()),
+            fast: height_to_rpc(light_client.get_fast_height()),
+        })
+    }
+
+    /// Verifies the event inclusion proof without confirming anything, served over RPC.
+    /// On failure returns the name of the error the confirmation would fail with.
+    pub fn dry_run_event_inclusion(
+        gateway_id: ChainId,
+        speed_mode: SpeedMode,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<u128>, Bytes> {
+        <Pallet<T> as Portal<T>>::verify_event_inclusion(gateway_id, speed_mode, source, message)
+            .map(|receipt| InclusionReceipt {
+                height: receipt.height.saturated_into(),
+                including_header: receipt.including_header,
+                message: receipt.message,
+            })
+            .map_err(|err| <&'static str>::from(err).as_bytes().to_vec())
+    }
+
+    /// Recode the verified message from the gateway's codec into `out_codec`, laid out by the
+    /// gateway's ABI stored in XDNS.
+    fn recode_inclusion_message(
+        gateway_id: ChainId,
+        message: Bytes,
+        abi_descriptor: Bytes,
+        out_codec: Codec,
+    ) -> Result<Bytes, DispatchError> {
+        let in_codec = <T as Config>::Xdns::get_target_codec(&gateway_id)
+            .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+        let gateway_abi = <T as Config>::Xdns::get_target_abi(&gateway_id)
+            .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+
+        recode_with_gateway_abi(message, abi_descriptor, in_codec, &gateway_abi, out_codec)
+    }
+
+    fn finalized_height_or_zero(gateway_id: ChainId) -> Result<BlockNumberFor<T>, DispatchError> {
+        match <Pallet<T> as Portal<T>>::get_finalized_height(gateway_id)? {
+            HeightResult::Height(height) => Ok(height),
+            HeightResult::NotActive => Ok(Zero::zero()),
+        }
     }
+
+    /// Record the relayer's useful submission and pay out the reward from the gateway's budget.
+    fn reward_relayer(gateway_id: ChainId, relayer: &T::AccountId, headers_advanced: u32) {
+        let reward =
+            <RelayerRewardsBudgets<T>>::mutate_exists(
+                gateway_id,
+                |maybe_budget| match maybe_budget {
+                    Some(budget) => {
+                        let reward = budget
+                            .reward_per_header
+                            .saturating_mul(BalanceOf::<T>::from(headers_advanced))
+                            .min(budget.remaining);
+                        budget.remaining = budget.remaining.saturating_sub(reward);
+                        reward
+                    },
+                    None => Zero::zero(),
+                },
+            );
+
+        if !reward.is_zero() {
+            T::Rewards::add_pending_claim(
+                relayer,
+                CircuitRole::Relayer,
+                reward,
+                BenefitSource::RelayerRewards,
+                None,
+            );
+        }
+
+        if !<Relayers<T>>::contains_key(gateway_id, relayer) {
+            <RelayersCount<T>>::mutate(gateway_id, |count| *count = count.saturating_add(1));
+        }
+        <Relayers<T>>::mutate(gateway_id, relayer, |maybe_record| {
+            let record = maybe_record.get_or_insert_with(Default::default);
+            record.useful_submissions = record.useful_submissions.saturating_add(1);
+            record.headers_advanced = record.headers_advanced.saturating_add(headers_advanced);
+            record.last_submission_at = frame_system::Pallet::<T>::block_number();
+            record.total_rewarded = record.total_rewarded.saturating_add(reward);
+        });
+
+        Self::deposit_event(Event::HeadersRelayed(
+            gateway_id,
+            relayer.clone(),
+            headers_advanced,
+            reward,
+        ));
+    }
+}
+
+/// Recode `message` laid out by `abi_descriptor` from `in_codec` into `out_codec`.
+/// Accounts and values narrower than the circuit's 32 bytes accounts and u128 values, like
+/// 20 bytes accounts on EVM gateways, are widened when recoding to SCALE.
+///
+/// Note: before the gateway ABI was taken into account, 20 bytes accounts of EVM gateways were
+/// recoded to SCALE as 20 bytes. No storage holds recoded messages, so no migration is needed,
+/// but callers decoding inclusion receipts of existing EVM gateways now receive 32 bytes accounts.
+pub fn recode_with_gateway_abi(
+    message: Bytes,
+    abi_descriptor: Bytes,
+    in_codec: Codec,
+    gateway_abi: &GatewayABIConfig,
+    out_codec: Codec,
+) -> Result<Bytes, DispatchError> {
+    let widen_to_circuit_types = out_codec == Codec::Scale
+        && (gateway_abi.address_length < 32 || gateway_abi.value_type_size < 16);
+
+    let abi: Abi = abi_descriptor.try_into()?;
+    FilledAbi::try_fill_abi(abi, message, in_codec.clone())?.recode_as(
+        &in_codec,
+        &out_codec,
+        widen_to_circuit_types,
+    )
 }
 
 pub fn match_light_client_by_gateway_id<T: Config>(
@@ -332,18 +665,12 @@ impl<T: Config> Portal<T> for Pallet<T> {
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
         let mut inclusion_check = Self::verify_state_inclusion(gateway_id, speed_mode, message)?;
 
-        let in_codec = match_vendor_with_codec(
-            <T as Config>::Xdns::get_verification_vendor(&gateway_id)
-                .map_err(|_| Error::<T>::GatewayVendorNotFound)?,
-        );
-
-        let recoded_message = recode_bytes_with_descriptor(
+        inclusion_check.message = Self::recode_inclusion_message(
+            gateway_id,
             inclusion_check.message,
             abi_descriptor,
-            in_codec,
             out_codec,
         )?;
-        inclusion_check.message = recoded_message;
 
         Ok(inclusion_check)
     }
@@ -357,18 +684,12 @@ impl<T: Config> Portal<T> for Pallet<T> {
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
         let mut inclusion_check = Self::verify_tx_inclusion(gateway_id, speed_mode, message)?;
 
-        let in_codec = match_vendor_with_codec(
-            <T as Config>::Xdns::get_verification_vendor(&gateway_id)
-                .map_err(|_| Error::<T>::GatewayVendorNotFound)?,
-        );
-
-        let recoded_message = recode_bytes_with_descriptor(
+        inclusion_check.message = Self::recode_inclusion_message(
+            gateway_id,
             inclusion_check.message,
             abi_descriptor,
-            in_codec,
             out_codec,
         )?;
-        inclusion_check.message = recoded_message;
 
         Ok(inclusion_check)
     }
@@ -388,18 +709,12 @@ impl<T: Config> Portal<T> for Pallet<T> {
             message,
         )?;
 
-        let in_codec = match_vendor_with_codec(
-            <T as Config>::Xdns::get_verification_vendor(&gateway_id)
-                .map_err(|_| Error::<T>::GatewayVendorNotFound)?,
-        );
-
-        let recoded_message = recode_bytes_with_descriptor(
+        inclusion_check.message = Self::recode_inclusion_message(
+            gateway_id,
             inclusion_check.message,
             abi_descriptor,
-            in_codec,
             out_codec,
         )?;
-        inclusion_check.message = recoded_message;
 
         Ok(inclusion_check)
     }
@@ -432,4 +747,12 @@ impl<T: Config> Portal<T> for Pallet<T> {
     fn turn_off(origin: OriginFor<T>, gateway_id: [u8; 4]) -> Result<bool, DispatchError> {
         match_light_client_by_gateway_id::<T>(gateway_id)?.turn_off(origin)
     }
+
+    fn pin_header(
+        gateway_id: ChainId,
+        including_header: Bytes,
+        until: BlockNumberFor<T>,
+    ) -> Result<(), DispatchError> {
+        match_light_client_by_gateway_id::<T>(gateway_id)?.pin_header(including_header, until)
+    }
 }
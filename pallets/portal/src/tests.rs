@@ -3,16 +3,26 @@ mod tests {
     use ::pallet_eth2_finality_verifier::mock::{generate_epoch_update, generate_initialization};
     use circuit_mock_runtime::{ExtBuilder, Portal, RuntimeOrigin as Origin, XDNS, *};
     use codec::Encode;
-    use frame_support::assert_ok;
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{Currency, Get},
+    };
     use pallet_grandpa_finality_verifier::{
         bridges::test_utils::{authorities, test_header_with_correct_parent},
         mock::produce_mock_headers_range,
         types::RelaychainRegistrationData,
     };
 
+    use t3rn_abi::recode::Codec;
     use t3rn_primitives::{
-        portal::{HeaderResult, HeightResult, Portal as PortalT},
-        EthereumToken, ExecutionVendor, GatewayVendor, TokenInfo,
+        claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
+        portal::{
+            GatewayHeights, HeaderResult, HeightResult, Portal as PortalT, RelayerRecord,
+            RelayerRewardsBudget,
+        },
+        xdns::default_gateway_abi,
+        EthereumToken, ExecutionVendor, GatewayVendor, SpeedMode, TokenInfo, TreasuryAccount,
+        TreasuryAccountProvider,
     };
 
     fn get_test_initialize_genesis_data() -> RelaychainRegistrationData<AccountId> {
@@ -305,6 +315,61 @@ mod tests {
                 assert_eq!(gateway.verification_vendor, GatewayVendor::XBI);
             });
     }
+    #[test]
+    fn permissionless_registration_initializes_light_client_of_pending_gateway() {
+        use frame_support::traits::{Currency, Get, ReservableCurrency};
+        use t3rn_primitives::xdns::Xdns;
+
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let registrant = AccountId::new([7u8; 32]);
+                let bond = <Runtime as pallet_xdns::Config>::GatewayRegistrationBond::get();
+                let _ = Balances::deposit_creating(&registrant, bond * 2);
+                let token_props = TokenInfo::Ethereum(EthereumToken {
+                    address: Some([0u8; 20]),
+                    decimals: 0,
+                    symbol: vec![0u8; 1],
+                });
+                assert_ok!(XDNS::register_new_token(
+                    &Origin::root(),
+                    101u32,
+                    token_props.clone()
+                ));
+                let register_pending = |encoded_registration_data: Vec<u8>| {
+                    Portal::register_gateway_permissionless(
+                        Origin::signed(registrant.clone()),
+                        *b"pndg",
+                        101u32,
+                        GatewayVendor::Kusama,
+                        ExecutionVendor::Substrate,
+                        t3rn_abi::Codec::Scale,
+                        None,
+                        vec![(*b"tran", Some(2))],
+                        token_props.clone(),
+                        encoded_registration_data,
+                    )
+                };
+
+                // Registration is rolled back, bond included, if the light client can't be initialized
+                assert!(register_pending(vec![1, 2, 3]).is_err());
+                assert!(XDNS::gateways(*b"pndg").is_none());
+                assert_eq!(Balances::reserved_balance(&registrant), 0);
+
+                assert_ok!(register_pending(
+                    get_test_initialize_genesis_data().encode()
+                ));
+                assert!(XDNS::pending_gateways(*b"pndg").is_some());
+                assert_eq!(Balances::reserved_balance(&registrant), bond);
+                assert_eq!(
+                    Portal::get_finalized_height(*b"pndg"),
+                    Ok(HeightResult::Height(0))
+                );
+            });
+    }
+
     #[test]
     fn test_initialize_and_submit_rococo() {
         let data = produce_mock_headers_range(1, 5);
@@ -335,6 +400,323 @@ mod tests {
         );
     }
 
+    fn treasury() -> AccountId {
+        <Runtime as TreasuryAccountProvider<AccountId>>::get_treasury_account(
+            TreasuryAccount::Treasury,
+        )
+    }
+
+    fn initialize_rococo_with_relayer_budget(reward_per_header: Balance, budget: Balance) {
+        let _ = Balances::deposit_creating(&treasury(), budget + 1);
+        assert_ok!(Portal::initialize(
+            Origin::root(),
+            [0, 0, 0, 0],
+            get_test_initialize_genesis_data().encode()
+        ));
+        assert_ok!(Portal::set_relayer_rewards_budget(
+            Origin::root(),
+            [0, 0, 0, 0],
+            reward_per_header,
+            budget,
+        ));
+    }
+
+    #[test]
+    fn submit_headers_rewards_relayer_per_header_advanced() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(10, 1000);
+
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    5,
+                    produce_mock_headers_range(1, 5).encode(),
+                ));
+
+                assert_eq!(
+                    Portal::get_finalized_height([0, 0, 0, 0]),
+                    Ok(HeightResult::Height(5))
+                );
+                assert_eq!(
+                    Portal::relayers([0, 0, 0, 0], BOB),
+                    Some(RelayerRecord {
+                        useful_submissions: 1,
+                        headers_advanced: 5,
+                        last_submission_at: System::block_number(),
+                        total_rewarded: 50,
+                    })
+                );
+                assert_eq!(
+                    Portal::relayer_rewards_budget([0, 0, 0, 0]),
+                    Some(RelayerRewardsBudget {
+                        reward_per_header: 10,
+                        remaining: 950,
+                    })
+                );
+                assert_eq!(
+                    Rewards::get_pending_claims(BOB),
+                    Some(vec![ClaimableArtifacts {
+                        beneficiary: BOB,
+                        role: CircuitRole::Relayer,
+                        total_round_claim: 50,
+                        non_native_asset_id: None,
+                        benefit_source: BenefitSource::RelayerRewards,
+                    }])
+                );
+            });
+    }
+
+    #[test]
+    fn submit_headers_caps_reward_at_remaining_budget() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(10, 30);
+
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    5,
+                    produce_mock_headers_range(1, 5).encode(),
+                ));
+
+                assert_eq!(
+                    Portal::relayers([0, 0, 0, 0], BOB).map(|record| record.total_rewarded),
+                    Some(30)
+                );
+                assert_eq!(
+                    Portal::relayer_rewards_budget([0, 0, 0, 0]).map(|budget| budget.remaining),
+                    Some(0)
+                );
+            });
+    }
+
+    #[test]
+    fn submit_headers_rejects_stale_and_duplicate_submissions() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(10, 1000);
+                let submission = produce_mock_headers_range(1, 5).encode();
+
+                assert_noop!(
+                    Portal::submit_headers(
+                        Origin::signed(BOB),
+                        [0, 0, 0, 0],
+                        0,
+                        submission.clone(),
+                    ),
+                    pallet_portal::Error::<Runtime>::StaleHeaderSubmission
+                );
+                assert_noop!(
+                    Portal::submit_headers(
+                        Origin::signed(BOB),
+                        [0, 0, 0, 0],
+                        6,
+                        submission.clone(),
+                    ),
+                    pallet_portal::Error::<Runtime>::HeaderSubmissionBelowTargetHeight
+                );
+
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    5,
+                    submission.clone(),
+                ));
+
+                assert_noop!(
+                    Portal::submit_headers(Origin::signed(ALICE), [0, 0, 0, 0], 5, submission),
+                    pallet_portal::Error::<Runtime>::DuplicateHeaderSubmission
+                );
+                assert_eq!(Portal::relayers([0, 0, 0, 0], ALICE), None);
+            });
+    }
+
+    #[test]
+    fn submit_headers_rejects_any_recently_accepted_submission() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(10, 1000);
+                let first_submission = produce_mock_headers_range(1, 5).encode();
+
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    5,
+                    first_submission.clone(),
+                ));
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    10,
+                    produce_mock_headers_range(6, 10).encode(),
+                ));
+
+                assert_noop!(
+                    Portal::submit_headers(
+                        Origin::signed(ALICE),
+                        [0, 0, 0, 0],
+                        10,
+                        first_submission
+                    ),
+                    pallet_portal::Error::<Runtime>::DuplicateHeaderSubmission
+                );
+            });
+    }
+
+    #[test]
+    fn submit_headers_rejects_new_relayers_above_the_gateway_bound() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(10, 1000);
+
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    5,
+                    produce_mock_headers_range(1, 5).encode(),
+                ));
+                assert_eq!(Portal::relayers_count([0, 0, 0, 0]), 1);
+
+                let max_relayers = <Runtime as pallet_portal::Config>::MaxRelayersPerGateway::get();
+                pallet_portal::RelayersCount::<Runtime>::insert([0, 0, 0, 0], max_relayers);
+
+                assert_noop!(
+                    Portal::submit_headers(
+                        Origin::signed(ALICE),
+                        [0, 0, 0, 0],
+                        10,
+                        produce_mock_headers_range(6, 10).encode(),
+                    ),
+                    pallet_portal::Error::<Runtime>::TooManyRelayers
+                );
+                // Relayers already recorded keep relaying
+                assert_ok!(Portal::submit_headers(
+                    Origin::signed(BOB),
+                    [0, 0, 0, 0],
+                    10,
+                    produce_mock_headers_range(6, 10).encode(),
+                ));
+            });
+    }
+
+    #[test]
+    fn relayer_rewards_budget_is_topped_up_from_treasury() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(10, 1000);
+
+                let _ = Balances::deposit_creating(&treasury(), 500);
+                let treasury_before = Balances::free_balance(treasury());
+                let issuance_before = Balances::total_issuance();
+                assert_ok!(Portal::set_relayer_rewards_budget(
+                    Origin::root(),
+                    [0, 0, 0, 0],
+                    20,
+                    500,
+                ));
+
+                assert_eq!(
+                    Portal::relayer_rewards_budget([0, 0, 0, 0]),
+                    Some(RelayerRewardsBudget {
+                        reward_per_header: 20,
+                        remaining: 1500,
+                    })
+                );
+                assert_eq!(Balances::free_balance(treasury()), treasury_before - 500);
+                assert_eq!(Balances::total_issuance(), issuance_before - 500);
+
+                // The Treasury can't fund more than it holds
+                assert!(Portal::set_relayer_rewards_budget(
+                    Origin::root(),
+                    [0, 0, 0, 0],
+                    20,
+                    Balances::free_balance(treasury()),
+                )
+                .is_err());
+            });
+    }
+
+    #[test]
+    fn rpc_fetches_heartbeat_and_heights_of_initialized_gateway() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(0, 0);
+                assert_ok!(Portal::submit_encoded_headers(
+                    [0, 0, 0, 0],
+                    produce_mock_headers_range(1, 5).encode()
+                ));
+
+                assert_eq!(
+                    Portal::fetch_heights([0, 0, 0, 0]),
+                    Some(GatewayHeights {
+                        finalized: Some(5),
+                        rational: Some(5),
+                        fast: Some(5),
+                    })
+                );
+
+                let heartbeat = Portal::fetch_heartbeat([0, 0, 0, 0]).unwrap();
+                assert_eq!(heartbeat.last_finalized_height, 5);
+                assert!(heartbeat.ever_initialized);
+                assert!(!heartbeat.is_halted);
+
+                assert_eq!(Portal::fetch_heights(*b"none"), None);
+                assert_eq!(Portal::fetch_heartbeat(*b"none"), None);
+            });
+    }
+
+    #[test]
+    fn rpc_dry_run_of_event_inclusion_returns_exact_error() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                initialize_rococo_with_relayer_budget(0, 0);
+
+                assert_eq!(
+                    Portal::dry_run_event_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        None,
+                        vec![1, 2, 3],
+                    ),
+                    Err(b"HeaderDataDecodingError".to_vec())
+                );
+                assert_eq!(
+                    Portal::dry_run_event_inclusion(
+                        *b"none",
+                        SpeedMode::Finalized,
+                        None,
+                        vec![1, 2, 3],
+                    ),
+                    Err(b"GatewayVendorNotFound".to_vec())
+                );
+            });
+    }
+
     fn test_get_latest_finalized_rational_fast_heights(vendor: GatewayVendor) {
         ExtBuilder::default()
             .with_standard_sfx_abi()
@@ -432,6 +814,7 @@ mod tests {
                     GatewayVendor::Sepolia => *b"sepl",
                     GatewayVendor::XBI => *b"xbi_",
                     GatewayVendor::Attesters => *b"atts",
+                    GatewayVendor::Standalone => *b"stnd",
                 };
                 let origin = Origin::root();
                 let result = Portal::turn_on(origin, gateway_id);
@@ -439,6 +822,46 @@ mod tests {
             });
     }
 
+    #[test]
+    fn recoding_inclusion_message_to_scale_widens_accounts_of_evm_gateways() {
+        let address = [7u8; 20];
+        // RLP encoded 20 bytes string
+        let rlp_message = [vec![0x80 + 20], address.to_vec()].concat();
+        let descriptor = b"to:Account20,".to_vec();
+
+        let widened = crate::recode_with_gateway_abi(
+            rlp_message.clone(),
+            descriptor.clone(),
+            Codec::Rlp,
+            &default_gateway_abi(&ExecutionVendor::EVM),
+            Codec::Scale,
+        )
+        .unwrap();
+        assert_eq!(widened, [vec![0u8; 12], address.to_vec()].concat());
+
+        // Gateways with 32 bytes accounts keep the message as laid out
+        let kept = crate::recode_with_gateway_abi(
+            rlp_message.clone(),
+            descriptor.clone(),
+            Codec::Rlp,
+            &default_gateway_abi(&ExecutionVendor::Substrate),
+            Codec::Scale,
+        )
+        .unwrap();
+        assert_eq!(kept, address.to_vec());
+
+        // Nor is anything widened when recoding to RLP
+        let rlp_recoded = crate::recode_with_gateway_abi(
+            rlp_message.clone(),
+            descriptor,
+            Codec::Rlp,
+            &default_gateway_abi(&ExecutionVendor::EVM),
+            Codec::Rlp,
+        )
+        .unwrap();
+        assert_eq!(rlp_recoded, address.to_vec());
+    }
+
     // #[test]
     // #[ignore]
     // fn run_e2e_tests() {
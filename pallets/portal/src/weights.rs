@@ -40,7 +40,15 @@ pub trait WeightInfo {
     fn register_gateway() -> Weight;
     fn set_owner() -> Weight;
     fn set_operational() -> Weight;
-    fn submit_headers() -> Weight;
+    fn submit_headers(b: u32) -> Weight;
+    fn set_relayer_rewards_budget() -> Weight;
+    fn get_latest_heartbeat() -> Weight;
+    fn get_latest_finalized_header() -> Weight;
+    fn get_height() -> Weight;
+    fn verify_event_inclusion(n: u32) -> Weight;
+    fn verify_state_inclusion(n: u32) -> Weight;
+    fn verify_tx_inclusion(n: u32) -> Weight;
+    fn register_gateway_permissionless(s: u32) -> Weight;
 }
 
 /// Weights for pallet_xdns using the Substrate node and recommended hardware.
@@ -65,8 +73,86 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 
-    fn submit_headers() -> Weight {
-        Weight::from_parts(25_265_000_u64, 0u64).saturating_add(T::DbWeight::get().reads(1_u64))
+    // Estimated until regenerated from `benchmarking.rs`. Charged per submitted byte at the cost
+    // of the GRANDPA justification's ed25519 checks, which covers the Aura seals' sr25519 checks.
+    // Storage: Portal Relayers (r:1 w:1)
+    // Storage: Portal RelayersCount (r:1 w:1)
+    // Storage: Portal RecentSubmissions (r:1 w:1)
+    // Storage: Portal RelayerRewardsBudgets (r:1 w:1)
+    // Storage: XDNS Gateways (r:3 w:0)
+    // Storage: Rewards PendingClaims (r:1 w:1)
+    // Storage: light client, best header, authorities and imported headers (r:8 w:6)
+    fn submit_headers(b: u32) -> Weight {
+        Weight::from_parts(64_000_000_u64, 18_000u64)
+            .saturating_add(Weight::from_parts(420_000_u64, 1u64).saturating_mul(b as u64))
+            .saturating_add(T::DbWeight::get().reads(16_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+
+    // Estimated until regenerated from `benchmarking.rs`.
+    // Storage: System Account (r:1 w:1)
+    // Storage: Portal RelayerRewardsBudgets (r:1 w:1)
+    fn set_relayer_rewards_budget() -> Weight {
+        Weight::from_parts(38_000_000_u64, 3_600u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    // Estimated until regenerated from `benchmarking.rs` with
+    // `node/run-benchmark.sh pallet_portal pallets/portal/src/weights.rs`. Queries read the
+    // gateway's vendor from XDNS and resolve the light client's heights or best header.
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best header, confirmation depths, halted and initialized flags (r:5 w:0)
+    fn get_latest_heartbeat() -> Weight {
+        Weight::from_parts(24_000_000_u64, 4_600u64).saturating_add(T::DbWeight::get().reads(6_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best hash and imported header (r:2 w:0)
+    fn get_latest_finalized_header() -> Weight {
+        Weight::from_parts(18_420_000_u64, 3_800u64).saturating_add(T::DbWeight::get().reads(3_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best number and confirmation depths (r:2 w:0)
+    fn get_height() -> Weight {
+        Weight::from_parts(18_420_000_u64, 2_600u64).saturating_add(T::DbWeight::get().reads(3_u64))
+    }
+
+    // Inclusion checks are charged at the worst case across vendors, as the precompile doesn't
+    // know the gateway's vendor before paying. Ethereum and Sepolia receipt proofs hash each
+    // proven byte with keccak and decode it from RLP, which costs the most per byte.
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client imported header, best number and confirmation depths (r:3 w:0)
+    fn verify_event_inclusion(n: u32) -> Weight {
+        Weight::from_parts(96_310_000_u64, 6_300u64)
+            .saturating_add(Weight::from_parts(5_100_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client imported header, best number and confirmation depths (r:3 w:0)
+    fn verify_state_inclusion(n: u32) -> Weight {
+        Weight::from_parts(84_000_000_u64, 5_800u64)
+            .saturating_add(Weight::from_parts(5_100_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client imported header, best number and confirmation depths (r:3 w:0)
+    fn verify_tx_inclusion(n: u32) -> Weight {
+        Weight::from_parts(86_000_000_u64, 5_800u64)
+            .saturating_add(Weight::from_parts(5_100_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+    }
+
+    fn register_gateway_permissionless(s: u32) -> Weight {
+        Weight::from_parts(87_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_000_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s as u64)))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s as u64)))
     }
 }
 
@@ -90,7 +176,88 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 
-    fn submit_headers() -> Weight {
-        Weight::from_parts(25_265_000_u64, 0u64).saturating_add(RocksDbWeight::get().reads(1_u64))
+    // Estimated until regenerated from `benchmarking.rs`. Charged per submitted byte at the cost
+    // of the GRANDPA justification's ed25519 checks, which covers the Aura seals' sr25519 checks.
+    // Storage: Portal Relayers (r:1 w:1)
+    // Storage: Portal RelayersCount (r:1 w:1)
+    // Storage: Portal RecentSubmissions (r:1 w:1)
+    // Storage: Portal RelayerRewardsBudgets (r:1 w:1)
+    // Storage: XDNS Gateways (r:3 w:0)
+    // Storage: Rewards PendingClaims (r:1 w:1)
+    // Storage: light client, best header, authorities and imported headers (r:8 w:6)
+    fn submit_headers(b: u32) -> Weight {
+        Weight::from_parts(64_000_000_u64, 18_000u64)
+            .saturating_add(Weight::from_parts(420_000_u64, 1u64).saturating_mul(b as u64))
+            .saturating_add(RocksDbWeight::get().reads(16_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+
+    // Estimated until regenerated from `benchmarking.rs`.
+    // Storage: System Account (r:1 w:1)
+    // Storage: Portal RelayerRewardsBudgets (r:1 w:1)
+    fn set_relayer_rewards_budget() -> Weight {
+        Weight::from_parts(38_000_000_u64, 3_600u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    // Estimated until regenerated from `benchmarking.rs` with
+    // `node/run-benchmark.sh pallet_portal pallets/portal/src/weights.rs`. Queries read the
+    // gateway's vendor from XDNS and resolve the light client's heights or best header.
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best header, confirmation depths, halted and initialized flags (r:5 w:0)
+    fn get_latest_heartbeat() -> Weight {
+        Weight::from_parts(24_000_000_u64, 4_600u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best hash and imported header (r:2 w:0)
+    fn get_latest_finalized_header() -> Weight {
+        Weight::from_parts(18_420_000_u64, 3_800u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best number and confirmation depths (r:2 w:0)
+    fn get_height() -> Weight {
+        Weight::from_parts(18_420_000_u64, 2_600u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+    }
+
+    // Inclusion checks are charged at the worst case across vendors, as the precompile doesn't
+    // know the gateway's vendor before paying. Ethereum and Sepolia receipt proofs hash each
+    // proven byte with keccak and decode it from RLP, which costs the most per byte.
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client imported header, best number and confirmation depths (r:3 w:0)
+    fn verify_event_inclusion(n: u32) -> Weight {
+        Weight::from_parts(96_310_000_u64, 6_300u64)
+            .saturating_add(Weight::from_parts(5_100_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client imported header, best number and confirmation depths (r:3 w:0)
+    fn verify_state_inclusion(n: u32) -> Weight {
+        Weight::from_parts(84_000_000_u64, 5_800u64)
+            .saturating_add(Weight::from_parts(5_100_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+    }
+
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client imported header, best number and confirmation depths (r:3 w:0)
+    fn verify_tx_inclusion(n: u32) -> Weight {
+        Weight::from_parts(86_000_000_u64, 5_800u64)
+            .saturating_add(Weight::from_parts(5_100_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+    }
+
+    fn register_gateway_permissionless(s: u32) -> Weight {
+        Weight::from_parts(87_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_000_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s as u64)))
     }
 }
@@ -0,0 +1,31 @@
+//! Runtime API definition required by Rewards RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding access to the distribution history, earnings ledger and vesting balances.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_rewards::{DistributionRecord, EarningsEntry};
+use sp_runtime::sp_std;
+use sp_std::prelude::*;
+pub use t3rn_primitives::{claimable::CircuitRole, common::RoundIndex};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Rewards
+    pub trait RewardsRuntimeApi<AccountId, Balance, BlockNumber> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns up to `limit` distribution records, oldest first, starting at the given record index
+        fn fetch_distribution_history(from: u32, limit: u32) -> Vec<(u32, DistributionRecord<BlockNumber, Balance>)>;
+
+        /// Returns the account's earnings of up to `limit` rounds starting at the given round
+        fn fetch_earnings(account: AccountId, from_round: RoundIndex, limit: u32) -> Vec<(RoundIndex, Vec<EarningsEntry<Balance>>)>;
+
+        /// Returns the account's locked and currently claimable vesting balances, per role
+        fn fetch_vesting_balances(account: AccountId) -> Vec<(CircuitRole, Balance, Balance)>;
+    }
+}
@@ -0,0 +1,129 @@
+//! RPC interface for the Rewards pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+pub use pallet_rewards_rpc_runtime_api::RewardsRuntimeApi;
+use pallet_rewards_rpc_runtime_api::{CircuitRole, DistributionRecord, EarningsEntry, RoundIndex};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::sp_std;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+use sp_std::prelude::*;
+use std::sync::Arc;
+
+const RUNTIME_ERROR: i64 = 1;
+
+/// The most records or rounds returned by a single page.
+const MAX_PAGE_LIMIT: u32 = 256;
+
+#[rpc(client, server)]
+pub trait RewardsApi<AccountId, Balance, BlockNumber> {
+    /// Returns up to `limit` distribution records, oldest first, starting at the given record index
+    #[method(name = "rewards_fetchDistributionHistory")]
+    fn fetch_distribution_history(
+        &self,
+        from: u32,
+        limit: u32,
+    ) -> RpcResult<Vec<(u32, DistributionRecord<BlockNumber, Balance>)>>;
+
+    /// Returns the account's earnings of up to `limit` rounds starting at the given round
+    #[method(name = "rewards_fetchEarnings")]
+    fn fetch_earnings(
+        &self,
+        account: AccountId,
+        from_round: RoundIndex,
+        limit: u32,
+    ) -> RpcResult<Vec<(RoundIndex, Vec<EarningsEntry<Balance>>)>>;
+
+    /// Returns the account's locked and currently claimable vesting balances, per role
+    #[method(name = "rewards_fetchVestingBalances")]
+    fn fetch_vesting_balances(
+        &self,
+        account: AccountId,
+    ) -> RpcResult<Vec<(CircuitRole, Balance, Balance)>>;
+}
+
+/// A struct that implements the [`RewardsApiServer`].
+pub struct Rewards<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Rewards<C, P> {
+    /// Create new `Rewards` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, BlockNumber> RewardsApiServer<AccountId, Balance, BlockNumber>
+    for Rewards<C, Block>
+where
+    AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
+    BlockNumber: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: RewardsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    fn fetch_distribution_history(
+        &self,
+        from: u32,
+        limit: u32,
+    ) -> RpcResult<Vec<(u32, DistributionRecord<BlockNumber, Balance>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_distribution_history(at, from, limit.min(MAX_PAGE_LIMIT))
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_earnings(
+        &self,
+        account: AccountId,
+        from_round: RoundIndex,
+        limit: u32,
+    ) -> RpcResult<Vec<(RoundIndex, Vec<EarningsEntry<Balance>>)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_earnings(at, account, from_round, limit.min(MAX_PAGE_LIMIT))
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_vesting_balances(
+        &self,
+        account: AccountId,
+    ) -> RpcResult<Vec<(CircuitRole, Balance, Balance)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_vesting_balances(at, account)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(jsonrpsee::types::ErrorObject::owned(
+        RUNTIME_ERROR as i32,
+        "Runtime Error - Rewards RPC",
+        Some(format!("{err:?}")),
+    )))
+}
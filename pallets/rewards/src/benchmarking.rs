@@ -0,0 +1,16 @@
+//! Benchmarking setup for pallet-rewards
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use t3rn_primitives::claimable::BenefitSource;
+
+benchmarks! {
+    set_vesting_rule {
+        let period: BlockNumberFor<T> = 100u32.into();
+    }: _(RawOrigin::Root, BenefitSource::Inflation, Some(period))
+    verify {
+        assert_eq!(VestingRules::<T>::get(BenefitSource::Inflation), Some(period));
+    }
+}
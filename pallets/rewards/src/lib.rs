@@ -1,6 +1,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use crate::pallet::*;
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use t3rn_primitives::{
+    clock::{OnNewRound, RoundHandlerId},
+    common::RoundInfo,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -27,8 +40,8 @@ pub mod pallet {
         circuit::{CircuitStatus, FullSideEffect},
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
         clock::Clock as ClockTrait,
-        common::RoundInfo,
-        rewards::RewardsWriteApi,
+        common::{RoundIndex, RoundInfo},
+        rewards::{AmbassadorsReadApi, RewardsWriteApi},
         TreasuryAccount, TreasuryAccountProvider,
     };
 
@@ -42,6 +55,7 @@ pub mod pallet {
     }
 
     #[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
     pub struct DistributionRecord<BlockNumber, Balance> {
         pub block_number: BlockNumber,
         pub attester_rewards: Balance,
@@ -61,6 +75,26 @@ pub mod pallet {
         pub parachain: Balance,
     }
 
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct EarningsEntry<Balance> {
+        pub role: CircuitRole,
+        pub benefit_source: BenefitSource,
+        pub non_native_asset_id: Option<u32>,
+        pub amount: Balance,
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, TypeInfo)]
+    pub struct VestingSchedule<BlockNumber, Balance> {
+        pub role: CircuitRole,
+        pub benefit_source: BenefitSource,
+        pub non_native_asset_id: Option<u32>,
+        pub total: Balance,
+        pub released: Balance,
+        pub starting_block: BlockNumber,
+        pub period: BlockNumber,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -113,8 +147,45 @@ pub mod pallet {
         #[pallet::constant]
         type InflationDistributionPeriod: Get<BlockNumberFor<Self>>;
 
+        /// The number of blocks claimed inflation rewards vest linearly over, unless overridden
+        /// with `set_vesting_rule`. Zero pays inflation rewards out immediately.
+        ///
+        /// Default: 1_314_000 (assuming six months at 12s block time)
+        #[pallet::constant]
+        type InflationVestingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of vesting schedules per account. Claims that would start
+        /// a vesting schedule beyond it join the oldest schedule of the same role, source
+        /// and asset, vesting on its timeline, or stay pending if there is no such schedule.
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
         type AvailableBootstrapSpenditure: Get<BalanceOf<Self>>;
 
+        /// The number of inflation distributions the bootstrap pool is paid out over.
+        /// Each distribution receives a linearly decaying slice of `AvailableBootstrapSpenditure`.
+        ///
+        /// Default: 26 (one year assuming one distribution per two weeks)
+        #[pallet::constant]
+        type TotalBootstrapDistributions: Get<u32>;
+
+        /// The number of the most recent distribution records kept in the history ring buffer.
+        ///
+        /// Default: 512
+        #[pallet::constant]
+        type DistributionHistoryDepth: Get<u32>;
+
+        /// The number of the most recent rounds kept in each account's earnings ledger.
+        ///
+        /// Default: 512
+        #[pallet::constant]
+        type EarningsLedgerDepth: Get<u32>;
+
+        /// The maximum number of accumulated settlements read per bootstrap distribution to pay
+        /// out the executors' share. The next distribution continues after the last one read.
+        #[pallet::constant]
+        type MaxBootstrapExecutorPayouts: Get<u32>;
+
         type AttesterBootstrapRewards: Get<Percent>;
 
         type CollatorBootstrapRewards: Get<Percent>;
@@ -134,6 +205,9 @@ pub mod pallet {
         >;
 
         type Attesters: AttestersReadApi<Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
+
+        /// Weights gathered through benchmarking.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -180,13 +254,37 @@ pub mod pallet {
     #[pallet::storage]
     pub type DistributionBlock<T: Config> = StorageValue<_, BlockNumberFor<T>>;
 
+    /// Ring buffer of the last `DistributionHistoryDepth` distribution records, keyed by slot.
+    #[pallet::storage]
+    pub type DistributionHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32,
+        DistributionRecord<frame_system::pallet_prelude::BlockNumberFor<T>, BalanceOf<T>>,
+    >;
+
+    /// Number of distribution records written so far; the next one lands in slot `count % depth`.
+    #[pallet::storage]
+    #[pallet::getter(fn distribution_history_count)]
+    pub type DistributionHistoryCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Amounts credited to each account per round they were earned in, broken down by role,
+    /// benefit source and asset. Entries are kept after the rewards are claimed.
     #[pallet::storage]
-    pub type DistributionHistory<T: Config> = StorageValue<
+    pub type EarningsLedger<T: Config> = StorageDoubleMap<
         _,
-        Vec<DistributionRecord<frame_system::pallet_prelude::BlockNumberFor<T>, BalanceOf<T>>>,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        RoundIndex,
+        Vec<EarningsEntry<BalanceOf<T>>>,
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn storage_migrations_done)]
+    pub type StorageMigrations<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     #[pallet::storage]
     pub type IsDistributionHalted<T: Config> = StorageValue<_, bool, ValueQuery>;
 
@@ -203,6 +301,48 @@ pub mod pallet {
     #[pallet::storage]
     pub type IsClaimingHalted<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// Accounts allowed to refer orders to the circuit, with the block they were registered at.
+    #[pallet::storage]
+    #[pallet::getter(fn ambassadors)]
+    pub type Ambassadors<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// Share of the executor fee of a successful order accrued to its referring ambassador.
+    #[pallet::storage]
+    #[pallet::getter(fn ambassador_rewards_share)]
+    pub type AmbassadorRewardsShare<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
+    /// Number of distributions the bootstrap pool has already paid out in.
+    #[pallet::storage]
+    #[pallet::getter(fn bootstrap_distributions)]
+    pub type BootstrapDistributions<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Total amount paid out of the bootstrap pool so far.
+    #[pallet::storage]
+    #[pallet::getter(fn bootstrap_pool_distributed)]
+    pub type BootstrapPoolDistributed<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Raw key of the last accumulated settlement the executors' bootstrap share was paid out for,
+    /// if the previous distribution stopped at `MaxBootstrapExecutorPayouts`.
+    #[pallet::storage]
+    pub type BootstrapExecutorsCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    /// Vesting period per benefit source, overriding the default of `InflationVestingPeriod` for
+    /// inflation and immediate payouts for the other sources. Zero pays out immediately.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_rules)]
+    pub type VestingRules<T: Config> =
+        StorageMap<_, Blake2_128Concat, BenefitSource, BlockNumberFor<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<VestingSchedule<BlockNumberFor<T>, BalanceOf<T>>, T::MaxVestingSchedules>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn get_pending_claims)]
     pub type PendingClaims<T: Config> = StorageMap<
@@ -222,6 +362,18 @@ pub mod pallet {
         NewMaxRewardExecutorsKickbackSet(Percent, Percent),
         Claimed(T::AccountId, Vec<(BalanceOf<T>, Option<u32>)>),
         PendingClaim(T::AccountId, BalanceOf<T>),
+        // distribution index, amount paid out of the bootstrap pool
+        BootstrapRewardsDistributed(u32, BalanceOf<T>),
+        // total amount paid out of the bootstrap pool
+        BootstrapPoolDepleted(BalanceOf<T>),
+        // benefit source, vesting period in blocks (None restores the default)
+        VestingRuleSet(BenefitSource, Option<BlockNumberFor<T>>),
+        VestingScheduled(T::AccountId, BalanceOf<T>),
+        VestedClaimed(T::AccountId, Vec<(BalanceOf<T>, Option<u32>)>),
+        AmbassadorRegistered(T::AccountId),
+        AmbassadorDeregistered(T::AccountId),
+        // old, new share of the executor fee accrued to referring ambassadors
+        NewAmbassadorRewardsShareSet(Percent, Percent),
     }
 
     #[pallet::error]
@@ -232,6 +384,9 @@ pub mod pallet {
         AttesterNotFound,
         TryIntoConversionU128ToBalanceFailed,
         Halted,
+        NoVestedRewards,
+        AmbassadorAlreadyRegistered,
+        AmbassadorNotFound,
     }
 
     #[pallet::call]
@@ -316,6 +471,9 @@ pub mod pallet {
                 };
 
                 let mut total_claimed_assets: Vec<(BalanceOf<T>, Option<u32>)> = vec![];
+                let mut deferred_claims: Vec<ClaimableArtifacts<T::AccountId, BalanceOf<T>>> =
+                    vec![];
+                let current_block = frame_system::Pallet::<T>::block_number();
 
                 for claim in claims_to_process.iter() {
                     ensure!(
@@ -323,6 +481,47 @@ pub mod pallet {
                         Error::<T>::NoPendingClaims
                     );
 
+                    // claims from vesting sources turn into schedules unlocking over time
+                    let vesting_period = Self::vesting_period(&claim.benefit_source);
+                    if !vesting_period.is_zero() {
+                        let scheduled = VestingSchedules::<T>::mutate(&who, |schedules| {
+                            let schedule = VestingSchedule {
+                                role: claim.role.clone(),
+                                benefit_source: claim.benefit_source.clone(),
+                                non_native_asset_id: claim.non_native_asset_id,
+                                total: claim.total_round_claim,
+                                released: Zero::zero(),
+                                starting_block: current_block,
+                                period: vesting_period,
+                            };
+                            // beyond MaxVestingSchedules the claim joins the oldest matching schedule
+                            schedules.try_push(schedule).is_ok()
+                                || schedules
+                                    .iter_mut()
+                                    .find(|schedule| {
+                                        schedule.role == claim.role
+                                            && schedule.benefit_source == claim.benefit_source
+                                            && schedule.non_native_asset_id
+                                                == claim.non_native_asset_id
+                                    })
+                                    .map(|oldest| {
+                                        oldest.total =
+                                            oldest.total.saturating_add(claim.total_round_claim)
+                                    })
+                                    .is_some()
+                        });
+                        // with no schedule to join, the claim stays pending until some are claimed
+                        if !scheduled {
+                            deferred_claims.push(claim.clone());
+                            continue
+                        }
+                        Self::deposit_event(Event::VestingScheduled(
+                            who.clone(),
+                            claim.total_round_claim,
+                        ));
+                        continue
+                    }
+
                     // accumulate the total round claim per asset
                     if let Some(position) = total_claimed_assets
                         .iter()
@@ -340,7 +539,9 @@ pub mod pallet {
                     T::AccountManager::deposit_immediately(&who, *balance, *asset_id);
                 }
                 // remove processed claims
-                pending_claims.retain(|claim| !claims_to_process.contains(claim));
+                pending_claims.retain(|claim| {
+                    !claims_to_process.contains(claim) || deferred_claims.contains(claim)
+                });
                 *maybe_pending_claims = Some(pending_claims);
 
                 // reset the subjects to future inflation distribution of accumulated claims for executors
@@ -353,9 +554,271 @@ pub mod pallet {
                 Ok(().into())
             })
         }
+
+        #[pallet::weight(T::WeightInfo::set_vesting_rule())]
+        pub fn set_vesting_rule(
+            origin: OriginFor<T>,
+            benefit_source: BenefitSource,
+            maybe_period: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match maybe_period {
+                Some(period) => VestingRules::<T>::insert(&benefit_source, period),
+                None => VestingRules::<T>::remove(&benefit_source),
+            }
+            Self::deposit_event(Event::VestingRuleSet(benefit_source, maybe_period));
+            Ok(())
+        }
+
+        #[pallet::weight(Pallet::<T>::claim_vested_weight(T::MaxVestingSchedules::get()))]
+        pub fn claim_vested(
+            origin: OriginFor<T>,
+            role_to_claim: Option<CircuitRole>,
+        ) -> DispatchResultWithPostInfo {
+            // ensure claiming is not halted
+            ensure!(!IsClaimingHalted::<T>::get(), Error::<T>::Halted);
+
+            let who = ensure_signed(origin)?;
+            let current_block = frame_system::Pallet::<T>::block_number();
+
+            VestingSchedules::<T>::try_mutate(&who, |schedules| {
+                let mut total_released_assets: Vec<(BalanceOf<T>, Option<u32>)> = vec![];
+                let schedules_count = schedules.len() as u32;
+
+                for schedule in schedules.iter_mut().filter(|schedule| {
+                    role_to_claim
+                        .as_ref()
+                        .map_or(true, |role| &schedule.role == role)
+                }) {
+                    let releasable = Self::vested_amount(schedule, current_block)
+                        .saturating_sub(schedule.released);
+                    if releasable.is_zero() {
+                        continue
+                    }
+                    schedule.released = schedule.released.saturating_add(releasable);
+
+                    // accumulate the released amount per asset
+                    if let Some(position) = total_released_assets
+                        .iter()
+                        .position(|&(_, asset_id)| asset_id == schedule.non_native_asset_id)
+                    {
+                        let (balance, _) = &mut total_released_assets[position];
+                        *balance = balance.saturating_add(releasable);
+                    } else {
+                        total_released_assets.push((releasable, schedule.non_native_asset_id));
+                    }
+                }
+
+                ensure!(
+                    !total_released_assets.is_empty(),
+                    Error::<T>::NoVestedRewards
+                );
+
+                for (balance, asset_id) in total_released_assets.iter() {
+                    T::AccountManager::deposit_immediately(&who, *balance, *asset_id);
+                }
+                // remove fully vested schedules
+                schedules.retain(|schedule| schedule.released < schedule.total);
+
+                Self::deposit_event(Event::VestedClaimed(who.clone(), total_released_assets));
+
+                Ok(Some(Self::claim_vested_weight(schedules_count)).into())
+            })
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn register_ambassador(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                !Ambassadors::<T>::contains_key(&who),
+                Error::<T>::AmbassadorAlreadyRegistered
+            );
+            Ambassadors::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+            Self::deposit_event(Event::AmbassadorRegistered(who));
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn deregister_ambassador(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                Ambassadors::<T>::take(&who).is_some(),
+                Error::<T>::AmbassadorNotFound
+            );
+            Self::deposit_event(Event::AmbassadorDeregistered(who));
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_ambassador_rewards_share(
+            origin: OriginFor<T>,
+            new_share: Percent,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let old_share = AmbassadorRewardsShare::<T>::get();
+            AmbassadorRewardsShare::<T>::put(new_share);
+            Self::deposit_event(Event::NewAmbassadorRewardsShareSet(old_share, new_share));
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// The number of blocks claims from the given benefit source vest over. Zero pays out immediately.
+        pub fn vesting_period(benefit_source: &BenefitSource) -> BlockNumberFor<T> {
+            VestingRules::<T>::get(benefit_source).unwrap_or_else(|| match benefit_source {
+                BenefitSource::Inflation => T::InflationVestingPeriod::get(),
+                _ => Zero::zero(),
+            })
+        }
+
+        /// Weight of claiming the vested rewards out of the given number of schedules,
+        /// each depositing the released amount. The earnings were noted when credited.
+        pub fn claim_vested_weight(schedules: u32) -> Weight {
+            Weight::from_parts(100_000, 0).saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(2, 2)
+                    .saturating_mul(schedules as u64),
+            )
+        }
+
+        /// Weight of crediting a pending claim: updating the pending claims and noting the
+        /// earnings, including the expired rounds pruned when a new round is opened.
+        pub fn pending_claim_weight() -> Weight {
+            T::DbWeight::get().reads_writes(3, 3)
+        }
+
+        /// Part of the schedule unlocked at the given block, including the already released part.
+        pub fn vested_amount(
+            schedule: &VestingSchedule<BlockNumberFor<T>, BalanceOf<T>>,
+            at: BlockNumberFor<T>,
+        ) -> BalanceOf<T> {
+            let elapsed = at.saturating_sub(schedule.starting_block);
+            if elapsed >= schedule.period {
+                return schedule.total
+            }
+            Perbill::from_rational(elapsed, schedule.period).mul_floor(schedule.total)
+        }
+
+        /// Locked and currently claimable vesting balances of the account, per role.
+        pub fn vesting_balances(
+            who: &T::AccountId,
+        ) -> Vec<(CircuitRole, BalanceOf<T>, BalanceOf<T>)> {
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let mut balances: Vec<(CircuitRole, BalanceOf<T>, BalanceOf<T>)> = vec![];
+
+            for schedule in VestingSchedules::<T>::get(who) {
+                let vested = Self::vested_amount(&schedule, current_block);
+                let locked = schedule.total.saturating_sub(vested);
+                let claimable = vested.saturating_sub(schedule.released);
+
+                if let Some((_, role_locked, role_claimable)) = balances
+                    .iter_mut()
+                    .find(|(role, _, _)| role == &schedule.role)
+                {
+                    *role_locked = role_locked.saturating_add(locked);
+                    *role_claimable = role_claimable.saturating_add(claimable);
+                } else {
+                    balances.push((schedule.role, locked, claimable));
+                }
+            }
+
+            balances
+        }
+
+        /// Writes the record into the next slot of the history ring buffer, overwriting the oldest one once full.
+        pub fn push_distribution_record(
+            record: DistributionRecord<BlockNumberFor<T>, BalanceOf<T>>,
+        ) {
+            let count = DistributionHistoryCount::<T>::get();
+            DistributionHistory::<T>::insert(count % T::DistributionHistoryDepth::get(), record);
+            DistributionHistoryCount::<T>::put(count.saturating_add(1));
+        }
+
+        pub fn last_distribution_record(
+        ) -> Option<DistributionRecord<BlockNumberFor<T>, BalanceOf<T>>> {
+            let count = DistributionHistoryCount::<T>::get();
+            if count == 0 {
+                return None
+            }
+            DistributionHistory::<T>::get((count - 1) % T::DistributionHistoryDepth::get())
+        }
+
+        /// Up to `limit` distribution records, oldest first, starting at the given record index.
+        /// Records that fell out of the ring buffer are skipped.
+        pub fn distribution_history(
+            from: u32,
+            limit: u32,
+        ) -> Vec<(u32, DistributionRecord<BlockNumberFor<T>, BalanceOf<T>>)> {
+            let count = DistributionHistoryCount::<T>::get();
+            let depth = T::DistributionHistoryDepth::get();
+            let oldest_kept = count.saturating_sub(depth);
+            let start = from.max(oldest_kept);
+            let end = start.saturating_add(limit).min(count);
+
+            (start..end)
+                .filter_map(|index| {
+                    DistributionHistory::<T>::get(index % depth).map(|record| (index, record))
+                })
+                .collect()
+        }
+
+        /// Adds the credited amount to the account's ledger entry for the round it was earned in,
+        /// pruning the rounds older than `EarningsLedgerDepth` when a new round is opened.
+        pub fn note_earnings(
+            who: &T::AccountId,
+            round: RoundIndex,
+            role: &CircuitRole,
+            benefit_source: &BenefitSource,
+            non_native_asset_id: Option<u32>,
+            amount: BalanceOf<T>,
+        ) {
+            if !EarningsLedger::<T>::contains_key(who, round) {
+                let oldest_kept =
+                    round.saturating_sub(T::EarningsLedgerDepth::get().saturating_sub(1));
+                let expired_rounds = EarningsLedger::<T>::iter_key_prefix(who)
+                    .filter(|noted_round| *noted_round < oldest_kept)
+                    .collect::<Vec<RoundIndex>>();
+                for expired_round in expired_rounds {
+                    EarningsLedger::<T>::remove(who, expired_round);
+                }
+            }
+
+            EarningsLedger::<T>::mutate(who, round, |entries| {
+                if let Some(entry) = entries.iter_mut().find(|entry| {
+                    &entry.role == role
+                        && &entry.benefit_source == benefit_source
+                        && entry.non_native_asset_id == non_native_asset_id
+                }) {
+                    entry.amount = entry.amount.saturating_add(amount);
+                } else {
+                    entries.push(EarningsEntry {
+                        role: role.clone(),
+                        benefit_source: benefit_source.clone(),
+                        non_native_asset_id,
+                        amount,
+                    });
+                }
+            });
+        }
+
+        /// The account's earnings of up to `limit` rounds starting at the given round, skipping rounds without earnings.
+        pub fn earnings(
+            who: &T::AccountId,
+            from_round: RoundIndex,
+            limit: u32,
+        ) -> Vec<(RoundIndex, Vec<EarningsEntry<BalanceOf<T>>>)> {
+            (from_round..from_round.saturating_add(limit))
+                .filter_map(|round| {
+                    let entries = EarningsLedger::<T>::get(who, round);
+                    if entries.is_empty() {
+                        None
+                    } else {
+                        Some((round, entries))
+                    }
+                })
+                .collect()
+        }
+
         pub fn distribute_inflation() -> Weight {
             // Ensure distribution is not halted
             if IsDistributionHalted::<T>::get() {
@@ -387,6 +850,11 @@ pub mod pallet {
             log::debug!("executor_rewards: {:?}", executor_rewards);
             log::debug!("collator_rewards: {:?}", collator_rewards);
             log::debug!("treasury_rewards: {:?}", treasury_rewards);
+
+            // Distribute bootstrap rewards from the treasury account, before executor inflation rewards
+            // reset the accumulated settlements the executors' activity is measured by
+            let bootstrap_weight = Self::distribute_bootstrap_rewards();
+
             // Distribute rewards to attesters
             let attester_rewards_distributed = Self::distribute_attester_rewards(attester_rewards);
 
@@ -402,10 +870,6 @@ pub mod pallet {
                 treasury_rewards,
             );
 
-            // Distribute bootstrap rewards from the treasury account
-            // todo: uncomment this when bootstrap rewards are implemented
-            // Self::distribute_bootstrap_rewards()?;
-
             // Update the distribution block
             DistributionBlock::<T>::put(frame_system::Pallet::<T>::block_number());
 
@@ -423,11 +887,225 @@ pub mod pallet {
                     + treasury_rewards,
                 available: balance_for_distribution,
             };
-            let mut history = DistributionHistory::<T>::get();
-            history.push(distribution_record);
-            DistributionHistory::<T>::put(history);
+            Self::push_distribution_record(distribution_record);
+
+            T::DbWeight::get()
+                .reads_writes(8, 8)
+                .saturating_add(bootstrap_weight)
+        }
+
+        /// Amount the bootstrap pool pays out in the given distribution. The slices decay linearly
+        /// over `TotalBootstrapDistributions`, and the last one pays out whatever is left of the pool.
+        pub fn bootstrap_allowance(
+            distribution_index: u32,
+            already_distributed: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let available = T::AvailableBootstrapSpenditure::get();
+            let remaining = available.saturating_sub(already_distributed);
+            let total_distributions = T::TotalBootstrapDistributions::get();
+            if distribution_index >= total_distributions {
+                return Zero::zero()
+            }
+            if distribution_index == total_distributions - 1 {
+                return remaining
+            }
+            let total_slices = total_distributions.saturating_mul(total_distributions + 1) / 2;
+            Perbill::from_rational(total_distributions - distribution_index, total_slices)
+                .mul_floor(available)
+                .min(remaining)
+        }
+
+        pub fn is_bootstrap_pool_depleted() -> bool {
+            BootstrapDistributions::<T>::get() >= T::TotalBootstrapDistributions::get()
+                || BootstrapPoolDistributed::<T>::get() >= T::AvailableBootstrapSpenditure::get()
+        }
+
+        /// Pays this distribution's slice of the bootstrap pool out of the Treasury account to the
+        /// attesters, collators and executors active this period. Roles without any activity leave
+        /// their share in the treasury. Stops once the pool is depleted.
+        pub fn distribute_bootstrap_rewards() -> Weight {
+            if Self::is_bootstrap_pool_depleted() {
+                return T::DbWeight::get().reads(2)
+            }
+
+            let distribution_index = BootstrapDistributions::<T>::get();
+            let already_distributed = BootstrapPoolDistributed::<T>::get();
+            let treasury_account =
+                T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury);
+            let spendable = T::Currency::free_balance(&treasury_account)
+                .saturating_sub(T::Currency::minimum_balance());
+            let available_this_distribution =
+                Self::bootstrap_allowance(distribution_index, already_distributed).min(spendable);
+
+            if available_this_distribution.is_zero() {
+                log::warn!(
+                    "Bootstrap rewards distribution {:?} skipped: treasury has no spendable balance",
+                    distribution_index
+                );
+                return T::DbWeight::get().reads(3)
+            }
+
+            let mut payouts = Self::bootstrap_attester_payouts(
+                T::AttesterBootstrapRewards::get().mul_floor(available_this_distribution),
+            );
+            payouts.extend(Self::bootstrap_collator_payouts(
+                T::CollatorBootstrapRewards::get().mul_floor(available_this_distribution),
+            ));
+            let (executor_payouts, settlements_read) = Self::bootstrap_executor_payouts(
+                T::ExecutorBootstrapRewards::get().mul_floor(available_this_distribution),
+            );
+            payouts.extend(executor_payouts);
+            payouts.retain(|(_, _, reward)| !reward.is_zero());
+            let executors_weight = T::DbWeight::get().reads_writes(settlements_read, 1);
+
+            let total_payout = payouts
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |acc, (_, _, reward)| {
+                    acc.saturating_add(*reward)
+                });
+
+            if total_payout.is_zero() {
+                return T::DbWeight::get().reads(6).saturating_add(executors_weight)
+            }
+
+            if let Err(e) = T::Currency::withdraw(
+                &treasury_account,
+                total_payout,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::KeepAlive,
+            ) {
+                log::error!(
+                    "Bootstrap rewards distribution {:?} failed to withdraw {:?} from treasury: {:?}",
+                    distribution_index,
+                    total_payout,
+                    e
+                );
+                return T::DbWeight::get().reads(6).saturating_add(executors_weight)
+            }
+
+            let payouts_count = payouts.len() as u64;
+            for (beneficiary, role, reward) in payouts {
+                Self::update_pending_claims(
+                    &beneficiary,
+                    role,
+                    reward,
+                    BenefitSource::BootstrapPool,
+                    None,
+                );
+            }
+
+            let total_distributed = already_distributed.saturating_add(total_payout);
+            BootstrapDistributions::<T>::put(distribution_index.saturating_add(1));
+            BootstrapPoolDistributed::<T>::put(total_distributed);
+            Self::deposit_event(Event::BootstrapRewardsDistributed(
+                distribution_index,
+                total_payout,
+            ));
+
+            if Self::is_bootstrap_pool_depleted() {
+                Self::deposit_event(Event::BootstrapPoolDepleted(total_distributed));
+            }
+
+            T::DbWeight::get()
+                .reads_writes(6, 3)
+                .saturating_add(Self::pending_claim_weight().saturating_mul(payouts_count))
+                .saturating_add(executors_weight)
+        }
+
+        /// Splits the attesters' bootstrap share equally between the honest active set.
+        fn bootstrap_attester_payouts(
+            share: BalanceOf<T>,
+        ) -> Vec<(T::AccountId, CircuitRole, BalanceOf<T>)> {
+            let honest_active_set = T::Attesters::honest_active_set();
+            if honest_active_set.is_empty() {
+                return vec![]
+            }
+
+            let reward_per_attester = share / BalanceOf::<T>::from(honest_active_set.len() as u32);
+
+            honest_active_set
+                .into_iter()
+                .map(|attester| (attester, CircuitRole::Attester, reward_per_attester))
+                .collect()
+        }
+
+        /// Splits the collators' bootstrap share proportionally to the blocks authored this period.
+        fn bootstrap_collator_payouts(
+            share: BalanceOf<T>,
+        ) -> Vec<(T::AccountId, CircuitRole, BalanceOf<T>)> {
+            let authors_this_period = AuthorsThisPeriod::<T>::get();
+            let total_blocks = authors_this_period
+                .values()
+                .fold(0u32, |acc, count| acc.saturating_add(*count));
+            if total_blocks == 0 {
+                return vec![]
+            }
+
+            authors_this_period
+                .into_iter()
+                .map(|(author, block_count)| {
+                    let reward = Perbill::from_rational(block_count, total_blocks).mul_floor(share);
+                    (author, CircuitRole::Collator, reward)
+                })
+                .collect()
+        }
+
+        /// Splits the executors' bootstrap share proportionally to their accumulated native settlements.
+        /// Reads at most `MaxBootstrapExecutorPayouts` settlements, starting after the last one read
+        /// by the previous distribution and wrapping around once the map is exhausted.
+        /// Returns the payouts along with the number of storage reads taken.
+        fn bootstrap_executor_payouts(
+            share: BalanceOf<T>,
+        ) -> (Vec<(T::AccountId, CircuitRole, BalanceOf<T>)>, u64) {
+            let page_size = T::MaxBootstrapExecutorPayouts::get() as usize;
+            let cursor = BootstrapExecutorsCursor::<T>::take();
+            let resumed = cursor.is_some();
+
+            let mut settlements = match cursor {
+                Some(last_raw_key) => AccumulatedSettlements::<T>::iter_from(last_raw_key),
+                None => AccumulatedSettlements::<T>::iter(),
+            };
+            let mut page: Vec<_> = settlements.by_ref().take(page_size).collect();
+            if page.is_empty() && resumed {
+                settlements = AccumulatedSettlements::<T>::iter();
+                page = settlements.by_ref().take(page_size).collect();
+            }
+            if page_size > 0 && page.len() == page_size {
+                BootstrapExecutorsCursor::<T>::put(settlements.last_raw_key().to_vec());
+            }
+            let reads = (page.len() as u64).saturating_add(1);
+
+            let accumulated_native_settlements = page
+                .into_iter()
+                .filter(|(_account, asset_type, _)| asset_type == &AssetType::<u32>::Native)
+                .map(|(account, _, accumulated_settlement)| (account, accumulated_settlement))
+                .collect::<Vec<(T::AccountId, BalanceOf<T>)>>();
+
+            let total_settled = match Self::total_settled_executions_this_round(
+                accumulated_native_settlements.clone(),
+            ) {
+                Ok(total_settled) if !total_settled.is_zero() => total_settled,
+                Ok(_) => return (vec![], reads),
+                Err(e) => {
+                    log::error!(
+                        "Arithmetic Overflow when calculating executor bootstrap rewards: {:?}",
+                        e
+                    );
+                    return (vec![], reads)
+                },
+            };
+
+            let payouts = Self::executions_proportionally_of_total_this_round(
+                accumulated_native_settlements,
+                total_settled,
+            )
+            .into_iter()
+            .map(|(executor, _, proportion)| {
+                (executor, CircuitRole::Executor, proportion.mul_floor(share))
+            })
+            .collect();
 
-            T::DbWeight::get().reads_writes(8, 8)
+            (payouts, reads)
         }
 
         pub fn distribute_attester_rewards(current_distribution: BalanceOf<T>) -> BalanceOf<T> {
@@ -598,6 +1276,11 @@ pub mod pallet {
         }
 
         pub fn process_accumulated_settlements() -> Weight {
+            Self::process_round_settlements(T::Clock::current_round())
+        }
+
+        /// Accumulate the executor and ambassador settlements of the given round into pending claims.
+        pub fn process_round_settlements(round: RoundInfo<BlockNumberFor<T>>) -> Weight {
             let mut weight: Weight = Zero::zero();
 
             // Ensure settlements accumulation is not halted
@@ -605,20 +1288,20 @@ pub mod pallet {
                 return T::DbWeight::get().reads_writes(1, 0)
             }
 
-            // Get current round info
-            let current_round = T::Clock::current_round();
-            if <LastProcessedRound<T>>::get() == Some(current_round) {
+            if <LastProcessedRound<T>>::get() == Some(round) {
                 return T::DbWeight::get().reads_writes(2, 0)
             }
 
             // Get the total accumulated settlements
-            let executions_this_round = Self::executions_this_round();
+            let executions_this_round =
+                T::AccountManager::get_round_settlements_by_role(round, CircuitRole::Executor);
 
             // Now process the claims
             for (executor, settlement) in executions_this_round {
                 // Update the pending claims for the executor
                 Self::add_to_pending_claims(
                     &executor,
+                    round.index,
                     CircuitRole::Executor,
                     settlement.settlement_amount,
                     BenefitSource::TrafficRewards,
@@ -652,10 +1335,28 @@ pub mod pallet {
                     },
                 }
 
-                weight += T::DbWeight::get().reads_writes(1, 1);
+                weight += T::DbWeight::get()
+                    .reads_writes(1, 1)
+                    .saturating_add(Self::pending_claim_weight());
+            }
+
+            // Ambassadors receive their referral share of executor fees as traffic rewards
+            for (ambassador, settlement) in
+                T::AccountManager::get_round_settlements_by_role(round, CircuitRole::Ambassador)
+            {
+                Self::add_to_pending_claims(
+                    &ambassador,
+                    round.index,
+                    CircuitRole::Ambassador,
+                    settlement.settlement_amount,
+                    BenefitSource::TrafficRewards,
+                    settlement.maybe_asset_id,
+                );
+
+                weight += Self::pending_claim_weight();
             }
 
-            <LastProcessedRound<T>>::put(current_round);
+            <LastProcessedRound<T>>::put(round);
 
             weight
         }
@@ -767,13 +1468,25 @@ pub mod pallet {
                 )
         }
 
+        /// Credits the reward earned in the given round, merging it into the pending claim of
+        /// the same role, benefit source and asset if there is one.
         fn add_to_pending_claims(
             account: &T::AccountId,
+            earned_round: RoundIndex,
             role: CircuitRole,
             reward: BalanceOf<T>,
             benefit_source: BenefitSource,
             non_native_asset_id: Option<u32>,
         ) {
+            Self::note_earnings(
+                account,
+                earned_round,
+                &role,
+                &benefit_source,
+                non_native_asset_id,
+                reward,
+            );
+
             PendingClaims::<T>::mutate(account, |maybe_pending_claims| {
                 let mut pending_claims = maybe_pending_claims.take().unwrap_or_default();
 
@@ -808,6 +1521,15 @@ pub mod pallet {
             benefit_source: BenefitSource,
             non_native_asset_id: Option<u32>,
         ) {
+            Self::note_earnings(
+                account,
+                T::Clock::current_round().index,
+                &role,
+                &benefit_source,
+                non_native_asset_id,
+                reward,
+            );
+
             let claim = ClaimableArtifacts {
                 beneficiary: account.clone(),
                 role,
@@ -916,15 +1638,88 @@ pub mod pallet {
             }
             false
         }
-    }
-
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_finalize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {}
 
-        fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
-            Self::process_update_estimated_treasury_balance()
-        }
+        fn add_pending_claim(
+            beneficiary: &T::AccountId,
+            role: CircuitRole,
+            amount: BalanceOf<T>,
+            benefit_source: BenefitSource,
+            non_native_asset_id: Option<u32>,
+        ) {
+            Self::add_to_pending_claims(
+                beneficiary,
+                T::Clock::current_round().index,
+                role,
+                amount,
+                benefit_source,
+                non_native_asset_id,
+            );
+        }
+    }
+
+    impl<T: Config> AmbassadorsReadApi<T::AccountId> for Pallet<T> {
+        fn is_ambassador(who: &T::AccountId) -> bool {
+            Ambassadors::<T>::contains_key(who)
+        }
+
+        fn ambassador_share() -> Percent {
+            AmbassadorRewardsShare::<T>::get()
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_finalize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {}
+
+        fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
+            Self::process_update_estimated_treasury_balance()
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            // Define the current storage migration version.
+            const CURRENT_STORAGE_VERSION: u32 = 1;
+            // Migrate the storage entries.
+            StorageMigrations::<T>::try_mutate(|current_version| {
+                match *current_version {
+                    // Storage Migration: unbounded DistributionHistory vector -> bounded DistributionHistory ring buffer
+                    0 => {
+                        let history = deprecated::DistributionHistory::<T>::take();
+                        let history_len = history.len();
+                        // Keep the most recent records only
+                        let skipped =
+                            history_len.saturating_sub(T::DistributionHistoryDepth::get() as usize);
+                        for record in history.into_iter().skip(skipped) {
+                            Self::push_distribution_record(record);
+                        }
+                        *current_version = CURRENT_STORAGE_VERSION;
+                        // Return the weight consumed by the migration.
+                        Ok::<Weight, DispatchError>(T::DbWeight::get().reads_writes(
+                            2,
+                            (history_len.saturating_sub(skipped) as u64).saturating_add(3),
+                        ))
+                    },
+                    // Add more migration cases here, if needed in the future
+                    _ => {
+                        // No migration needed.
+                        Ok::<Weight, DispatchError>(Default::default())
+                    },
+                }
+            })
+            .unwrap_or_default()
+        }
+    }
+
+    /// Storage items replaced by newer layouts, kept only to be migrated.
+    /// The unbounded history vector lives under the prefix key of the ring buffer map replacing it.
+    pub mod deprecated {
+        use super::*;
+
+        #[frame_support::storage_alias]
+        pub type DistributionHistory<T: Config> = StorageValue<
+            Pallet<T>,
+            Vec<DistributionRecord<BlockNumberFor<T>, BalanceOf<T>>>,
+            ValueQuery,
+        >;
     }
 
     // The genesis config type.
@@ -948,6 +1743,18 @@ pub mod pallet {
     }
 }
 
+/// Id under which the settlements accumulation is registered as a round handler with the clock.
+pub const SETTLEMENTS_ROUND_HANDLER: RoundHandlerId = *b"rwdsettl";
+
+impl<T: Config> OnNewRound<T> for Pallet<T> {
+    fn on_new_round(
+        past_round: RoundInfo<BlockNumberFor<T>>,
+        _new_round: RoundInfo<BlockNumberFor<T>>,
+    ) -> Weight {
+        Self::process_round_settlements(past_round)
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use frame_support::{
@@ -956,19 +1763,23 @@ pub mod test {
     };
     use sp_core::H256;
 
-    use sp_runtime::Percent;
+    use sp_runtime::{Perbill, Percent};
+
+    use crate::{deprecated, DistributionRecord, EarningsEntry};
     use t3rn_mini_mock_runtime::{
-        AccountId, Authors, AuthorsThisPeriod, Balance, Balances, Clock, ConfigRewards,
-        DistributionHistory, ExtBuilder, MiniRuntime, PendingClaims, Rewards, RewardsError,
-        RuntimeOrigin, SettlementsPerRound, System,
+        AccountId, AccountManager, AccumulatedSettlements, AssetType, Authors, AuthorsThisPeriod,
+        Balance, Balances, BootstrapDistributions, BootstrapExecutorsCursor,
+        BootstrapPoolDistributed, Clock, ConfigRewards, DistributionHistory,
+        DistributionHistoryCount, EarningsLedger, ExtBuilder, MiniRuntime, PendingClaims, Rewards,
+        RewardsError, RoundHandlersWeightLimit, RuntimeOrigin, SettlementsPerRound, System,
     };
 
     use t3rn_primitives::{
-        account_manager::{Outcome, Settlement},
+        account_manager::{AccountManager as AccountManagerApi, Outcome, Settlement},
         circuit::{Cause, CircuitStatus, FullSideEffect, SecurityLvl, SideEffect},
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
         clock::Clock as ClockApi,
-        rewards::RewardsWriteApi,
+        rewards::{AmbassadorsReadApi, RewardsWriteApi},
         TreasuryAccount, TreasuryAccountProvider,
     };
     #[test]
@@ -999,10 +1810,8 @@ pub mod test {
                 Clock::on_initialize(distribution_period * cnt);
 
                 // Retrieve the last distribution record
-                let history = DistributionHistory::<MiniRuntime>::get();
-
-                assert_eq!(history.len(), cnt as usize);
-                let last_record = history.last().unwrap();
+                assert_eq!(DistributionHistoryCount::<MiniRuntime>::get(), cnt);
+                let last_record = Rewards::last_distribution_record().unwrap();
 
                 // Add this round's rewards to the total
                 actual_total_rewards += last_record.distributed;
@@ -1030,8 +1839,7 @@ pub mod test {
             Clock::on_initialize(distribution_period);
 
             // Retrieve the last distribution record
-            let history = DistributionHistory::<MiniRuntime>::get();
-            let last_record = history.last().unwrap();
+            let last_record = Rewards::last_distribution_record().unwrap();
 
             let treasury_account =
                 <MiniRuntime as TreasuryAccountProvider<AccountId>>::get_treasury_account(
@@ -1097,6 +1905,42 @@ pub mod test {
         });
     }
 
+    #[test]
+    fn test_settlements_of_the_past_round_are_accumulated_by_the_round_handler() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let executor = AccountId::from([1u8; 32]);
+            SettlementsPerRound::<MiniRuntime>::insert(
+                Clock::current_round(),
+                H256::repeat_byte(1),
+                Settlement {
+                    requester: AccountId::from([101u8; 32]),
+                    recipient: executor.clone(),
+                    settlement_amount: 100 as Balance,
+                    outcome: Outcome::Commit,
+                    source: BenefitSource::TrafficRewards,
+                    role: CircuitRole::Executor,
+                    maybe_asset_id: None,
+                },
+            );
+
+            System::set_block_number(300u32);
+            Clock::check_bump_round(300u32);
+            Clock::process_round_handlers(300u32, RoundHandlersWeightLimit::get());
+
+            assert_eq!(
+                Rewards::get_pending_claims(executor.clone()),
+                Some(vec![ClaimableArtifacts {
+                    beneficiary: executor,
+                    role: CircuitRole::Executor,
+                    total_round_claim: 100 as Balance,
+                    benefit_source: BenefitSource::TrafficRewards,
+                    non_native_asset_id: None,
+                },])
+            );
+        });
+    }
+
     #[test]
     fn test_distribution_to_executors_subsidies_settlement_proportionally_with_others() {
         let mut ext = ExtBuilder::default().build();
@@ -1299,9 +2143,9 @@ pub mod test {
             }
 
             // As per the above, the first author should get 26361491056934 + 31 other authors 26369862750000
-            let distribution_history = DistributionHistory::<MiniRuntime>::get();
-            assert!(distribution_history.last().is_some());
-            let last_distribution_entry = distribution_history.last().unwrap();
+            let last_distribution_entry = Rewards::last_distribution_record();
+            assert!(last_distribution_entry.is_some());
+            let last_distribution_entry = last_distribution_entry.unwrap();
 
             assert_eq!(
                 last_distribution_entry.collator_rewards,
@@ -1714,4 +2558,683 @@ pub mod test {
             assert_eq!(Rewards::get_pending_claims(executor), None);
         });
     }
+
+    #[test]
+    fn test_inflation_claims_vest_linearly_while_traffic_rewards_pay_out_immediately() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let beneficiary = AccountId::from([99u8; 32]);
+            const INITIAL_BALANCE: Balance = 1;
+            Balances::deposit_creating(&beneficiary, INITIAL_BALANCE);
+
+            assert_ok!(Rewards::set_vesting_rule(
+                RuntimeOrigin::root(),
+                BenefitSource::Inflation,
+                Some(100),
+            ));
+
+            Rewards::add_pending_claim(
+                &beneficiary,
+                CircuitRole::Executor,
+                1000,
+                BenefitSource::Inflation,
+                None,
+            );
+            Rewards::add_pending_claim(
+                &beneficiary,
+                CircuitRole::Executor,
+                100,
+                BenefitSource::TrafficRewards,
+                None,
+            );
+
+            assert_ok!(Rewards::claim(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                None
+            ));
+
+            // Only the traffic rewards are paid out, the inflation rewards start vesting
+            assert_eq!(
+                Balances::free_balance(&beneficiary),
+                100 as Balance + INITIAL_BALANCE
+            );
+            assert_eq!(
+                Rewards::get_pending_claims(beneficiary.clone()),
+                Some(vec![])
+            );
+            assert_eq!(
+                Rewards::vesting_balances(&beneficiary),
+                vec![(CircuitRole::Executor, 1000, 0)]
+            );
+            assert_err!(
+                Rewards::claim_vested(RuntimeOrigin::signed(beneficiary.clone()), None),
+                RewardsError::<MiniRuntime>::NoVestedRewards
+            );
+
+            // Half of the vesting period elapsed
+            System::set_block_number(51);
+            assert_eq!(
+                Rewards::vesting_balances(&beneficiary),
+                vec![(CircuitRole::Executor, 500, 500)]
+            );
+            assert_ok!(Rewards::claim_vested(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                Some(CircuitRole::Executor)
+            ));
+            assert_eq!(
+                Balances::free_balance(&beneficiary),
+                600 as Balance + INITIAL_BALANCE
+            );
+            assert_eq!(
+                Rewards::vesting_balances(&beneficiary),
+                vec![(CircuitRole::Executor, 500, 0)]
+            );
+
+            // The whole vesting period elapsed
+            System::set_block_number(200);
+            assert_ok!(Rewards::claim_vested(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                None
+            ));
+            assert_eq!(
+                Balances::free_balance(&beneficiary),
+                1100 as Balance + INITIAL_BALANCE
+            );
+            assert_eq!(Rewards::get_vesting_schedules(beneficiary.clone()), vec![]);
+            assert_err!(
+                Rewards::claim_vested(RuntimeOrigin::signed(beneficiary), None),
+                RewardsError::<MiniRuntime>::NoVestedRewards
+            );
+        });
+    }
+
+    #[test]
+    fn test_vesting_schedules_are_bounded_and_claim_vested_is_charged_per_schedule() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let beneficiary = AccountId::from([99u8; 32]);
+            Balances::deposit_creating(&beneficiary, 1);
+
+            assert_ok!(Rewards::set_vesting_rule(
+                RuntimeOrigin::root(),
+                BenefitSource::Inflation,
+                Some(100),
+            ));
+
+            let max_schedules = <MiniRuntime as ConfigRewards>::MaxVestingSchedules::get();
+            for _ in 0..max_schedules {
+                Rewards::add_pending_claim(
+                    &beneficiary,
+                    CircuitRole::Executor,
+                    100,
+                    BenefitSource::Inflation,
+                    None,
+                );
+                assert_ok!(Rewards::claim(
+                    RuntimeOrigin::signed(beneficiary.clone()),
+                    None
+                ));
+            }
+            assert_eq!(
+                Rewards::get_vesting_schedules(beneficiary.clone()).len() as u32,
+                max_schedules
+            );
+
+            // Claims beyond the bound join the oldest schedule of the same role, source and asset
+            Rewards::add_pending_claim(
+                &beneficiary,
+                CircuitRole::Executor,
+                100,
+                BenefitSource::Inflation,
+                None,
+            );
+            assert_ok!(Rewards::claim(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                None
+            ));
+            let schedules = Rewards::get_vesting_schedules(beneficiary.clone());
+            assert_eq!(schedules.len() as u32, max_schedules);
+            assert_eq!(schedules[0].total, 200);
+            assert_eq!(schedules[1].total, 100);
+
+            // Claims with no schedule to join stay pending, without holding up the other claims
+            Rewards::add_pending_claim(
+                &beneficiary,
+                CircuitRole::Attester,
+                100,
+                BenefitSource::Inflation,
+                None,
+            );
+            Rewards::add_pending_claim(
+                &beneficiary,
+                CircuitRole::Executor,
+                50,
+                BenefitSource::TrafficRewards,
+                None,
+            );
+            assert_ok!(Rewards::claim(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                None
+            ));
+            assert_eq!(Balances::free_balance(&beneficiary), 51);
+            let pending_claims =
+                Rewards::get_pending_claims(beneficiary.clone()).unwrap_or_default();
+            assert_eq!(pending_claims.len(), 1);
+            assert_eq!(pending_claims[0].role, CircuitRole::Attester);
+
+            System::set_block_number(200);
+            let post_info =
+                Rewards::claim_vested(RuntimeOrigin::signed(beneficiary.clone()), None).unwrap();
+            assert_eq!(
+                post_info.actual_weight,
+                Some(Rewards::claim_vested_weight(max_schedules))
+            );
+            assert_eq!(Rewards::get_vesting_schedules(beneficiary.clone()), vec![]);
+
+            // Claiming the vested rewards frees up the schedules
+            assert_ok!(Rewards::claim(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                None
+            ));
+            assert_eq!(Rewards::get_vesting_schedules(beneficiary).len(), 1);
+        });
+    }
+
+    fn history_depth() -> u32 {
+        <MiniRuntime as ConfigRewards>::DistributionHistoryDepth::get()
+    }
+
+    fn distribution_record(block_number: u32) -> DistributionRecord<u32, Balance> {
+        DistributionRecord {
+            block_number,
+            attester_rewards: 1,
+            collator_rewards: 2,
+            executor_rewards: 3,
+            treasury_rewards: 4,
+            available: 10,
+            distributed: 10,
+        }
+    }
+
+    #[test]
+    fn test_distribution_history_is_a_bounded_paginated_ring_buffer() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_eq!(Rewards::last_distribution_record(), None);
+
+            for block_number in 0..history_depth() + 10 {
+                Rewards::push_distribution_record(distribution_record(block_number));
+            }
+
+            assert_eq!(
+                DistributionHistoryCount::<MiniRuntime>::get(),
+                history_depth() + 10
+            );
+            assert_eq!(
+                DistributionHistory::<MiniRuntime>::iter_keys().count(),
+                history_depth() as usize
+            );
+            assert_eq!(
+                Rewards::last_distribution_record(),
+                Some(distribution_record(history_depth() + 9))
+            );
+
+            // The 10 oldest records were overwritten
+            let first_page = Rewards::distribution_history(0, 3);
+            assert_eq!(
+                first_page,
+                vec![
+                    (10, distribution_record(10)),
+                    (11, distribution_record(11)),
+                    (12, distribution_record(12)),
+                ]
+            );
+
+            let last_page = Rewards::distribution_history(history_depth() + 8, 5);
+            assert_eq!(
+                last_page,
+                vec![
+                    (
+                        history_depth() + 8,
+                        distribution_record(history_depth() + 8)
+                    ),
+                    (
+                        history_depth() + 9,
+                        distribution_record(history_depth() + 9)
+                    ),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_unbounded_distribution_history_is_migrated_into_ring_buffer() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let old_history = (0..history_depth() + 2)
+                .map(distribution_record)
+                .collect::<Vec<_>>();
+            deprecated::DistributionHistory::<MiniRuntime>::put(old_history);
+
+            Rewards::on_runtime_upgrade();
+
+            assert!(!deprecated::DistributionHistory::<MiniRuntime>::exists());
+            assert_eq!(Rewards::storage_migrations_done(), 1);
+            assert_eq!(
+                DistributionHistoryCount::<MiniRuntime>::get(),
+                history_depth()
+            );
+            assert_eq!(
+                Rewards::distribution_history(0, 1),
+                vec![(0, distribution_record(2))]
+            );
+            assert_eq!(
+                Rewards::last_distribution_record(),
+                Some(distribution_record(history_depth() + 1))
+            );
+
+            // Running the upgrade again doesn't touch the migrated history
+            Rewards::on_runtime_upgrade();
+            assert_eq!(
+                DistributionHistoryCount::<MiniRuntime>::get(),
+                history_depth()
+            );
+        });
+    }
+
+    #[test]
+    fn test_earnings_are_noted_in_ledger_per_earned_round_role_and_source() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let beneficiary = AccountId::from([99u8; 32]);
+            Balances::deposit_creating(&beneficiary, 1);
+
+            let round = Clock::current_round().index;
+            for (role, amount, benefit_source) in [
+                (CircuitRole::Executor, 100, BenefitSource::TrafficRewards),
+                (CircuitRole::Executor, 50, BenefitSource::Inflation),
+                (CircuitRole::Collator, 20, BenefitSource::Inflation),
+                (CircuitRole::Executor, 10, BenefitSource::TrafficRewards),
+            ] {
+                Rewards::add_pending_claim(&beneficiary, role, amount, benefit_source, None);
+            }
+
+            // Claiming in a later round leaves the earnings under the round they were earned in
+            System::set_block_number(300u32);
+            Clock::check_bump_round(300u32);
+            assert_ok!(Rewards::claim(
+                RuntimeOrigin::signed(beneficiary.clone()),
+                None
+            ));
+            assert_eq!(
+                EarningsLedger::<MiniRuntime>::get(&beneficiary, round + 1),
+                vec![]
+            );
+
+            Rewards::add_pending_claim(
+                &beneficiary,
+                CircuitRole::Executor,
+                5,
+                BenefitSource::TrafficRewards,
+                None,
+            );
+            let next_round_entries = vec![EarningsEntry {
+                role: CircuitRole::Executor,
+                benefit_source: BenefitSource::TrafficRewards,
+                non_native_asset_id: None,
+                amount: 5,
+            }];
+
+            let expected_entries = vec![
+                EarningsEntry {
+                    role: CircuitRole::Executor,
+                    benefit_source: BenefitSource::TrafficRewards,
+                    non_native_asset_id: None,
+                    amount: 110,
+                },
+                EarningsEntry {
+                    role: CircuitRole::Executor,
+                    benefit_source: BenefitSource::Inflation,
+                    non_native_asset_id: None,
+                    amount: 50,
+                },
+                EarningsEntry {
+                    role: CircuitRole::Collator,
+                    benefit_source: BenefitSource::Inflation,
+                    non_native_asset_id: None,
+                    amount: 20,
+                },
+            ];
+            assert_eq!(
+                EarningsLedger::<MiniRuntime>::get(&beneficiary, round),
+                expected_entries
+            );
+            assert_eq!(
+                Rewards::earnings(&beneficiary, 0, round + 2),
+                vec![(round, expected_entries), (round + 1, next_round_entries)]
+            );
+            assert_eq!(Rewards::earnings(&beneficiary, round + 2, 10), vec![]);
+        });
+    }
+
+    #[test]
+    fn test_referral_share_of_executor_fee_is_claimable_by_registered_ambassador() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let requester = AccountId::from([101u8; 32]);
+            let executor = AccountId::from([1u8; 32]);
+            let ambassador = AccountId::from([7u8; 32]);
+            let sfx_id = H256::from([1u8; 32]);
+            let referral_id = H256::from([2u8; 32]);
+
+            assert_ok!(Rewards::register_ambassador(
+                RuntimeOrigin::root(),
+                ambassador.clone()
+            ));
+            assert_err!(
+                Rewards::register_ambassador(RuntimeOrigin::root(), ambassador.clone()),
+                RewardsError::<MiniRuntime>::AmbassadorAlreadyRegistered
+            );
+            assert_ok!(Rewards::set_ambassador_rewards_share(
+                RuntimeOrigin::root(),
+                Percent::from_percent(10)
+            ));
+            assert!(<Rewards as AmbassadorsReadApi<AccountId>>::is_ambassador(
+                &ambassador
+            ));
+            assert_eq!(
+                <Rewards as AmbassadorsReadApi<AccountId>>::ambassador_share(),
+                Percent::from_percent(10)
+            );
+
+            SettlementsPerRound::<MiniRuntime>::insert(
+                Clock::current_round(),
+                sfx_id,
+                Settlement {
+                    requester,
+                    recipient: executor.clone(),
+                    settlement_amount: 100 as Balance,
+                    outcome: Outcome::Commit,
+                    source: BenefitSource::TrafficFees,
+                    role: CircuitRole::Executor,
+                    maybe_asset_id: None,
+                },
+            );
+            assert!(<AccountManager as AccountManagerApi<
+                AccountId,
+                Balance,
+                H256,
+                u32,
+                u32,
+            >>::split_settlement(
+                sfx_id,
+                referral_id,
+                &ambassador,
+                CircuitRole::Ambassador,
+                10 as Balance,
+            ));
+
+            Rewards::process_accumulated_settlements();
+
+            assert_eq!(
+                Rewards::get_pending_claims(executor.clone()),
+                Some(vec![ClaimableArtifacts {
+                    beneficiary: executor,
+                    role: CircuitRole::Executor,
+                    total_round_claim: 90 as Balance,
+                    benefit_source: BenefitSource::TrafficRewards,
+                    non_native_asset_id: None,
+                }])
+            );
+            assert_eq!(
+                Rewards::get_pending_claims(ambassador.clone()),
+                Some(vec![ClaimableArtifacts {
+                    beneficiary: ambassador.clone(),
+                    role: CircuitRole::Ambassador,
+                    total_round_claim: 10 as Balance,
+                    benefit_source: BenefitSource::TrafficRewards,
+                    non_native_asset_id: None,
+                }])
+            );
+
+            assert_ok!(Rewards::deregister_ambassador(
+                RuntimeOrigin::root(),
+                ambassador.clone()
+            ));
+            assert!(!<Rewards as AmbassadorsReadApi<AccountId>>::is_ambassador(
+                &ambassador
+            ));
+            assert_err!(
+                Rewards::deregister_ambassador(RuntimeOrigin::root(), ambassador),
+                RewardsError::<MiniRuntime>::AmbassadorNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn test_bootstrap_pool_allowances_decay_and_total_to_available_spenditure() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let available = <MiniRuntime as ConfigRewards>::AvailableBootstrapSpenditure::get();
+
+            let total_distributions =
+                <MiniRuntime as ConfigRewards>::TotalBootstrapDistributions::get();
+
+            let mut distributed = 0 as Balance;
+            let mut last_allowance = Balance::MAX;
+            for distribution_index in 0..total_distributions {
+                let allowance = Rewards::bootstrap_allowance(distribution_index, distributed);
+                assert!(allowance > 0);
+                assert!(allowance <= last_allowance);
+                last_allowance = allowance;
+                distributed += allowance;
+            }
+
+            assert_eq!(distributed, available);
+            assert_eq!(
+                Rewards::bootstrap_allowance(total_distributions, distributed),
+                0
+            );
+        });
+    }
+
+    #[test]
+    fn test_bootstrap_rewards_distributed_to_collators_and_executors_by_activity() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let available = <MiniRuntime as ConfigRewards>::AvailableBootstrapSpenditure::get();
+            let treasury_account = MiniRuntime::get_treasury_account(TreasuryAccount::Treasury);
+            Balances::deposit_creating(&treasury_account, available * 2);
+
+            let collator_a = AccountId::from([1u8; 32]);
+            let collator_b = AccountId::from([2u8; 32]);
+            let executor_c = AccountId::from([3u8; 32]);
+            let executor_d = AccountId::from([4u8; 32]);
+
+            AuthorsThisPeriod::<MiniRuntime>::put(
+                vec![(collator_a.clone(), 3u32), (collator_b.clone(), 1u32)]
+                    .into_iter()
+                    .collect::<std::collections::BTreeMap<_, _>>(),
+            );
+            AccumulatedSettlements::<MiniRuntime>::insert(
+                &executor_c,
+                AssetType::<u32>::Native,
+                300 as Balance,
+            );
+            AccumulatedSettlements::<MiniRuntime>::insert(
+                &executor_d,
+                AssetType::<u32>::Native,
+                100 as Balance,
+            );
+
+            let allowance = Rewards::bootstrap_allowance(0, 0);
+            let collator_share = Percent::from_percent(20).mul_floor(allowance);
+            let executor_share = Percent::from_percent(40).mul_floor(allowance);
+
+            Rewards::distribute_bootstrap_rewards();
+
+            let expected_claims = vec![
+                (
+                    collator_a,
+                    CircuitRole::Collator,
+                    Perbill::from_percent(75).mul_floor(collator_share),
+                ),
+                (
+                    collator_b,
+                    CircuitRole::Collator,
+                    Perbill::from_percent(25).mul_floor(collator_share),
+                ),
+                (
+                    executor_c,
+                    CircuitRole::Executor,
+                    Perbill::from_percent(75).mul_floor(executor_share),
+                ),
+                (
+                    executor_d,
+                    CircuitRole::Executor,
+                    Perbill::from_percent(25).mul_floor(executor_share),
+                ),
+            ];
+
+            let mut total_paid = 0 as Balance;
+            for (beneficiary, role, reward) in expected_claims {
+                assert_eq!(
+                    Rewards::get_pending_claims(beneficiary.clone()),
+                    Some(vec![ClaimableArtifacts {
+                        beneficiary,
+                        role,
+                        total_round_claim: reward,
+                        benefit_source: BenefitSource::BootstrapPool,
+                        non_native_asset_id: None,
+                    }])
+                );
+                total_paid += reward;
+            }
+
+            // No attesters are active, so their share stays in the treasury
+            assert_eq!(
+                Balances::free_balance(&treasury_account),
+                available * 2 - total_paid
+            );
+            assert_eq!(BootstrapDistributions::<MiniRuntime>::get(), 1);
+            assert_eq!(BootstrapPoolDistributed::<MiniRuntime>::get(), total_paid);
+        });
+    }
+
+    #[test]
+    fn test_bootstrap_executor_payouts_are_paginated_across_distributions() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let available = <MiniRuntime as ConfigRewards>::AvailableBootstrapSpenditure::get();
+            let treasury_account = MiniRuntime::get_treasury_account(TreasuryAccount::Treasury);
+            Balances::deposit_creating(&treasury_account, available * 2);
+            assert_eq!(
+                <MiniRuntime as ConfigRewards>::MaxBootstrapExecutorPayouts::get(),
+                2
+            );
+
+            let executors: Vec<AccountId> = (3u8..6).map(|i| AccountId::from([i; 32])).collect();
+            for executor in executors.iter() {
+                AccumulatedSettlements::<MiniRuntime>::insert(
+                    executor,
+                    AssetType::<u32>::Native,
+                    100 as Balance,
+                );
+            }
+            let executor_share = |distribution_index| {
+                Percent::from_percent(40).mul_floor(Rewards::bootstrap_allowance(
+                    distribution_index,
+                    BootstrapPoolDistributed::<MiniRuntime>::get(),
+                ))
+            };
+            let executor_claim = |executor: &AccountId| {
+                Rewards::get_pending_claims(executor.clone())
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|claim| claim.total_round_claim)
+                    .collect::<Vec<_>>()
+            };
+
+            // The first page only covers the first two executors, splitting the share between them
+            let first_share = executor_share(0);
+            Rewards::distribute_bootstrap_rewards();
+            assert_eq!(
+                executor_claim(&executors[0]),
+                vec![Perbill::from_percent(50).mul_floor(first_share)]
+            );
+            assert_eq!(
+                executor_claim(&executors[1]),
+                vec![Perbill::from_percent(50).mul_floor(first_share)]
+            );
+            assert_eq!(executor_claim(&executors[2]), vec![]);
+            assert!(BootstrapExecutorsCursor::<MiniRuntime>::get().is_some());
+
+            // The next distribution continues with the remaining executor and wraps around
+            let second_share = executor_share(1);
+            Rewards::distribute_bootstrap_rewards();
+            assert_eq!(executor_claim(&executors[2]), vec![second_share]);
+            assert_eq!(executor_claim(&executors[0]).len(), 1);
+            assert_eq!(BootstrapExecutorsCursor::<MiniRuntime>::get(), None);
+        });
+    }
+
+    #[test]
+    fn test_bootstrap_rewards_stop_once_pool_is_depleted() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let available = <MiniRuntime as ConfigRewards>::AvailableBootstrapSpenditure::get();
+            let treasury_account = MiniRuntime::get_treasury_account(TreasuryAccount::Treasury);
+            Balances::deposit_creating(&treasury_account, available * 2);
+
+            let collator = AccountId::from([1u8; 32]);
+            AuthorsThisPeriod::<MiniRuntime>::put(
+                vec![(collator.clone(), 1u32)]
+                    .into_iter()
+                    .collect::<std::collections::BTreeMap<_, _>>(),
+            );
+
+            let total_distributions =
+                <MiniRuntime as ConfigRewards>::TotalBootstrapDistributions::get();
+            for _ in 0..total_distributions + 2 {
+                Rewards::distribute_bootstrap_rewards();
+            }
+
+            assert!(Rewards::is_bootstrap_pool_depleted());
+            assert_eq!(
+                BootstrapDistributions::<MiniRuntime>::get(),
+                total_distributions
+            );
+
+            let distributed = BootstrapPoolDistributed::<MiniRuntime>::get();
+            assert!(distributed <= available);
+            assert_eq!(
+                Rewards::get_pending_claims(collator).map(|claims| claims.len()),
+                Some(total_distributions as usize)
+            );
+            assert_eq!(
+                Balances::free_balance(&treasury_account),
+                available * 2 - distributed
+            );
+        });
+    }
+
+    #[test]
+    fn test_bootstrap_rewards_skipped_with_empty_treasury() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let collator = AccountId::from([1u8; 32]);
+            AuthorsThisPeriod::<MiniRuntime>::put(
+                vec![(collator.clone(), 1u32)]
+                    .into_iter()
+                    .collect::<std::collections::BTreeMap<_, _>>(),
+            );
+
+            Rewards::distribute_bootstrap_rewards();
+
+            assert_eq!(Rewards::get_pending_claims(collator), None);
+            assert_eq!(BootstrapDistributions::<MiniRuntime>::get(), 0);
+        });
+    }
 }
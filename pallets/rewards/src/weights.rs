@@ -0,0 +1,36 @@
+//! Weights for pallet_rewards
+//!
+//! Estimated, not yet regenerated from the benchmarks in `benchmarking.rs`.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_rewards.
+pub trait WeightInfo {
+    fn set_vesting_rule() -> Weight;
+}
+
+/// Weights for pallet_rewards using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Estimated until regenerated from the `set_vesting_rule` benchmark.
+    // Storage: Rewards VestingRules (r:0 w:1)
+    fn set_vesting_rule() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    // Estimated until regenerated from the `set_vesting_rule` benchmark.
+    // Storage: Rewards VestingRules (r:0 w:1)
+    fn set_vesting_rule() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,207 @@
+//! Node CLI sub-commands moving the XDNS topology between the chain and readable JSON/TOML files.
+
+use codec::{Decode, Encode};
+use log::info;
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{StorageProvider, UsageProvider};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{fmt::Debug, path::PathBuf, str::FromStr, sync::Arc};
+
+pub mod topology;
+
+pub use topology::TopologyFile;
+use topology::XDNS_PALLET_PREFIX;
+
+/// XDNS topology sub-commands.
+#[derive(Debug, clap::Subcommand)]
+pub enum XdnsTopologyCmd {
+    /// Export the XDNS topology from the node's state or a chain spec into a JSON/TOML file.
+    Export(ExportTopologyCmd),
+
+    /// Validate a JSON/TOML XDNS topology file.
+    Validate(ValidateTopologyCmd),
+
+    /// Import a JSON/TOML XDNS topology file into the genesis of a plain chain spec.
+    Import(ImportTopologyCmd),
+}
+
+/// The `xdns export` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportTopologyCmd {
+    /// Topology file to write, in the format given by its `.json` or `.toml` extension.
+    #[arg(long, short, value_name = "PATH")]
+    pub output: PathBuf,
+
+    /// Read the topology from the given plain or raw chain spec instead of the node's state.
+    #[arg(long, value_name = "PATH")]
+    pub from_spec: Option<PathBuf>,
+
+    /// Block hash or number to read the node's state at. Defaults to the best block.
+    #[arg(long, value_name = "HASH or NUMBER")]
+    pub at: Option<BlockNumberOrHash>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+/// The `xdns validate` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ValidateTopologyCmd {
+    /// Topology file to validate.
+    #[arg(value_name = "PATH")]
+    pub topology: PathBuf,
+}
+
+/// The `xdns import` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ImportTopologyCmd {
+    /// Topology file to import.
+    #[arg(value_name = "PATH")]
+    pub topology: PathBuf,
+
+    /// Plain chain spec to import the topology into.
+    #[arg(long, value_name = "PATH")]
+    pub spec: PathBuf,
+
+    /// Chain spec to write. Defaults to overwriting the input chain spec.
+    #[arg(long, short, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+fn read_chain_spec(path: &PathBuf) -> sc_cli::Result<serde_json::Value> {
+    let content = std::fs::read(path)?;
+    serde_json::from_slice(&content)
+        .map_err(|e| format!("failed to parse chain spec {}: {e}", path.display()).into())
+}
+
+fn ensure_valid<AccountId>(topology: &TopologyFile<AccountId>) -> sc_cli::Result<()>
+where
+    AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned,
+{
+    topology
+        .validate()
+        .map_err(|errors| format!("invalid XDNS topology:\n  {}", errors.join("\n  ")).into())
+}
+
+impl ExportTopologyCmd {
+    /// Export the topology read from the node's state.
+    pub async fn run<AccountId, B, BA, C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+    where
+        AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned,
+        B: BlockT,
+        C: UsageProvider<B> + StorageProvider<B, BA> + HeaderBackend<B>,
+        BA: sc_client_api::backend::Backend<B>,
+        <B::Hash as FromStr>::Err: Debug,
+        NumberFor<B>: FromStr,
+        <NumberFor<B> as FromStr>::Err: Debug,
+    {
+        let hash = match self.at.as_ref().map(|at| at.parse::<B>()).transpose()? {
+            Some(id) => client.expect_block_hash_from_id(&id)?,
+            None => client.usage_info().chain.best_hash,
+        };
+        info!("Exporting XDNS topology at block {:?}...", hash);
+
+        let prefix = StorageKey(twox_128(XDNS_PALLET_PREFIX).to_vec());
+        let pairs = client
+            .storage_pairs(hash, Some(&prefix), None)?
+            .map(|(key, value)| (key.0, value.0));
+        let topology = TopologyFile::<AccountId>::from_storage(pairs)?;
+
+        self.write(topology)
+    }
+
+    /// Export the topology read from the chain spec given with `--from-spec`.
+    pub fn run_from_spec<AccountId>(&self) -> sc_cli::Result<()>
+    where
+        AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned,
+    {
+        let spec_path = self
+            .from_spec
+            .as_ref()
+            .ok_or("export from a chain spec requires --from-spec")?;
+        let topology = TopologyFile::<AccountId>::from_chain_spec(&read_chain_spec(spec_path)?)?;
+        self.write(topology)
+    }
+
+    fn write<AccountId>(&self, topology: TopologyFile<AccountId>) -> sc_cli::Result<()>
+    where
+        AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned,
+    {
+        if let Err(errors) = topology.validate() {
+            errors
+                .iter()
+                .for_each(|error| log::warn!("exported XDNS topology: {}", error));
+        }
+        topology.write(&self.output)?;
+        println!(
+            "Exported {} gateways, {} tokens and {} SFX ABIs into {}",
+            topology.gateways.len(),
+            topology.tokens.len(),
+            topology.sfx_abis.len(),
+            self.output.display()
+        );
+        Ok(())
+    }
+}
+
+impl ValidateTopologyCmd {
+    pub fn run<AccountId>(&self) -> sc_cli::Result<()>
+    where
+        AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned,
+    {
+        let topology = TopologyFile::<AccountId>::read(&self.topology)?;
+        ensure_valid(&topology)?;
+        println!("XDNS topology {} is valid", self.topology.display());
+        Ok(())
+    }
+}
+
+impl ImportTopologyCmd {
+    pub fn run<AccountId>(&self) -> sc_cli::Result<()>
+    where
+        AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned,
+    {
+        let topology = TopologyFile::<AccountId>::read(&self.topology)?;
+        ensure_valid(&topology)?;
+
+        let mut spec = read_chain_spec(&self.spec)?;
+        topology.import_into_chain_spec(&mut spec)?;
+
+        let output = self.output.as_ref().unwrap_or(&self.spec);
+        let content = serde_json::to_string_pretty(&spec)
+            .map_err(|e| format!("failed to serialize chain spec: {e}"))?;
+        std::fs::write(output, content)?;
+        println!(
+            "Imported XDNS topology {} into {}",
+            self.topology.display(),
+            output.display()
+        );
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ExportTopologyCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
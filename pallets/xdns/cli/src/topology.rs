@@ -0,0 +1,755 @@
+//! Human readable XDNS topology file, mirroring the gateways, their ABIs and versions, tokens,
+//! SFX ABIs and remote order/bidding addresses stored by pallet-xdns.
+
+use circuit_runtime_types::AssetId;
+use codec::{Decode, Encode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use sp_core::{hashing::twox_128, H256};
+use std::{collections::BTreeSet, path::Path};
+use t3rn_abi::{Codec, SFXAbi};
+use t3rn_primitives::{
+    gateway::GatewayABIConfig,
+    xdns::{GatewayRecord, TokenRecord},
+    ChainId, ExecutionVendor, GatewayVendor, TokenInfo,
+};
+
+/// Name of pallet-xdns in `construct_runtime!` of all t3rn runtimes.
+pub const XDNS_PALLET_PREFIX: &[u8] = b"XDNS";
+
+/// Serializes 4 bytes ids as readable strings, e.g. "roco" or "tran".
+/// Ids that aren't printable ASCII are written as hex, e.g. "0x03030303".
+pub mod four_byte_id {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn to_string(id: &[u8; 4]) -> String {
+        match std::str::from_utf8(id) {
+            Ok(id_str) if id_str.chars().all(|c| c.is_ascii_graphic()) => id_str.to_string(),
+            _ => format!("0x{}", hex::encode(id)),
+        }
+    }
+
+    pub fn from_str(id: &str) -> Result<[u8; 4], String> {
+        let bytes = match id.strip_prefix("0x") {
+            Some(hex_id) => hex::decode(hex_id).map_err(|e| format!("invalid hex id {id}: {e}"))?,
+            None => id.as_bytes().to_vec(),
+        };
+        bytes
+            .try_into()
+            .map_err(|_| format!("id {id} must be exactly 4 bytes long"))
+    }
+
+    pub fn serialize<S: Serializer>(id: &[u8; 4], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_string(id))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 4], D::Error> {
+        from_str(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowedSideEffect {
+    #[serde(with = "four_byte_id")]
+    pub id: [u8; 4],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pallet_index_memo: Option<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GatewayEntry<AccountId> {
+    #[serde(with = "four_byte_id")]
+    pub gateway_id: ChainId,
+    pub verification_vendor: GatewayVendor,
+    pub execution_vendor: ExecutionVendor,
+    pub codec: Codec,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registrant: Option<AccountId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escrow_account: Option<AccountId>,
+    #[serde(default)]
+    pub allowed_side_effects: Vec<AllowedSideEffect>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_order_address: Option<H256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_bidding_address: Option<H256>,
+    /// Hasher, crypto and type sizes; the execution vendor's default ABI applies if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway_abi: Option<GatewayABIConfig>,
+    /// Latest version of the gateway's record and SFX ABIs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// SFX ABIs registered for this gateway, including the ones derived from the standard ABIs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sfx_abis: Vec<SfxAbiEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenEntry {
+    pub token_id: AssetId,
+    #[serde(with = "four_byte_id")]
+    pub gateway_id: ChainId,
+    pub token_props: TokenInfo,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SfxAbiEntry {
+    #[serde(with = "four_byte_id")]
+    pub id: [u8; 4],
+    pub abi: SFXAbi,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopologyFile<AccountId> {
+    #[serde(default)]
+    pub gateways: Vec<GatewayEntry<AccountId>>,
+    #[serde(default)]
+    pub tokens: Vec<TokenEntry>,
+    #[serde(default)]
+    pub sfx_abis: Vec<SfxAbiEntry>,
+}
+
+impl<AccountId> Default for TopologyFile<AccountId> {
+    fn default() -> Self {
+        TopologyFile {
+            gateways: vec![],
+            tokens: vec![],
+            sfx_abis: vec![],
+        }
+    }
+}
+
+/// Supported topology file formats, recognized by the file extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TopologyFormat {
+    Json,
+    Toml,
+}
+
+impl TopologyFormat {
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Ok(TopologyFormat::Json),
+            Some("toml") => Ok(TopologyFormat::Toml),
+            _ => Err(format!(
+                "unknown topology format of {}, expected a .json or .toml file",
+                path.display()
+            )),
+        }
+    }
+}
+
+fn storage_prefix(storage_name: &[u8]) -> Vec<u8> {
+    [twox_128(XDNS_PALLET_PREFIX), twox_128(storage_name)].concat()
+}
+
+fn decode_bytes<V: Decode>(bytes: &[u8], what: &str) -> Result<V, String> {
+    V::decode(&mut &bytes[..]).map_err(|e| format!("failed to decode {what}: {e}"))
+}
+
+fn genesis_bytes(xdns: &Value, field: &str) -> Result<Vec<u8>, String> {
+    match xdns.get(field) {
+        None | Some(Value::Null) => Ok(vec![]),
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|e| format!("XDNS genesis field {field} isn't a byte array: {e}")),
+    }
+}
+
+fn decode_genesis_field<V: Decode + Default>(xdns: &Value, field: &str) -> Result<V, String> {
+    let bytes = genesis_bytes(xdns, field)?;
+    if bytes.is_empty() {
+        return Ok(V::default())
+    }
+    decode_bytes(&bytes, field)
+}
+
+/// XDNS items the topology is made of, as read from either the storage or the genesis config.
+struct TopologyParts<AccountId> {
+    gateway_records: Vec<GatewayRecord<AccountId>>,
+    tokens: Vec<TokenRecord>,
+    sfx_abis: Vec<([u8; 4], SFXAbi)>,
+    gateway_sfx_abis: Vec<(ChainId, [u8; 4], SFXAbi)>,
+    gateway_abis: Vec<(ChainId, GatewayABIConfig)>,
+    gateway_versions: Vec<(ChainId, u32)>,
+    remote_order_addresses: Vec<(ChainId, H256)>,
+    remote_bidding_addresses: Vec<(ChainId, H256)>,
+}
+
+fn value_of<V: Clone>(values: &[(ChainId, V)], gateway_id: &ChainId) -> Option<V> {
+    values
+        .iter()
+        .find(|(id, _)| id == gateway_id)
+        .map(|(_, value)| value.clone())
+}
+
+impl<AccountId: Clone + Encode + Decode + Serialize + DeserializeOwned> TopologyFile<AccountId> {
+    fn from_parts(parts: TopologyParts<AccountId>) -> Self {
+        let TopologyParts {
+            gateway_records,
+            tokens,
+            sfx_abis,
+            gateway_sfx_abis,
+            gateway_abis,
+            gateway_versions,
+            remote_order_addresses,
+            remote_bidding_addresses,
+        } = parts;
+
+        let gateways = gateway_records
+            .into_iter()
+            .map(|record| GatewayEntry {
+                remote_order_address: value_of(&remote_order_addresses, &record.gateway_id),
+                remote_bidding_address: value_of(&remote_bidding_addresses, &record.gateway_id),
+                gateway_abi: value_of(&gateway_abis, &record.gateway_id),
+                version: value_of(&gateway_versions, &record.gateway_id),
+                sfx_abis: {
+                    let mut entries: Vec<SfxAbiEntry> = gateway_sfx_abis
+                        .iter()
+                        .filter(|(gateway_id, ..)| gateway_id == &record.gateway_id)
+                        .map(|(_, id, abi)| SfxAbiEntry {
+                            id: *id,
+                            abi: abi.clone(),
+                        })
+                        .collect();
+                    entries.sort_by_key(|entry| entry.id);
+                    entries
+                },
+                gateway_id: record.gateway_id,
+                verification_vendor: record.verification_vendor,
+                execution_vendor: record.execution_vendor,
+                codec: record.codec,
+                registrant: record.registrant,
+                escrow_account: record.escrow_account,
+                allowed_side_effects: record
+                    .allowed_side_effects
+                    .into_iter()
+                    .map(|(id, pallet_index_memo)| AllowedSideEffect {
+                        id,
+                        pallet_index_memo,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let tokens = tokens
+            .into_iter()
+            .map(|token| TokenEntry {
+                token_id: token.token_id,
+                gateway_id: token.gateway_id,
+                token_props: token.token_props,
+            })
+            .collect();
+
+        let sfx_abis = sfx_abis
+            .into_iter()
+            .map(|(id, abi)| SfxAbiEntry { id, abi })
+            .collect();
+
+        TopologyFile {
+            gateways,
+            tokens,
+            sfx_abis,
+        }
+    }
+
+    /// Reads the topology out of the raw XDNS storage, e.g. the node's state or a raw chain spec.
+    pub fn from_storage(
+        pairs: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+    ) -> Result<Self, String> {
+        let gateways_prefix = storage_prefix(b"Gateways");
+        let tokens_prefix = storage_prefix(b"Tokens");
+        let sfx_abis_prefix = storage_prefix(b"StandardSFXABIs");
+        let gateway_sfx_abis_prefix = storage_prefix(b"SFXABIRegistry");
+        let gateway_abis_prefix = storage_prefix(b"GatewayABIs");
+        let gateway_versions_prefix = storage_prefix(b"GatewayVersions");
+        let order_addresses_prefix = storage_prefix(b"RemoteOrderAddresses");
+        let bidding_addresses_prefix = storage_prefix(b"RemoteBiddingAddresses");
+
+        let mut gateway_records = vec![];
+        let mut tokens = vec![];
+        let mut sfx_abis = vec![];
+        let mut gateway_sfx_abis = vec![];
+        let mut gateway_abis = vec![];
+        let mut gateway_versions = vec![];
+        let mut remote_order_addresses = vec![];
+        let mut remote_bidding_addresses = vec![];
+
+        // All of the above maps are keyed with the Identity hasher, so ids are the key suffix
+        let id_suffix = |key: &[u8], prefix: &[u8]| -> Result<[u8; 4], String> {
+            key[prefix.len()..]
+                .try_into()
+                .map_err(|_| format!("unexpected XDNS storage key 0x{}", hex::encode(key)))
+        };
+
+        for (key, value) in pairs {
+            if key.starts_with(&gateways_prefix) {
+                gateway_records.push(decode_bytes(&value, "gateway record")?);
+            } else if key.starts_with(&tokens_prefix) {
+                tokens.push(decode_bytes(&value, "token record")?);
+            } else if key.starts_with(&sfx_abis_prefix) {
+                let id = id_suffix(&key, &sfx_abis_prefix)?;
+                sfx_abis.push((id, decode_bytes(&value, "SFX ABI")?));
+            } else if key.starts_with(&gateway_sfx_abis_prefix) {
+                // Double map keyed with Identity twice: the gateway id followed by the SFX id
+                let ids = &key[gateway_sfx_abis_prefix.len()..];
+                if ids.len() != 8 {
+                    return Err(format!(
+                        "unexpected XDNS storage key 0x{}",
+                        hex::encode(&key)
+                    ))
+                }
+                let gateway_id: [u8; 4] = ids[..4].try_into().expect("length checked above");
+                let sfx_id: [u8; 4] = ids[4..].try_into().expect("length checked above");
+                gateway_sfx_abis.push((
+                    gateway_id,
+                    sfx_id,
+                    decode_bytes(&value, "gateway SFX ABI")?,
+                ));
+            } else if key.starts_with(&gateway_abis_prefix) {
+                let id = id_suffix(&key, &gateway_abis_prefix)?;
+                gateway_abis.push((id, decode_bytes(&value, "gateway ABI")?));
+            } else if key.starts_with(&gateway_versions_prefix) {
+                let id = id_suffix(&key, &gateway_versions_prefix)?;
+                gateway_versions.push((id, decode_bytes(&value, "gateway version")?));
+            } else if key.starts_with(&order_addresses_prefix) {
+                let id = id_suffix(&key, &order_addresses_prefix)?;
+                remote_order_addresses.push((id, decode_bytes(&value, "remote order address")?));
+            } else if key.starts_with(&bidding_addresses_prefix) {
+                let id = id_suffix(&key, &bidding_addresses_prefix)?;
+                remote_bidding_addresses
+                    .push((id, decode_bytes(&value, "remote bidding address")?));
+            }
+        }
+
+        Ok(Self::from_parts(TopologyParts {
+            gateway_records,
+            tokens,
+            sfx_abis,
+            gateway_sfx_abis,
+            gateway_abis,
+            gateway_versions,
+            remote_order_addresses,
+            remote_bidding_addresses,
+        }))
+    }
+
+    /// Reads the topology out of the `xdns` section of a plain chain spec's genesis.
+    pub fn from_genesis_config(xdns: &Value) -> Result<Self, String> {
+        let gateway_sfx_abis: Vec<(ChainId, Vec<([u8; 4], SFXAbi)>)> =
+            decode_genesis_field(xdns, "gatewaySfxAbis")?;
+        Ok(Self::from_parts(TopologyParts {
+            gateway_records: decode_genesis_field(xdns, "knownGatewayRecords")?,
+            tokens: decode_genesis_field(xdns, "knownTokens")?,
+            sfx_abis: decode_genesis_field(xdns, "standardSfxAbi")?,
+            gateway_sfx_abis: gateway_sfx_abis
+                .into_iter()
+                .flat_map(|(gateway_id, sfx_abis)| {
+                    sfx_abis
+                        .into_iter()
+                        .map(move |(id, abi)| (gateway_id, id, abi))
+                })
+                .collect(),
+            gateway_abis: decode_genesis_field(xdns, "gatewayAbis")?,
+            gateway_versions: decode_genesis_field(xdns, "gatewayVersions")?,
+            remote_order_addresses: decode_genesis_field(xdns, "remoteOrderAddresses")?,
+            remote_bidding_addresses: decode_genesis_field(xdns, "remoteBiddingAddresses")?,
+        }))
+    }
+
+    /// Reads the topology out of either a plain or a raw chain spec.
+    pub fn from_chain_spec(spec: &Value) -> Result<Self, String> {
+        let genesis = spec
+            .get("genesis")
+            .ok_or("chain spec is missing the genesis section")?;
+
+        if let Some(top) = genesis.pointer("/raw/top").and_then(Value::as_object) {
+            let xdns_prefix = twox_128(XDNS_PALLET_PREFIX);
+            let mut pairs = vec![];
+            for (key, value) in top {
+                let key = hex::decode(key.trim_start_matches("0x"))
+                    .map_err(|e| format!("invalid raw storage key {key}: {e}"))?;
+                if !key.starts_with(&xdns_prefix) {
+                    continue
+                }
+                let value = value
+                    .as_str()
+                    .ok_or("raw storage values must be hex strings")?;
+                let value = hex::decode(value.trim_start_matches("0x"))
+                    .map_err(|e| format!("invalid raw storage value {value}: {e}"))?;
+                pairs.push((key, value));
+            }
+            return Self::from_storage(pairs)
+        }
+
+        let xdns = genesis
+            .pointer("/runtime/xdns")
+            .ok_or("chain spec genesis has neither raw storage nor the XDNS genesis config")?;
+        Self::from_genesis_config(xdns)
+    }
+
+    fn gateway_records(&self) -> Vec<GatewayRecord<AccountId>> {
+        self.gateways
+            .iter()
+            .map(|gateway| GatewayRecord {
+                gateway_id: gateway.gateway_id,
+                verification_vendor: gateway.verification_vendor.clone(),
+                execution_vendor: gateway.execution_vendor.clone(),
+                codec: gateway.codec.clone(),
+                registrant: gateway.registrant.clone(),
+                escrow_account: gateway.escrow_account.clone(),
+                allowed_side_effects: gateway
+                    .allowed_side_effects
+                    .iter()
+                    .map(|sfx| (sfx.id, sfx.pallet_index_memo))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn token_records(&self) -> Vec<TokenRecord> {
+        self.tokens
+            .iter()
+            .map(|token| TokenRecord {
+                token_id: token.token_id,
+                gateway_id: token.gateway_id,
+                token_props: token.token_props.clone(),
+            })
+            .collect()
+    }
+
+    /// Writes the topology into the `xdns` section of a plain chain spec's genesis.
+    /// SFX ABIs already present in the chain spec are kept if the topology doesn't list any.
+    pub fn import_into_chain_spec(&self, spec: &mut Value) -> Result<(), String> {
+        if spec.pointer("/genesis/raw").is_some() {
+            return Err("topology can only be imported into a plain chain spec; \
+                convert it to raw with `build-spec --raw` afterwards"
+                .into())
+        }
+        let xdns = spec
+            .pointer_mut("/genesis/runtime/xdns")
+            .and_then(Value::as_object_mut)
+            .ok_or("chain spec genesis is missing the XDNS genesis config")?;
+
+        let order_addresses: Vec<(ChainId, H256)> = self
+            .gateways
+            .iter()
+            .filter_map(|gateway| Some((gateway.gateway_id, gateway.remote_order_address?)))
+            .collect();
+        let bidding_addresses: Vec<(ChainId, H256)> = self
+            .gateways
+            .iter()
+            .filter_map(|gateway| Some((gateway.gateway_id, gateway.remote_bidding_address?)))
+            .collect();
+        let gateway_sfx_abis: Vec<(ChainId, Vec<([u8; 4], SFXAbi)>)> = self
+            .gateways
+            .iter()
+            .filter(|gateway| !gateway.sfx_abis.is_empty())
+            .map(|gateway| {
+                let sfx_abis = gateway
+                    .sfx_abis
+                    .iter()
+                    .map(|entry| (entry.id, entry.abi.clone()))
+                    .collect();
+                (gateway.gateway_id, sfx_abis)
+            })
+            .collect();
+        let gateway_abis: Vec<(ChainId, GatewayABIConfig)> = self
+            .gateways
+            .iter()
+            .filter_map(|gateway| Some((gateway.gateway_id, gateway.gateway_abi.clone()?)))
+            .collect();
+        let gateway_versions: Vec<(ChainId, u32)> = self
+            .gateways
+            .iter()
+            .filter_map(|gateway| Some((gateway.gateway_id, gateway.version?)))
+            .collect();
+
+        xdns.insert(
+            "knownGatewayRecords".into(),
+            self.gateway_records().encode().into(),
+        );
+        xdns.insert("knownTokens".into(), self.token_records().encode().into());
+        xdns.insert(
+            "remoteOrderAddresses".into(),
+            order_addresses.encode().into(),
+        );
+        xdns.insert(
+            "remoteBiddingAddresses".into(),
+            bidding_addresses.encode().into(),
+        );
+        xdns.insert("gatewaySfxAbis".into(), gateway_sfx_abis.encode().into());
+        xdns.insert("gatewayAbis".into(), gateway_abis.encode().into());
+        xdns.insert("gatewayVersions".into(), gateway_versions.encode().into());
+        if !self.sfx_abis.is_empty() {
+            let sfx_abis: Vec<([u8; 4], SFXAbi)> = self
+                .sfx_abis
+                .iter()
+                .map(|entry| (entry.id, entry.abi.clone()))
+                .collect();
+            xdns.insert("standardSfxAbi".into(), sfx_abis.encode().into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the topology for inconsistencies that would make its import fail at genesis.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = vec![];
+
+        let mut gateway_ids = BTreeSet::new();
+        for gateway in self.gateways.iter() {
+            if !gateway_ids.insert(gateway.gateway_id) {
+                errors.push(format!(
+                    "gateway {} is listed more than once",
+                    four_byte_id::to_string(&gateway.gateway_id)
+                ));
+            }
+        }
+
+        let known_sfx_ids: BTreeSet<[u8; 4]> = if self.sfx_abis.is_empty() {
+            t3rn_abi::standard::standard_sfx_abi()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect()
+        } else {
+            self.sfx_abis.iter().map(|entry| entry.id).collect()
+        };
+        for gateway in self.gateways.iter() {
+            for sfx in gateway.allowed_side_effects.iter() {
+                if !known_sfx_ids.contains(&sfx.id) {
+                    errors.push(format!(
+                        "gateway {} allows side effect {} without a known SFX ABI",
+                        four_byte_id::to_string(&gateway.gateway_id),
+                        four_byte_id::to_string(&sfx.id)
+                    ));
+                }
+            }
+        }
+
+        let mut token_links = BTreeSet::new();
+        for token in self.tokens.iter() {
+            if !gateway_ids.contains(&token.gateway_id) {
+                errors.push(format!(
+                    "token {} is linked to unknown gateway {}",
+                    token.token_id,
+                    four_byte_id::to_string(&token.gateway_id)
+                ));
+            }
+            if !token_links.insert((token.token_id, token.gateway_id)) {
+                errors.push(format!(
+                    "token {} is linked to gateway {} more than once",
+                    token.token_id,
+                    four_byte_id::to_string(&token.gateway_id)
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let format = TopologyFormat::from_path(path)?;
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        match format {
+            TopologyFormat::Json => serde_json::from_str(&content).map_err(|e| e.to_string()),
+            TopologyFormat::Toml => toml::from_str(&content).map_err(|e| e.to_string()),
+        }
+        .map_err(|e| format!("failed to parse topology from {}: {e}", path.display()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let content = match TopologyFormat::from_path(path)? {
+            TopologyFormat::Json => serde_json::to_string_pretty(self).map_err(|e| e.to_string()),
+            TopologyFormat::Toml => toml::to_string_pretty(self).map_err(|e| e.to_string()),
+        }
+        .map_err(|e| format!("failed to serialize topology: {e}"))?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_runtime::AccountId32;
+    use t3rn_primitives::SubstrateToken;
+
+    fn topology() -> TopologyFile<AccountId32> {
+        TopologyFile::from_parts(TopologyParts {
+            gateway_records: vec![GatewayRecord {
+                gateway_id: *b"roco",
+                verification_vendor: GatewayVendor::Rococo,
+                execution_vendor: ExecutionVendor::Substrate,
+                codec: Codec::Scale,
+                registrant: None,
+                escrow_account: Some(AccountId32::new([1u8; 32])),
+                allowed_side_effects: vec![(*b"tran", Some(2)), (*b"tass", None)],
+            }],
+            tokens: vec![TokenRecord {
+                token_id: 1,
+                gateway_id: *b"roco",
+                token_props: TokenInfo::Substrate(SubstrateToken {
+                    id: 1,
+                    symbol: b"ROC".to_vec(),
+                    decimals: 12,
+                }),
+            }],
+            sfx_abis: vec![],
+            gateway_sfx_abis: vec![],
+            gateway_abis: vec![],
+            gateway_versions: vec![(*b"roco", 1)],
+            remote_order_addresses: vec![(*b"roco", H256::repeat_byte(1))],
+            remote_bidding_addresses: vec![],
+        })
+    }
+
+    #[test]
+    fn topology_round_trips_through_json_and_toml() {
+        let topology = topology();
+
+        let json = serde_json::to_string_pretty(&topology).unwrap();
+        assert!(json.contains("\"gateway_id\": \"roco\""));
+        assert_eq!(
+            serde_json::from_str::<TopologyFile<AccountId32>>(&json).unwrap(),
+            topology
+        );
+
+        let toml = toml::to_string_pretty(&topology).unwrap();
+        assert_eq!(
+            toml::from_str::<TopologyFile<AccountId32>>(&toml).unwrap(),
+            topology
+        );
+    }
+
+    #[test]
+    fn topology_imported_into_chain_spec_reads_back_the_same() {
+        let topology = topology();
+        let mut spec = serde_json::json!({
+            "genesis": { "runtime": { "xdns": {
+                "knownGatewayRecords": [],
+                "standardSfxAbi": t3rn_abi::standard::standard_sfx_abi().encode(),
+            } } }
+        });
+
+        topology.import_into_chain_spec(&mut spec).unwrap();
+        let read_back = TopologyFile::<AccountId32>::from_chain_spec(&spec).unwrap();
+
+        assert_eq!(read_back.gateways, topology.gateways);
+        assert_eq!(read_back.tokens, topology.tokens);
+        assert_eq!(
+            read_back.sfx_abis.len(),
+            t3rn_abi::standard::standard_sfx_abi().len()
+        );
+        assert_eq!(read_back.validate(), Ok(()));
+    }
+
+    fn xdns_storage_pairs() -> Vec<(Vec<u8>, Vec<u8>)> {
+        let prefix = twox_128(XDNS_PALLET_PREFIX).to_vec();
+        let mut pairs = vec![];
+        let mut key = prefix.clone();
+        while let Some(next_key) = sp_io::storage::next_key(&key) {
+            if !next_key.starts_with(&prefix) {
+                break
+            }
+            let value = sp_io::storage::get(&next_key).expect("next key has a value");
+            pairs.push((next_key.clone(), value.to_vec()));
+            key = next_key;
+        }
+        pairs
+    }
+
+    #[test]
+    fn topology_exported_from_storage_rebuilds_the_same_storage_at_genesis() {
+        use circuit_mock_runtime::{ExtBuilder, Runtime, RuntimeOrigin, XDNS};
+        use sp_runtime::BuildStorage;
+        use t3rn_primitives::{gateway::HasherAlgo, xdns::Xdns};
+
+        const GATEWAY: ChainId = [3, 3, 3, 3];
+        let custom_abi = GatewayABIConfig {
+            hasher: HasherAlgo::Keccak256,
+            ..Default::default()
+        };
+
+        let exported = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                XDNS::set_gateway_abi(
+                    RuntimeOrigin::root(),
+                    GATEWAY,
+                    Codec::Scale,
+                    custom_abi.clone(),
+                )
+                .unwrap();
+                let tran_abi = pallet_xdns::StandardSFXABIs::<Runtime>::get(*b"tran").unwrap();
+                <XDNS as Xdns<Runtime, _>>::extend_sfx_abi(
+                    RuntimeOrigin::root(),
+                    GATEWAY,
+                    *b"cust",
+                    tran_abi,
+                )
+                .unwrap();
+                TopologyFile::<AccountId32>::from_storage(xdns_storage_pairs()).unwrap()
+            });
+
+        let gateway = exported
+            .gateways
+            .iter()
+            .find(|gateway| gateway.gateway_id == GATEWAY)
+            .unwrap();
+        assert_eq!(gateway.gateway_abi, Some(custom_abi));
+        assert_eq!(gateway.version, Some(2));
+        assert!(gateway.sfx_abis.iter().any(|entry| entry.id == *b"cust"));
+
+        let mut spec = serde_json::json!({
+            "genesis": { "runtime": { "xdns": {
+                "knownGatewayRecords": [],
+            } } }
+        });
+        exported.import_into_chain_spec(&mut spec).unwrap();
+        let genesis: pallet_xdns::GenesisConfig<Runtime> =
+            serde_json::from_value(spec.pointer("/genesis/runtime/xdns").unwrap().clone()).unwrap();
+
+        let reimported = sp_io::TestExternalities::new(genesis.build_storage().unwrap())
+            .execute_with(|| {
+                TopologyFile::<AccountId32>::from_storage(xdns_storage_pairs()).unwrap()
+            });
+
+        assert_eq!(reimported.gateways, exported.gateways);
+        assert_eq!(reimported.sfx_abis, exported.sfx_abis);
+    }
+
+    #[test]
+    fn validation_reports_unknown_gateways_and_side_effects() {
+        let mut topology = topology();
+        topology.gateways[0]
+            .allowed_side_effects
+            .push(AllowedSideEffect {
+                id: *b"nope",
+                pallet_index_memo: None,
+            });
+        topology.tokens[0].gateway_id = *b"miss";
+
+        assert_eq!(
+            topology.validate(),
+            Err(vec![
+                "gateway roco allows side effect nope without a known SFX ABI".to_string(),
+                "token 1 is linked to unknown gateway miss".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn four_byte_ids_fall_back_to_hex() {
+        assert_eq!(four_byte_id::to_string(&[3u8; 4]), "0x03030303");
+        assert_eq!(four_byte_id::from_str("0x03030303"), Ok([3u8; 4]));
+        assert_eq!(four_byte_id::from_str("roco"), Ok(*b"roco"));
+        assert!(four_byte_id::from_str("rococo").is_err());
+    }
+}
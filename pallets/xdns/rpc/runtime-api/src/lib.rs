@@ -7,15 +7,26 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+pub use sp_core::H256;
 use sp_runtime::sp_std;
 use sp_std::prelude::*;
-use t3rn_primitives::xdns::{FullGatewayRecord, GatewayRecord};
-pub use t3rn_primitives::{gateway::GatewayABIConfig, ChainId};
+pub use t3rn_abi::SFXAbi;
+pub use t3rn_primitives::{
+    circuit::AdaptiveTimeout,
+    gateway::GatewayABIConfig,
+    xdns::{EpochEstimate, FullGatewayRecord, GatewayRecord, TokenRecord},
+    ChainId, GatewayActivity, GatewayVendor, SpeedMode,
+};
+pub use t3rn_types::sfx::{Sfx4bId, TargetId};
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
-    pub trait XdnsRuntimeApi<AccountId> where
+    ///
+    /// Version 2 adds the `BlockNumber` generic and the methods from `list_available_mint_assets` on.
+    #[api_version(2)]
+    pub trait XdnsRuntimeApi<AccountId, BlockNumber> where
         AccountId: Codec,
+        BlockNumber: Codec,
     {
         /// Returns metadata for all known Blockchains
         fn fetch_records() -> Vec<GatewayRecord<AccountId>>;
@@ -26,5 +37,30 @@ sp_api::decl_runtime_apis! {
         fn fetch_abi(chain_id: ChainId) -> Option<GatewayABIConfig>;
 
         fn retreive_treasury_address(treasury_account: t3rn_primitives::TreasuryAccount) -> AccountId;
+
+        /// Returns the assets mintable on the given gateway
+        fn list_available_mint_assets(gateway_id: TargetId) -> Vec<TokenRecord>;
+
+        /// Returns all of the SFX ABIs enrolled to the given gateway
+        fn fetch_all_sfx_abi(gateway_id: ChainId) -> Vec<(Sfx4bId, SFXAbi)>;
+
+        /// Returns the address of the remote order contract on the given gateway
+        fn fetch_remote_order_contract_address(gateway_id: TargetId) -> Option<H256>;
+
+        /// Returns the address of the remote bidding contract on the given gateway
+        fn fetch_remote_bidding_contract_address(gateway_id: TargetId) -> Option<H256>;
+
+        /// Returns the last activity of all gateways
+        fn read_last_activity_overview() -> Vec<GatewayActivity<BlockNumber>>;
+
+        /// Returns the last epochs estimated for the given verifier
+        fn fetch_epoch_history(verifier: GatewayVendor) -> Option<Vec<EpochEstimate<BlockNumber>>>;
+
+        /// Estimates the timeouts of an xtx submitted at the current block against the slowest of the targets
+        fn estimate_adaptive_timeout_on_slowest_target(
+            target_ids: Vec<TargetId>,
+            speed_mode: SpeedMode,
+            emergency_offset: BlockNumber,
+        ) -> AdaptiveTimeout<BlockNumber, TargetId>;
     }
 }
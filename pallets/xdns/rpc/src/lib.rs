@@ -7,7 +7,10 @@ use jsonrpsee::{
     types::error::CallError,
 };
 pub use pallet_xdns_rpc_runtime_api::XdnsRuntimeApi;
-use pallet_xdns_rpc_runtime_api::{ChainId, GatewayABIConfig};
+use pallet_xdns_rpc_runtime_api::{
+    AdaptiveTimeout, ChainId, EpochEstimate, GatewayABIConfig, GatewayActivity, GatewayVendor,
+    SFXAbi, Sfx4bId, SpeedMode, TargetId, TokenRecord, H256,
+};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_core::sp_std;
@@ -19,7 +22,7 @@ use t3rn_primitives::xdns::{FullGatewayRecord, GatewayRecord};
 const RUNTIME_ERROR: i64 = 1;
 
 #[rpc(client, server)]
-pub trait XdnsApi<AccountId> {
+pub trait XdnsApi<AccountId, BlockNumber> {
     /// Returns all known XDNS records
     #[method(name = "xdns_fetchRecords")]
     fn fetch_records(&self) -> RpcResult<Vec<GatewayRecord<AccountId>>>;
@@ -29,6 +32,40 @@ pub trait XdnsApi<AccountId> {
 
     #[method(name = "xdns_fetchFullRecords")]
     fn fetch_full_gateway_records(&self) -> RpcResult<Vec<FullGatewayRecord<AccountId>>>;
+
+    /// Returns the assets mintable on the given gateway
+    #[method(name = "xdns_listAvailableMintAssets")]
+    fn list_available_mint_assets(&self, gateway_id: TargetId) -> RpcResult<Vec<TokenRecord>>;
+
+    /// Returns all of the SFX ABIs enrolled to the given gateway
+    #[method(name = "xdns_fetchAllSfxAbi")]
+    fn fetch_all_sfx_abi(&self, gateway_id: ChainId) -> RpcResult<Vec<(Sfx4bId, SFXAbi)>>;
+
+    #[method(name = "xdns_fetchRemoteOrderAddress")]
+    fn fetch_remote_order_contract_address(&self, gateway_id: TargetId) -> RpcResult<H256>;
+
+    #[method(name = "xdns_fetchRemoteBiddingAddress")]
+    fn fetch_remote_bidding_contract_address(&self, gateway_id: TargetId) -> RpcResult<H256>;
+
+    /// Returns the last activity of all gateways
+    #[method(name = "xdns_fetchGatewaysOverview")]
+    fn read_last_activity_overview(&self) -> RpcResult<Vec<GatewayActivity<BlockNumber>>>;
+
+    /// Returns the last epochs estimated for the given verifier
+    #[method(name = "xdns_fetchEpochHistory")]
+    fn fetch_epoch_history(
+        &self,
+        verifier: GatewayVendor,
+    ) -> RpcResult<Vec<EpochEstimate<BlockNumber>>>;
+
+    /// Estimates the timeouts of an xtx submitted now against the slowest of the targets
+    #[method(name = "xdns_estimateAdaptiveTimeout")]
+    fn estimate_adaptive_timeout_on_slowest_target(
+        &self,
+        target_ids: Vec<TargetId>,
+        speed_mode: SpeedMode,
+        emergency_offset: BlockNumber,
+    ) -> RpcResult<AdaptiveTimeout<BlockNumber, TargetId>>;
 }
 
 /// A struct that implements the [`XdnsApiServer`].
@@ -48,12 +85,13 @@ impl<C, P> Xdns<C, P> {
 }
 
 #[async_trait]
-impl<C, Block, AccountId> XdnsApiServer<AccountId> for Xdns<C, Block>
+impl<C, Block, AccountId, BlockNumber> XdnsApiServer<AccountId, BlockNumber> for Xdns<C, Block>
 where
     AccountId: Codec + MaybeDisplay,
+    BlockNumber: Codec + MaybeDisplay,
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: XdnsRuntimeApi<Block, AccountId>,
+    C::Api: XdnsRuntimeApi<Block, AccountId, BlockNumber>,
 {
     fn fetch_records(&self) -> RpcResult<Vec<GatewayRecord<AccountId>>> {
         let api = self.client.runtime_api();
@@ -89,6 +127,100 @@ where
         }
         .map_err(runtime_error_into_rpc_err)
     }
+
+    fn list_available_mint_assets(&self, gateway_id: TargetId) -> RpcResult<Vec<TokenRecord>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .list_available_mint_assets(at, gateway_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_all_sfx_abi(&self, gateway_id: ChainId) -> RpcResult<Vec<(Sfx4bId, SFXAbi)>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_all_sfx_abi(at, gateway_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_remote_order_contract_address(&self, gateway_id: TargetId) -> RpcResult<H256> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<H256> = api
+            .fetch_remote_order_contract_address(at, gateway_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        result
+            .ok_or("Remote order address doesn't exist")
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn fetch_remote_bidding_contract_address(&self, gateway_id: TargetId) -> RpcResult<H256> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result: Option<H256> = api
+            .fetch_remote_bidding_contract_address(at, gateway_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        result
+            .ok_or("Remote bidding address doesn't exist")
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn read_last_activity_overview(&self) -> RpcResult<Vec<GatewayActivity<BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .read_last_activity_overview(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_epoch_history(
+        &self,
+        verifier: GatewayVendor,
+    ) -> RpcResult<Vec<EpochEstimate<BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .fetch_epoch_history(at, verifier)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result.unwrap_or_default())
+    }
+
+    fn estimate_adaptive_timeout_on_slowest_target(
+        &self,
+        target_ids: Vec<TargetId>,
+        speed_mode: SpeedMode,
+        emergency_offset: BlockNumber,
+    ) -> RpcResult<AdaptiveTimeout<BlockNumber, TargetId>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let result = api
+            .estimate_adaptive_timeout_on_slowest_target(
+                at,
+                target_ids,
+                speed_mode,
+                emergency_offset,
+            )
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
 }
 
 fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
@@ -1,224 +1,315 @@
-// //! Benchmarking setup for pallet-xdns
-//
-// use super::*;
-// use crate::Pallet as XDNS;
-// use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-// use frame_system::RawOrigin;
-// use sp_std::prelude::*;
-// use t3rn_primitives::{xdns::Xdns, TokenSysProps};
-//
-// use sp_runtime::create_runtime_str;
-// use sp_version::RuntimeVersion;
-//
-// const USER_SEED: u32 = 999666;
-// pub const TEST_RUNTIME_VERSION: RuntimeVersion = RuntimeVersion {
-//     spec_name: create_runtime_str!("test-runtime"),
-//     impl_name: create_runtime_str!("test-runtime"),
-//     authoring_version: 1,
-//     spec_version: 1,
-//     impl_version: 1,
-//     apis: sp_version::create_apis_vec!([]),
-//     transaction_version: 1,
-//     state_version: 0,
-// };
-//
-// benchmarks! {
-//     add_new_xdns_record {
-//         let url = b"some_url".to_vec();
-//         let gateway_id = b"test";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//     }: _(RawOrigin::Root, url, *gateway_id, gateway_abi, gateway_vendor, gateway_type, gateway_genesis, gateway_sys_props, vec![])
-//     verify {
-//         assert!(
-//             XDNSRegistry::<T>::get(T::Hashing::hash(b"test"))
-//                 .is_some()
-//         );
-//     }
-//
-//     update_ttl {
-//         let url = b"some_url".to_vec();
-//
-//         let gateway_id = b"gate";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//
-//         XDNS::<T>::add_new_xdns_record(
-//             RawOrigin::Root.into(),
-//             url,
-//             *gateway_id,
-//             gateway_abi,
-//             gateway_vendor,
-//             gateway_type,
-//             gateway_genesis,
-//             gateway_sys_props,
-//             vec![],
-//         )
-//         .unwrap();
-//
-//         let gateway_hash = T::Hashing::hash(b"gate");
-//
-//     }: _(RawOrigin::Root, *b"gate", 2)
-//     verify {
-//         assert_eq!(
-//             XDNSRegistry::<T>::get(gateway_hash)
-//                 .unwrap()
-//                 .last_finalized,
-//             Some(2)
-//         );
-//     }
-//
-//     purge_xdns_record {
-//         let requester: T::AccountId = account("TEST", 1u32, USER_SEED);
-//         let url = b"some_url".to_vec();
-//
-//         let gateway_id = b"gate";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//
-//         XDNS::<T>::add_new_xdns_record(
-//             RawOrigin::Root.into(),
-//             url,
-//             *gateway_id,
-//             gateway_abi,
-//             gateway_vendor,
-//             gateway_type,
-//             gateway_genesis,
-//             gateway_sys_props,
-//             vec![],
-//         )
-//         .unwrap();
-//
-//         let gateway_hash = T::Hashing::hash(b"gate");
-//     }: _(RawOrigin::Root, requester, gateway_hash.clone())
-//     verify{
-//         assert!(
-//             XDNSRegistry::<T>::get(gateway_hash)
-//                 .is_none()
-//         );
-//     }
-//
-//     best_available {
-//         let url = b"some_url".to_vec();
-//
-//         let gateway_id = b"gate";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//
-//         XDNS::<T>::add_new_xdns_record(
-//             RawOrigin::Root.into(),
-//             url,
-//             *gateway_id,
-//             gateway_abi,
-//             gateway_vendor,
-//             gateway_type,
-//             gateway_genesis,
-//             gateway_sys_props,
-//             vec![],
-//         )
-//         .unwrap();
-//     }: {XDNS::<T>::best_available(*b"gate")}
-//     verify{}
-// }
-//
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use crate::mock::Test;
-//     use frame_support::assert_ok;
-//     use sp_io::TestExternalities;
-//
-//     pub fn new_test_ext() -> TestExternalities {
-//         let t = frame_system::GenesisConfig::default()
-//             .build_storage::<Test>()
-//             .unwrap();
-//         TestExternalities::new(t)
-//     }
-//
-//     #[test]
-//     fn benchmark_add_new_xdns_record() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_add_new_xdns_record::<Test>());
-//         });
-//     }
-//
-//     #[test]
-//     fn benchmark_update_ttl() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_update_ttl::<Test>());
-//         });
-//     }
-//
-//     #[test]
-//     fn benchmark_purge_xdns_record() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_purge_xdns_record::<Test>());
-//         });
-//     }
-//
-//     #[test]
-//     fn benchmark_best_available() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_best_available::<Test>());
-//         });
-//     }
-// }
-//
-// impl_benchmark_test_suite!(
-//     XDNS,
-//     crate::benchmarking::tests::new_test_ext(),
-//     crate::mock::Test
-// );
+//! Benchmarking setup for pallet-xdns
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as XDNS;
+
+use circuit_runtime_types::AssetId;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Currency, Get, Hooks, ReservableCurrency};
+use frame_system::RawOrigin;
+use sp_std::vec;
+use t3rn_abi::{sfx_abi::SFXAbi, Codec};
+use t3rn_primitives::{
+    attesters::AttestersReadApi,
+    xdns::{GatewayRecord, ScheduledGatewayChange, Xdns, MAX_GATEWAY_PROMOTION_VOTES},
+    EthereumToken, ExecutionVendor, TokenInfo,
+};
+use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
+
+const USER_SEED: u32 = 999666;
+const BENCHMARK_GATEWAY_ID: TargetId = *b"bnch";
+const BENCHMARK_ASSET_ID: AssetId = 999666;
+/// Upper bound of the benchmarked number of SFX ABIs of a gateway.
+const MAX_SFX_ABIS: u32 = 64;
+/// Upper bound of the benchmarked number of registered assets priced next to the aggregated one.
+const MAX_PRICED_ASSETS: u32 = 32;
+
+fn ethereum_token(decimals: u8) -> TokenInfo {
+    TokenInfo::Ethereum(EthereumToken {
+        symbol: b"BNCH".to_vec(),
+        decimals,
+        address: Some([1u8; 20]),
+    })
+}
+
+fn register_gateway<T: Config>(gateway_id: TargetId) {
+    XDNS::<T>::add_new_gateway(
+        gateway_id,
+        GatewayVendor::Ethereum,
+        ExecutionVendor::EVM,
+        Codec::Rlp,
+        None,
+        None,
+        vec![(*b"tran", Some(2))],
+    )
+    .expect("gateway registers in XDNS::runtime_benchmarks");
+}
+
+/// SFX ABIs of the standard transfer interface under distinct ids.
+fn sfx_abis(count: u32) -> Vec<(Sfx4bId, SFXAbi)> {
+    let tran_abi =
+        SFXAbi::get_standard_interface(*b"tran").expect("transfer is a standard interface");
+    (0..count)
+        .map(|i| (i.to_be_bytes(), tran_abi.clone()))
+        .collect()
+}
+
+fn gateway_record<T: Config>(allowed_side_effects: u32) -> GatewayRecord<T::AccountId> {
+    GatewayRecord {
+        gateway_id: BENCHMARK_GATEWAY_ID,
+        verification_vendor: GatewayVendor::Ethereum,
+        execution_vendor: ExecutionVendor::EVM,
+        codec: Codec::Rlp,
+        registrant: None,
+        escrow_account: None,
+        allowed_side_effects: vec![(*b"tran", Some(2)); allowed_side_effects as usize],
+    }
+}
+
+fn register_pending_gateway<T: Config>() -> T::AccountId {
+    let registrant: T::AccountId = account("REGISTRANT", 0, USER_SEED);
+    T::Currency::make_free_balance_be(
+        &registrant,
+        T::GatewayRegistrationBond::get().saturating_mul(2u32.into()),
+    );
+    XDNS::<T>::add_pending_gateway(
+        registrant.clone(),
+        BENCHMARK_GATEWAY_ID,
+        GatewayVendor::Ethereum,
+        ExecutionVendor::EVM,
+        Codec::Rlp,
+        None,
+        vec![(*b"tran", Some(2))],
+    )
+    .expect("pending gateway registers in XDNS::runtime_benchmarks");
+    registrant
+}
+
+fn register_asset<T: Config>(asset_id: AssetId) {
+    XDNS::<T>::register_new_token(&RawOrigin::Root.into(), asset_id, ethereum_token(12))
+        .expect("asset registers in XDNS::runtime_benchmarks");
+}
+
+fn register_canonical_asset_with_member<T: Config>() {
+    register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+    register_asset::<T>(BENCHMARK_ASSET_ID);
+    XDNS::<T>::register_canonical_asset(
+        RawOrigin::Root.into(),
+        BENCHMARK_ASSET_ID,
+        b"BNCH".to_vec(),
+        12,
+    )
+    .expect("canonical asset registers in XDNS::runtime_benchmarks");
+    XDNS::<T>::add_canonical_asset_member(
+        RawOrigin::Root.into(),
+        BENCHMARK_ASSET_ID,
+        BENCHMARK_GATEWAY_ID,
+        ethereum_token(18),
+    )
+    .expect("canonical asset member adds in XDNS::runtime_benchmarks");
+}
+
+fn whitelist_price_feeders<T: Config>(count: u32) -> Vec<T::AccountId> {
+    (0..count)
+        .map(|i| {
+            let feeder: T::AccountId = account("FEEDER", i, USER_SEED);
+            XDNS::<T>::add_price_feeder(RawOrigin::Root.into(), feeder.clone())
+                .expect("price feeder whitelists in XDNS::runtime_benchmarks");
+            feeder
+        })
+        .collect()
+}
+
+fn submit_prices<T: Config>(asset_id: AssetId, feeders: &[T::AccountId]) {
+    for feeder in feeders {
+        XDNS::<T>::submit_asset_price(
+            RawOrigin::Signed(feeder.clone()).into(),
+            asset_id,
+            1_000u32.into(),
+        )
+        .expect("asset price submits in XDNS::runtime_benchmarks");
+    }
+}
+
+/// Registers the given number of assets next to the benchmarked one, each priced by all of the feeders.
+fn price_other_assets<T: Config>(count: u32, feeders: &[T::AccountId]) {
+    for i in 0..count {
+        let asset_id = BENCHMARK_ASSET_ID + 1 + i;
+        register_asset::<T>(asset_id);
+        submit_prices::<T>(asset_id, feeders);
+    }
+}
+
+benchmarks! {
+    register_canonical_asset {
+        register_asset::<T>(BENCHMARK_ASSET_ID);
+    }: _(RawOrigin::Root, BENCHMARK_ASSET_ID, b"BNCH".to_vec(), 12)
+    verify {
+        assert!(<CanonicalAssets<T>>::contains_key(BENCHMARK_ASSET_ID));
+    }
+
+    add_canonical_asset_member {
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+        register_asset::<T>(BENCHMARK_ASSET_ID);
+        XDNS::<T>::register_canonical_asset(
+            RawOrigin::Root.into(),
+            BENCHMARK_ASSET_ID,
+            b"BNCH".to_vec(),
+            12,
+        )?;
+    }: _(RawOrigin::Root, BENCHMARK_ASSET_ID, BENCHMARK_GATEWAY_ID, ethereum_token(18))
+    verify {
+        assert!(<Tokens<T>>::contains_key(BENCHMARK_ASSET_ID, BENCHMARK_GATEWAY_ID));
+    }
+
+    remove_canonical_asset_member {
+        register_canonical_asset_with_member::<T>();
+    }: _(RawOrigin::Root, BENCHMARK_ASSET_ID, BENCHMARK_GATEWAY_ID)
+    verify {
+        assert!(!<Tokens<T>>::contains_key(BENCHMARK_ASSET_ID, BENCHMARK_GATEWAY_ID));
+    }
+
+    schedule_gateway_override {
+        let s in 1 .. MAX_SFX_ABIS;
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+    }: _(RawOrigin::Root, gateway_record::<T>(s), 10u32.into())
+    verify {
+        assert!(<ScheduledGatewayChanges<T>>::contains_key::<BlockNumberFor<T>>(10u32.into()));
+    }
+
+    schedule_sfx_abi_override {
+        let s in 1 .. MAX_SFX_ABIS;
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID, sfx_abis(s), 10u32.into())
+    verify {
+        assert!(<ScheduledGatewayChanges<T>>::contains_key::<BlockNumberFor<T>>(10u32.into()));
+    }
+
+    schedule_gateway_purge {
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID, 10u32.into())
+    verify {
+        assert!(<ScheduledGatewayChanges<T>>::contains_key::<BlockNumberFor<T>>(10u32.into()));
+    }
+
+    cancel_scheduled_gateway_changes {
+        let c in 1 .. T::MaxScheduledGatewayChangesPerBlock::get();
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+        for _ in 0..c {
+            XDNS::<T>::schedule_gateway_purge(
+                RawOrigin::Root.into(),
+                BENCHMARK_GATEWAY_ID,
+                10u32.into(),
+            )?;
+        }
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID, 10u32.into())
+    verify {
+        assert!(!<ScheduledGatewayChanges<T>>::contains_key::<BlockNumberFor<T>>(10u32.into()));
+    }
+
+    enact_scheduled_gateway_change {
+        let a in 1 .. MAX_SFX_ABIS;
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+        XDNS::<T>::schedule_sfx_abi_override(
+            RawOrigin::Root.into(),
+            BENCHMARK_GATEWAY_ID,
+            sfx_abis(a),
+            10u32.into(),
+        )?;
+        let version = <GatewayVersions<T>>::get(BENCHMARK_GATEWAY_ID);
+    }: {
+        XDNS::<T>::on_initialize(10u32.into());
+    }
+    verify {
+        assert_eq!(<GatewayVersions<T>>::get(BENCHMARK_GATEWAY_ID), version + 1);
+    }
+
+    promote_pending_gateway {
+        let registrant = register_pending_gateway::<T>();
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID)
+    verify {
+        assert!(!<PendingGateways<T>>::contains_key(BENCHMARK_GATEWAY_ID));
+        assert!(T::Currency::reserved_balance(&registrant).is_zero());
+    }
+
+    vote_gateway_promotion {
+        let c in 1 .. MAX_GATEWAY_PROMOTION_VOTES;
+        register_pending_gateway::<T>();
+        // The c-th vote is the majority of the committee, promoting the gateway
+        let committee: Vec<T::AccountId> =
+            (0..2 * c - 1).map(|i| account("ATTESTER", i, USER_SEED)).collect();
+        T::AttestersRead::set_current_committee(committee.clone());
+        for attester in committee.iter().take(c as usize - 1) {
+            XDNS::<T>::vote_gateway_promotion(
+                RawOrigin::Signed(attester.clone()).into(),
+                BENCHMARK_GATEWAY_ID,
+            )?;
+        }
+        let voter = committee[c as usize - 1].clone();
+    }: _(RawOrigin::Signed(voter), BENCHMARK_GATEWAY_ID)
+    verify {
+        assert!(!<PendingGateways<T>>::contains_key(BENCHMARK_GATEWAY_ID));
+    }
+
+    slash_pending_gateway {
+        let registrant = register_pending_gateway::<T>();
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID)
+    verify {
+        assert!(!<Gateways<T>>::contains_key(BENCHMARK_GATEWAY_ID));
+        assert!(T::Currency::reserved_balance(&registrant).is_zero());
+    }
+
+    set_gateway_abi {
+        register_gateway::<T>(BENCHMARK_GATEWAY_ID);
+        let gateway_abi = t3rn_primitives::xdns::default_gateway_abi(&ExecutionVendor::Substrate);
+    }: _(RawOrigin::Root, BENCHMARK_GATEWAY_ID, Codec::Scale, gateway_abi.clone())
+    verify {
+        assert_eq!(<GatewayABIs<T>>::get(BENCHMARK_GATEWAY_ID), Some(gateway_abi));
+    }
+
+    add_price_feeder {
+        let feeder: T::AccountId = account("FEEDER", 0, USER_SEED);
+    }: _(RawOrigin::Root, feeder.clone())
+    verify {
+        assert!(<PriceFeeders<T>>::get().contains(&feeder));
+    }
+
+    remove_price_feeder {
+        let a in 1 .. MAX_PRICED_ASSETS;
+        let feeders = whitelist_price_feeders::<T>(1);
+        for i in 0..a {
+            register_asset::<T>(BENCHMARK_ASSET_ID + i);
+        }
+    }: _(RawOrigin::Root, feeders[0].clone())
+    verify {
+        assert!(<PriceFeeders<T>>::get().is_empty());
+    }
+
+    submit_asset_price {
+        let f in T::MinPriceFeeds::get().max(1) .. T::MaxPriceFeeders::get();
+        let a in 0 .. MAX_PRICED_ASSETS;
+        let feeders = whitelist_price_feeders::<T>(f);
+        price_other_assets::<T>(a, &feeders);
+        register_asset::<T>(BENCHMARK_ASSET_ID);
+        submit_prices::<T>(BENCHMARK_ASSET_ID, &feeders[1..]);
+    }: _(RawOrigin::Signed(feeders[0].clone()), BENCHMARK_ASSET_ID, 1_000u32.into())
+    verify {
+        assert!(<AssetEstimatesValidUntil<T>>::contains_key(BENCHMARK_ASSET_ID));
+    }
+
+    aggregate_asset_price {
+        let f in T::MinPriceFeeds::get().max(1) .. T::MaxPriceFeeders::get();
+        let a in 0 .. MAX_PRICED_ASSETS;
+        let feeders = whitelist_price_feeders::<T>(f);
+        price_other_assets::<T>(a, &feeders);
+        register_asset::<T>(BENCHMARK_ASSET_ID);
+        submit_prices::<T>(BENCHMARK_ASSET_ID, &feeders);
+        <AssetEstimatesValidUntil<T>>::remove(BENCHMARK_ASSET_ID);
+    }: {
+        XDNS::<T>::aggregate_asset_price(BENCHMARK_ASSET_ID);
+    }
+    verify {
+        assert!(<AssetEstimatesValidUntil<T>>::contains_key(BENCHMARK_ASSET_ID));
+    }
+}
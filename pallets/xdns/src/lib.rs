@@ -8,7 +8,7 @@
 #![allow(clippy::too_many_arguments)]
 use codec::Encode;
 
-use frame_support::sp_runtime::traits::Zero;
+use frame_support::sp_runtime::traits::{One, Zero};
 use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 
 pub use t3rn_types::{
@@ -16,8 +16,13 @@ pub use t3rn_types::{
     sfx::{EventSignature, SideEffectId, SideEffectName},
 };
 
-use frame_support::sp_runtime::traits::Saturating;
-use t3rn_primitives::reexport_currency_types;
+use frame_support::{sp_runtime::traits::Saturating, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+use t3rn_primitives::{
+    clock::{OnNewRound, RoundHandlerId},
+    common::RoundInfo,
+    reexport_currency_types,
+};
 pub use t3rn_primitives::{ChainId, GatewayGenesisConfig, GatewayType, GatewayVendor};
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
@@ -46,12 +51,15 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{
             fungible::{Inspect, Mutate},
-            Currency, Time,
+            BalanceStatus, Currency, ReservableCurrency, Time,
         },
     };
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
-    use sp_runtime::{traits::CheckedDiv, SaturatedConversion};
+    use sp_runtime::{
+        helpers_128bit::multiply_by_rational_with_rounding, traits::CheckedDiv, Rounding,
+        SaturatedConversion,
+    };
     use sp_std::convert::TryInto;
     use t3rn_abi::{sfx_abi::SFXAbi, Codec};
     use t3rn_primitives::{
@@ -60,7 +68,10 @@ pub mod pallet {
         light_client::{LightClientAsyncAPI, LightClientHeartbeat},
         portal::Portal,
         xdns::{
-            EpochEstimate, FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, TokenRecord, Xdns,
+            default_gateway_abi, CanonicalAssetGroup, EpochEstimate, FullGatewayRecord,
+            GatewayHealthRecord, GatewayRecord, GatewayRecordVersion, PalletAssetsOverlay,
+            PendingGatewayRecord, ScheduledGatewayChange, TokenRecord, Xdns, ASSET_PRICE_PRECISION,
+            MAX_GATEWAY_PROMOTION_VOTES,
         },
         Bytes, ChainId, ExecutionVendor, FinalityVerifierActivity, GatewayActivity, GatewayVendor,
         SpeedMode, TokenInfo, TreasuryAccount, TreasuryAccountProvider, XDNSTopology,
@@ -82,7 +93,7 @@ pub mod pallet {
         /// A type that provides inspection and mutation to some fungible assets
         type Balances: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
 
-        type Currency: Currency<Self::AccountId>;
+        type Currency: ReservableCurrency<Self::AccountId>;
 
         type AssetsOverlay: PalletAssetsOverlay<Self, BalanceOf<Self>>;
 
@@ -99,6 +110,28 @@ pub mod pallet {
         type SelfGatewayId: Get<ChainId>;
 
         type Time: Time;
+
+        /// Number of blocks after which a submitted asset price is no longer used
+        type PriceFeedMaxAge: Get<BlockNumberFor<Self>>;
+
+        /// Minimal number of fresh price submissions needed to estimate an asset price
+        type MinPriceFeeds: Get<u32>;
+
+        /// Maximal number of whitelisted price feeders, bounding the submissions aggregated per asset
+        type MaxPriceFeeders: Get<u32>;
+
+        /// Number of the latest versions kept in the history of each gateway
+        type MaxGatewayHistory: Get<u32>;
+
+        /// Maximal number of scheduled gateway changes enacted in a single block;
+        /// the changes beyond are carried over to the next block
+        type MaxScheduledGatewayChangesPerBlock: Get<u32>;
+
+        /// Bond reserved from the registrant of a gateway registered without governance
+        type GatewayRegistrationBond: Get<BalanceOf<Self>>;
+
+        /// Maximum reward in native of a single order on a gateway pending promotion
+        type PendingGatewayMaxOrderValue: Get<BalanceOf<Self>>;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -114,10 +147,11 @@ pub mod pallet {
         // dispatched.
         //
         // This function must return the weight consumed by `on_initialize` and `on_finalize`.
-        fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
-            // Anything that needs to be done at the start of the block.
-            // We don't do anything here.
-            Zero::zero()
+        fn on_initialize(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
+            // Enact the gateway changes scheduled for this block.
+            let weight = Self::enact_scheduled_gateway_changes(n);
+            // Re-aggregate the asset estimates whose oldest fresh price expired.
+            weight.saturating_add(Self::expire_asset_estimates(n))
         }
 
         // `on_finalize` is executed at the end of block after all extrinsic are dispatched.
@@ -140,7 +174,7 @@ pub mod pallet {
             // Define the maximum weight of this migration.
             let max_weight = T::DbWeight::get().reads_writes(10, 10);
             // Define the current storage migration version.
-            const CURRENT_STORAGE_VERSION: u32 = 2;
+            const CURRENT_STORAGE_VERSION: u32 = 4;
             // Migrate the storage entries.
             StorageMigrations::<T>::try_mutate(|current_version| {
                 match *current_version {
@@ -191,10 +225,23 @@ pub mod pallet {
                             191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204,
                             8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101,
                             171, 135, 85,]);
+                        // Storage Migration: Fill in GatewayABIs for the gateways registered before the ABI was stored per gateway
+                        // Storage Migration Details: v1.5.0-rc -> v1.5.1-rc
+                        let gateway_abis_weight = Self::fill_in_missing_gateway_abis();
+                        let gateway_versions_weight = Self::fill_in_missing_gateway_versions();
                         // Set migrations_done to true
                         *current_version = CURRENT_STORAGE_VERSION;
                         // Return the weight consumed by the migration.
-                        Ok::<Weight, DispatchError>(T::DbWeight::get().writes(1))
+                        Ok::<Weight, DispatchError>(T::DbWeight::get().writes(1).saturating_add(gateway_abis_weight).saturating_add(gateway_versions_weight))
+                    }
+                    // Storage Migration: Snapshot the first version of the gateways registered before gateways were versioned
+                    // Storage Migration Details: v1.5.1-rc -> v1.5.2-rc
+                    3 => {
+                        let gateway_versions_weight = Self::fill_in_missing_gateway_versions();
+                        // Set migrations_done to true
+                        *current_version = CURRENT_STORAGE_VERSION;
+                        // Return the weight consumed by the migration.
+                        Ok::<Weight, DispatchError>(T::DbWeight::get().writes(1).saturating_add(gateway_versions_weight))
                     }
                     // Add more migration cases here, if needed in the future
                     _ => {
@@ -208,6 +255,38 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// Stores the default ABI of the execution layer for each gateway registered without one.
+        pub fn fill_in_missing_gateway_abis() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            for (gateway_id, gateway_record) in <Gateways<T>>::iter() {
+                reads += 2;
+                if !<GatewayABIs<T>>::contains_key(gateway_id) {
+                    <GatewayABIs<T>>::insert(
+                        gateway_id,
+                        default_gateway_abi(&gateway_record.execution_vendor),
+                    );
+                    writes += 1;
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        /// Stores the first version of each gateway registered before gateways were versioned.
+        pub fn fill_in_missing_gateway_versions() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            for gateway_id in <Gateways<T>>::iter_keys() {
+                reads += 1;
+                if <GatewayVersions<T>>::get(gateway_id) == 0 {
+                    Self::snapshot_gateway_version(gateway_id);
+                    reads += 2;
+                    writes += 2;
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
         pub fn check_for_manual_verifier_overview_process(
             n: frame_system::pallet_prelude::BlockNumberFor<T>,
         ) -> Weight {
@@ -313,22 +392,52 @@ pub mod pallet {
                 );
             }
 
-            if !is_moving {
-                if let Some(previous) = historic_overview.iter().rev().nth(1).cloned() {
-                    if let Some((target_finality_height_increase, _)) =
-                        FinalityVerifierActivity::determine_finalized_reports_increase(&[
-                            previous,
-                            last_record.clone(),
-                            new_activity,
-                        ])
-                    {
-                        if target_finality_height_increase > Zero::zero() {
-                            is_moving = true;
-                        }
-                    }
+            let (was_active, verifier_finalized_height) =
+                (last_record.is_active, last_record.finalized_height);
+
+            // Score each gateway of the verifier out of its own heartbeat instead of trusting
+            // a single (non-)move of the finalized height, keeping the previous verdict unless
+            // the score clearly crosses the thresholds.
+            let epoch_history = EpochHistory::<T>::get(&verifier).unwrap_or_default();
+            let (mut scored_gateways, mut any_gateway_healthy) = (0u32, false);
+            let all_gateway_ids = <AllGatewayIds<T>>::get();
+            total_weight = total_weight.saturating_add(T::DbWeight::get().reads(2));
+            for gateway_id in all_gateway_ids {
+                // Resolving the vendor of each registered gateway reads its record
+                total_weight = total_weight.saturating_add(T::DbWeight::get().reads(1));
+                if Self::get_verification_vendor(&gateway_id).ok() != Some(verifier.clone()) {
+                    continue
                 }
+                let mut gateway_heartbeat = match verifier {
+                    GatewayVendor::XBI => latest_heartbeat.clone(),
+                    _ => match T::Portal::get_latest_heartbeat(&gateway_id) {
+                        Ok(heartbeat) if heartbeat.ever_initialized => heartbeat,
+                        // Light clients never initialized have no heights of their own yet
+                        _ => latest_heartbeat.clone(),
+                    },
+                };
+                // A halted verifier halts all of its gateways
+                gateway_heartbeat.is_halted = gateway_heartbeat.is_halted || !is_active;
+                let is_healthy = Self::update_gateway_health(
+                    n,
+                    gateway_id,
+                    &epoch_history,
+                    gateway_heartbeat,
+                    verifier_finalized_height,
+                    was_active,
+                );
+                scored_gateways += 1;
+                any_gateway_healthy = any_gateway_healthy || is_healthy;
+                total_weight =
+                    total_weight.saturating_add(<T as Config>::WeightInfo::update_gateway_health());
             }
-            let was_active = last_record.is_active;
+            // The verifier stays active as long as any of its gateways is healthy
+            let is_healthy = if scored_gateways > 0 {
+                any_gateway_healthy
+            } else {
+                is_active
+            };
+
             let activity = if is_active {
                 FinalityVerifierActivity {
                     verifier: verifier.clone(),
@@ -337,7 +446,7 @@ pub mod pallet {
                     finalized_height,
                     updated_height,
                     epoch: new_epoch,
-                    is_active: is_active && is_moving,
+                    is_active: is_healthy,
                 }
             } else {
                 last_record.reported_at = n;
@@ -360,6 +469,56 @@ pub mod pallet {
             total_weight
         }
 
+        /// Scores the gateway out of its own heartbeat and the epoch history of its verifier.
+        /// The gateway only counts as moving if its own finalized height increased, so that
+        /// other gateways of the same verifier can't hide its stall. A gateway scored for the
+        /// first time is compared against the height its verifier last reported.
+        /// Returns whether the gateway is healthy after the update.
+        pub fn update_gateway_health(
+            n: frame_system::pallet_prelude::BlockNumberFor<T>,
+            gateway_id: TargetId,
+            epoch_history: &[EpochEstimate<BlockNumberFor<T>>],
+            heartbeat: LightClientHeartbeat<T>,
+            verifier_finalized_height: BlockNumberFor<T>,
+            was_verifier_active: bool,
+        ) -> bool {
+            let previous_health = GatewayHealth::<T>::get(gateway_id);
+            let is_moving = heartbeat.last_finalized_height
+                > previous_health
+                    .as_ref()
+                    .map_or(verifier_finalized_height, |health| {
+                        health.last_finalized_height
+                    });
+            let last_moved_at = if is_moving {
+                Some(n)
+            } else {
+                previous_health
+                    .as_ref()
+                    .and_then(|health| health.last_moved_at)
+            };
+            let stalled_for = last_moved_at
+                .map(|moved_at| n.saturating_sub(moved_at))
+                .unwrap_or(n);
+            let score = GatewayHealthRecord::score(stalled_for, epoch_history);
+            let was_healthy = previous_health
+                .map(|health| health.is_healthy)
+                .unwrap_or(was_verifier_active);
+            let is_healthy = !heartbeat.is_halted
+                && last_moved_at.is_some()
+                && GatewayHealthRecord::<BlockNumberFor<T>>::is_healthy_after(was_healthy, score);
+            GatewayHealth::<T>::insert(
+                gateway_id,
+                GatewayHealthRecord {
+                    score,
+                    last_moved_at,
+                    last_finalized_height: heartbeat.last_finalized_height,
+                    is_healthy,
+                    updated_at: n,
+                },
+            );
+            is_healthy
+        }
+
         pub fn update_historic_overview(
             verifier: GatewayVendor,
             activity: FinalityVerifierActivity<BlockNumberFor<T>>,
@@ -668,47 +827,196 @@ pub mod pallet {
             gateway_id: TargetId,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            if !<Gateways<T>>::contains_key(gateway_id) {
-                Err(Error::<T>::XdnsRecordNotFound.into())
-            } else {
-                // Get associated finality verifier
-                let verifier = <Gateways<T>>::get(gateway_id)
-                    .expect("Ensured by Gateways::contains_key() above during purge_gateway_record")
-                    .verification_vendor;
+            Self::do_purge_gateway_record(gateway_id)?;
+            Self::deposit_event(Event::<T>::GatewayRecordPurged(requester, gateway_id));
+            Ok(().into())
+        }
 
-                <Gateways<T>>::remove(gateway_id);
+        /// Promotes the gateway pending promotion to full activity and releases the registrant's bond. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::promote_pending_gateway())]
+        pub fn promote_pending_gateway(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::do_promote_pending_gateway(gateway_id)?;
+            Ok(().into())
+        }
 
-                let token_ids = GatewayTokens::<T>::get(gateway_id);
+        /// Votes to promote the gateway pending promotion as a member of the current attesters committee.
+        /// The gateway is promoted once the majority of the committee voted for it.
+        /// Votes of attesters who left the committee since are dropped and no longer counted.
+        #[pallet::weight(< T as Config >::WeightInfo::vote_gateway_promotion(
+            MAX_GATEWAY_PROMOTION_VOTES
+        ))]
+        pub fn vote_gateway_promotion(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            let attester = ensure_signed(origin)?;
+            let committee = T::AttestersRead::current_committee();
+            ensure!(
+                committee.contains(&attester),
+                Error::<T>::NotInAttestersCommittee
+            );
 
-                token_ids.iter().for_each(|token_id| {
-                    <Tokens<T>>::remove(token_id, gateway_id);
-                    if gateway_id == T::SelfGatewayId::get() {
-                        <AllTokenIds<T>>::mutate(|all_token_ids| {
-                            all_token_ids.retain(|id| id != token_id);
-                        });
-                    }
-                });
+            let votes = <PendingGateways<T>>::try_mutate(gateway_id, |maybe_pending| {
+                let pending = maybe_pending
+                    .as_mut()
+                    .ok_or(Error::<T>::PendingGatewayNotFound)?;
+                ensure!(
+                    !pending.promotion_votes.contains(&attester),
+                    Error::<T>::GatewayPromotionAlreadyVoted
+                );
+                pending
+                    .promotion_votes
+                    .retain(|voter| committee.contains(voter));
+                pending
+                    .promotion_votes
+                    .try_push(attester.clone())
+                    .map_err(|_| Error::<T>::TooManyGatewayPromotionVotes)?;
+                Ok::<usize, Error<T>>(pending.promotion_votes.len())
+            })?;
+            Self::deposit_event(Event::<T>::GatewayPromotionVoted(attester, gateway_id));
 
-                <GatewayTokens<T>>::remove(gateway_id);
+            if votes.saturating_mul(2) > committee.len() {
+                Self::do_promote_pending_gateway(gateway_id)?;
+            }
+            Ok(Some(<T as Config>::WeightInfo::vote_gateway_promotion(
+                votes as u32,
+            ))
+            .into())
+        }
 
-                <AllGatewayIds<T>>::mutate(|all_gateway_ids| {
-                    all_gateway_ids.retain(|&id| id != gateway_id);
-                });
+        /// Slashes the bond of the gateway pending promotion into the treasury and removes the gateway,
+        /// once its registration is shown to be malicious or misconfigured. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::slash_pending_gateway())]
+        pub fn slash_pending_gateway(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let pending =
+                <PendingGateways<T>>::take(gateway_id).ok_or(Error::<T>::PendingGatewayNotFound)?;
+
+            let treasury = T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury);
+            let not_slashed = T::Currency::repatriate_reserved(
+                &pending.registrant,
+                &treasury,
+                pending.bond,
+                BalanceStatus::Free,
+            )?;
+            Self::do_purge_gateway_record(gateway_id)?;
 
-                let current_block = <frame_system::Pallet<T>>::block_number();
+            Self::deposit_event(Event::<T>::PendingGatewaySlashed(
+                pending.registrant,
+                gateway_id,
+                pending.bond.saturating_sub(not_slashed),
+            ));
+            Ok(().into())
+        }
 
-                let latest_heartbeat = T::Portal::get_latest_heartbeat_by_vendor(verifier.clone());
-                let epoch = latest_heartbeat.last_finalized_height;
-                let _weight = Self::process_single_verifier_overview(
-                    current_block,
-                    verifier,
-                    epoch,
-                    latest_heartbeat,
+        /// Schedules the gateway record to be overridden at a future block. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::schedule_gateway_override(
+            gateway_record.allowed_side_effects.len() as u32
+        ))]
+        pub fn schedule_gateway_override(
+            origin: OriginFor<T>,
+            gateway_record: GatewayRecord<T::AccountId>,
+            enact_at: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            for (sfx_4b_id, _) in gateway_record.allowed_side_effects.iter() {
+                ensure!(
+                    <StandardSFXABIs<T>>::contains_key(sfx_4b_id),
+                    Error::<T>::SideEffectABINotFound
                 );
-
-                Self::deposit_event(Event::<T>::GatewayRecordPurged(requester, gateway_id));
-                Ok(().into())
             }
+            Self::schedule_gateway_change(
+                gateway_record.gateway_id,
+                ScheduledGatewayChange::OverrideGateway(gateway_record),
+                enact_at,
+            )?;
+            Ok(().into())
+        }
+
+        /// Schedules the SFX ABIs of the gateway to be overridden at a future block. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::schedule_sfx_abi_override(
+            new_sfx_abis.len() as u32
+        ))]
+        pub fn schedule_sfx_abi_override(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            new_sfx_abis: Vec<(Sfx4bId, SFXAbi)>,
+            enact_at: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(gateway_id),
+                Error::<T>::XdnsRecordNotFound
+            );
+            Self::schedule_gateway_change(
+                gateway_id,
+                ScheduledGatewayChange::OverrideSfxAbi(new_sfx_abis),
+                enact_at,
+            )?;
+            Ok(().into())
+        }
+
+        /// Schedules the gateway to be removed from the onchain registry at a future block. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::schedule_gateway_purge())]
+        pub fn schedule_gateway_purge(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            enact_at: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(gateway_id),
+                Error::<T>::XdnsRecordNotFound
+            );
+            Self::schedule_gateway_change(
+                gateway_id,
+                ScheduledGatewayChange::PurgeGateway,
+                enact_at,
+            )?;
+            Ok(().into())
+        }
+
+        /// Cancels all changes of the gateway scheduled for the given block. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::cancel_scheduled_gateway_changes(
+            T::MaxScheduledGatewayChangesPerBlock::get()
+        ))]
+        pub fn cancel_scheduled_gateway_changes(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            enact_at: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let scheduled_count =
+                <ScheduledGatewayChanges<T>>::try_mutate_exists(enact_at, |maybe_changes| {
+                    let changes = maybe_changes
+                        .as_mut()
+                        .ok_or(Error::<T>::ScheduledGatewayChangeNotFound)?;
+                    let scheduled_count = changes.len();
+                    changes.retain(|(scheduled_gateway_id, _)| *scheduled_gateway_id != gateway_id);
+                    if changes.len() == scheduled_count {
+                        return Err(Error::<T>::ScheduledGatewayChangeNotFound)
+                    }
+                    if changes.is_empty() {
+                        *maybe_changes = None;
+                    }
+                    Ok(scheduled_count as u32)
+                })?;
+            Self::deposit_event(Event::<T>::ScheduledGatewayChangesCancelled(
+                gateway_id, enact_at,
+            ));
+            Ok(
+                Some(<T as Config>::WeightInfo::cancel_scheduled_gateway_changes(
+                    scheduled_count,
+                ))
+                .into(),
+            )
         }
 
         #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
@@ -725,6 +1033,14 @@ pub mod pallet {
                 token_ids.retain(|&x_token_id| x_token_id != token_id);
             });
 
+            <CanonicalAssets<T>>::mutate(token_id, |maybe_group| {
+                if let Some(group) = maybe_group {
+                    group
+                        .members
+                        .retain(|(member_id, _)| member_id != &gateway_id);
+                }
+            });
+
             Ok(().into())
         }
 
@@ -772,9 +1088,232 @@ pub mod pallet {
                 all_token_ids.retain(|&id| id != token_id);
             });
 
+            <CanonicalAssets<T>>::remove(token_id);
+
+            Ok(().into())
+        }
+
+        /// Groups the representations of the asset on many gateways under its canonical decimals. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::register_canonical_asset())]
+        pub fn register_canonical_asset(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            symbol: Vec<u8>,
+            decimals: u8,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <AllTokenIds<T>>::get().contains(&asset_id),
+                Error::<T>::TokenRecordNotFoundInAssetsOverlay
+            );
+            ensure!(
+                !<CanonicalAssets<T>>::contains_key(asset_id),
+                Error::<T>::CanonicalAssetAlreadyExists
+            );
+
+            <CanonicalAssets<T>>::insert(
+                asset_id,
+                CanonicalAssetGroup {
+                    asset_id,
+                    symbol,
+                    decimals,
+                    members: vec![],
+                },
+            );
+            Self::deposit_event(Event::<T>::CanonicalAssetRegistered(asset_id, decimals));
+            Ok(().into())
+        }
+
+        /// Adds or overrides the representation of the canonical asset on the gateway,
+        /// linking the asset to the gateway with the same token properties. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::add_canonical_asset_member())]
+        pub fn add_canonical_asset_member(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            gateway_id: TargetId,
+            token_props: TokenInfo,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(gateway_id),
+                Error::<T>::GatewayRecordNotFound
+            );
+
+            <CanonicalAssets<T>>::try_mutate(asset_id, |maybe_group| {
+                let group = maybe_group
+                    .as_mut()
+                    .ok_or(Error::<T>::CanonicalAssetNotFound)?;
+                group
+                    .members
+                    .retain(|(member_id, _)| member_id != &gateway_id);
+                group.members.push((gateway_id, token_props.clone()));
+                Ok::<(), Error<T>>(())
+            })?;
+            Self::override_token(asset_id, gateway_id, token_props)?;
+
+            Self::deposit_event(Event::<T>::CanonicalAssetMemberAdded(asset_id, gateway_id));
+            Ok(().into())
+        }
+
+        /// Removes the representation of the canonical asset on the gateway, unlinking the asset
+        /// from the gateway so that its amounts there are no longer read in the member's decimals.
+        /// Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::remove_canonical_asset_member())]
+        pub fn remove_canonical_asset_member(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            <CanonicalAssets<T>>::try_mutate(asset_id, |maybe_group| {
+                let group = maybe_group
+                    .as_mut()
+                    .ok_or(Error::<T>::CanonicalAssetNotFound)?;
+                ensure!(
+                    group.member(&gateway_id).is_some(),
+                    Error::<T>::CanonicalAssetMemberNotFound
+                );
+                group
+                    .members
+                    .retain(|(member_id, _)| member_id != &gateway_id);
+                Ok::<(), Error<T>>(())
+            })?;
+            <Tokens<T>>::remove(asset_id, gateway_id);
+            <GatewayTokens<T>>::mutate(gateway_id, |token_ids| {
+                token_ids.retain(|&token_id| token_id != asset_id);
+            });
+
+            Self::deposit_event(Event::<T>::CanonicalAssetMemberRemoved(
+                asset_id, gateway_id,
+            ));
             Ok(().into())
         }
 
+        /// Sets the codec, hasher and crypto the gateway's messages are decoded with. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_gateway_abi())]
+        pub fn set_gateway_abi(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            codec: Codec,
+            gateway_abi: GatewayABIConfig,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            <Gateways<T>>::try_mutate(gateway_id, |maybe_record| match maybe_record {
+                Some(record) => {
+                    record.codec = codec.clone();
+                    Ok(())
+                },
+                None => Err(Error::<T>::XdnsRecordNotFound),
+            })?;
+            <GatewayABIs<T>>::insert(gateway_id, gateway_abi.clone());
+
+            Self::deposit_event(Event::<T>::GatewayABIUpdated(
+                gateway_id,
+                codec,
+                gateway_abi,
+            ));
+            Ok(().into())
+        }
+
+        /// Whitelists an account to submit asset prices. Root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::add_price_feeder())]
+        pub fn add_price_feeder(
+            origin: OriginFor<T>,
+            feeder: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            <PriceFeeders<T>>::try_mutate(|feeders| {
+                if feeders.contains(&feeder) {
+                    return Err(Error::<T>::PriceFeederAlreadyExists)
+                }
+                feeders
+                    .try_push(feeder.clone())
+                    .map_err(|_| Error::<T>::TooManyPriceFeeders)
+            })?;
+            Self::deposit_event(Event::<T>::PriceFeederAdded(feeder));
+            Ok(().into())
+        }
+
+        /// Removes a price feeder along with its submissions and re-aggregates the affected estimates. Root only access.
+        /// Charged for re-aggregating every registered asset, refunded down to the assets the feeder priced.
+        #[pallet::weight({
+            let assets = Pallet::<T>::price_estimates_bounds().0;
+            < T as Config >::WeightInfo::remove_price_feeder(assets).saturating_add(
+                Pallet::<T>::aggregate_asset_price_weight(T::MaxPriceFeeders::get())
+                    .saturating_mul(assets.into()),
+            )
+        })]
+        pub fn remove_price_feeder(
+            origin: OriginFor<T>,
+            feeder: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            <PriceFeeders<T>>::try_mutate(|feeders| {
+                let index = feeders
+                    .iter()
+                    .position(|known| known == &feeder)
+                    .ok_or(Error::<T>::PriceFeederNotFound)?;
+                feeders.remove(index);
+                Ok::<(), Error<T>>(())
+            })?;
+            let all_token_ids = <AllTokenIds<T>>::get();
+            let mut reaggregated = 0u32;
+            for asset_id in all_token_ids.iter() {
+                if <PriceSubmissions<T>>::take(asset_id, &feeder).is_some() {
+                    Self::aggregate_asset_price(*asset_id);
+                    reaggregated += 1;
+                }
+            }
+            Self::deposit_event(Event::<T>::PriceFeederRemoved(feeder));
+            let feeders = <PriceFeeders<T>>::decode_len().unwrap_or_default() as u32;
+            Ok(Some(
+                <T as Config>::WeightInfo::remove_price_feeder(all_token_ids.len() as u32)
+                    .saturating_add(
+                        Self::aggregate_asset_price_weight(feeders)
+                            .saturating_mul(reaggregated.into()),
+                    ),
+            )
+            .into())
+        }
+
+        /// Submits the feeder's price of the asset, quoted in native per `ASSET_PRICE_PRECISION` of the asset.
+        /// The asset estimates are re-aggregated as the median of fresh submissions,
+        /// charged per submission of the whitelisted feeders and per registered asset.
+        #[pallet::weight(Pallet::<T>::submit_asset_price_weight(T::MaxPriceFeeders::get()))]
+        pub fn submit_asset_price(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            price: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let feeder = ensure_signed(origin)?;
+            let feeders = <PriceFeeders<T>>::get();
+            ensure!(feeders.contains(&feeder), Error::<T>::NotPriceFeeder);
+            ensure!(
+                asset_id != T::SelfTokenId::get(),
+                Error::<T>::CannotPriceNativeAsset
+            );
+            ensure!(
+                <AllTokenIds<T>>::get().contains(&asset_id),
+                Error::<T>::TokenRecordNotFoundInAssetsOverlay
+            );
+            ensure!(!price.is_zero(), Error::<T>::InvalidAssetPrice);
+
+            <PriceSubmissions<T>>::insert(
+                asset_id,
+                &feeder,
+                (price, frame_system::Pallet::<T>::block_number()),
+            );
+            Self::deposit_event(Event::<T>::AssetPriceSubmitted(feeder, asset_id, price));
+
+            if let Some(median) = Self::aggregate_asset_price(asset_id) {
+                Self::deposit_event(Event::<T>::AssetEstimatesUpdated(asset_id, median));
+            }
+            // Submissions of removed feeders are taken along with them, so there are at most as many as feeders
+            Ok(Some(Self::submit_asset_price_weight(feeders.len() as u32)).into())
+        }
+
         #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
         pub fn zip_topology(origin: OriginFor<T>) -> DispatchResult {
             let _ = ensure_signed(origin)?;
@@ -823,6 +1362,38 @@ pub mod pallet {
         XdnsRecordUpdated(TargetId),
         /// \[xdns_topology\]
         XDNSTopologyZip(XDNSTopology<T::AccountId>),
+        /// \[gateway_4b_id, codec, gateway_abi\]
+        GatewayABIUpdated(TargetId, Codec, GatewayABIConfig),
+        /// \[feeder\]
+        PriceFeederAdded(T::AccountId),
+        /// \[feeder\]
+        PriceFeederRemoved(T::AccountId),
+        /// \[feeder, asset_id, price\]
+        AssetPriceSubmitted(T::AccountId, AssetId, BalanceOf<T>),
+        /// \[asset_id, median_price\]
+        AssetEstimatesUpdated(AssetId, BalanceOf<T>),
+        /// \[gateway_4b_id, enact_at\]
+        GatewayChangeScheduled(TargetId, BlockNumberFor<T>),
+        /// \[gateway_4b_id, enact_at\]
+        ScheduledGatewayChangesCancelled(TargetId, BlockNumberFor<T>),
+        /// \[gateway_4b_id, version\]
+        ScheduledGatewayChangeEnacted(TargetId, u32),
+        /// \[gateway_4b_id, enact_at\]
+        ScheduledGatewayChangeFailed(TargetId, BlockNumberFor<T>),
+        /// \[registrant, gateway_4b_id, bond\]
+        PendingGatewayRegistered(T::AccountId, TargetId, BalanceOf<T>),
+        /// \[attester, gateway_4b_id\]
+        GatewayPromotionVoted(T::AccountId, TargetId),
+        /// \[gateway_4b_id\]
+        PendingGatewayPromoted(TargetId),
+        /// \[registrant, gateway_4b_id, slashed_bond\]
+        PendingGatewaySlashed(T::AccountId, TargetId, BalanceOf<T>),
+        /// \[asset_id, canonical_decimals\]
+        CanonicalAssetRegistered(AssetId, u8),
+        /// \[asset_id, gateway_4b_id\]
+        CanonicalAssetMemberAdded(AssetId, TargetId),
+        /// \[asset_id, gateway_4b_id\]
+        CanonicalAssetMemberRemoved(AssetId, TargetId),
     }
 
     // Errors inform users that something went wrong.
@@ -858,6 +1429,44 @@ pub mod pallet {
         TopologyDecodeError,
         /// Empty topology submitted at Unzip
         EmptyTopologySubmitted,
+        /// Price feeder is already whitelisted
+        PriceFeederAlreadyExists,
+        /// Price feeder not found
+        PriceFeederNotFound,
+        /// The maximal number of price feeders is already whitelisted
+        TooManyPriceFeeders,
+        /// Only whitelisted price feeders can submit asset prices
+        NotPriceFeeder,
+        /// Native asset is the quote asset and can't be priced
+        CannotPriceNativeAsset,
+        /// Asset price must be non-zero
+        InvalidAssetPrice,
+        /// Gateway changes can only be scheduled for future blocks
+        ScheduledChangeMustBeInFuture,
+        /// No change of the gateway is scheduled for that block
+        ScheduledGatewayChangeNotFound,
+        /// Gateway version not found in the gateway history
+        GatewayVersionNotFound,
+        /// Gateway is not pending promotion
+        PendingGatewayNotFound,
+        /// Registrant can't reserve the gateway registration bond
+        InsufficientBalanceForRegistrationBond,
+        /// Only members of the current attesters committee can vote on gateway promotions
+        NotInAttestersCommittee,
+        /// The maximal number of promotion votes is already cast on the gateway
+        TooManyGatewayPromotionVotes,
+        /// Attester already voted to promote the gateway
+        GatewayPromotionAlreadyVoted,
+        /// Asset is already grouped as a canonical asset
+        CanonicalAssetAlreadyExists,
+        /// Canonical asset group not found
+        CanonicalAssetNotFound,
+        /// Canonical asset has no representation on that gateway
+        CanonicalAssetMemberNotFound,
+        /// Amount overflows after normalising it to the canonical decimals
+        CanonicalAssetAmountOverflow,
+        /// No fresh price estimate to convert native amounts into the asset
+        AssetPriceEstimateUnavailable,
     }
 
     // Deprecated storage entry -- StandardSideEffects
@@ -888,6 +1497,59 @@ pub mod pallet {
     pub type Gateways<T: Config> =
         StorageMap<_, Identity, TargetId, GatewayRecord<T::AccountId>, OptionQuery>;
 
+    // Hasher, crypto and type sizes of each gateway; the codec is kept in GatewayRecord
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_abis)]
+    pub type GatewayABIs<T: Config> =
+        StorageMap<_, Identity, TargetId, GatewayABIConfig, OptionQuery>;
+
+    // Gateways registered without governance, pending promotion to full activity
+    #[pallet::storage]
+    #[pallet::getter(fn pending_gateways)]
+    pub type PendingGateways<T: Config> = StorageMap<
+        _,
+        Identity,
+        TargetId,
+        PendingGatewayRecord<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    // Current version of each gateway, bumped with each change of its record or SFX ABIs
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_versions)]
+    pub type GatewayVersions<T: Config> = StorageMap<_, Identity, TargetId, u32, ValueQuery>;
+
+    // Record and SFX ABIs of the latest `MaxGatewayHistory` versions of the gateway
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_history)]
+    pub type GatewayHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        TargetId,
+        Twox64Concat,
+        u32,
+        GatewayRecordVersion<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    // Number of in-flight xtxs confirming against each version of the gateway.
+    //     Pinned versions are kept in the history beyond `MaxGatewayHistory` until released.
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_version_pins)]
+    pub type GatewayVersionPins<T: Config> =
+        StorageDoubleMap<_, Identity, TargetId, Twox64Concat, u32, u32, ValueQuery>;
+
+    // Gateway changes to be enacted at the start of the given block
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_gateway_changes)]
+    pub type ScheduledGatewayChanges<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Vec<(TargetId, ScheduledGatewayChange<T::AccountId>)>,
+        OptionQuery,
+    >;
+
     // Token can be stored in multiple gateways and on each Gateway be mapped to a different TokenRecord (Substrate, Eth etc.)
     #[pallet::storage]
     #[pallet::getter(fn tokens)]
@@ -980,12 +1642,54 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // Accounts allowed to submit asset prices
+    #[pallet::storage]
+    #[pallet::getter(fn price_feeders)]
+    pub type PriceFeeders<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxPriceFeeders>, ValueQuery>;
+
+    // Latest price of each feeder in native per ASSET_PRICE_PRECISION of the asset, with the block it was submitted at
+    #[pallet::storage]
+    #[pallet::getter(fn price_submissions)]
+    pub type PriceSubmissions<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        (BalanceOf<T>, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    // Block until which the aggregated estimates of the asset are fresh
+    #[pallet::storage]
+    #[pallet::getter(fn asset_estimates_valid_until)]
+    pub type AssetEstimatesValidUntil<T: Config> =
+        StorageMap<_, Identity, AssetId, BlockNumberFor<T>, OptionQuery>;
+
+    // Assets whose aggregated estimates expire at the block, re-aggregated on its initialization
+    #[pallet::storage]
+    #[pallet::getter(fn asset_estimates_expiring)]
+    pub type AssetEstimatesExpiring<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<AssetId>, ValueQuery>;
+
     // Keep last 10 epoch estimates
     #[pallet::storage]
     #[pallet::getter(fn epoch_history)]
     pub type EpochHistory<T: Config> =
         StorageMap<_, Identity, GatewayVendor, Vec<EpochEstimate<BlockNumberFor<T>>>>;
 
+    // Same assets represented on many gateways, keyed by the AssetId they're accounted as on the Circuit
+    #[pallet::storage]
+    #[pallet::getter(fn canonical_assets)]
+    pub type CanonicalAssets<T: Config> = StorageMap<_, Identity, AssetId, CanonicalAssetGroup>;
+
+    // Health of each gateway scored out of its verifier's epoch history and its own stalls
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_health)]
+    pub type GatewayHealth<T: Config> =
+        StorageMap<_, Identity, TargetId, GatewayHealthRecord<BlockNumberFor<T>>>;
+
     // The genesis config type.
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
@@ -995,6 +1699,25 @@ pub mod pallet {
         // pub known_gateway_records: Vec<GatewayRecord<T::AccountId>>,
         // pub standard_sfx_abi: Vec<(Sfx4bId, SFXAbi)>,
         pub standard_sfx_abi: Vec<u8>,
+        // SCALE-encoded Vec<TokenRecord>, registered after the gateways they are linked to.
+        #[serde(default)]
+        pub known_tokens: Vec<u8>,
+        // SCALE-encoded Vec<(TargetId, H256)>
+        #[serde(default)]
+        pub remote_order_addresses: Vec<u8>,
+        // SCALE-encoded Vec<(TargetId, H256)>
+        #[serde(default)]
+        pub remote_bidding_addresses: Vec<u8>,
+        // SCALE-encoded Vec<(TargetId, Vec<(Sfx4bId, SFXAbi)>)>, registered on top of the ones
+        // derived from the standard SFX ABIs and the gateway's allowed side effects.
+        #[serde(default)]
+        pub gateway_sfx_abis: Vec<u8>,
+        // SCALE-encoded Vec<(TargetId, GatewayABIConfig)>, defaulting to the execution vendor's ABI.
+        #[serde(default)]
+        pub gateway_abis: Vec<u8>,
+        // SCALE-encoded Vec<(TargetId, u32)>, the version each gateway's genesis record starts at.
+        #[serde(default)]
+        pub gateway_versions: Vec<u8>,
         #[serde(skip)]
         pub _marker: PhantomData<T>,
     }
@@ -1016,7 +1739,24 @@ pub mod pallet {
                 <StandardSFXABIs<T>>::insert(sfx_4b_id, sfx_abi);
             }
 
+            let gateway_abis: Vec<(TargetId, GatewayABIConfig)> =
+                Decode::decode(&mut &self.gateway_abis[..]).unwrap_or_default();
+            let gateway_versions: Vec<(TargetId, u32)> =
+                Decode::decode(&mut &self.gateway_versions[..]).unwrap_or_default();
+
             for gateway_record in known_gateway_records {
+                let gateway_id = gateway_record.gateway_id;
+                if let Some((_, gateway_abi)) =
+                    gateway_abis.iter().find(|(id, _)| *id == gateway_id)
+                {
+                    <GatewayABIs<T>>::insert(gateway_id, gateway_abi.clone());
+                }
+                // The genesis snapshot of the gateway is taken at the version following this one
+                if let Some((_, version)) =
+                    gateway_versions.iter().find(|(id, _)| *id == gateway_id)
+                {
+                    <GatewayVersions<T>>::insert(gateway_id, version.saturating_sub(1));
+                }
                 Pallet::<T>::override_gateway(
                     gateway_record.gateway_id,
                     gateway_record.verification_vendor,
@@ -1034,6 +1774,71 @@ pub mod pallet {
                 })
                 .ok();
             }
+
+            let gateway_sfx_abis: Vec<(TargetId, Vec<(Sfx4bId, SFXAbi)>)> =
+                Decode::decode(&mut &self.gateway_sfx_abis[..]).unwrap_or_default();
+            for (gateway_id, sfx_abis) in gateway_sfx_abis {
+                if !<Gateways<T>>::contains_key(gateway_id) {
+                    log::error!(
+                        "XDNS -- on-genesis: SFX ABIs of unknown gateway {:?} skipped",
+                        gateway_id
+                    );
+                    continue
+                }
+                for (sfx_4b_id, sfx_abi) in sfx_abis {
+                    <SFXABIRegistry<T>>::insert(gateway_id, sfx_4b_id, sfx_abi);
+                }
+                // Keep the genesis snapshot of the gateway in line with its SFX ABIs
+                let version = <GatewayVersions<T>>::get(gateway_id);
+                <GatewayHistory<T>>::mutate(gateway_id, version, |maybe_versioned| {
+                    if let Some(versioned) = maybe_versioned {
+                        versioned.sfx_abis = <SFXABIRegistry<T>>::iter_prefix(gateway_id).collect();
+                    }
+                });
+            }
+
+            let mut known_tokens: Vec<TokenRecord> =
+                Decode::decode(&mut &self.known_tokens[..]).unwrap_or_default();
+            // Assets are created along with their self-gateway record, before linking to remote gateways
+            known_tokens.sort_by_key(|token| token.gateway_id != T::SelfGatewayId::get());
+
+            for token in known_tokens {
+                let result = if token.gateway_id == T::SelfGatewayId::get() {
+                    Pallet::<T>::register_new_token(
+                        &frame_system::RawOrigin::Root.into(),
+                        token.token_id,
+                        token.token_props,
+                    )
+                } else {
+                    Pallet::<T>::link_token_to_gateway(
+                        token.token_id,
+                        token.gateway_id,
+                        token.token_props,
+                    )
+                };
+                result
+                    .map_err(|e| {
+                        log::error!(
+                            "XDNS -- on-genesis: failed to register token {:?} on gateway {:?}: {:?}",
+                            token.token_id,
+                            token.gateway_id,
+                            e
+                        );
+                    })
+                    .ok();
+            }
+
+            let remote_order_addresses: Vec<(TargetId, H256)> =
+                Decode::decode(&mut &self.remote_order_addresses[..]).unwrap_or_default();
+            for (gateway_id, address) in remote_order_addresses {
+                <RemoteOrderAddresses<T>>::insert(gateway_id, address);
+            }
+
+            let remote_bidding_addresses: Vec<(TargetId, H256)> =
+                Decode::decode(&mut &self.remote_bidding_addresses[..]).unwrap_or_default();
+            for (gateway_id, address) in remote_bidding_addresses {
+                <RemoteBiddingAddresses<T>>::insert(gateway_id, address);
+            }
         }
     }
 
@@ -1113,6 +1918,384 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Promotes the pending gateway to full activity and releases the registrant's bond.
+        fn do_promote_pending_gateway(gateway_id: TargetId) -> DispatchResult {
+            let pending =
+                <PendingGateways<T>>::take(gateway_id).ok_or(Error::<T>::PendingGatewayNotFound)?;
+            T::Currency::unreserve(&pending.registrant, pending.bond);
+            Self::deposit_event(Event::<T>::PendingGatewayPromoted(gateway_id));
+            Ok(())
+        }
+
+        /// Removes the gateway record, its ABI and its tokens from the onchain registry,
+        /// releasing the bond of a pending registration. The removal is recorded as a new
+        /// version of the gateway, while the earlier versions, SFX ABIs included, stay
+        /// in the gateway history for the xtxs still in flight.
+        pub fn do_purge_gateway_record(gateway_id: TargetId) -> DispatchResult {
+            // Get associated finality verifier
+            let verifier = <Gateways<T>>::get(gateway_id)
+                .ok_or(Error::<T>::XdnsRecordNotFound)?
+                .verification_vendor;
+
+            <Gateways<T>>::remove(gateway_id);
+            <GatewayABIs<T>>::remove(gateway_id);
+
+            let token_ids = GatewayTokens::<T>::get(gateway_id);
+
+            token_ids.iter().for_each(|token_id| {
+                <Tokens<T>>::remove(token_id, gateway_id);
+                if gateway_id == T::SelfGatewayId::get() {
+                    <AllTokenIds<T>>::mutate(|all_token_ids| {
+                        all_token_ids.retain(|id| id != token_id);
+                    });
+                }
+            });
+
+            <GatewayTokens<T>>::remove(gateway_id);
+            <GatewayHealth<T>>::remove(gateway_id);
+
+            <AllGatewayIds<T>>::mutate(|all_gateway_ids| {
+                all_gateway_ids.retain(|&id| id != gateway_id);
+            });
+
+            // Release the bond of a pending gateway purged without being slashed
+            if let Some(pending) = <PendingGateways<T>>::take(gateway_id) {
+                T::Currency::unreserve(&pending.registrant, pending.bond);
+            }
+
+            Self::snapshot_gateway_version(gateway_id);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+
+            let latest_heartbeat = T::Portal::get_latest_heartbeat_by_vendor(verifier.clone());
+            let epoch = latest_heartbeat.last_finalized_height;
+            let _weight = Self::process_single_verifier_overview(
+                current_block,
+                verifier,
+                epoch,
+                latest_heartbeat,
+            );
+
+            Ok(())
+        }
+
+        /// Stores the current record and SFX ABIs of the gateway as its next version,
+        /// dropping the versions beyond `MaxGatewayHistory` from the history.
+        /// Versions still pinned by in-flight xtxs are dropped once released.
+        pub fn snapshot_gateway_version(gateway_id: TargetId) -> u32 {
+            let version = <GatewayVersions<T>>::get(gateway_id).saturating_add(1);
+            if let Some(evicted_version) = version.checked_sub(T::MaxGatewayHistory::get()) {
+                if !<GatewayVersionPins<T>>::contains_key(gateway_id, evicted_version) {
+                    <GatewayHistory<T>>::remove(gateway_id, evicted_version);
+                }
+            }
+            <GatewayHistory<T>>::insert(
+                gateway_id,
+                version,
+                GatewayRecordVersion {
+                    version,
+                    record: <Gateways<T>>::get(gateway_id),
+                    sfx_abis: <SFXABIRegistry<T>>::iter_prefix(gateway_id).collect(),
+                    enacted_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            <GatewayVersions<T>>::insert(gateway_id, version);
+            version
+        }
+
+        /// Versions of the gateway kept in the history, from the oldest.
+        pub fn gateway_version_history(
+            gateway_id: TargetId,
+        ) -> Vec<GatewayRecordVersion<T::AccountId, BlockNumberFor<T>>> {
+            let mut history: Vec<GatewayRecordVersion<T::AccountId, BlockNumberFor<T>>> =
+                <GatewayHistory<T>>::iter_prefix_values(gateway_id).collect();
+            history.sort_by_key(|versioned| versioned.version);
+            history
+        }
+
+        /// Whether the version of the gateway is older than the latest `MaxGatewayHistory` versions.
+        fn is_gateway_version_expired(gateway_id: TargetId, version: u32) -> bool {
+            version.saturating_add(T::MaxGatewayHistory::get())
+                <= <GatewayVersions<T>>::get(gateway_id)
+        }
+
+        fn schedule_gateway_change(
+            gateway_id: TargetId,
+            change: ScheduledGatewayChange<T::AccountId>,
+            enact_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure!(
+                enact_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ScheduledChangeMustBeInFuture
+            );
+            <ScheduledGatewayChanges<T>>::append(enact_at, (gateway_id, change));
+            Self::deposit_event(Event::<T>::GatewayChangeScheduled(gateway_id, enact_at));
+            Ok(())
+        }
+
+        /// Applies the gateway changes scheduled for the block, each resulting in a new gateway version.
+        /// At most `MaxScheduledGatewayChangesPerBlock` changes are enacted,
+        /// the rest is carried over ahead of the changes scheduled for the next block.
+        pub fn enact_scheduled_gateway_changes(n: BlockNumberFor<T>) -> Weight {
+            let mut scheduled_changes = match <ScheduledGatewayChanges<T>>::take(n) {
+                Some(changes) => changes,
+                None => return T::DbWeight::get().reads(1),
+            };
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            let max_changes = T::MaxScheduledGatewayChangesPerBlock::get() as usize;
+            if scheduled_changes.len() > max_changes {
+                let carried_over = scheduled_changes.split_off(max_changes);
+                <ScheduledGatewayChanges<T>>::mutate(
+                    n.saturating_add(One::one()),
+                    |next_changes| {
+                        let mut changes = carried_over;
+                        changes.extend(next_changes.take().unwrap_or_default());
+                        *next_changes = Some(changes);
+                    },
+                );
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            }
+
+            for (gateway_id, change) in scheduled_changes {
+                // SFX ABIs of the gateway are read or rewritten along with each new version
+                let sfx_abis = <Gateways<T>>::get(gateway_id)
+                    .map(|record| record.allowed_side_effects.len())
+                    .unwrap_or_default()
+                    .saturating_add(match &change {
+                        ScheduledGatewayChange::OverrideGateway(record) =>
+                            record.allowed_side_effects.len(),
+                        ScheduledGatewayChange::OverrideSfxAbi(new_sfx_abis) => new_sfx_abis.len(),
+                        ScheduledGatewayChange::PurgeGateway => 0,
+                    }) as u32;
+                weight = weight.saturating_add(
+                    T::DbWeight::get()
+                        .reads(1)
+                        .saturating_add(T::WeightInfo::enact_scheduled_gateway_change(sfx_abis)),
+                );
+                let enacted = match change {
+                    ScheduledGatewayChange::OverrideGateway(record) => Self::override_gateway(
+                        record.gateway_id,
+                        record.verification_vendor,
+                        record.execution_vendor,
+                        record.codec,
+                        record.registrant,
+                        record.escrow_account,
+                        record.allowed_side_effects,
+                    ),
+                    ScheduledGatewayChange::OverrideSfxAbi(new_sfx_abis) =>
+                        Self::override_sfx_abi(gateway_id, new_sfx_abis),
+                    ScheduledGatewayChange::PurgeGateway =>
+                        Self::do_purge_gateway_record(gateway_id),
+                };
+                match enacted {
+                    Ok(()) => Self::deposit_event(Event::<T>::ScheduledGatewayChangeEnacted(
+                        gateway_id,
+                        <GatewayVersions<T>>::get(gateway_id),
+                    )),
+                    Err(e) => {
+                        log::error!(
+                            "XDNS -- failed to enact scheduled change of gateway {:?}: {:?}",
+                            gateway_id,
+                            e
+                        );
+                        Self::deposit_event(Event::<T>::ScheduledGatewayChangeFailed(
+                            gateway_id, n,
+                        ));
+                    },
+                }
+            }
+
+            weight
+        }
+
+        /// Registered assets and gateways, bounding the cross rates written per aggregated asset.
+        pub fn price_estimates_bounds() -> (u32, u32) {
+            (
+                <AllTokenIds<T>>::decode_len().unwrap_or_default() as u32,
+                <AllGatewayIds<T>>::decode_len().unwrap_or_default() as u32,
+            )
+        }
+
+        /// Weight of the cross rates written per target, on top of the benchmarked aggregation
+        /// which registers each asset on a single target.
+        fn per_target_cross_rates_weight(assets: u32, targets: u32) -> Weight {
+            let rates = (assets as u64)
+                .saturating_mul(targets as u64)
+                .saturating_mul(2);
+            T::DbWeight::get().reads_writes(rates, rates)
+        }
+
+        /// Weight of aggregating the given number of price submissions into the estimates of an asset,
+        /// along with its cross rates against every registered asset.
+        pub fn aggregate_asset_price_weight(submissions: u32) -> Weight {
+            let (assets, targets) = Self::price_estimates_bounds();
+            <T as Config>::WeightInfo::aggregate_asset_price(submissions, assets)
+                .saturating_add(Self::per_target_cross_rates_weight(assets, targets))
+        }
+
+        /// Weight of submitting a price re-aggregated out of the given number of submissions.
+        pub fn submit_asset_price_weight(submissions: u32) -> Weight {
+            let (assets, targets) = Self::price_estimates_bounds();
+            <T as Config>::WeightInfo::submit_asset_price(submissions, assets)
+                .saturating_add(Self::per_target_cross_rates_weight(assets, targets))
+        }
+
+        /// Re-aggregates the estimates of the assets whose oldest fresh submission expired at this block,
+        /// so that neither their prices nor their cross rates are served past `PriceFeedMaxAge`.
+        pub fn expire_asset_estimates(n: BlockNumberFor<T>) -> Weight {
+            let expiring = <AssetEstimatesExpiring<T>>::take(n);
+            if expiring.is_empty() {
+                return T::DbWeight::get().reads(1)
+            }
+            let feeders = <PriceFeeders<T>>::decode_len().unwrap_or_default() as u32;
+            let mut weight = T::DbWeight::get().reads_writes(2, 1);
+            for asset_id in expiring {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                // Estimates refreshed since are kept until their own expiry
+                match <AssetEstimatesValidUntil<T>>::get(asset_id) {
+                    Some(valid_until) if valid_until < n => {
+                        if let Some(median) = Self::aggregate_asset_price(asset_id) {
+                            Self::deposit_event(Event::<T>::AssetEstimatesUpdated(
+                                asset_id, median,
+                            ));
+                        }
+                        weight = weight.saturating_add(Self::aggregate_asset_price_weight(feeders));
+                    },
+                    _ => {},
+                }
+            }
+            weight
+        }
+
+        /// Aggregates fresh submissions of whitelisted feeders into the asset estimates:
+        /// the median price in native, the highest price as the cost estimate, and the cross-asset rates.
+        /// Estimates are cleared while there are fewer than `MinPriceFeeds` fresh submissions.
+        pub fn aggregate_asset_price(asset_id: AssetId) -> Option<BalanceOf<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let max_age = T::PriceFeedMaxAge::get();
+            let feeders = <PriceFeeders<T>>::get();
+
+            let mut fresh: Vec<(BalanceOf<T>, BlockNumberFor<T>)> =
+                <PriceSubmissions<T>>::iter_prefix(asset_id)
+                    .filter(|(feeder, (_, submitted_at))| {
+                        feeders.contains(feeder) && now.saturating_sub(*submitted_at) <= max_age
+                    })
+                    .map(|(_, submission)| submission)
+                    .collect();
+
+            if fresh.is_empty() || (fresh.len() as u32) < T::MinPriceFeeds::get() {
+                Self::clear_asset_estimates(asset_id);
+                return None
+            }
+
+            fresh.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let mid = fresh.len() / 2;
+            let median = if fresh.len() % 2 == 0 {
+                fresh[mid - 1].0.saturating_add(fresh[mid].0) / BalanceOf::<T>::from(2u32)
+            } else {
+                fresh[mid].0
+            };
+            let highest = fresh[fresh.len() - 1].0;
+            let oldest_submission = fresh
+                .iter()
+                .map(|(_, submitted_at)| *submitted_at)
+                .min()
+                .unwrap_or(now);
+
+            <AssetEstimatesInNative<T>>::insert(asset_id, median);
+            <AssetCostEstimatesInNative<T>>::insert(asset_id, highest);
+            let valid_until = oldest_submission.saturating_add(max_age);
+            <AssetEstimatesValidUntil<T>>::insert(asset_id, valid_until);
+            <AssetEstimatesExpiring<T>>::mutate(
+                valid_until.saturating_add(One::one()),
+                |expiring| {
+                    if !expiring.contains(&asset_id) {
+                        expiring.push(asset_id);
+                    }
+                },
+            );
+
+            for (other_asset_id, other_price) in Self::priced_assets_except(asset_id) {
+                let rate = Self::rate_between(median, other_price);
+                let reverse_rate = Self::rate_between(other_price, median);
+                <AssetEstimates<T>>::insert((asset_id, other_asset_id), rate);
+                <AssetEstimates<T>>::insert((other_asset_id, asset_id), reverse_rate);
+                for (target, _) in <Tokens<T>>::iter_prefix(asset_id) {
+                    if <Tokens<T>>::contains_key(other_asset_id, target) {
+                        <PerTargetAssetEstimates<T>>::insert(
+                            target,
+                            (asset_id, other_asset_id),
+                            rate,
+                        );
+                        <PerTargetAssetEstimates<T>>::insert(
+                            target,
+                            (other_asset_id, asset_id),
+                            reverse_rate,
+                        );
+                    }
+                }
+            }
+
+            Some(median)
+        }
+
+        /// Clears the estimates of the asset along with its cross rates against every registered asset,
+        /// including the ones whose price expired at the same time.
+        fn clear_asset_estimates(asset_id: AssetId) {
+            <AssetEstimatesInNative<T>>::remove(asset_id);
+            <AssetCostEstimatesInNative<T>>::remove(asset_id);
+            <AssetEstimatesValidUntil<T>>::remove(asset_id);
+            let native_asset_id = T::SelfTokenId::get();
+            let mut other_asset_ids: Vec<AssetId> = <AllTokenIds<T>>::get()
+                .into_iter()
+                .filter(|other| *other != asset_id && *other != native_asset_id)
+                .collect();
+            if asset_id != native_asset_id {
+                other_asset_ids.push(native_asset_id);
+            }
+            for other_asset_id in other_asset_ids {
+                <AssetEstimates<T>>::remove((asset_id, other_asset_id));
+                <AssetEstimates<T>>::remove((other_asset_id, asset_id));
+                for (target, _) in <Tokens<T>>::iter_prefix(asset_id) {
+                    <PerTargetAssetEstimates<T>>::remove(target, (asset_id, other_asset_id));
+                    <PerTargetAssetEstimates<T>>::remove(target, (other_asset_id, asset_id));
+                }
+            }
+        }
+
+        /// All assets with a fresh price in native, native included.
+        fn priced_assets_except(asset_id: AssetId) -> Vec<(AssetId, BalanceOf<T>)> {
+            let native_asset_id = T::SelfTokenId::get();
+            let mut priced_assets: Vec<(AssetId, BalanceOf<T>)> = <AllTokenIds<T>>::get()
+                .into_iter()
+                .filter(|other| *other != asset_id && *other != native_asset_id)
+                .filter_map(|other| {
+                    <Self as Xdns<T, BalanceOf<T>>>::get_asset_price_in_native(other)
+                        .map(|price| (other, price))
+                })
+                .collect();
+            if asset_id != native_asset_id {
+                priced_assets.push((
+                    native_asset_id,
+                    ASSET_PRICE_PRECISION.saturated_into::<BalanceOf<T>>(),
+                ));
+            }
+            priced_assets
+        }
+
+        /// Amount of the quote asset worth `ASSET_PRICE_PRECISION` of the base asset.
+        fn rate_between(base_price: BalanceOf<T>, quote_price: BalanceOf<T>) -> BalanceOf<T> {
+            multiply_by_rational_with_rounding(
+                ASSET_PRICE_PRECISION,
+                base_price.saturated_into::<u128>(),
+                quote_price.saturated_into::<u128>(),
+                Rounding::Down,
+            )
+            .unwrap_or_default()
+            .saturated_into::<BalanceOf<T>>()
+        }
+
         pub fn update_epoch_history(
             verifier: &GatewayVendor,
             epoch_duration_in_remote_blocks: frame_system::pallet_prelude::BlockNumberFor<T>,
@@ -1330,6 +2513,49 @@ pub mod pallet {
             )
         }
 
+        fn add_pending_gateway(
+            registrant: T::AccountId,
+            gateway_id: TargetId,
+            verification_vendor: GatewayVendor,
+            execution_vendor: ExecutionVendor,
+            codec: Codec,
+            escrow_account: Option<T::AccountId>,
+            allowed_side_effects: Vec<(TargetId, Option<u8>)>,
+        ) -> DispatchResult {
+            let bond = T::GatewayRegistrationBond::get();
+            T::Currency::reserve(&registrant, bond)
+                .map_err(|_| Error::<T>::InsufficientBalanceForRegistrationBond)?;
+
+            Self::add_new_gateway(
+                gateway_id,
+                verification_vendor,
+                execution_vendor,
+                codec,
+                Some(registrant.clone()),
+                escrow_account,
+                allowed_side_effects,
+            )?;
+
+            <PendingGateways<T>>::insert(
+                gateway_id,
+                PendingGatewayRecord {
+                    registrant: registrant.clone(),
+                    bond,
+                    max_order_value: T::PendingGatewayMaxOrderValue::get(),
+                    promotion_votes: Default::default(),
+                },
+            );
+            Self::deposit_event(Event::<T>::PendingGatewayRegistered(
+                registrant, gateway_id, bond,
+            ));
+
+            Ok(())
+        }
+
+        fn get_pending_gateway_max_order_value(gateway_id: &ChainId) -> Option<BalanceOf<T>> {
+            <PendingGateways<T>>::get(gateway_id).map(|pending| pending.max_order_value)
+        }
+
         fn override_gateway(
             gateway_id: TargetId,
             verification_vendor: GatewayVendor,
@@ -1357,6 +2583,9 @@ pub mod pallet {
                     },
                 }
             }
+            if !<GatewayABIs<T>>::contains_key(gateway_id) {
+                <GatewayABIs<T>>::insert(gateway_id, default_gateway_abi(&execution_vendor));
+            }
             <Gateways<T>>::insert(
                 gateway_id,
                 GatewayRecord {
@@ -1375,6 +2604,7 @@ pub mod pallet {
                     .map(|i| ids.remove(i));
                 ids.push(gateway_id);
             });
+            Self::snapshot_gateway_version(gateway_id);
             Self::deposit_event(Event::<T>::GatewayRecordStored(gateway_id));
 
             Ok(())
@@ -1399,6 +2629,8 @@ pub mod pallet {
                 },
             })?;
 
+            Self::snapshot_gateway_version(gateway_id);
+
             Ok(())
         }
 
@@ -1426,6 +2658,8 @@ pub mod pallet {
                 });
             }
 
+            Self::snapshot_gateway_version(gateway_id);
+
             Ok(())
         }
 
@@ -1439,6 +2673,52 @@ pub mod pallet {
             <SFXABIRegistry<T>>::get(gateway_id, sfx_4b_id)
         }
 
+        fn get_gateway_version(gateway_id: &ChainId) -> u32 {
+            <GatewayVersions<T>>::get(gateway_id)
+        }
+
+        fn pin_gateway_version(gateway_id: &ChainId, version: u32) {
+            <GatewayVersionPins<T>>::mutate(gateway_id, version, |pins| {
+                *pins = pins.saturating_add(1)
+            });
+        }
+
+        fn unpin_gateway_version(gateway_id: &ChainId, version: u32) {
+            let pins = <GatewayVersionPins<T>>::get(gateway_id, version).saturating_sub(1);
+            if pins > 0 {
+                <GatewayVersionPins<T>>::insert(gateway_id, version, pins);
+                return
+            }
+            <GatewayVersionPins<T>>::remove(gateway_id, version);
+            // The version was kept beyond `MaxGatewayHistory` only for the pinning xtxs
+            if Self::is_gateway_version_expired(*gateway_id, version) {
+                <GatewayHistory<T>>::remove(gateway_id, version);
+            }
+        }
+
+        fn get_sfx_abi_at_version(
+            gateway_id: &ChainId,
+            sfx_4b_id: Sfx4bId,
+            version: u32,
+        ) -> Option<SFXAbi> {
+            <GatewayHistory<T>>::get(gateway_id, version)?
+                .sfx_abis
+                .into_iter()
+                .find(|(versioned_sfx_4b_id, _)| *versioned_sfx_4b_id == sfx_4b_id)
+                .map(|(_, sfx_abi)| sfx_abi)
+        }
+
+        fn get_target_codec_at_version(
+            chain_id: &ChainId,
+            version: u32,
+        ) -> Result<Codec, DispatchError> {
+            match <GatewayHistory<T>>::get(chain_id, version).and_then(|versioned| versioned.record)
+            {
+                Some(rec) => Ok(rec.codec),
+                None => Err(Error::<T>::GatewayVersionNotFound.into()),
+            }
+        }
+
         fn add_escrow_account(
             origin: OriginFor<T>,
             gateway_id: ChainId,
@@ -1454,6 +2734,8 @@ pub mod pallet {
                 },
             })?;
 
+            Self::snapshot_gateway_version(gateway_id);
+
             Ok(())
         }
 
@@ -1470,6 +2752,11 @@ pub mod pallet {
                 return SecurityLvl::Escrow
             }
 
+            // Gateways pending promotion are limited to optimistic execution
+            if <PendingGateways<T>>::contains_key(chain_id) {
+                return SecurityLvl::Optimistic
+            }
+
             match Self::get_escrow_account(chain_id) {
                 Ok(_) => SecurityLvl::Escrow,
                 Err(_) => SecurityLvl::Optimistic,
@@ -1491,6 +2778,16 @@ pub mod pallet {
             }
         }
 
+        fn get_target_abi(chain_id: &ChainId) -> Result<GatewayABIConfig, DispatchError> {
+            match <GatewayABIs<T>>::get(chain_id) {
+                Some(abi) => Ok(abi),
+                None => match <Gateways<T>>::get(chain_id) {
+                    Some(rec) => Ok(default_gateway_abi(&rec.execution_vendor)),
+                    None => Err(Error::<T>::XdnsRecordNotFound.into()),
+                },
+            }
+        }
+
         fn get_escrow_account(chain_id: &ChainId) -> Result<Bytes, DispatchError> {
             match <Gateways<T>>::get(chain_id) {
                 Some(rec) => match rec.escrow_account {
@@ -1540,11 +2837,38 @@ pub mod pallet {
 
         fn is_target_active(gateway_id: TargetId, security_lvl: &SecurityLvl) -> bool {
             match Self::read_last_activity(gateway_id) {
-                Some(activity) => activity.security_lvl >= *security_lvl && activity.is_active,
+                Some(activity) =>
+                    activity.security_lvl >= *security_lvl
+                        && activity.is_active
+                        && Self::get_gateway_health(&gateway_id)
+                            .map_or(true, |health| health.is_healthy),
                 None => false,
             }
         }
 
+        fn get_gateway_health(
+            gateway_id: &ChainId,
+        ) -> Option<GatewayHealthRecord<BlockNumberFor<T>>> {
+            <GatewayHealth<T>>::get(gateway_id)
+        }
+
+        fn get_canonical_asset_group(asset_id: AssetId) -> Option<CanonicalAssetGroup> {
+            <CanonicalAssets<T>>::get(asset_id)
+        }
+
+        fn normalize_asset_amount_to_canonical(
+            asset_id: AssetId,
+            gateway_id: &TargetId,
+            amount: u128,
+        ) -> Result<u128, DispatchError> {
+            match <CanonicalAssets<T>>::get(asset_id) {
+                Some(group) => group
+                    .to_canonical(gateway_id, amount)
+                    .ok_or_else(|| Error::<T>::CanonicalAssetAmountOverflow.into()),
+                None => Ok(amount),
+            }
+        }
+
         fn get_remote_order_contract_address(gateway_id: TargetId) -> Result<H256, DispatchError> {
             <RemoteOrderAddresses<T>>::get(gateway_id)
                 .ok_or(Error::<T>::RemoteOrderAddressNotFound.into())
@@ -1561,6 +2885,44 @@ pub mod pallet {
             T::SelfTokenId::get()
         }
 
+        fn get_asset_price_in_native(asset_id: AssetId) -> Option<BalanceOf<T>> {
+            if asset_id == T::SelfTokenId::get() {
+                return Some(ASSET_PRICE_PRECISION.saturated_into::<BalanceOf<T>>())
+            }
+            let valid_until = <AssetEstimatesValidUntil<T>>::get(asset_id)?;
+            if valid_until < frame_system::Pallet::<T>::block_number() {
+                return None
+            }
+            let price = <AssetEstimatesInNative<T>>::get(asset_id);
+            if price.is_zero() {
+                None
+            } else {
+                Some(price)
+            }
+        }
+
+        fn convert_asset_amount(
+            from_asset_id: Option<AssetId>,
+            to_asset_id: Option<AssetId>,
+            amount: BalanceOf<T>,
+        ) -> Option<BalanceOf<T>> {
+            let native_asset_id = T::SelfTokenId::get();
+            let from_asset_id = from_asset_id.unwrap_or(native_asset_id);
+            let to_asset_id = to_asset_id.unwrap_or(native_asset_id);
+            if from_asset_id == to_asset_id {
+                return Some(amount)
+            }
+            let from_price = Self::get_asset_price_in_native(from_asset_id)?;
+            let to_price = Self::get_asset_price_in_native(to_asset_id)?;
+            multiply_by_rational_with_rounding(
+                amount.saturated_into::<u128>(),
+                from_price.saturated_into::<u128>(),
+                to_price.saturated_into::<u128>(),
+                Rounding::Down,
+            )
+            .map(|converted| converted.saturated_into::<BalanceOf<T>>())
+        }
+
         fn mint(asset_id: AssetId, user: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
             assert!(
                 Self::check_asset_is_mintable(T::SelfGatewayId::get(), asset_id),
@@ -1648,8 +3010,16 @@ pub mod pallet {
                 .iter()
                 .map(|(vendor, target)| {
                     let epoch_history = <EpochHistory<T>>::get(vendor);
-                    let (local_offset, remote_offset) =
+                    let (mut local_offset, remote_offset) =
                         vendor.calculate_offsets(speed_mode, emergency_offset, epoch_history);
+                    // Give degraded verifiers proportionally more local blocks to finalize
+                    if let Some(health) = <GatewayHealth<T>>::get(target) {
+                        if health.score < 100 {
+                            local_offset = local_offset
+                                .saturating_mul(BlockNumberFor::<T>::from(100u32))
+                                / BlockNumberFor::<T>::from(health.score.max(25) as u32);
+                        }
+                    }
                     (vendor.clone(), *target, local_offset, remote_offset)
                 })
                 .max_by_key(|(_, _, submit_by_local_offset, _)| *submit_by_local_offset)
@@ -1695,3 +3065,55 @@ pub mod pallet {
         }
     }
 }
+
+/// Converts native balances into XDNS-registered assets at fresh XDNS price estimates.
+/// Lets `pallet_asset_tx_payment` charge transaction fees in any asset priced by XDNS.
+/// Assets without a fresh XDNS price are converted with `Fallback` instead, e.g. with
+/// `pallet_assets::BalanceToAssetBalance` at the ratio of their minimum balances, so they can
+/// keep paying transaction fees while their price feed is stale or missing.
+pub struct XdnsAssetBalance<T, Fallback>(sp_std::marker::PhantomData<(T, Fallback)>);
+
+impl<T, Fallback, AssetBalance>
+    frame_support::traits::tokens::ConversionToAssetBalance<
+        BalanceOf<T>,
+        circuit_runtime_types::AssetId,
+        AssetBalance,
+    > for XdnsAssetBalance<T, Fallback>
+where
+    T: Config,
+    Fallback: frame_support::traits::tokens::ConversionToAssetBalance<
+        BalanceOf<T>,
+        circuit_runtime_types::AssetId,
+        AssetBalance,
+    >,
+    AssetBalance: From<BalanceOf<T>>,
+{
+    type Error = Error<T>;
+
+    fn to_asset_balance(
+        balance: BalanceOf<T>,
+        asset_id: circuit_runtime_types::AssetId,
+    ) -> Result<AssetBalance, Self::Error> {
+        match <Pallet<T> as t3rn_primitives::xdns::Xdns<T, BalanceOf<T>>>::convert_asset_amount(
+            None,
+            Some(asset_id),
+            balance,
+        ) {
+            Some(converted) => Ok(converted.into()),
+            None => Fallback::to_asset_balance(balance, asset_id)
+                .map_err(|_| Error::<T>::AssetPriceEstimateUnavailable),
+        }
+    }
+}
+
+/// Id under which the verifier overview is registered as a round handler with the clock.
+pub const VERIFIER_OVERVIEW_ROUND_HANDLER: RoundHandlerId = *b"xdnsovrv";
+
+impl<T: Config> OnNewRound<T> for Pallet<T> {
+    fn on_new_round(
+        _past_round: RoundInfo<BlockNumberFor<T>>,
+        new_round: RoundInfo<BlockNumberFor<T>>,
+    ) -> Weight {
+        Self::process_all_verifier_overviews(new_round.head)
+    }
+}
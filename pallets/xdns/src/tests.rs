@@ -23,25 +23,33 @@ use codec::Decode;
 
 use frame_support::pallet_prelude::Weight;
 
-use frame_support::{assert_err, assert_noop, assert_ok, traits::OnInitialize};
-use sp_core::{crypto::AccountId32, H256};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    traits::{Get, OnInitialize},
+};
+use sp_core::{crypto::AccountId32, H160, H256};
 use sp_runtime::DispatchError;
 use t3rn_primitives::{
     circuit::SecurityLvl::{Escrow, Optimistic},
     clock::OnHookQueues,
+    gateway::{CryptoAlgo, HasherAlgo},
+    light_client::LightClientAsyncAPI,
     portal::Portal as PortalT,
-    xdns::{FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, Xdns},
+    xdns::{
+        default_gateway_abi, FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, Xdns,
+        ASSET_PRICE_PRECISION,
+    },
     EthereumToken, ExecutionVendor,
     ExecutionVendor::{Substrate, EVM},
     FinalityVerifierActivity, GatewayActivity, GatewayVendor,
     GatewayVendor::{Ethereum, Kusama, Polkadot, Rococo},
-    SpeedMode, SubstrateToken, TokenInfo, XDNSTopology,
+    SpeedMode, SubstrateToken, TokenInfo, TreasuryAccount, TreasuryAccountProvider, XDNSTopology,
 };
 
 use t3rn_abi::Codec::{Rlp, Scale};
 use t3rn_primitives::{
-    xdns::EpochEstimate,
-    GatewayVendor::{Attesters, Sepolia, XBI},
+    xdns::{EpochEstimate, GatewayHealthRecord},
+    GatewayVendor::{Attesters, Sepolia, Standalone, XBI},
 };
 
 use t3rn_types::fsx::SecurityLvl;
@@ -1276,6 +1284,15 @@ fn on_initialize_should_update_update_verifiers_overview_no_more_often_than_each
                     epoch: 0,
                     is_active: false,
                 },
+                FinalityVerifierActivity {
+                    verifier: Standalone,
+                    reported_at: 74,
+                    justified_height: 0,
+                    finalized_height: 0,
+                    updated_height: 0,
+                    epoch: 0,
+                    is_active: false,
+                },
             ];
 
             let expected_verifier_overview_all_on = vec![
@@ -1342,6 +1359,15 @@ fn on_initialize_should_update_update_verifiers_overview_no_more_often_than_each
                     epoch: 26,
                     is_active: true,
                 },
+                FinalityVerifierActivity {
+                    verifier: Standalone,
+                    reported_at: 17,
+                    justified_height: 24,
+                    finalized_height: 24,
+                    updated_height: 24,
+                    epoch: 26,
+                    is_active: true,
+                },
             ];
 
             assert_eq!(XDNS::verifier_overview(), expected_verifier_overview_all_on);
@@ -1757,7 +1783,7 @@ fn xdns_overview_returns_activity_for_all_registered_but_not_active_after_turnin
             }
             assert_eq!(
                 XDNS::process_all_verifier_overviews(100),
-                Weight::from_parts(2125000000u64, 0)
+                Weight::from_parts(3175000000u64, 0)
             );
             assert_eq!(XDNS::process_overview(100), ());
 
@@ -2057,11 +2083,14 @@ fn test_storage_migration_v144_to_v145_that_kills_old_xdns_records_entry() {
             );
 
             pallet_xdns::StorageMigrations::<Runtime>::set(2);
+            let gateways_count = pallet_xdns::Gateways::<Runtime>::iter().count() as u64;
 
             // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
             let consumed_weight =
                 <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
-            let max_weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(0, 1);
+            // All gateways had their ABIs and versions stored at genesis, so only the reads are added
+            let max_weight = <Runtime as frame_system::Config>::DbWeight::get()
+                .reads_writes(3 * gateways_count, 1);
             assert_eq!(consumed_weight, max_weight);
 
             assert_eq!(
@@ -2076,3 +2105,1207 @@ fn test_storage_migration_v144_to_v145_that_kills_old_xdns_records_entry() {
             );
         });
 }
+
+#[test]
+fn test_storage_migration_v150_to_v151_that_fills_in_missing_gateway_abis() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Gateways registered before the ABI was stored per gateway
+            pallet_xdns::GatewayABIs::<Runtime>::remove(*b"eth2");
+            pallet_xdns::GatewayABIs::<Runtime>::remove(*b"pdot");
+
+            pallet_xdns::StorageMigrations::<Runtime>::set(2);
+            let gateways_count = pallet_xdns::Gateways::<Runtime>::iter().count() as u64;
+
+            let consumed_weight =
+                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            let max_weight = <Runtime as frame_system::Config>::DbWeight::get()
+                .reads_writes(3 * gateways_count, 3);
+            assert_eq!(consumed_weight, max_weight);
+            assert_eq!(pallet_xdns::StorageMigrations::<Runtime>::get(), 4);
+
+            assert_eq!(
+                XDNS::gateway_abis(*b"eth2"),
+                Some(default_gateway_abi(&EVM))
+            );
+            assert_eq!(
+                XDNS::gateway_abis(*b"pdot"),
+                Some(default_gateway_abi(&Substrate))
+            );
+
+            // Migration runs only once
+            let consumed_weight =
+                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            assert_eq!(consumed_weight, Weight::zero());
+        });
+}
+
+#[test]
+fn test_gateway_abi_falls_back_to_execution_vendor_default_when_not_stored() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Chains migrated straight from before v1.4.45-rc skip the fill-in of GatewayABIs
+            pallet_xdns::GatewayABIs::<Runtime>::remove(*b"eth2");
+
+            assert_eq!(XDNS::get_target_abi(b"eth2"), Ok(default_gateway_abi(&EVM)));
+            assert_err!(
+                XDNS::get_target_abi(b"none"),
+                pallet_xdns::Error::<Runtime>::XdnsRecordNotFound
+            );
+        });
+}
+
+#[test]
+fn test_gateway_abi_is_derived_from_execution_vendor_at_registration() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let evm_abi = XDNS::get_target_abi(b"eth2").unwrap();
+            assert_eq!(evm_abi.hasher, HasherAlgo::Keccak256);
+            assert_eq!(evm_abi.crypto, CryptoAlgo::Ecdsa);
+            assert_eq!(evm_abi.address_length, 20);
+
+            assert_eq!(
+                XDNS::get_target_abi(b"pdot"),
+                Ok(GatewayABIConfig::default())
+            );
+            assert_err!(
+                XDNS::get_target_abi(b"none"),
+                pallet_xdns::Error::<Runtime>::XdnsRecordNotFound
+            );
+        });
+}
+
+#[test]
+fn test_set_gateway_abi_overrides_codec_and_abi() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let gateway_abi = default_gateway_abi(&EVM);
+
+            assert_noop!(
+                XDNS::set_gateway_abi(
+                    Origin::signed(ALICE),
+                    *b"pdot",
+                    t3rn_abi::Codec::Rlp,
+                    gateway_abi.clone()
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::set_gateway_abi(
+                    Origin::root(),
+                    *b"none",
+                    t3rn_abi::Codec::Rlp,
+                    gateway_abi.clone()
+                ),
+                pallet_xdns::Error::<Runtime>::XdnsRecordNotFound
+            );
+
+            assert_ok!(XDNS::set_gateway_abi(
+                Origin::root(),
+                *b"pdot",
+                t3rn_abi::Codec::Rlp,
+                gateway_abi.clone()
+            ));
+
+            assert_eq!(XDNS::get_target_codec(b"pdot"), Ok(t3rn_abi::Codec::Rlp));
+            assert_eq!(XDNS::get_target_abi(b"pdot"), Ok(gateway_abi.clone()));
+
+            // Re-registering the gateway keeps the configured ABI
+            assert_ok!(XDNS::override_gateway(
+                *b"pdot",
+                Polkadot,
+                Substrate,
+                t3rn_abi::Codec::Rlp,
+                None,
+                None,
+                vec![],
+            ));
+            assert_eq!(XDNS::get_target_abi(b"pdot"), Ok(gateway_abi));
+
+            // Purging the gateway removes its ABI
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"pdot"));
+            assert_eq!(XDNS::gateway_abis(*b"pdot"), None);
+        });
+}
+
+fn register_priced_asset(asset_id: u32) {
+    assert_ok!(XDNS::register_new_token(
+        &Origin::root(),
+        asset_id,
+        TokenInfo::Substrate(SubstrateToken {
+            id: asset_id,
+            symbol: b"prcd".to_vec(),
+            decimals: 12,
+        })
+    ));
+}
+
+#[test]
+fn only_whitelisted_price_feeders_can_submit_asset_prices() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let feeder = AccountId32::new([1u8; 32]);
+            let priced_asset: u32 = 1111;
+            register_priced_asset(priced_asset);
+
+            assert_noop!(
+                XDNS::submit_asset_price(Origin::signed(feeder.clone()), priced_asset, 1),
+                pallet_xdns::Error::<Runtime>::NotPriceFeeder
+            );
+            assert_noop!(
+                XDNS::add_price_feeder(Origin::signed(feeder.clone()), feeder.clone()),
+                DispatchError::BadOrigin
+            );
+
+            assert_ok!(XDNS::add_price_feeder(Origin::root(), feeder.clone()));
+            assert_noop!(
+                XDNS::add_price_feeder(Origin::root(), feeder.clone()),
+                pallet_xdns::Error::<Runtime>::PriceFeederAlreadyExists
+            );
+            assert_noop!(
+                XDNS::submit_asset_price(Origin::signed(feeder.clone()), 3333, 1),
+                pallet_xdns::Error::<Runtime>::CannotPriceNativeAsset
+            );
+            assert_noop!(
+                XDNS::submit_asset_price(Origin::signed(feeder.clone()), 2222, 1),
+                pallet_xdns::Error::<Runtime>::TokenRecordNotFoundInAssetsOverlay
+            );
+            assert_noop!(
+                XDNS::submit_asset_price(Origin::signed(feeder.clone()), priced_asset, 0),
+                pallet_xdns::Error::<Runtime>::InvalidAssetPrice
+            );
+
+            assert_ok!(XDNS::submit_asset_price(
+                Origin::signed(feeder.clone()),
+                priced_asset,
+                ASSET_PRICE_PRECISION * 2
+            ));
+            assert_eq!(
+                XDNS::get_asset_price_in_native(priced_asset),
+                Some(ASSET_PRICE_PRECISION * 2)
+            );
+
+            // Removing the feeder drops its submissions and with them the estimates
+            assert_ok!(XDNS::remove_price_feeder(Origin::root(), feeder.clone()));
+            assert_eq!(XDNS::get_asset_price_in_native(priced_asset), None);
+            assert_eq!(XDNS::asset_estimates_in_native(priced_asset), 0);
+            assert_noop!(
+                XDNS::remove_price_feeder(Origin::root(), feeder),
+                pallet_xdns::Error::<Runtime>::PriceFeederNotFound
+            );
+        });
+}
+
+#[test]
+fn bounds_price_feeders_and_charges_asset_price_submissions_per_feeder() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let priced_asset: u32 = 1111;
+            register_priced_asset(priced_asset);
+
+            let max_feeders = <Runtime as pallet_xdns::Config>::MaxPriceFeeders::get();
+            for i in 0..max_feeders {
+                assert_ok!(XDNS::add_price_feeder(
+                    Origin::root(),
+                    AccountId32::new([i as u8 + 1; 32])
+                ));
+            }
+            assert_noop!(
+                XDNS::add_price_feeder(Origin::root(), AccountId32::new([255u8; 32])),
+                pallet_xdns::Error::<Runtime>::TooManyPriceFeeders
+            );
+
+            assert_ok!(XDNS::remove_price_feeder(
+                Origin::root(),
+                AccountId32::new([2u8; 32])
+            ));
+            let post_info = XDNS::submit_asset_price(
+                Origin::signed(AccountId32::new([1u8; 32])),
+                priced_asset,
+                ASSET_PRICE_PRECISION,
+            )
+            .unwrap();
+            assert_eq!(
+                post_info.actual_weight,
+                Some(XDNS::submit_asset_price_weight(max_feeders - 1))
+            );
+        });
+}
+
+#[test]
+fn aggregates_asset_prices_by_median_into_estimates_and_cross_rates() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let priced_asset: u32 = 1111;
+            let other_priced_asset: u32 = 2222;
+            register_priced_asset(priced_asset);
+            register_priced_asset(other_priced_asset);
+
+            let feeders: Vec<AccountId32> = (1u8..=3).map(|i| AccountId32::new([i; 32])).collect();
+            for (feeder, price) in feeders.iter().zip([2u128, 10, 4]) {
+                assert_ok!(XDNS::add_price_feeder(Origin::root(), feeder.clone()));
+                assert_ok!(XDNS::submit_asset_price(
+                    Origin::signed(feeder.clone()),
+                    priced_asset,
+                    ASSET_PRICE_PRECISION * price
+                ));
+            }
+            assert_ok!(XDNS::submit_asset_price(
+                Origin::signed(feeders[0].clone()),
+                other_priced_asset,
+                ASSET_PRICE_PRECISION / 2
+            ));
+
+            assert_eq!(
+                XDNS::asset_estimates_in_native(priced_asset),
+                ASSET_PRICE_PRECISION * 4
+            );
+            assert_eq!(
+                XDNS::asset_cost_estimates_in_native(priced_asset),
+                ASSET_PRICE_PRECISION * 10
+            );
+            assert_eq!(
+                XDNS::asset_estimates((priced_asset, 3333)),
+                ASSET_PRICE_PRECISION * 4
+            );
+            assert_eq!(
+                XDNS::asset_estimates((3333, priced_asset)),
+                ASSET_PRICE_PRECISION / 4
+            );
+            assert_eq!(
+                XDNS::asset_estimates((priced_asset, other_priced_asset)),
+                ASSET_PRICE_PRECISION * 8
+            );
+            assert_eq!(
+                XDNS::asset_estimates((other_priced_asset, priced_asset)),
+                ASSET_PRICE_PRECISION / 8
+            );
+
+            assert_eq!(
+                XDNS::convert_asset_amount(None, Some(priced_asset), 100),
+                Some(25)
+            );
+            assert_eq!(
+                XDNS::convert_asset_amount(Some(priced_asset), None, 100),
+                Some(400)
+            );
+            assert_eq!(
+                XDNS::convert_asset_amount(Some(priced_asset), Some(other_priced_asset), 100),
+                Some(800)
+            );
+            assert_eq!(XDNS::convert_asset_amount(Some(3333), None, 100), Some(100));
+            assert_eq!(XDNS::convert_asset_amount(Some(3), Some(3), 100), Some(100));
+            assert_eq!(XDNS::convert_asset_amount(Some(3), None, 100), None);
+        });
+}
+
+#[test]
+fn stale_asset_prices_are_not_used_for_conversions() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let feeder = AccountId32::new([1u8; 32]);
+            let priced_asset: u32 = 1111;
+            register_priced_asset(priced_asset);
+            System::set_block_number(1);
+
+            assert_ok!(XDNS::add_price_feeder(Origin::root(), feeder.clone()));
+            assert_ok!(XDNS::submit_asset_price(
+                Origin::signed(feeder.clone()),
+                priced_asset,
+                ASSET_PRICE_PRECISION * 2
+            ));
+            assert_eq!(XDNS::asset_estimates_valid_until(priced_asset), Some(601));
+
+            System::set_block_number(601);
+            assert_eq!(
+                XDNS::convert_asset_amount(Some(priced_asset), None, 10),
+                Some(20)
+            );
+
+            System::set_block_number(602);
+            assert_eq!(XDNS::get_asset_price_in_native(priced_asset), None);
+            assert_eq!(
+                XDNS::convert_asset_amount(Some(priced_asset), None, 10),
+                None
+            );
+
+            assert_ok!(XDNS::submit_asset_price(
+                Origin::signed(feeder),
+                priced_asset,
+                ASSET_PRICE_PRECISION * 3
+            ));
+            assert_eq!(XDNS::asset_estimates_valid_until(priced_asset), Some(1202));
+            assert_eq!(
+                XDNS::convert_asset_amount(Some(priced_asset), None, 10),
+                Some(30)
+            );
+        });
+}
+
+#[test]
+fn cross_rates_expire_together_with_asset_prices() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let feeder = AccountId32::new([1u8; 32]);
+            let (priced_asset, other_priced_asset, later_priced_asset) = (1111, 2222, 4444);
+            register_priced_asset(priced_asset);
+            register_priced_asset(other_priced_asset);
+            register_priced_asset(later_priced_asset);
+            assert_ok!(XDNS::add_price_feeder(Origin::root(), feeder.clone()));
+
+            System::set_block_number(1);
+            for asset_id in [priced_asset, other_priced_asset] {
+                assert_ok!(XDNS::submit_asset_price(
+                    Origin::signed(feeder.clone()),
+                    asset_id,
+                    ASSET_PRICE_PRECISION * 2
+                ));
+            }
+            System::set_block_number(300);
+            assert_ok!(XDNS::submit_asset_price(
+                Origin::signed(feeder.clone()),
+                later_priced_asset,
+                ASSET_PRICE_PRECISION * 4
+            ));
+            assert_eq!(
+                XDNS::asset_estimates_expiring(602),
+                vec![priced_asset, other_priced_asset]
+            );
+            assert_eq!(
+                XDNS::asset_estimates((priced_asset, other_priced_asset)),
+                ASSET_PRICE_PRECISION
+            );
+
+            System::set_block_number(602);
+            XDNS::on_initialize(602);
+
+            assert_eq!(XDNS::asset_estimates_expiring(602), vec![]);
+            for asset_id in [priced_asset, other_priced_asset] {
+                assert_eq!(XDNS::asset_estimates_valid_until(asset_id), None);
+                assert_eq!(XDNS::asset_estimates_in_native(asset_id), 0);
+                assert_eq!(XDNS::asset_estimates((asset_id, 3333)), 0);
+                assert_eq!(XDNS::asset_estimates((3333, asset_id)), 0);
+                assert_eq!(XDNS::asset_estimates((asset_id, later_priced_asset)), 0);
+                assert_eq!(XDNS::asset_estimates((later_priced_asset, asset_id)), 0);
+            }
+            // Cross rates between assets expiring at the same block are cleared as well
+            assert_eq!(XDNS::asset_estimates((priced_asset, other_priced_asset)), 0);
+            assert_eq!(XDNS::asset_estimates((other_priced_asset, priced_asset)), 0);
+            assert_eq!(
+                XDNS::asset_estimates((later_priced_asset, 3333)),
+                ASSET_PRICE_PRECISION * 4
+            );
+            assert_eq!(
+                XDNS::get_asset_price_in_native(later_priced_asset),
+                Some(ASSET_PRICE_PRECISION * 4)
+            );
+        });
+}
+
+#[test]
+fn removing_price_feeder_is_charged_per_registered_asset_and_reaggregated_price() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let feeder = AccountId32::new([1u8; 32]);
+            let priced_asset: u32 = 1111;
+            register_priced_asset(priced_asset);
+            register_priced_asset(2222);
+            assert_ok!(XDNS::add_price_feeder(Origin::root(), feeder.clone()));
+            assert_ok!(XDNS::submit_asset_price(
+                Origin::signed(feeder.clone()),
+                priced_asset,
+                ASSET_PRICE_PRECISION
+            ));
+
+            let registered_assets = XDNS::all_token_ids().len() as u32;
+            let post_info = XDNS::remove_price_feeder(Origin::root(), feeder).unwrap();
+            assert_eq!(
+                post_info.actual_weight,
+                Some(
+                    <Runtime as pallet_xdns::Config>::WeightInfo::remove_price_feeder(
+                        registered_assets
+                    )
+                    .saturating_add(XDNS::aggregate_asset_price_weight(0))
+                )
+            );
+        });
+}
+
+#[test]
+fn scheduled_sfx_abi_override_is_enacted_as_new_gateway_version_keeping_history() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let initial_version = XDNS::get_gateway_version(b"gate");
+            assert!(initial_version > 0);
+
+            let initial_tran_abi = XDNS::get_sfx_abi(b"gate", *b"tran").unwrap();
+            let mut updated_tran_abi = initial_tran_abi.clone();
+            updated_tran_abi.maybe_prefix_memo = Some(7);
+
+            assert_noop!(
+                XDNS::schedule_sfx_abi_override(
+                    Origin::root(),
+                    *b"gate",
+                    vec![(*b"tran", updated_tran_abi.clone())],
+                    1
+                ),
+                pallet_xdns::Error::<Runtime>::ScheduledChangeMustBeInFuture
+            );
+            assert_noop!(
+                XDNS::schedule_sfx_abi_override(
+                    Origin::root(),
+                    *b"none",
+                    vec![(*b"tran", updated_tran_abi.clone())],
+                    10
+                ),
+                pallet_xdns::Error::<Runtime>::XdnsRecordNotFound
+            );
+
+            assert_ok!(XDNS::schedule_sfx_abi_override(
+                Origin::root(),
+                *b"gate",
+                vec![(*b"tran", updated_tran_abi.clone())],
+                10
+            ));
+
+            // Nothing changes before the scheduled block
+            XDNS::on_initialize(9);
+            assert_eq!(XDNS::get_gateway_version(b"gate"), initial_version);
+            assert_eq!(
+                XDNS::get_sfx_abi(b"gate", *b"tran"),
+                Some(initial_tran_abi.clone())
+            );
+
+            System::set_block_number(10);
+            XDNS::on_initialize(10);
+            assert_eq!(XDNS::get_gateway_version(b"gate"), initial_version + 1);
+            assert_eq!(
+                XDNS::get_sfx_abi(b"gate", *b"tran"),
+                Some(updated_tran_abi.clone())
+            );
+            assert_eq!(XDNS::scheduled_gateway_changes(10), None);
+            assert!(System::events().iter().any(|record| record.event
+                == RuntimeEvent::XDNS(
+                    pallet_xdns::Event::<Runtime>::ScheduledGatewayChangeEnacted(
+                        *b"gate",
+                        initial_version + 1
+                    )
+                )));
+
+            // Xtxs validated against the previous version keep seeing its ABI and codec
+            assert_eq!(
+                XDNS::get_sfx_abi_at_version(b"gate", *b"tran", initial_version),
+                Some(initial_tran_abi)
+            );
+            assert_eq!(
+                XDNS::get_sfx_abi_at_version(b"gate", *b"tran", initial_version + 1),
+                Some(updated_tran_abi)
+            );
+            assert_eq!(
+                XDNS::get_target_codec_at_version(b"gate", initial_version),
+                XDNS::get_target_codec(b"gate")
+            );
+
+            let history = XDNS::gateway_version_history(*b"gate");
+            assert_eq!(history.len() as u32, initial_version + 1);
+            assert_eq!(history.last().unwrap().enacted_at, 10);
+        });
+}
+
+#[test]
+fn scheduled_gateway_purge_can_be_cancelled_and_keeps_purged_version_in_history() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let initial_version = XDNS::get_gateway_version(b"pdot");
+
+            assert_ok!(XDNS::schedule_gateway_purge(Origin::root(), *b"pdot", 5));
+            assert_ok!(XDNS::cancel_scheduled_gateway_changes(
+                Origin::root(),
+                *b"pdot",
+                5
+            ));
+            assert_noop!(
+                XDNS::cancel_scheduled_gateway_changes(Origin::root(), *b"pdot", 5),
+                pallet_xdns::Error::<Runtime>::ScheduledGatewayChangeNotFound
+            );
+
+            XDNS::on_initialize(5);
+            assert!(XDNS::gateways(*b"pdot").is_some());
+            assert_eq!(XDNS::get_gateway_version(b"pdot"), initial_version);
+
+            assert_ok!(XDNS::schedule_gateway_purge(Origin::root(), *b"pdot", 6));
+            System::set_block_number(6);
+            XDNS::on_initialize(6);
+
+            assert!(XDNS::gateways(*b"pdot").is_none());
+            assert_eq!(XDNS::get_gateway_version(b"pdot"), initial_version + 1);
+            assert_eq!(
+                XDNS::gateway_history(*b"pdot", initial_version + 1)
+                    .unwrap()
+                    .record,
+                None
+            );
+            assert!(XDNS::gateway_history(*b"pdot", initial_version)
+                .unwrap()
+                .record
+                .is_some());
+            assert_err!(
+                XDNS::get_target_codec_at_version(b"pdot", initial_version + 1),
+                pallet_xdns::Error::<Runtime>::GatewayVersionNotFound
+            );
+        });
+}
+
+#[test]
+fn gateway_history_keeps_only_the_latest_versions() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let max_history = <Runtime as pallet_xdns::Config>::MaxGatewayHistory::get();
+            for _ in 0..max_history {
+                XDNS::snapshot_gateway_version(*b"pdot");
+            }
+            let current_version = XDNS::get_gateway_version(b"pdot");
+
+            let history = XDNS::gateway_version_history(*b"pdot");
+            assert_eq!(history.len() as u32, max_history);
+            assert_eq!(history.last().unwrap().version, current_version);
+            assert!(XDNS::gateway_history(*b"pdot", current_version - max_history).is_none());
+            assert!(XDNS::gateway_history(*b"pdot", current_version - max_history + 1).is_some());
+        });
+}
+
+#[test]
+fn gateway_history_keeps_pinned_versions_until_released() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let max_history = <Runtime as pallet_xdns::Config>::MaxGatewayHistory::get();
+            let pinned_version = XDNS::get_gateway_version(b"pdot");
+            let tran_abi = XDNS::get_sfx_abi(b"pdot", *b"tran");
+            XDNS::pin_gateway_version(b"pdot", pinned_version);
+            XDNS::pin_gateway_version(b"pdot", pinned_version);
+
+            for _ in 0..max_history {
+                XDNS::snapshot_gateway_version(*b"pdot");
+            }
+
+            // Evicted from the kept history, yet still readable by the pinning xtxs
+            assert_eq!(
+                XDNS::get_sfx_abi_at_version(b"pdot", *b"tran", pinned_version),
+                tran_abi
+            );
+            assert_eq!(
+                XDNS::gateway_version_history(*b"pdot")
+                    .first()
+                    .unwrap()
+                    .version,
+                pinned_version
+            );
+
+            XDNS::unpin_gateway_version(b"pdot", pinned_version);
+            assert_eq!(XDNS::gateway_version_pins(*b"pdot", pinned_version), 1);
+            assert!(XDNS::gateway_history(*b"pdot", pinned_version).is_some());
+
+            XDNS::unpin_gateway_version(b"pdot", pinned_version);
+            assert_eq!(XDNS::gateway_version_pins(*b"pdot", pinned_version), 0);
+            assert!(XDNS::gateway_history(*b"pdot", pinned_version).is_none());
+            assert_eq!(
+                XDNS::gateway_version_history(*b"pdot").len() as u32,
+                max_history
+            );
+        });
+}
+
+#[test]
+fn unpinned_version_within_kept_history_stays_in_history() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let pinned_version = XDNS::get_gateway_version(b"pdot");
+            XDNS::pin_gateway_version(b"pdot", pinned_version);
+            XDNS::snapshot_gateway_version(*b"pdot");
+
+            XDNS::unpin_gateway_version(b"pdot", pinned_version);
+            assert!(XDNS::gateway_history(*b"pdot", pinned_version).is_some());
+        });
+}
+
+#[test]
+fn scheduled_gateway_changes_beyond_block_limit_are_carried_over_to_next_block() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let max_changes =
+                <Runtime as pallet_xdns::Config>::MaxScheduledGatewayChangesPerBlock::get();
+            let initial_version = XDNS::get_gateway_version(b"gate");
+            let tran_abi = XDNS::get_sfx_abi(b"gate", *b"tran").unwrap();
+
+            for _ in 0..max_changes + 1 {
+                assert_ok!(XDNS::schedule_sfx_abi_override(
+                    Origin::root(),
+                    *b"gate",
+                    vec![(*b"tran", tran_abi.clone())],
+                    10
+                ));
+            }
+            assert_ok!(XDNS::schedule_gateway_purge(Origin::root(), *b"pdot", 11));
+
+            System::set_block_number(10);
+            XDNS::on_initialize(10);
+            assert_eq!(
+                XDNS::get_gateway_version(b"gate"),
+                initial_version + max_changes
+            );
+            assert_eq!(XDNS::scheduled_gateway_changes(10), None);
+
+            // The overflow is enacted ahead of the changes scheduled for the next block
+            let next_changes = XDNS::scheduled_gateway_changes(11).unwrap();
+            assert_eq!(next_changes.len(), 2);
+            assert_eq!(next_changes[0].0, *b"gate");
+            assert_eq!(next_changes[1].0, *b"pdot");
+
+            System::set_block_number(11);
+            XDNS::on_initialize(11);
+            assert_eq!(
+                XDNS::get_gateway_version(b"gate"),
+                initial_version + max_changes + 1
+            );
+            assert!(XDNS::gateways(*b"pdot").is_none());
+            assert_eq!(XDNS::scheduled_gateway_changes(11), None);
+        });
+}
+
+fn register_pending_gateway(registrant: &AccountId32) {
+    assert_ok!(XDNS::add_pending_gateway(
+        registrant.clone(),
+        *b"pndg",
+        Rococo,
+        Substrate,
+        Scale,
+        Some(registrant.clone()),
+        vec![(*b"tran", Some(2))],
+    ));
+}
+
+#[test]
+fn pending_gateway_reserves_registration_bond_and_is_capped_to_optimistic() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let registrant = AccountId32::new([7u8; 32]);
+            let bond = <Runtime as pallet_xdns::Config>::GatewayRegistrationBond::get();
+
+            assert_noop!(
+                XDNS::add_pending_gateway(
+                    registrant.clone(),
+                    *b"pndg",
+                    Rococo,
+                    Substrate,
+                    Scale,
+                    None,
+                    vec![(*b"tran", Some(2))],
+                ),
+                pallet_xdns::Error::<Runtime>::InsufficientBalanceForRegistrationBond
+            );
+
+            Balances::make_free_balance_be(&registrant, bond * 2);
+            register_pending_gateway(&registrant);
+
+            assert_eq!(Balances::reserved_balance(&registrant), bond);
+            assert!(XDNS::gateways(*b"pndg").is_some());
+            assert_eq!(XDNS::get_gateway_max_security_lvl(b"pndg"), Optimistic);
+            assert_eq!(
+                XDNS::get_pending_gateway_max_order_value(b"pndg"),
+                Some(<Runtime as pallet_xdns::Config>::PendingGatewayMaxOrderValue::get())
+            );
+
+            assert_noop!(
+                XDNS::promote_pending_gateway(Origin::signed(registrant.clone()), *b"pndg"),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(XDNS::promote_pending_gateway(Origin::root(), *b"pndg"));
+
+            assert_eq!(Balances::reserved_balance(&registrant), 0);
+            assert_eq!(XDNS::get_pending_gateway_max_order_value(b"pndg"), None);
+            assert_eq!(XDNS::get_gateway_max_security_lvl(b"pndg"), Escrow);
+            assert_noop!(
+                XDNS::promote_pending_gateway(Origin::root(), *b"pndg"),
+                pallet_xdns::Error::<Runtime>::PendingGatewayNotFound
+            );
+        });
+}
+
+#[test]
+fn pending_gateway_is_promoted_by_majority_of_current_committee_votes() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let registrant = AccountId32::new([7u8; 32]);
+            let bond = <Runtime as pallet_xdns::Config>::GatewayRegistrationBond::get();
+            Balances::make_free_balance_be(&registrant, bond * 2);
+            register_pending_gateway(&registrant);
+
+            let attesters: Vec<AccountId32> =
+                (1u8..=4).map(|i| AccountId32::new([i; 32])).collect();
+            pallet_attesters::CurrentCommittee::<Runtime>::put(attesters.clone());
+
+            assert_ok!(XDNS::vote_gateway_promotion(
+                Origin::signed(attesters[0].clone()),
+                *b"pndg"
+            ));
+            assert_noop!(
+                XDNS::vote_gateway_promotion(Origin::signed(attesters[0].clone()), *b"pndg"),
+                pallet_xdns::Error::<Runtime>::GatewayPromotionAlreadyVoted
+            );
+            assert_ok!(XDNS::vote_gateway_promotion(
+                Origin::signed(attesters[1].clone()),
+                *b"pndg"
+            ));
+
+            // The committee rotates; votes of attesters who left are no longer counted
+            let rotated_attesters: Vec<AccountId32> =
+                (2u8..=5).map(|i| AccountId32::new([i; 32])).collect();
+            pallet_attesters::CurrentCommittee::<Runtime>::put(rotated_attesters.clone());
+
+            assert_ok!(XDNS::vote_gateway_promotion(
+                Origin::signed(rotated_attesters[1].clone()),
+                *b"pndg"
+            ));
+            assert_eq!(
+                XDNS::pending_gateways(*b"pndg")
+                    .unwrap()
+                    .promotion_votes
+                    .into_inner(),
+                vec![attesters[1].clone(), rotated_attesters[1].clone()]
+            );
+
+            assert_ok!(XDNS::vote_gateway_promotion(
+                Origin::signed(rotated_attesters[2].clone()),
+                *b"pndg"
+            ));
+            assert!(XDNS::pending_gateways(*b"pndg").is_none());
+            assert_eq!(Balances::reserved_balance(&registrant), 0);
+        });
+}
+
+#[test]
+fn slashing_pending_gateway_moves_bond_to_treasury_and_purges_gateway() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let registrant = AccountId32::new([7u8; 32]);
+            let bond = <Runtime as pallet_xdns::Config>::GatewayRegistrationBond::get();
+            let treasury = <Runtime as pallet_xdns::Config>::TreasuryAccounts::get_treasury_account(
+                TreasuryAccount::Treasury,
+            );
+            let treasury_balance_before = Balances::free_balance(&treasury);
+
+            Balances::make_free_balance_be(&registrant, bond * 2);
+            register_pending_gateway(&registrant);
+
+            assert_noop!(
+                XDNS::vote_gateway_promotion(Origin::signed(registrant.clone()), *b"pndg"),
+                pallet_xdns::Error::<Runtime>::NotInAttestersCommittee
+            );
+            assert_ok!(XDNS::slash_pending_gateway(Origin::root(), *b"pndg"));
+
+            assert_eq!(Balances::reserved_balance(&registrant), 0);
+            assert_eq!(Balances::free_balance(&registrant), bond);
+            assert_eq!(
+                Balances::free_balance(&treasury),
+                treasury_balance_before + bond
+            );
+            assert!(XDNS::gateways(*b"pndg").is_none());
+            assert!(XDNS::pending_gateways(*b"pndg").is_none());
+        });
+}
+
+#[test]
+fn gateway_health_score_penalises_stalls_latency_and_variance() {
+    let steady = vec![EpochEstimate::<u32> {
+        local: 8,
+        remote: 16,
+        moving_average_local: 0,
+        moving_average_remote: 0,
+    }];
+    assert_eq!(GatewayHealthRecord::score(0, &steady), 100);
+    // Stalls up to 2 expected epochs are tolerated
+    assert_eq!(GatewayHealthRecord::score(16, &steady), 100);
+    assert_eq!(GatewayHealthRecord::score(32, &steady), 50);
+    assert_eq!(GatewayHealthRecord::score(48, &steady), 0);
+    // Without any epochs observed, the default epoch length is expected
+    assert_eq!(GatewayHealthRecord::<u32>::score(100, &[]), 100);
+
+    let slowing_down = vec![
+        EpochEstimate::<u32> {
+            local: 10,
+            remote: 16,
+            moving_average_local: 0,
+            moving_average_remote: 0,
+        },
+        EpochEstimate::<u32> {
+            local: 20,
+            remote: 16,
+            moving_average_local: 10,
+            moving_average_remote: 16,
+        },
+    ];
+    // 30 for doubling the epoch length and 6 for the variance of 10 and 20 blocks long epochs
+    assert_eq!(GatewayHealthRecord::score(0, &slowing_down), 64);
+
+    assert!(GatewayHealthRecord::<u32>::is_healthy_after(true, 40));
+    assert!(!GatewayHealthRecord::<u32>::is_healthy_after(true, 39));
+    assert!(GatewayHealthRecord::<u32>::is_healthy_after(false, 70));
+    assert!(!GatewayHealthRecord::<u32>::is_healthy_after(false, 69));
+}
+
+#[test]
+fn stalled_verifier_stays_active_until_health_drops_below_deactivation_threshold() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::gateway_health(*b"pdot"),
+                Some(GatewayHealthRecord {
+                    score: 100,
+                    last_moved_at: Some(17),
+                    last_finalized_height: XDNS::verifier_overview_history(Polkadot)
+                        .last()
+                        .unwrap()
+                        .finalized_height,
+                    is_healthy: true,
+                    updated_at: 17,
+                })
+            );
+            assert!(XDNS::is_target_active(*b"pdot", &Optimistic));
+
+            let last_record = XDNS::verifier_overview_history(Polkadot)
+                .last()
+                .cloned()
+                .unwrap();
+            let mut stalled_heartbeat = Portal::get_latest_heartbeat_by_vendor(Polkadot);
+            stalled_heartbeat.last_finalized_height = last_record.finalized_height;
+            stalled_heartbeat.last_rational_height = last_record.justified_height;
+            stalled_heartbeat.last_fast_height = last_record.updated_height;
+
+            // Stalled for 30 blocks scores below the activation but above the deactivation threshold
+            System::set_block_number(47);
+            XDNS::on_new_epoch(Polkadot, last_record.epoch, stalled_heartbeat.clone());
+            XDNS::process_overview(47);
+            assert_eq!(XDNS::gateway_health(*b"pdot").unwrap().score, 57);
+            assert!(XDNS::gateway_health(*b"pdot").unwrap().is_healthy);
+            assert!(XDNS::is_target_active(*b"pdot", &Optimistic));
+
+            System::set_block_number(57);
+            XDNS::on_new_epoch(Polkadot, last_record.epoch, stalled_heartbeat);
+            XDNS::process_overview(57);
+            assert_eq!(XDNS::gateway_health(*b"pdot").unwrap().score, 25);
+            assert!(!XDNS::gateway_health(*b"pdot").unwrap().is_healthy);
+            assert!(!XDNS::is_target_active(*b"pdot", &Optimistic));
+        });
+}
+
+#[test]
+fn unhealthy_gateway_does_not_deactivate_other_gateways_of_the_same_verifier() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::get_verification_vendor(&[1, 1, 1, 1]),
+                XDNS::get_verification_vendor(b"pdot")
+            );
+            assert!(XDNS::is_target_active(*b"pdot", &Optimistic));
+            assert!(XDNS::is_target_active([1, 1, 1, 1], &Optimistic));
+
+            let mut health = XDNS::gateway_health([1, 1, 1, 1]).unwrap();
+            health.score = 0;
+            health.is_healthy = false;
+            pallet_xdns::GatewayHealth::<Runtime>::insert([1, 1, 1, 1], health);
+
+            assert!(!XDNS::is_target_active([1, 1, 1, 1], &Optimistic));
+            assert!(XDNS::is_target_active(*b"pdot", &Optimistic));
+            assert!(XDNS::gateway_health(*b"pdot").unwrap().is_healthy);
+        });
+}
+
+#[test]
+fn moving_gateway_does_not_hide_stall_of_another_gateway_of_the_same_verifier() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+        .execute_with(|| {
+            let epoch_history = XDNS::epoch_history(Polkadot).unwrap_or_default();
+            let stalled = XDNS::gateway_health(*b"pdot").unwrap();
+            let mut stalled_heartbeat = Portal::get_latest_heartbeat_by_vendor(Polkadot);
+            stalled_heartbeat.last_finalized_height = stalled.last_finalized_height;
+            let mut moving_heartbeat = stalled_heartbeat.clone();
+            moving_heartbeat.last_finalized_height = XDNS::gateway_health([1, 1, 1, 1])
+                .unwrap()
+                .last_finalized_height
+                + 8;
+
+            System::set_block_number(57);
+            assert!(XDNS::update_gateway_health(
+                57,
+                [1, 1, 1, 1],
+                &epoch_history,
+                moving_heartbeat,
+                stalled.last_finalized_height,
+                true,
+            ));
+            assert!(!XDNS::update_gateway_health(
+                57,
+                *b"pdot",
+                &epoch_history,
+                stalled_heartbeat,
+                stalled.last_finalized_height,
+                true,
+            ));
+
+            assert_eq!(
+                XDNS::gateway_health([1, 1, 1, 1]).unwrap().last_moved_at,
+                Some(57)
+            );
+            assert_eq!(
+                XDNS::gateway_health(*b"pdot").unwrap().last_moved_at,
+                stalled.last_moved_at
+            );
+            assert!(XDNS::is_target_active([1, 1, 1, 1], &Optimistic));
+            assert!(!XDNS::is_target_active(*b"pdot", &Optimistic));
+        });
+}
+
+#[test]
+fn purging_gateway_removes_its_health_record() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+        .execute_with(|| {
+            assert!(XDNS::gateway_health(*b"pdot").is_some());
+
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"pdot"));
+
+            assert_eq!(XDNS::gateway_health(*b"pdot"), None);
+            assert!(XDNS::gateway_health([1, 1, 1, 1]).is_some());
+        });
+}
+
+#[test]
+fn canonical_asset_groups_normalise_amounts_across_gateways() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let usdc: u32 = 1984;
+            assert_noop!(
+                XDNS::register_canonical_asset(Origin::root(), usdc, b"USDC".to_vec(), 6),
+                pallet_xdns::Error::<Runtime>::TokenRecordNotFoundInAssetsOverlay
+            );
+            register_priced_asset(usdc);
+
+            assert_noop!(
+                XDNS::register_canonical_asset(Origin::signed(ALICE), usdc, b"USDC".to_vec(), 6),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(XDNS::register_canonical_asset(
+                Origin::root(),
+                usdc,
+                b"USDC".to_vec(),
+                6
+            ));
+            assert_noop!(
+                XDNS::register_canonical_asset(Origin::root(), usdc, b"USDC".to_vec(), 6),
+                pallet_xdns::Error::<Runtime>::CanonicalAssetAlreadyExists
+            );
+
+            let usdc_on_eth = TokenInfo::Ethereum(EthereumToken {
+                symbol: b"USDC".to_vec(),
+                decimals: 6,
+                address: Some([2; 20]),
+            });
+            let usdc_on_ksma = TokenInfo::Substrate(SubstrateToken {
+                id: usdc,
+                symbol: b"USDC".to_vec(),
+                decimals: 12,
+            });
+            assert_ok!(XDNS::add_canonical_asset_member(
+                Origin::root(),
+                usdc,
+                *b"eth2",
+                usdc_on_eth.clone()
+            ));
+            assert_ok!(XDNS::add_canonical_asset_member(
+                Origin::root(),
+                usdc,
+                *b"ksma",
+                usdc_on_ksma
+            ));
+            assert_noop!(
+                XDNS::add_canonical_asset_member(
+                    Origin::root(),
+                    usdc,
+                    *b"none",
+                    usdc_on_eth.clone()
+                ),
+                pallet_xdns::Error::<Runtime>::GatewayRecordNotFound
+            );
+
+            // Members are linked as tokens, so that remote orders resolve to the canonical asset
+            assert_eq!(
+                XDNS::get_token_by_eth_address(*b"eth2", H160::from([2; 20]))
+                    .unwrap()
+                    .token_id,
+                usdc
+            );
+
+            let group = XDNS::get_canonical_asset_group(usdc).unwrap();
+            assert_eq!(group.member(b"eth2"), Some(&usdc_on_eth));
+            assert_eq!(
+                group.convert_between(b"ksma", b"eth2", 5_000_000_000_000),
+                Some(5_000_000)
+            );
+            assert_eq!(
+                group.from_canonical(b"ksma", 5_000_000),
+                Some(5_000_000_000_000)
+            );
+
+            assert_eq!(
+                XDNS::normalize_asset_amount_to_canonical(usdc, b"ksma", 1_500_000_999_999),
+                Ok(1_500_000)
+            );
+            assert_eq!(
+                XDNS::normalize_asset_amount_to_canonical(usdc, b"eth2", 1_500_000),
+                Ok(1_500_000)
+            );
+            // Gateways without a member and assets without a group keep the amounts as they are
+            assert_eq!(
+                XDNS::normalize_asset_amount_to_canonical(usdc, b"pdot", 1_500_000),
+                Ok(1_500_000)
+            );
+            assert_eq!(
+                XDNS::normalize_asset_amount_to_canonical(2222, b"ksma", 1_500_000),
+                Ok(1_500_000)
+            );
+
+            assert_ok!(XDNS::remove_canonical_asset_member(
+                Origin::root(),
+                usdc,
+                *b"ksma"
+            ));
+            assert_noop!(
+                XDNS::remove_canonical_asset_member(Origin::root(), usdc, *b"ksma"),
+                pallet_xdns::Error::<Runtime>::CanonicalAssetMemberNotFound
+            );
+            assert_eq!(
+                XDNS::normalize_asset_amount_to_canonical(usdc, b"ksma", 1_500_000),
+                Ok(1_500_000)
+            );
+        });
+}
+
+#[test]
+fn removing_canonical_asset_member_unlinks_the_asset_from_the_gateway() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let usdc: u32 = 1984;
+            register_priced_asset(usdc);
+            assert_ok!(XDNS::register_canonical_asset(
+                Origin::root(),
+                usdc,
+                b"USDC".to_vec(),
+                6
+            ));
+            assert_ok!(XDNS::add_canonical_asset_member(
+                Origin::root(),
+                usdc,
+                *b"eth2",
+                TokenInfo::Ethereum(EthereumToken {
+                    symbol: b"USDC".to_vec(),
+                    decimals: 18,
+                    address: Some([2; 20]),
+                })
+            ));
+            assert!(XDNS::tokens(usdc, *b"eth2").is_some());
+            assert!(XDNS::gateway_tokens(*b"eth2").contains(&usdc));
+
+            assert_ok!(XDNS::remove_canonical_asset_member(
+                Origin::root(),
+                usdc,
+                *b"eth2"
+            ));
+
+            // The gateway's representation is gone with the member, so no order on the gateway
+            // resolves to the asset and gets normalised against the group anymore
+            assert!(XDNS::tokens(usdc, *b"eth2").is_none());
+            assert!(!XDNS::gateway_tokens(*b"eth2").contains(&usdc));
+            assert!(XDNS::get_token_by_eth_address(*b"eth2", H160::from([2; 20])).is_err());
+        });
+}
@@ -42,6 +42,23 @@ pub trait WeightInfo {
     fn purge_gateway() -> Weight;
     fn reboot_self_gateway() -> Weight;
     fn best_available() -> Weight;
+    fn register_canonical_asset() -> Weight;
+    fn add_canonical_asset_member() -> Weight;
+    fn remove_canonical_asset_member() -> Weight;
+    fn enact_scheduled_gateway_change(a: u32) -> Weight;
+    fn schedule_gateway_override(s: u32) -> Weight;
+    fn schedule_sfx_abi_override(s: u32) -> Weight;
+    fn schedule_gateway_purge() -> Weight;
+    fn cancel_scheduled_gateway_changes(c: u32) -> Weight;
+    fn promote_pending_gateway() -> Weight;
+    fn vote_gateway_promotion(c: u32) -> Weight;
+    fn slash_pending_gateway() -> Weight;
+    fn update_gateway_health() -> Weight;
+    fn set_gateway_abi() -> Weight;
+    fn add_price_feeder() -> Weight;
+    fn remove_price_feeder(a: u32) -> Weight;
+    fn submit_asset_price(f: u32, a: u32) -> Weight;
+    fn aggregate_asset_price(f: u32, a: u32) -> Weight;
 }
 
 /// Weights for pallet_xdns using the Substrate node and recommended hardware.
@@ -62,7 +79,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn purge_gateway() -> Weight {
         Weight::from_parts(58_912_000_u64, 0u64)
             .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_2_u64))
     }
 
     fn best_available() -> Weight {
@@ -74,6 +91,157 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+
+    fn register_canonical_asset() -> Weight {
+        Weight::from_parts(31_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn add_canonical_asset_member() -> Weight {
+        Weight::from_parts(47_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    fn remove_canonical_asset_member() -> Weight {
+        Weight::from_parts(35_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn enact_scheduled_gateway_change(a: u32) -> Weight {
+        Weight::from_parts(75_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_500_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a as u64)))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a as u64)))
+    }
+
+    fn schedule_gateway_override(s: u32) -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_000_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s as u64)))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn schedule_sfx_abi_override(s: u32) -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_500_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn schedule_gateway_purge() -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn cancel_scheduled_gateway_changes(c: u32) -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(500_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn promote_pending_gateway() -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn vote_gateway_promotion(c: u32) -> Weight {
+        Weight::from_parts(35_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(400_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn slash_pending_gateway() -> Weight {
+        Weight::from_parts(91_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+
+    // Estimated, not benchmarked: scoring a gateway reads its light client's heartbeat
+    // through the Portal and reads and writes its health record.
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best header, confirmation depths, halted and initialized flags (r:5 w:0)
+    // Storage: XDNS GatewayHealth (r:1 w:1)
+    fn update_gateway_health() -> Weight {
+        Weight::from_parts(36_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    // Estimated until regenerated from the `set_gateway_abi` benchmark.
+    // Storage: XDNS Gateways (r:1 w:1)
+    // Storage: XDNS GatewayABIs (r:0 w:1)
+    fn set_gateway_abi() -> Weight {
+        Weight::from_parts(26_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    // Estimated until regenerated from the `add_price_feeder`, `remove_price_feeder`,
+    // `submit_asset_price` and `aggregate_asset_price` benchmarks.
+    // Storage: XDNS PriceFeeders (r:1 w:1)
+    fn add_price_feeder() -> Weight {
+        Weight::from_parts(21_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    // Storage: XDNS PriceFeeders (r:1 w:1)
+    // Storage: XDNS AllTokenIds (r:1 w:0)
+    // Storage: XDNS PriceSubmissions (r:a w:a)
+    fn remove_price_feeder(a: u32) -> Weight {
+        Weight::from_parts(24_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(3_200_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a as u64)))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a as u64)))
+    }
+
+    // Storage: XDNS PriceFeeders (r:1 w:0)
+    // Storage: XDNS AllTokenIds (r:2 w:0)
+    // Storage: XDNS PriceSubmissions (r:f w:1)
+    // Storage: XDNS AssetEstimatesInNative, AssetCostEstimatesInNative, AssetEstimatesValidUntil (r:0 w:3)
+    // Storage: XDNS AssetEstimatesExpiring (r:1 w:1)
+    // Storage: XDNS AssetEstimatesValidUntil, AssetEstimatesInNative, Tokens of each other asset (r:3a w:0)
+    // Storage: XDNS AssetEstimates, PerTargetAssetEstimates of each other asset (r:0 w:4a)
+    fn submit_asset_price(f: u32, a: u32) -> Weight {
+        Weight::from_parts(52_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(6_100_000_u64, 0u64).saturating_mul(f as u64))
+            .saturating_add(Weight::from_parts(9_400_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f as u64)))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a as u64)))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(a as u64)))
+    }
+
+    // Storage: XDNS PriceFeeders (r:1 w:0)
+    // Storage: XDNS AllTokenIds (r:1 w:0)
+    // Storage: XDNS PriceSubmissions (r:f w:0)
+    // Storage: XDNS AssetEstimatesInNative, AssetCostEstimatesInNative, AssetEstimatesValidUntil (r:0 w:3)
+    // Storage: XDNS AssetEstimatesExpiring (r:1 w:1)
+    // Storage: XDNS AssetEstimatesValidUntil, AssetEstimatesInNative, Tokens of each other asset (r:3a w:0)
+    // Storage: XDNS AssetEstimates, PerTargetAssetEstimates of each other asset (r:0 w:4a)
+    fn aggregate_asset_price(f: u32, a: u32) -> Weight {
+        Weight::from_parts(33_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(6_100_000_u64, 0u64).saturating_mul(f as u64))
+            .saturating_add(Weight::from_parts(9_400_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f as u64)))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a as u64)))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(a as u64)))
+    }
 }
 
 // For backwards compatibility and tests
@@ -93,7 +261,7 @@ impl WeightInfo for () {
     fn purge_gateway() -> Weight {
         Weight::from_parts(58_912_000_u64, 0u64)
             .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_2_u64))
     }
 
     fn best_available() -> Weight {
@@ -105,4 +273,155 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+
+    fn register_canonical_asset() -> Weight {
+        Weight::from_parts(31_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn add_canonical_asset_member() -> Weight {
+        Weight::from_parts(47_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn remove_canonical_asset_member() -> Weight {
+        Weight::from_parts(35_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn enact_scheduled_gateway_change(a: u32) -> Weight {
+        Weight::from_parts(75_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_500_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a as u64)))
+    }
+
+    fn schedule_gateway_override(s: u32) -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_000_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn schedule_sfx_abi_override(s: u32) -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_500_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn schedule_gateway_purge() -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn cancel_scheduled_gateway_changes(c: u32) -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(500_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn promote_pending_gateway() -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn vote_gateway_promotion(c: u32) -> Weight {
+        Weight::from_parts(35_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(400_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn slash_pending_gateway() -> Weight {
+        Weight::from_parts(91_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+
+    // Estimated, not benchmarked: scoring a gateway reads its light client's heartbeat
+    // through the Portal and reads and writes its health record.
+    // Storage: XDNS Gateways (r:1 w:0)
+    // Storage: light client best header, confirmation depths, halted and initialized flags (r:5 w:0)
+    // Storage: XDNS GatewayHealth (r:1 w:1)
+    fn update_gateway_health() -> Weight {
+        Weight::from_parts(36_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    // Estimated until regenerated from the `set_gateway_abi` benchmark.
+    // Storage: XDNS Gateways (r:1 w:1)
+    // Storage: XDNS GatewayABIs (r:0 w:1)
+    fn set_gateway_abi() -> Weight {
+        Weight::from_parts(26_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    // Estimated until regenerated from the `add_price_feeder`, `remove_price_feeder`,
+    // `submit_asset_price` and `aggregate_asset_price` benchmarks.
+    // Storage: XDNS PriceFeeders (r:1 w:1)
+    fn add_price_feeder() -> Weight {
+        Weight::from_parts(21_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    // Storage: XDNS PriceFeeders (r:1 w:1)
+    // Storage: XDNS AllTokenIds (r:1 w:0)
+    // Storage: XDNS PriceSubmissions (r:a w:a)
+    fn remove_price_feeder(a: u32) -> Weight {
+        Weight::from_parts(24_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(3_200_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a as u64)))
+    }
+
+    // Storage: XDNS PriceFeeders (r:1 w:0)
+    // Storage: XDNS AllTokenIds (r:2 w:0)
+    // Storage: XDNS PriceSubmissions (r:f w:1)
+    // Storage: XDNS AssetEstimatesInNative, AssetCostEstimatesInNative, AssetEstimatesValidUntil (r:0 w:3)
+    // Storage: XDNS AssetEstimatesExpiring (r:1 w:1)
+    // Storage: XDNS AssetEstimatesValidUntil, AssetEstimatesInNative, Tokens of each other asset (r:3a w:0)
+    // Storage: XDNS AssetEstimates, PerTargetAssetEstimates of each other asset (r:0 w:4a)
+    fn submit_asset_price(f: u32, a: u32) -> Weight {
+        Weight::from_parts(52_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(6_100_000_u64, 0u64).saturating_mul(f as u64))
+            .saturating_add(Weight::from_parts(9_400_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f as u64)))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(a as u64)))
+    }
+
+    // Storage: XDNS PriceFeeders (r:1 w:0)
+    // Storage: XDNS AllTokenIds (r:1 w:0)
+    // Storage: XDNS PriceSubmissions (r:f w:0)
+    // Storage: XDNS AssetEstimatesInNative, AssetCostEstimatesInNative, AssetEstimatesValidUntil (r:0 w:3)
+    // Storage: XDNS AssetEstimatesExpiring (r:1 w:1)
+    // Storage: XDNS AssetEstimatesValidUntil, AssetEstimatesInNative, Tokens of each other asset (r:3a w:0)
+    // Storage: XDNS AssetEstimates, PerTargetAssetEstimates of each other asset (r:0 w:4a)
+    fn aggregate_asset_price(f: u32, a: u32) -> Weight {
+        Weight::from_parts(33_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(6_100_000_u64, 0u64).saturating_mul(f as u64))
+            .saturating_add(Weight::from_parts(9_400_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f as u64)))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a as u64)))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(a as u64)))
+    }
 }
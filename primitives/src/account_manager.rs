@@ -1,4 +1,7 @@
-use crate::claimable::{BenefitSource, CircuitRole};
+use crate::{
+    claimable::{BenefitSource, CircuitRole},
+    common::{RoundIndex, RoundInfo},
+};
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
 use scale_info::TypeInfo;
@@ -21,6 +24,7 @@ pub struct Settlement<Account, Balance, AssetId> {
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequestCharge<Account, Balance, AssetId> {
     pub payee: Account,
     pub offered_reward: Balance,
@@ -39,6 +43,87 @@ pub enum Outcome {
     Slash,
 }
 
+/// Lifecycle state of a deposit awaiting finalization
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepositState<BlockNumber> {
+    /// Awaiting finalization
+    Pending,
+    /// Frozen pending a dispute since the given block; finalization is blocked until released
+    Held(BlockNumber),
+}
+
+/// Resolution requested for a deposit while on hold, applied once the hold is released
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum HeldDepositResolution {
+    /// Finalize the deposit with the given outcome
+    Finalize(Outcome),
+    /// Cancel the deposit, returning the offered reward to the payee
+    Cancel,
+}
+
+/// Snapshot of a deposit: the remaining charge, its state and the reward released so far
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositInfo<Account, Balance, AssetId, BlockNumber> {
+    pub charge: RequestCharge<Account, Balance, AssetId>,
+    pub state: DepositState<BlockNumber>,
+    pub released: Balance,
+}
+
+/// Running totals of the escrow ledger of a single asset
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscrowTotals<Balance> {
+    /// Withdrawn from payees into escrow
+    pub reserved: Balance,
+    /// Settled to recipients on commit
+    pub committed: Balance,
+    /// Returned to payees
+    pub reverted: Balance,
+    /// Slashed to the escrow account
+    pub slashed: Balance,
+    /// Charge fees paid out or retained
+    pub fees: Balance,
+}
+
+/// Running sum and count of the pending charges of a single asset
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingTotals<Balance> {
+    /// Sum of the offered rewards and charge fees still in escrow
+    pub amount: Balance,
+    pub charges: u32,
+}
+
+/// Reconciliation of the escrow ledger of a single asset at a round change
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetReconciliation<AssetId, Balance> {
+    pub maybe_asset_id: Option<AssetId>,
+    pub totals: EscrowTotals<Balance>,
+    /// Sum of the charges still pending
+    pub pending: Balance,
+    /// Sum of the pending charges deposited too many rounds ago
+    pub stuck: Balance,
+    pub stuck_charges: u32,
+    /// Balance of the escrow holding account above its minimum balance
+    pub escrowed: Balance,
+    /// Sum of the pending charges held by remote origins rather than by the holding account
+    pub remote_pending: Balance,
+    /// Whether reserved differs from the sum of committed, reverted, slashed, fees and pending,
+    /// or escrowed differs from pending less remote pending
+    pub mismatch: bool,
+}
+
+/// Per-asset reconciliation report stored at the end of a round
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReconciliationReport<AssetId, Balance> {
+    pub round: RoundIndex,
+    pub assets: Vec<AssetReconciliation<AssetId, Balance>>,
+}
+
 pub trait AccountManager<Account, Balance, Hash, BlockNumber, AssetId> {
     /// Lookup charge by Id and fail if not found
     fn get_charge_or_fail(
@@ -52,6 +137,11 @@ pub trait AccountManager<Account, Balance, Hash, BlockNumber, AssetId> {
     fn get_settlements_by_role(
         role: CircuitRole,
     ) -> Vec<(Account, Settlement<Account, Balance, AssetId>)>;
+    /// Get all settlements by role in the given round
+    fn get_round_settlements_by_role(
+        round: RoundInfo<BlockNumber>,
+        role: CircuitRole,
+    ) -> Vec<(Account, Settlement<Account, Balance, AssetId>)>;
     /// Bump contracts registry nonce in Account Manager nonce state and return charge request Id
     fn bump_contracts_registry_nonce() -> Result<Hash, DispatchError>;
     /// Validate deposit goes through
@@ -66,18 +156,49 @@ pub trait AccountManager<Account, Balance, Hash, BlockNumber, AssetId> {
         charge_id: Hash,
         request_charge: RequestCharge<Account, Balance, AssetId>,
     ) -> DispatchResult;
-    /// Finalize a transaction, with an optional reason for failures
+    /// Finalize a transaction, paying the charge fee to the optional recipient in the charged
+    /// asset and returning reserved fees exceeding the optional actual fees to the payee
     fn finalize(
         charge_id: Hash,
         outcome: Outcome,
         maybe_recipient: Option<Account>,
         maybe_actual_fees: Option<Balance>,
     ) -> DispatchResult;
-    /// Assert infallible finalize of a transaction if exists
+    /// Assert infallible finalize of a transaction if exists.
+    /// Deposits on hold record the outcome and are finalized once the hold is released.
     fn finalize_infallible(charge_id: Hash, outcome: Outcome) -> bool;
 
+    /// Finalize a portion of the offered reward with the given outcome, leaving the rest of
+    /// the charge pending
+    fn finalize_partial(charge_id: Hash, amount: Balance, outcome: Outcome) -> DispatchResult;
+
+    /// Freeze a pending deposit, blocking its finalization until the hold is released
+    fn hold(charge_id: Hash) -> DispatchResult;
+
+    /// Release the hold of a deposit, allowing its finalization again.
+    /// The given resolution settles the dispute, overriding the one requested while on hold.
+    fn release_hold(
+        charge_id: Hash,
+        maybe_resolution: Option<HeldDepositResolution>,
+    ) -> DispatchResult;
+
+    /// Check whether a deposit is on hold
+    fn is_held(charge_id: Hash) -> bool;
+
+    /// Cancel a deposit if exists, returning the offered reward to the payee.
+    /// Deposits on hold are cancelled once the hold is released.
     fn cancel_deposit(charge_id: Hash) -> bool;
 
+    /// Carve an amount out of a settlement of the current round and settle it to the beneficiary
+    /// in the given role under a new id. Returns false if the settlement can't cover the amount.
+    fn split_settlement(
+        settlement_id: Hash,
+        new_settlement_id: Hash,
+        beneficiary: &Account,
+        role: CircuitRole,
+        amount: Balance,
+    ) -> bool;
+
     fn assign_deposit(charge_id: Hash, recipient: &Account) -> bool;
 
     fn transfer_deposit(
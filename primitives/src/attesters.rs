@@ -385,6 +385,9 @@ pub trait AttestersReadApi<Account, Balance, BlockNumber> {
     fn estimate_finality_reward(target: &TargetId, blocks_delay: BlockNumber) -> Balance;
     fn estimate_batching_factor(target: &TargetId) -> Option<BatchingFactor>;
     // fn estimate_future_user_base(batching_factor: &BatchingFactor, n_epochs_ahead: u16) -> u16;
+    /// Sets the current committee for benchmarks of the pallets acting on the committee's behalf.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_current_committee(_committee: Vec<Account>) {}
 }
 
 pub struct AttestersReadApiEmptyMock<Account, Balance, Error> {
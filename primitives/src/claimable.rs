@@ -5,6 +5,7 @@ use sp_runtime::RuntimeDebug;
 use sp_std::{fmt::Debug, prelude::*};
 
 #[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum CircuitRole {
     Ambassador,
     Executor,
@@ -18,6 +19,7 @@ pub enum CircuitRole {
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum BenefitSource {
     BootstrapPool,
     Inflation,
@@ -26,6 +28,7 @@ pub enum BenefitSource {
     EscrowUnlock,
     Unsettled,
     SlashTreasury,
+    RelayerRewards,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
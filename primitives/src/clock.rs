@@ -1,6 +1,11 @@
-use crate::common::RoundInfo;
+use crate::common::{RoundIndex, RoundInfo};
+use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::Weight, sp_runtime::traits::Zero};
 use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
 use sp_std::marker::PhantomData;
 
 pub trait Clock<T: frame_system::Config> {
@@ -22,6 +27,71 @@ impl<T: frame_system::Config> Clock<T> for ClockMock<T> {
     }
 }
 
+pub trait OnNewRound<T: frame_system::Config> {
+    /// Called by the clock once the round has been bumped from `past_round` to `new_round`.
+    fn on_new_round(
+        past_round: RoundInfo<BlockNumberFor<T>>,
+        new_round: RoundInfo<BlockNumberFor<T>>,
+    ) -> Weight;
+}
+
+impl<T: frame_system::Config> OnNewRound<T> for () {
+    fn on_new_round(
+        _past_round: RoundInfo<BlockNumberFor<T>>,
+        _new_round: RoundInfo<BlockNumberFor<T>>,
+    ) -> Weight {
+        Zero::zero()
+    }
+}
+
+/// Identifier under which a round handler is registered with the clock.
+pub type RoundHandlerId = [u8; 8];
+
+/// A handler registered with the clock, run once per round within its weight budget.
+pub struct RoundHandler<T: frame_system::Config> {
+    pub id: RoundHandlerId,
+    /// Max. weight the handler is expected to consume in a single run.
+    pub weight_budget: Weight,
+    pub on_new_round: fn(RoundInfo<BlockNumberFor<T>>, RoundInfo<BlockNumberFor<T>>) -> Weight,
+}
+
+impl<T: frame_system::Config> RoundHandler<T> {
+    pub fn new<Handler: OnNewRound<T>>(id: RoundHandlerId, weight_budget: Weight) -> Self {
+        RoundHandler {
+            id,
+            weight_budget,
+            on_new_round: Handler::on_new_round,
+        }
+    }
+}
+
+/// Last run status of a round handler.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+pub struct RoundHandlerStatus<BlockNumber> {
+    /// Index of the round the handler was last scheduled for.
+    pub round: RoundIndex,
+    /// Block at which the run was scheduled.
+    pub scheduled_at: BlockNumber,
+    /// Block at which the run was executed, `None` while still pending.
+    pub ran_at: Option<BlockNumber>,
+    /// Weight consumed by the last executed run.
+    pub weight_consumed: Weight,
+    /// Number of blocks the run has been deferred by for lack of weight.
+    pub deferrals: u32,
+}
+
+/// Timings of the current round as seen by the clock.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RoundTimings<BlockNumber> {
+    pub current: RoundInfo<BlockNumber>,
+    /// First block at which the round can be bumped.
+    pub next_round_at: BlockNumber,
+    /// Blocks left until `next_round_at`.
+    pub blocks_remaining: BlockNumber,
+}
+
 pub trait OnHookQueues<T: frame_system::Config> {
     // Process the queues for the given block number, handle the intervals internally.
     fn process(n: BlockNumberFor<T>, hook_weight_limit: Weight) -> Weight;
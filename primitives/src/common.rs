@@ -46,6 +46,7 @@ pub type RoundIndex = u32;
 
 /// General round information consisting ofindex (one-based), head
 /// (beginning block number), and term (round length in number of blocks).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct RoundInfo<BlockNumber> {
     /// Current round index.
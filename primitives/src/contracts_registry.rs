@@ -6,10 +6,16 @@ use crate::{
     ChainId, Compose,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::traits::Currency;
+use frame_support::{
+    traits::{ConstU32, Currency},
+    BoundedVec,
+};
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Hash, RuntimeDebug};
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Hash, Zero},
+    Percent, RuntimeDebug,
+};
 
 use crate::Vec;
 
@@ -37,12 +43,42 @@ where
     >;
 }
 
+/// Maximum number of co-authors sharing the revenue of a single contract
+pub const MAX_REVENUE_SPLITS: u32 = 16;
+/// Maximum number of callers listed by a tiered or whitelisted pricing model
+pub const MAX_PRICING_ENTRIES: u32 = 32;
+
+pub type RevenueSplits<AccountId> = BoundedVec<(AccountId, Percent), ConstU32<MAX_REVENUE_SPLITS>>;
+
+/// Defines how the fee for a single use of a contract is derived.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PricingModel<AccountId, BalanceOf> {
+    /// Charge `fees_per_single_use` on every call
+    Flat,
+    /// Charge a percentage of the value transferred with the call
+    PercentOfValue(Percent),
+    /// Charge the fee listed for the caller, falling back to `fees_per_single_use`
+    TieredByCaller(BoundedVec<(AccountId, BalanceOf), ConstU32<MAX_PRICING_ENTRIES>>),
+    /// Calls are free for the listed callers, others pay `fees_per_single_use`
+    FreeForWhitelisted(BoundedVec<AccountId, ConstU32<MAX_PRICING_ENTRIES>>),
+}
+
+impl<AccountId, BalanceOf> Default for PricingModel<AccountId, BalanceOf> {
+    fn default() -> Self {
+        PricingModel::Flat
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub struct AuthorInfo<AccountId, BalanceOf> {
     /// Original code author
     pub account: AccountId,
     /// Optional remuneration fee for the author
     pub fees_per_single_use: Option<BalanceOf>,
+    /// Co-authors and their share of the revenue; the remainder goes to `account`
+    pub revenue_splits: RevenueSplits<AccountId>,
+    /// How the fee for a single use is priced
+    pub pricing: PricingModel<AccountId, BalanceOf>,
 }
 
 impl<AccountId, BalanceOf> AuthorInfo<AccountId, BalanceOf> {
@@ -50,9 +86,76 @@ impl<AccountId, BalanceOf> AuthorInfo<AccountId, BalanceOf> {
         Self {
             account,
             fees_per_single_use,
+            revenue_splits: BoundedVec::default(),
+            pricing: PricingModel::Flat,
+        }
+    }
+
+    pub fn with_revenue_splits(mut self, revenue_splits: RevenueSplits<AccountId>) -> Self {
+        self.revenue_splits = revenue_splits;
+        self
+    }
+
+    pub fn with_pricing(mut self, pricing: PricingModel<AccountId, BalanceOf>) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Co-author shares must be non-zero and must not exceed 100% in total.
+    pub fn has_valid_revenue_splits(&self) -> bool {
+        let mut total: u32 = 0;
+        for (_, share) in self.revenue_splits.iter() {
+            if share.is_zero() {
+                return false
+            }
+            total = total.saturating_add(share.deconstruct() as u32);
         }
+        total <= 100
     }
 }
+
+impl<AccountId: PartialEq + Clone, BalanceOf: AtLeast32BitUnsigned + Copy>
+    AuthorInfo<AccountId, BalanceOf>
+{
+    /// Fee owed by `caller` for a single use, given the `value` transferred with the call.
+    pub fn fee_for(&self, caller: &AccountId, value: BalanceOf) -> BalanceOf {
+        let flat = self.fees_per_single_use.unwrap_or_else(Zero::zero);
+        match &self.pricing {
+            PricingModel::Flat => flat,
+            PricingModel::PercentOfValue(share) => share.mul_floor(value),
+            PricingModel::TieredByCaller(tiers) => tiers
+                .iter()
+                .find(|(account, _)| account == caller)
+                .map(|(_, fee)| *fee)
+                .unwrap_or(flat),
+            PricingModel::FreeForWhitelisted(whitelist) =>
+                if whitelist.contains(caller) {
+                    Zero::zero()
+                } else {
+                    flat
+                },
+        }
+    }
+
+    /// Splits `amount` between co-authors by their shares. The author receives the remainder,
+    /// listed first. Zero shares are left out.
+    pub fn revenue_shares(&self, amount: BalanceOf) -> Vec<(AccountId, BalanceOf)> {
+        let mut shares = Vec::with_capacity(self.revenue_splits.len() + 1);
+        let mut remainder = amount;
+        for (co_author, share) in self.revenue_splits.iter() {
+            let co_author_share = share.mul_floor(amount).min(remainder);
+            if !co_author_share.is_zero() {
+                remainder -= co_author_share;
+                shares.push((co_author.clone(), co_author_share));
+            }
+        }
+        if !remainder.is_zero() {
+            shares.insert(0, (self.account.clone(), remainder));
+        }
+        shares
+    }
+}
+
 /// A preliminary representation of a contract in the onchain registry.
 #[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct RegistryContract<Hash, AccountId, BalanceOf, BlockNumber> {
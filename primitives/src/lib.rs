@@ -134,11 +134,12 @@ pub enum GatewayVendor {
     Sepolia,
     XBI,
     Attesters,
+    Standalone,
 }
 use sp_std::slice::Iter;
 impl GatewayVendor {
     pub fn iterator() -> Iter<'static, GatewayVendor> {
-        static VENDORS: [GatewayVendor; 7] = [
+        static VENDORS: [GatewayVendor; 8] = [
             GatewayVendor::Polkadot,
             GatewayVendor::Kusama,
             GatewayVendor::Rococo,
@@ -146,6 +147,7 @@ impl GatewayVendor {
             GatewayVendor::Sepolia,
             GatewayVendor::XBI,
             GatewayVendor::Attesters,
+            GatewayVendor::Standalone,
         ];
         VENDORS.iter()
     }
@@ -156,7 +158,8 @@ impl GatewayVendor {
             | GatewayVendor::Kusama
             | GatewayVendor::Rococo
             | GatewayVendor::Attesters
-            | GatewayVendor::XBI => match speed_mode {
+            | GatewayVendor::XBI
+            | GatewayVendor::Standalone => match speed_mode {
                 SpeedMode::Fast => 4u32.into(),
                 SpeedMode::Rational => 6u32.into(),
                 SpeedMode::Finalized => 8u32.into(),
@@ -368,6 +371,15 @@ pub struct EthereumToken {
     pub address: Option<[u8; 20]>,
 }
 
+impl TokenInfo {
+    pub fn decimals(&self) -> u8 {
+        match self {
+            TokenInfo::Substrate(token) => token.decimals,
+            TokenInfo::Ethereum(token) => token.decimals,
+        }
+    }
+}
+
 impl Default for TokenInfo {
     fn default() -> Self {
         Self::Substrate(SubstrateToken {
@@ -7,6 +7,9 @@ use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
 use t3rn_abi::types::Bytes;
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
 pub enum HeightResult<BlockNumber> {
     Height(BlockNumber),
@@ -20,6 +23,7 @@ pub enum HeaderResult {
 }
 
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct InclusionReceipt<BlockNumber> {
     pub height: BlockNumber,
     pub including_header: Bytes,
@@ -145,4 +149,14 @@ pub trait LightClient<T: frame_system::Config> {
         speed_mode: SpeedMode,
         message: Bytes,
     ) -> Result<Bytes, DispatchError>;
+
+    /// Keep the header an inclusion was verified against from being pruned until the given block.
+    /// Light clients not pruning their headers can rely on the default no-op.
+    fn pin_header(
+        &self,
+        _including_header: Bytes,
+        _until: BlockNumberFor<T>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
 }
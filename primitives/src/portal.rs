@@ -3,13 +3,16 @@ use crate::{
     gateway::GatewayABIConfig, ChainId, ExecutionSource, ExecutionVendor, GatewayGenesisConfig,
     GatewayType, GatewayVendor, SpeedMode, TokenInfo,
 };
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_system::pallet_prelude::BlockNumberFor;
 
 use crate::light_client::LightClientHeartbeat;
 use scale_info::TypeInfo;
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
 use sp_std::{convert::TryFrom, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use t3rn_abi::{recode::Codec, types::Bytes, Abi, FilledAbi};
 use t3rn_types::sfx::Sfx4bId;
 
@@ -27,6 +30,60 @@ pub struct RegistrationData {
     pub encoded_registration_data: Bytes,
 }
 
+/// Header relaying activity of a single relayer on a single gateway.
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo, Default, MaxEncodedLen)]
+pub struct RelayerRecord<BlockNumber, Balance> {
+    /// Number of submissions which advanced the gateway's finalized head.
+    pub useful_submissions: u32,
+    /// Number of headers the finalized head was advanced by, summed over all submissions.
+    pub headers_advanced: u32,
+    /// Local block number of the last useful submission.
+    pub last_submission_at: BlockNumber,
+    /// Total rewards recorded as claimable for the relayer.
+    pub total_rewarded: Balance,
+}
+
+/// Budget paying relayers per header advancing the gateway's finalized head.
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo, Default, MaxEncodedLen)]
+pub struct RelayerRewardsBudget<Balance> {
+    pub reward_per_header: Balance,
+    pub remaining: Balance,
+}
+
+/// Light client heartbeat of a gateway with heights widened to u128, as served over RPC.
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GatewayHeartbeat {
+    pub last_heartbeat: u128,
+    pub last_finalized_height: u128,
+    pub last_rational_height: u128,
+    pub last_fast_height: u128,
+    pub is_halted: bool,
+    pub ever_initialized: bool,
+}
+
+impl<T: frame_system::Config> From<LightClientHeartbeat<T>> for GatewayHeartbeat {
+    fn from(heartbeat: LightClientHeartbeat<T>) -> Self {
+        GatewayHeartbeat {
+            last_heartbeat: heartbeat.last_heartbeat.unique_saturated_into(),
+            last_finalized_height: heartbeat.last_finalized_height.unique_saturated_into(),
+            last_rational_height: heartbeat.last_rational_height.unique_saturated_into(),
+            last_fast_height: heartbeat.last_fast_height.unique_saturated_into(),
+            is_halted: heartbeat.is_halted,
+            ever_initialized: heartbeat.ever_initialized,
+        }
+    }
+}
+
+/// Heights of a gateway per speed mode, as served over RPC. `None` if the light client isn't active.
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GatewayHeights {
+    pub finalized: Option<u128>,
+    pub rational: Option<u128>,
+    pub fast: Option<u128>,
+}
+
 // This could be split into readable parts here, or even more specific traits in the future, if needed.
 // Something like `.. Portal: ReadHeaders + Submit { ..`
 pub trait Portal<T: frame_system::Config> {
@@ -140,6 +197,12 @@ pub trait Portal<T: frame_system::Config> {
     fn turn_on(origin: T::RuntimeOrigin, gateway_id: [u8; 4]) -> Result<bool, DispatchError>;
 
     fn turn_off(origin: T::RuntimeOrigin, gateway_id: [u8; 4]) -> Result<bool, DispatchError>;
+
+    fn pin_header(
+        gateway_id: ChainId,
+        including_header: Bytes,
+        until: BlockNumberFor<T>,
+    ) -> Result<(), DispatchError>;
 }
 
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
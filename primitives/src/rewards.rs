@@ -1,6 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use crate::circuit::CircuitStatus;
+use crate::{
+    circuit::CircuitStatus,
+    claimable::{BenefitSource, CircuitRole},
+};
 use sp_core::H256;
+use sp_runtime::Percent;
+use sp_std::marker::PhantomData;
 use t3rn_types::fsx::FullSideEffect;
 
 pub trait RewardsWriteApi<Account, Balance, BlockNumber> {
@@ -10,4 +15,35 @@ pub trait RewardsWriteApi<Account, Balance, BlockNumber> {
         status: &CircuitStatus,
         requester: Option<Account>,
     ) -> bool;
+
+    /// Record a reward to be claimed by the beneficiary in the given role.
+    fn add_pending_claim(
+        beneficiary: &Account,
+        role: CircuitRole,
+        amount: Balance,
+        benefit_source: BenefitSource,
+        non_native_asset_id: Option<u32>,
+    );
+}
+
+pub trait AmbassadorsReadApi<Account> {
+    /// Whether the account is registered as an ambassador allowed to refer orders.
+    fn is_ambassador(who: &Account) -> bool;
+
+    /// Share of the executor fee accrued to the referring ambassador on a successful order.
+    fn ambassador_share() -> Percent;
+}
+
+pub struct AmbassadorsEmptyMock<Account> {
+    _phantom: PhantomData<Account>,
+}
+
+impl<Account> AmbassadorsReadApi<Account> for AmbassadorsEmptyMock<Account> {
+    fn is_ambassador(_who: &Account) -> bool {
+        false
+    }
+
+    fn ambassador_share() -> Percent {
+        Percent::from_percent(0)
+    }
 }
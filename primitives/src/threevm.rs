@@ -182,29 +182,32 @@ where
 }
 
 pub struct Remunerated<Hash> {
-    pub remuneration_id: Option<Hash>,
+    /// One ledger entry per author or co-author receiving a share of the fee
+    pub remuneration_ids: Vec<Hash>,
 }
 
 impl<Hash> Default for Remunerated<Hash> {
     fn default() -> Self {
         Remunerated {
-            remuneration_id: None,
+            remuneration_ids: Vec::new(),
         }
     }
 }
 
 impl<Hash> Remunerated<Hash> {
-    pub fn new(id: Option<Hash>) -> Self {
+    pub fn new(ids: Vec<Hash>) -> Self {
         Remunerated {
-            remuneration_id: id,
+            remuneration_ids: ids,
         }
     }
 }
 
 pub trait Remuneration<T: ConfigSystem, Balance> {
-    /// Try to remunerate the fees from the given module
+    /// Try to remunerate the fees from the given module, priced by the author's pricing model
+    /// for the payee and the value transferred with the call
     fn try_remunerate<Module: ModuleOperations<T, Balance>>(
         payee: &T::AccountId,
+        value: Balance,
         module: &Module,
     ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError>;
 
@@ -215,6 +218,14 @@ pub trait Remuneration<T: ConfigSystem, Balance> {
         module: &Module,
     ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError>;
 
+    /// Try to remunerate the author persisted for the called contract, priced by the author's
+    /// pricing model for the payee and the value transferred with the call
+    fn try_remunerate_call(
+        payee: &T::AccountId,
+        contract: &T::AccountId,
+        value: Balance,
+    ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError>;
+
     /// Try to finalize a ledger item with an reason
     fn try_finalize(ledger_id: T::Hash, outcome: Outcome) -> DispatchResult;
 }
@@ -290,11 +301,10 @@ where
 impl<T: ConfigSystem, Balance: Encode + Decode> Remuneration<T, Balance> for NoopThreeVm {
     fn try_remunerate<Module: ModuleOperations<T, Balance>>(
         _payee: &T::AccountId,
+        _value: Balance,
         _module: &Module,
     ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
-        Ok(Remunerated {
-            remuneration_id: None,
-        })
+        Ok(Remunerated::default())
     }
 
     fn try_remunerate_exact<Module: ModuleOperations<T, Balance>>(
@@ -302,9 +312,15 @@ impl<T: ConfigSystem, Balance: Encode + Decode> Remuneration<T, Balance> for Noo
         _amount: Balance,
         _module: &Module,
     ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
-        Ok(Remunerated {
-            remuneration_id: None,
-        })
+        Ok(Remunerated::default())
+    }
+
+    fn try_remunerate_call(
+        _payee: &T::AccountId,
+        _contract: &T::AccountId,
+        _value: Balance,
+    ) -> Result<Remunerated<T::Hash>, sp_runtime::DispatchError> {
+        Ok(Remunerated::default())
     }
 
     fn try_finalize(_ledger_id: T::Hash, _outcome: Outcome) -> DispatchResult {
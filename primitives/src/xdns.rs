@@ -1,13 +1,19 @@
 use crate::{
-    gateway::GatewayABIConfig, light_client::LightClientHeartbeat, ChainId, ExecutionVendor,
-    GatewayActivity, GatewayGenesisConfig, GatewayType, GatewayVendor, SpeedMode, TokenInfo,
+    gateway::{CryptoAlgo, GatewayABIConfig, HasherAlgo},
+    light_client::LightClientHeartbeat,
+    ChainId, ExecutionVendor, GatewayActivity, GatewayGenesisConfig, GatewayType, GatewayVendor,
+    SpeedMode, TokenInfo,
 };
 use codec::{Decode, Encode};
-use frame_support::dispatch::{DispatchResult, DispatchResultWithPostInfo};
+use frame_support::{
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    traits::ConstU32,
+    BoundedVec,
+};
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use scale_info::TypeInfo;
 use sp_core::{H160, H256};
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
 use sp_std::vec::Vec;
 use t3rn_abi::sfx_abi::SFXAbi;
 use t3rn_types::sfx::{SecurityLvl, Sfx4bId};
@@ -71,6 +77,116 @@ pub struct EpochEstimate<BlockNumber> {
     pub moving_average_remote: BlockNumber,
 }
 
+/// Score needed by an inactive verifier to be considered healthy again.
+pub const HEALTH_SCORE_TO_ACTIVATE: u8 = 70;
+/// Score below which an active verifier is no longer considered healthy.
+pub const HEALTH_SCORE_TO_DEACTIVATE: u8 = 40;
+/// Expected epoch length in local blocks, assumed before any epoch was observed.
+pub const DEFAULT_EXPECTED_EPOCH_LENGTH: u32 = 50;
+
+const MAX_LATENCY_PENALTY: u32 = 30;
+const MAX_STALL_PENALTY: u32 = 100;
+const MAX_VARIANCE_PENALTY: u32 = 20;
+
+/// Health of the finality verifier of a single gateway.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayHealthRecord<BlockNumber> {
+    /// From 0 (unusable) to 100 (finalizing on schedule)
+    pub score: u8,
+
+    /// Local block at which the finalized height last moved
+    pub last_moved_at: Option<BlockNumber>,
+
+    /// Finalized height of the gateway at the last update
+    pub last_finalized_height: BlockNumber,
+
+    /// Score after hysteresis, so that verifiers don't flap between active and inactive
+    pub is_healthy: bool,
+
+    pub updated_at: BlockNumber,
+}
+
+impl<BlockNumber: Clone + UniqueSaturatedInto<u32>> GatewayHealthRecord<BlockNumber> {
+    /// Scores the verifier out of its epoch history, penalising:
+    ///  - latency of the last epoch against the moving average of epochs,
+    ///  - stalls of the finalized height longer than 2 expected epochs,
+    ///  - variance of the epoch durations.
+    pub fn score(stalled_for: BlockNumber, epoch_history: &[EpochEstimate<BlockNumber>]) -> u8 {
+        let locals: Vec<u32> = epoch_history
+            .iter()
+            .map(|epoch| epoch.local.clone().unique_saturated_into())
+            .collect();
+
+        let (last_local, moving_average_local) = epoch_history
+            .last()
+            .map(|epoch| {
+                (
+                    epoch.local.clone().unique_saturated_into(),
+                    epoch.moving_average_local.clone().unique_saturated_into(),
+                )
+            })
+            .unwrap_or((0u32, 0u32));
+
+        let expected_epoch_length = if moving_average_local > 0 {
+            moving_average_local
+        } else if last_local > 0 {
+            last_local
+        } else {
+            DEFAULT_EXPECTED_EPOCH_LENGTH
+        };
+
+        let latency_penalty = if moving_average_local > 0 && last_local > moving_average_local {
+            MAX_LATENCY_PENALTY.min(
+                MAX_LATENCY_PENALTY.saturating_mul(last_local - moving_average_local)
+                    / moving_average_local,
+            )
+        } else {
+            0
+        };
+
+        let stalled_for: u32 = stalled_for.unique_saturated_into();
+        let stall_tolerance = expected_epoch_length.saturating_mul(2);
+        let stall_penalty = if stalled_for > stall_tolerance {
+            MAX_STALL_PENALTY.min(
+                MAX_STALL_PENALTY.saturating_mul(stalled_for - stall_tolerance)
+                    / expected_epoch_length.saturating_mul(4),
+            )
+        } else {
+            0
+        };
+
+        // Mean absolute deviation relative to the mean, avoiding square roots on-chain
+        let variance_penalty = match locals.len() {
+            0 | 1 => 0,
+            len => {
+                let mean = locals.iter().sum::<u32>() / len as u32;
+                if mean == 0 {
+                    0
+                } else {
+                    let deviation =
+                        locals.iter().map(|local| local.abs_diff(mean)).sum::<u32>() / len as u32;
+                    MAX_VARIANCE_PENALTY.min(MAX_VARIANCE_PENALTY.saturating_mul(deviation) / mean)
+                }
+            },
+        };
+
+        100u32
+            .saturating_sub(latency_penalty)
+            .saturating_sub(stall_penalty)
+            .saturating_sub(variance_penalty) as u8
+    }
+
+    /// Applies hysteresis to the score: verifiers must score higher to be activated than to stay active.
+    pub fn is_healthy_after(was_healthy: bool, score: u8) -> bool {
+        if was_healthy {
+            score >= HEALTH_SCORE_TO_DEACTIVATE
+        } else {
+            score >= HEALTH_SCORE_TO_ACTIVATE
+        }
+    }
+}
+
 /// A preliminary representation of a xdns_record in the onchain registry.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -85,6 +201,76 @@ pub struct TokenRecord {
     pub token_props: TokenInfo,
 }
 
+/// Converts the amount between the decimals of two representations of the same asset.
+/// Scaling down rounds towards zero; scaling up returns None on overflow.
+pub fn convert_decimals(amount: u128, from_decimals: u8, to_decimals: u8) -> Option<u128> {
+    if from_decimals >= to_decimals {
+        Some(
+            10u128
+                .checked_pow((from_decimals - to_decimals) as u32)
+                .map(|scale| amount / scale)
+                .unwrap_or(0),
+        )
+    } else {
+        10u128
+            .checked_pow((to_decimals - from_decimals) as u32)
+            .and_then(|scale| amount.checked_mul(scale))
+    }
+}
+
+/// Same asset represented on many gateways, e.g. USDC on Ethereum, Sepolia and a parachain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanonicalAssetGroup {
+    /// AssetId the asset is accounted as on the Circuit
+    pub asset_id: AssetId,
+
+    pub symbol: Vec<u8>,
+
+    /// Decimals all of the amounts are normalised to on the Circuit
+    pub decimals: u8,
+
+    /// Token properties - decimals, symbol, address - of the asset on each gateway
+    pub members: Vec<(TargetId, TokenInfo)>,
+}
+
+impl CanonicalAssetGroup {
+    pub fn member(&self, gateway_id: &TargetId) -> Option<&TokenInfo> {
+        self.members
+            .iter()
+            .find(|(member_id, _)| member_id == gateway_id)
+            .map(|(_, token_props)| token_props)
+    }
+
+    /// Gateways the asset has no member on are assumed to use the canonical decimals.
+    pub fn decimals_on(&self, gateway_id: &TargetId) -> u8 {
+        self.member(gateway_id)
+            .map(|token_props| token_props.decimals())
+            .unwrap_or(self.decimals)
+    }
+
+    pub fn to_canonical(&self, gateway_id: &TargetId, amount: u128) -> Option<u128> {
+        convert_decimals(amount, self.decimals_on(gateway_id), self.decimals)
+    }
+
+    pub fn from_canonical(&self, gateway_id: &TargetId, amount: u128) -> Option<u128> {
+        convert_decimals(amount, self.decimals, self.decimals_on(gateway_id))
+    }
+
+    pub fn convert_between(
+        &self,
+        from_gateway_id: &TargetId,
+        to_gateway_id: &TargetId,
+        amount: u128,
+    ) -> Option<u128> {
+        convert_decimals(
+            amount,
+            self.decimals_on(from_gateway_id),
+            self.decimals_on(to_gateway_id),
+        )
+    }
+}
+
 /// A preliminary representation of a xdns_record in the onchain registry.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -111,6 +297,69 @@ pub struct GatewayRecord<AccountId> {
     pub allowed_side_effects: Vec<(Sfx4bId, Option<u8>)>,
 }
 
+/// Snapshot of a gateway record and its SFX ABIs, kept for every version of the gateway so that
+/// xtxs validated against an older version can still be confirmed against it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayRecordVersion<AccountId, BlockNumber> {
+    /// Version number, incremented with each change of the gateway
+    pub version: u32,
+
+    /// Gateway record as of this version; None if the gateway was purged
+    pub record: Option<GatewayRecord<AccountId>>,
+
+    /// SFX ABIs registered for the gateway as of this version
+    pub sfx_abis: Vec<(Sfx4bId, SFXAbi)>,
+
+    /// Block at which the version took effect
+    pub enacted_at: BlockNumber,
+}
+
+/// Maximum number of attesters' votes kept on a gateway pending promotion, bounding the committee voting on it
+pub const MAX_GATEWAY_PROMOTION_VOTES: u32 = 128;
+
+/// Gateway registered without governance, bonded by its registrant until promoted to full activity.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingGatewayRecord<AccountId, Balance> {
+    /// Account which registered the gateway and reserved the bond
+    pub registrant: AccountId,
+
+    /// Bond reserved by the registrant, slashed if the registration is malicious or misconfigured
+    pub bond: Balance,
+
+    /// Maximum reward in native of a single order on the gateway while it's pending
+    pub max_order_value: Balance,
+
+    /// Attesters voting to promote the gateway to full activity
+    pub promotion_votes: BoundedVec<AccountId, ConstU32<MAX_GATEWAY_PROMOTION_VOTES>>,
+}
+
+/// Change to a gateway, scheduled to take effect at a future block.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScheduledGatewayChange<AccountId> {
+    OverrideGateway(GatewayRecord<AccountId>),
+    OverrideSfxAbi(Vec<(Sfx4bId, SFXAbi)>),
+    PurgeGateway,
+}
+
+/// Asset prices are quoted as the amount of native base units worth this many base units of the asset.
+pub const ASSET_PRICE_PRECISION: u128 = 1_000_000_000_000;
+
+/// ABI assumed for gateways which were registered without specifying one, derived from the execution layer.
+pub fn default_gateway_abi(execution_vendor: &ExecutionVendor) -> GatewayABIConfig {
+    match execution_vendor {
+        ExecutionVendor::Substrate => GatewayABIConfig::default(),
+        ExecutionVendor::EVM => GatewayABIConfig {
+            hasher: HasherAlgo::Keccak256,
+            crypto: CryptoAlgo::Ecdsa,
+            address_length: 20,
+            ..GatewayABIConfig::default()
+        },
+    }
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct FullGatewayRecord<AccountId> {
@@ -287,6 +536,16 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn get_self_token_id() -> AssetId;
 
+    /// Fresh median price of the asset in native, quoted per `ASSET_PRICE_PRECISION` of the asset.
+    fn get_asset_price_in_native(asset_id: AssetId) -> Option<Balance>;
+
+    /// Converts the amount between assets using fresh price estimates; `None` stands for native.
+    fn convert_asset_amount(
+        from_asset_id: Option<AssetId>,
+        to_asset_id: Option<AssetId>,
+        amount: Balance,
+    ) -> Option<Balance>;
+
     fn add_new_gateway(
         gateway_id: [u8; 4],
         verification_vendor: GatewayVendor,
@@ -297,6 +556,20 @@ pub trait Xdns<T: frame_system::Config, Balance> {
         allowed_side_effects: Vec<([u8; 4], Option<u8>)>,
     ) -> DispatchResult;
 
+    /// Registers the gateway in the pending state, reserving the registration bond of the registrant.
+    fn add_pending_gateway(
+        registrant: T::AccountId,
+        gateway_id: [u8; 4],
+        verification_vendor: GatewayVendor,
+        execution_vendor: ExecutionVendor,
+        codec: t3rn_abi::Codec,
+        escrow_account: Option<T::AccountId>,
+        allowed_side_effects: Vec<([u8; 4], Option<u8>)>,
+    ) -> DispatchResult;
+
+    /// Maximum order value in native on a pending gateway; None for fully active gateways.
+    fn get_pending_gateway_max_order_value(gateway_id: &ChainId) -> Option<Balance>;
+
     fn override_gateway(
         gateway_id: [u8; 4],
         verification_vendor: GatewayVendor,
@@ -321,6 +594,29 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn get_sfx_abi(gateway_id: &ChainId, sfx_4b_id: Sfx4bId) -> Option<SFXAbi>;
 
+    /// Current version of the gateway; 0 if the gateway was never registered.
+    fn get_gateway_version(gateway_id: &ChainId) -> u32;
+
+    /// Keeps the version of the gateway in its history while an xtx is confirmed against it.
+    fn pin_gateway_version(gateway_id: &ChainId, version: u32);
+
+    /// Releases a pin of the gateway version, dropping the version from the history
+    /// once no xtx pins it and it is older than the kept history.
+    fn unpin_gateway_version(gateway_id: &ChainId, version: u32);
+
+    /// SFX ABI as registered at the given version of the gateway.
+    fn get_sfx_abi_at_version(
+        gateway_id: &ChainId,
+        sfx_4b_id: Sfx4bId,
+        version: u32,
+    ) -> Option<SFXAbi>;
+
+    /// Codec of the gateway as registered at the given version.
+    fn get_target_codec_at_version(
+        chain_id: &ChainId,
+        version: u32,
+    ) -> Result<t3rn_abi::Codec, DispatchError>;
+
     fn add_escrow_account(
         origin: OriginFor<T>,
         gateway_id: ChainId,
@@ -335,6 +631,8 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn get_target_codec(chain_id: &ChainId) -> Result<t3rn_abi::Codec, DispatchError>;
 
+    fn get_target_abi(chain_id: &ChainId) -> Result<GatewayABIConfig, DispatchError>;
+
     fn get_escrow_account(chain_id: &ChainId) -> Result<Vec<u8>, DispatchError>;
 
     fn fetch_full_gateway_records() -> Vec<FullGatewayRecord<T::AccountId>>;
@@ -343,6 +641,18 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn read_last_activity(gateway_id: ChainId) -> Option<GatewayActivity<BlockNumberFor<T>>>;
 
+    fn get_gateway_health(gateway_id: &ChainId) -> Option<GatewayHealthRecord<BlockNumberFor<T>>>;
+
+    fn get_canonical_asset_group(asset_id: AssetId) -> Option<CanonicalAssetGroup>;
+
+    /// Normalises the amount of the asset as represented on the gateway to the canonical decimals.
+    /// Amounts of assets without a canonical group are returned as they are.
+    fn normalize_asset_amount_to_canonical(
+        asset_id: AssetId,
+        gateway_id: &TargetId,
+        amount: u128,
+    ) -> Result<u128, DispatchError>;
+
     fn verify_active(
         gateway_id: &ChainId,
         max_acceptable_heartbeat_offset: BlockNumberFor<T>,
@@ -5,6 +5,7 @@ use frame_support::{
     dispatch::DispatchResultWithPostInfo,
     log,
     traits::{fungibles::Destroy, AsEnsureOriginWithArg, FindAuthor},
+    weights::Weight,
     Blake2_128Concat, RuntimeDebug, StorageHasher,
 };
 use frame_system::EnsureSigned;
@@ -50,8 +51,10 @@ use pallet_grandpa_finality_verifier::{
 };
 use pallet_portal::Error as PortalError;
 pub use pallet_rewards::{
-    Authors, AuthorsThisPeriod, Config as ConfigRewards, DistributionBlock, DistributionHistory,
-    Error as RewardsError, PendingClaims,
+    AccumulatedSettlements, AssetType, Authors, AuthorsThisPeriod, BootstrapDistributions,
+    BootstrapExecutorsCursor, BootstrapPoolDistributed, Config as ConfigRewards, DistributionBlock,
+    DistributionHistory, DistributionHistoryCount, EarningsLedger, Error as RewardsError,
+    PendingClaims,
 };
 
 use frame_support::parameter_types;
@@ -220,17 +223,44 @@ impl pallet_account_manager::Config for MiniRuntime {
     type Assets = Assets;
     type Clock = Clock;
     type Currency = Balances;
+    type DisputeOrigin = frame_system::EnsureRoot<Self::AccountId>;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type ReconciliationReportsToKeep = ConstU32<64>;
     type RuntimeEvent = RuntimeEvent;
+    type StuckChargeRounds = ConstU32<10>;
     type Time = Timestamp;
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub RoundHandlers: Vec<RoundHandler<MiniRuntime>> = vec![
+        RoundHandler::new::<XDNS>(
+            pallet_xdns::VERIFIER_OVERVIEW_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Rewards>(
+            pallet_rewards::SETTLEMENTS_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Attesters>(
+            pallet_attesters::SHUFFLING_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<AccountManager>(
+            pallet_account_manager::RECONCILIATION_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+    ];
+    pub const RoundHandlersWeightLimit: Weight = Weight::from_parts(50_000_000_000, 0);
+}
+
 impl pallet_clock::Config for MiniRuntime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
     type RoundDuration = ConstU32<300>;
+    type RoundHandlers = RoundHandlers;
+    type RoundHandlersWeightLimit = RoundHandlersWeightLimit;
     type RuntimeEvent = RuntimeEvent;
 }
 use circuit_runtime_types::UNIT as TRN;
@@ -248,6 +278,12 @@ parameter_types! {
     pub const OneYear: BlockNumber = 2_628_000; // (365.25 * 24 * 60 * 60) / 12; assuming 12s block time
     pub const InflationDistributionPeriod: BlockNumber = 100_800; // (14 * 24 * 60 * 60) / 12; assuming one distribution per two weeks
     pub const AvailableBootstrapSpenditure: Balance = 1_000_000 * (TRN as Balance); // 1 MLN UNIT
+    pub const InflationVestingPeriod: BlockNumber = 0; // inflation rewards are paid out immediately in tests
+    pub const MaxVestingSchedules: u32 = 8;
+    pub const TotalBootstrapDistributions: u32 = 26; // one year assuming one distribution per two weeks
+    pub const MaxBootstrapExecutorPayouts: u32 = 2;
+    pub const DistributionHistoryDepth: u32 = 512;
+    pub const EarningsLedgerDepth: u32 = 512;
 }
 
 pub struct FindAuthorMockRoundRobinRotate32;
@@ -278,16 +314,23 @@ impl pallet_rewards::Config for MiniRuntime {
     type CollatorBootstrapRewards = CollatorBootstrapRewards;
     type CollatorInflation = CollatorInflation;
     type Currency = Balances;
+    type DistributionHistoryDepth = DistributionHistoryDepth;
+    type EarningsLedgerDepth = EarningsLedgerDepth;
     type ExecutorBootstrapRewards = ExecutorBootstrapRewards;
     type ExecutorInflation = ExecutorInflation;
     type FindAuthor = FindAuthorMockRoundRobinRotate32;
     type InflationDistributionPeriod = InflationDistributionPeriod;
+    type InflationVestingPeriod = InflationVestingPeriod;
+    type MaxBootstrapExecutorPayouts = MaxBootstrapExecutorPayouts;
+    type MaxVestingSchedules = MaxVestingSchedules;
     type OneYear = OneYear;
     type RuntimeEvent = RuntimeEvent;
     type StartingRepatriationPercentage = StartingRepatriationPercentage;
+    type TotalBootstrapDistributions = TotalBootstrapDistributions;
     type TotalInflation = TotalInflation;
     type TreasuryAccounts = MiniRuntime;
     type TreasuryInflation = TreasuryInflation;
+    type WeightInfo = pallet_rewards::weights::SubstrateWeight<MiniRuntime>;
 }
 
 parameter_types! {
@@ -445,13 +488,25 @@ impl pallet_timestamp::Config for MiniRuntime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const GatewayRegistrationBond: Balance = 10_000;
+    pub const PendingGatewayMaxOrderValue: Balance = 1_000;
+}
+
 impl pallet_xdns::Config for MiniRuntime {
     type AssetsOverlay = MiniRuntime;
     type AttestersRead = Attesters;
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type GatewayRegistrationBond = GatewayRegistrationBond;
+    type MaxGatewayHistory = ConstU32<4>;
+    type MaxPriceFeeders = ConstU32<8>;
+    type MaxScheduledGatewayChangesPerBlock = ConstU32<4>;
+    type MinPriceFeeds = ConstU32<1>;
+    type PendingGatewayMaxOrderValue = PendingGatewayMaxOrderValue;
     type Portal = Portal;
+    type PriceFeedMaxAge = ConstU32<600>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = SelfGatewayId;
     type SelfTokenId = ConstU32<3333>;
@@ -468,6 +523,7 @@ parameter_types! {
 
 impl pallet_circuit::Config for MiniRuntime {
     type AccountManager = AccountManager;
+    type Ambassadors = Rewards;
     type Attesters = Attesters;
     type Balances = Balances;
     type Currency = Balances;
@@ -500,8 +556,12 @@ impl pallet_circuit_vacuum::Config for MiniRuntime {
 
 impl pallet_portal::Config for MiniRuntime {
     type Currency = Balances;
+    type MaxRecentSubmissions = ConstU32<16>;
+    type MaxRelayersPerGateway = ConstU32<128>;
+    type Rewards = Rewards;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type TreasuryAccounts = MiniRuntime;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<MiniRuntime>;
     type Xdns = XDNS;
 }
@@ -1092,6 +1152,12 @@ impl ExtBuilder {
         pallet_xdns::GenesisConfig::<MiniRuntime> {
             known_gateway_records: self.known_gateway_records.encode(),
             standard_sfx_abi: self.standard_sfx_abi.encode(),
+            known_tokens: vec![],
+            remote_order_addresses: vec![],
+            remote_bidding_addresses: vec![],
+            gateway_sfx_abis: vec![],
+            gateway_abis: vec![],
+            gateway_versions: vec![],
             _marker: Default::default(),
         }
         .assimilate_storage(&mut t)
@@ -1113,7 +1179,7 @@ use pallet_eth2_finality_verifier::{
     mock::{generate_epoch_update, generate_initialization},
     LightClientAsyncAPI,
 };
-use t3rn_primitives::threevm::AddressMapping;
+use t3rn_primitives::{clock::RoundHandler, threevm::AddressMapping};
 
 pub fn make_all_light_clients_move_2_times_by(move_by: u32) {
     use t3rn_primitives::portal::Portal as PortalT;
@@ -25,7 +25,9 @@ use sp_runtime::{
     Perbill,
 };
 
-use t3rn_primitives::light_client::LightClient;
+use frame_support::weights::Weight;
+use sp_std::{vec, vec::Vec};
+use t3rn_primitives::{clock::RoundHandler, light_client::LightClient};
 
 impl t3rn_primitives::EscrowTrait<Runtime> for Runtime {
     type Currency = Balances;
@@ -34,20 +36,56 @@ impl t3rn_primitives::EscrowTrait<Runtime> for Runtime {
 
 pub use hooks::GlobalOnInitQueues;
 
+parameter_types! {
+    pub RoundHandlers: Vec<RoundHandler<Runtime>> = vec![
+        RoundHandler::new::<XDNS>(
+            pallet_xdns::VERIFIER_OVERVIEW_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Rewards>(
+            pallet_rewards::SETTLEMENTS_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Attesters>(
+            pallet_attesters::SHUFFLING_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<AccountManager>(
+            pallet_account_manager::RECONCILIATION_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+    ];
+    pub const RoundHandlersWeightLimit: Weight = Weight::from_parts(50_000_000_000, 0);
+}
+
 impl pallet_clock::Config for Runtime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
     type RoundDuration = ConstU32<300>;
+    type RoundHandlers = RoundHandlers;
+    type RoundHandlersWeightLimit = RoundHandlersWeightLimit;
     type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+    pub const GatewayRegistrationBond: Balance = 10_000 * (TRN as Balance);
+    pub const PendingGatewayMaxOrderValue: Balance = 1_000 * (TRN as Balance);
+}
+
 impl pallet_xdns::Config for Runtime {
     type AssetsOverlay = Runtime;
     type AttestersRead = Attesters;
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type GatewayRegistrationBond = GatewayRegistrationBond;
+    type MaxGatewayHistory = ConstU32<4>;
+    type MaxPriceFeeders = ConstU32<8>;
+    type MaxScheduledGatewayChangesPerBlock = ConstU32<4>;
+    type MinPriceFeeds = ConstU32<1>;
+    type PendingGatewayMaxOrderValue = PendingGatewayMaxOrderValue;
     type Portal = Portal;
+    type PriceFeedMaxAge = ConstU32<600>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = SelfGatewayId;
     type SelfTokenId = ConstU32<3333>;
@@ -114,6 +152,12 @@ parameter_types! {
     pub const OneYear: BlockNumber = 2_628_000; // (365.25 * 24 * 60 * 60) / 12; assuming 12s block time
     pub const InflationDistributionPeriod: BlockNumber = 100_800; // (14 * 24 * 60 * 60) / 12; assuming one distribution per two weeks
     pub const AvailableBootstrapSpenditure: Balance = 1_000_000 * (TRN as Balance); // 1 MLN UNIT
+    pub const InflationVestingPeriod: BlockNumber = 0; // inflation rewards are paid out immediately in tests
+    pub const MaxVestingSchedules: u32 = 8;
+    pub const TotalBootstrapDistributions: u32 = 26; // one year assuming one distribution per two weeks
+    pub const MaxBootstrapExecutorPayouts: u32 = 512;
+    pub const DistributionHistoryDepth: u32 = 512;
+    pub const EarningsLedgerDepth: u32 = 512;
 }
 
 impl pallet_rewards::Config for Runtime {
@@ -126,16 +170,23 @@ impl pallet_rewards::Config for Runtime {
     type CollatorBootstrapRewards = CollatorBootstrapRewards;
     type CollatorInflation = CollatorInflation;
     type Currency = Balances;
+    type DistributionHistoryDepth = DistributionHistoryDepth;
+    type EarningsLedgerDepth = EarningsLedgerDepth;
     type ExecutorBootstrapRewards = ExecutorBootstrapRewards;
     type ExecutorInflation = ExecutorInflation;
     type FindAuthor = ();
     type InflationDistributionPeriod = InflationDistributionPeriod;
+    type InflationVestingPeriod = InflationVestingPeriod;
+    type MaxBootstrapExecutorPayouts = MaxBootstrapExecutorPayouts;
+    type MaxVestingSchedules = MaxVestingSchedules;
     type OneYear = OneYear;
     type RuntimeEvent = RuntimeEvent;
     type StartingRepatriationPercentage = StartingRepatriationPercentage;
+    type TotalBootstrapDistributions = TotalBootstrapDistributions;
     type TotalInflation = TotalInflation;
     type TreasuryAccounts = Runtime;
     type TreasuryInflation = TreasuryInflation;
+    type WeightInfo = pallet_rewards::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_contracts_registry::Config for Runtime {
@@ -170,6 +221,10 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
             GatewayVendor::Sepolia => Ok(Box::new(pallet_sepolia_finality_verifier::Pallet::<
                 Runtime,
             >(PhantomData))),
+            GatewayVendor::Standalone =>
+                Ok(Box::new(pallet_standalone_finality_verifier::Pallet::<
+                    Runtime,
+                >(PhantomData))),
             _ => Err(PortalError::<Runtime>::LightClientNotFoundByVendor),
         }
     }
@@ -177,8 +232,12 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
 
 impl pallet_portal::Config for Runtime {
     type Currency = Balances;
+    type MaxRecentSubmissions = ConstU32<16>;
+    type MaxRelayersPerGateway = ConstU32<128>;
+    type Rewards = Rewards;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type TreasuryAccounts = Runtime;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
@@ -273,6 +332,7 @@ impl PalletAssetsOverlay<Runtime, Balance> for Runtime {
 
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
+    type Ambassadors = Rewards;
     type Attesters = Attesters;
     type Balances = Balances;
     type Currency = Balances;
@@ -297,6 +357,7 @@ parameter_types! {
     pub const RococoVendor: GatewayVendor = GatewayVendor::Rococo;
     pub const KusamaVendor: GatewayVendor = GatewayVendor::Kusama;
     pub const PolkadotVendor: GatewayVendor = GatewayVendor::Polkadot;
+    pub const StandaloneVendor: GatewayVendor = GatewayVendor::Standalone;
 }
 
 #[derive(Debug)]
@@ -376,3 +437,11 @@ impl pallet_sepolia_finality_verifier::Config for Runtime {
     type SyncCommitteeSize = SyncCommitteeSize;
     type WeightInfo = pallet_sepolia_finality_verifier::weights::SubstrateWeight<Runtime>;
 }
+
+impl pallet_standalone_finality_verifier::Config for Runtime {
+    type HeadersToStore = HeadersToStore;
+    type LightClientAsyncAPI = XDNS;
+    type MyVendor = StandaloneVendor;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_standalone_finality_verifier::weights::SubstrateWeight<Runtime>;
+}
@@ -144,6 +144,8 @@ parameter_types! {
          (sp_core::H160([6u8; 20]), evm_precompile_util::precompile_mock::KnownPrecompile::Sha3FIPS512),
          (sp_core::H160([7u8; 20]), evm_precompile_util::precompile_mock::KnownPrecompile::ECRecoverPublicKey),
          (sp_core::H160([8u8; 20]), evm_precompile_util::precompile_mock::KnownPrecompile::Portal),
+         (sp_core::H160([10u8; 20]), evm_precompile_util::precompile_mock::KnownPrecompile::Vacuum),
+         (sp_core::H160([11u8; 20]), evm_precompile_util::precompile_mock::KnownPrecompile::ThreeVmDispatch),
          // TRN address
          (get_tokens_precompile_address(0), evm_precompile_util::precompile_mock::KnownPrecompile::Tokens),
          // TST address
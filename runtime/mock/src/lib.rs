@@ -112,6 +112,7 @@ frame_support::construct_runtime!(
         KusamaBridge: pallet_grandpa_finality_verifier::<Instance2> = 131,
         EthereumBridge: pallet_eth2_finality_verifier = 132,
         SepoliaBridge: pallet_sepolia_finality_verifier = 133,
+        StandaloneBridge: pallet_standalone_finality_verifier = 134,
     }
 );
 use frame_support::weights::WeightToFeeCoefficient;
@@ -347,6 +348,12 @@ impl ExtBuilder {
         pallet_xdns::GenesisConfig::<Runtime> {
             known_gateway_records: self.known_gateway_records.encode(),
             standard_sfx_abi: self.standard_sfx_abi.encode(),
+            known_tokens: vec![],
+            remote_order_addresses: vec![],
+            remote_bidding_addresses: vec![],
+            gateway_sfx_abis: vec![],
+            gateway_abis: vec![],
+            gateway_versions: vec![],
             _marker: Default::default(),
         }
         .assimilate_storage(&mut t)
@@ -17,11 +17,14 @@ impl pallet_account_manager::Config for Runtime {
     type Assets = Assets;
     type Clock = Clock;
     type Currency = Balances;
+    type DisputeOrigin = frame_system::EnsureRoot<Self::AccountId>;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type ReconciliationReportsToKeep = ConstU32<64>;
     type RuntimeEvent = RuntimeEvent;
+    type StuckChargeRounds = ConstU32<10>;
     type Time = Timestamp;
-    type WeightInfo = ();
+    type WeightInfo = pallet_account_manager::weights::SubstrateWeight<Runtime>;
 }
 
 pallet_account_manager::setup_currency_adapter!();
@@ -21,12 +21,14 @@ use frame_support::{
 };
 use frame_system::{pallet_prelude::OriginFor, Config};
 
+use frame_support::weights::Weight;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, Convert},
     Perbill,
 };
-use t3rn_primitives::GatewayVendor;
+use sp_std::{vec, vec::Vec};
+use t3rn_primitives::{clock::RoundHandler, GatewayVendor};
 
 pub type RococoLightClient = ();
 pub type PolkadotLightClient = pallet_grandpa_finality_verifier::Instance1;
@@ -88,6 +90,12 @@ parameter_types! {
     pub const InflationDistributionPeriod: BlockNumber = 100_800; // (14 * 24 * 60 * 60) / 12; assuming one distribution per two weeks
     pub const HourlyInflationDistributionPeriod: BlockNumber = 60 * 60 / 12; // (60 * 60) / 12; assuming 12s block time
     pub const AvailableBootstrapSpenditure: Balance = 1_000_000 * (TRN as Balance); // 1 MLN UNIT
+    pub const InflationVestingPeriod: BlockNumber = 1_314_000; // (182.625 * 24 * 60 * 60) / 12; six months assuming 12s block time
+    pub const MaxVestingSchedules: u32 = 64;
+    pub const TotalBootstrapDistributions: u32 = 26; // one year assuming one distribution per two weeks
+    pub const MaxBootstrapExecutorPayouts: u32 = 512;
+    pub const DistributionHistoryDepth: u32 = 512;
+    pub const EarningsLedgerDepth: u32 = 512;
 }
 
 impl pallet_rewards::Config for Runtime {
@@ -100,22 +108,53 @@ impl pallet_rewards::Config for Runtime {
     type CollatorBootstrapRewards = CollatorBootstrapRewards;
     type CollatorInflation = CollatorInflation;
     type Currency = Balances;
+    type DistributionHistoryDepth = DistributionHistoryDepth;
+    type EarningsLedgerDepth = EarningsLedgerDepth;
     type ExecutorBootstrapRewards = ExecutorBootstrapRewards;
     type ExecutorInflation = ExecutorInflation;
     type FindAuthor = ();
     type InflationDistributionPeriod = InflationDistributionPeriod;
+    type InflationVestingPeriod = InflationVestingPeriod;
+    type MaxBootstrapExecutorPayouts = MaxBootstrapExecutorPayouts;
+    type MaxVestingSchedules = MaxVestingSchedules;
     type OneYear = OneYear;
     type RuntimeEvent = RuntimeEvent;
     type StartingRepatriationPercentage = StartingRepatriationPercentage;
+    type TotalBootstrapDistributions = TotalBootstrapDistributions;
     type TotalInflation = TotalInflation;
     type TreasuryAccounts = Runtime;
     type TreasuryInflation = TreasuryInflation;
+    type WeightInfo = pallet_rewards::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub RoundHandlers: Vec<RoundHandler<Runtime>> = vec![
+        RoundHandler::new::<XDNS>(
+            pallet_xdns::VERIFIER_OVERVIEW_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Rewards>(
+            pallet_rewards::SETTLEMENTS_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Attesters>(
+            pallet_attesters::SHUFFLING_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<AccountManager>(
+            pallet_account_manager::RECONCILIATION_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+    ];
+    pub const RoundHandlersWeightLimit: Weight = Weight::from_parts(50_000_000_000, 0);
 }
 
 impl pallet_clock::Config for Runtime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
     type RoundDuration = ConstU32<300>;
+    type RoundHandlers = RoundHandlers;
+    type RoundHandlersWeightLimit = RoundHandlersWeightLimit;
     type RuntimeEvent = RuntimeEvent;
 }
 
@@ -130,13 +169,25 @@ impl pallet_vacuum::Config for Runtime {
     type Xdns = XDNS;
 }
 
+parameter_types! {
+    pub const GatewayRegistrationBond: Balance = 10_000 * (TRN as Balance);
+    pub const PendingGatewayMaxOrderValue: Balance = 1_000 * (TRN as Balance);
+}
+
 impl pallet_xdns::Config for Runtime {
     type AssetsOverlay = Runtime;
     type AttestersRead = Attesters;
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type GatewayRegistrationBond = GatewayRegistrationBond;
+    type MaxGatewayHistory = ConstU32<64>;
+    type MaxPriceFeeders = ConstU32<32>;
+    type MaxScheduledGatewayChangesPerBlock = ConstU32<32>;
+    type MinPriceFeeds = ConstU32<1>;
+    type PendingGatewayMaxOrderValue = PendingGatewayMaxOrderValue;
     type Portal = Portal;
+    type PriceFeedMaxAge = ConstU32<600>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = SelfGatewayId;
     type SelfTokenId = ConstU32<3333>;
@@ -250,6 +301,10 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
             GatewayVendor::Sepolia => Ok(Box::new(pallet_sepolia_finality_verifier::Pallet::<
                 Runtime,
             >(PhantomData))),
+            GatewayVendor::Standalone =>
+                Ok(Box::new(pallet_standalone_finality_verifier::Pallet::<
+                    Runtime,
+                >(PhantomData))),
             _ => Err(PortalError::<Runtime>::LightClientNotFoundByVendor),
         }
     }
@@ -257,8 +312,12 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
 
 impl pallet_portal::Config for Runtime {
     type Currency = Balances;
+    type MaxRecentSubmissions = ConstU32<16>;
+    type MaxRelayersPerGateway = ConstU32<128>;
+    type Rewards = Rewards;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type TreasuryAccounts = Runtime;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
@@ -281,6 +340,7 @@ parameter_types! {
 
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
+    type Ambassadors = Rewards;
     type Attesters = Attesters;
     type Balances = Balances;
     type Currency = Balances;
@@ -307,6 +367,7 @@ parameter_types! {
     pub const RococoVendor: GatewayVendor = GatewayVendor::Rococo;
     pub const KusamaVendor: GatewayVendor = GatewayVendor::Kusama;
     pub const PolkadotVendor: GatewayVendor = GatewayVendor::Polkadot;
+    pub const StandaloneVendor: GatewayVendor = GatewayVendor::Standalone;
 }
 
 #[derive(Debug)]
@@ -386,3 +447,11 @@ impl pallet_sepolia_finality_verifier::Config for Runtime {
     type SyncCommitteeSize = SyncCommitteeSize;
     type WeightInfo = pallet_sepolia_finality_verifier::weights::SubstrateWeight<Runtime>;
 }
+
+impl pallet_standalone_finality_verifier::Config for Runtime {
+    type HeadersToStore = HeadersToStore;
+    type LightClientAsyncAPI = XDNS;
+    type MyVendor = StandaloneVendor;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_standalone_finality_verifier::weights::SubstrateWeight<Runtime>;
+}
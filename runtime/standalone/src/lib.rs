@@ -139,6 +139,7 @@ construct_runtime!(
         KusamaBridge: pallet_grandpa_finality_verifier::<Instance2> = 131,
         EthereumBridge: pallet_eth2_finality_verifier = 132,
         SepoliaBridge: pallet_sepolia_finality_verifier = 133,
+        StandaloneBridge: pallet_standalone_finality_verifier = 135,
 
         Identity: pallet_identity = 122,
         RandomnessCollectiveFlip: pallet_randomness_collective_flip = 200,
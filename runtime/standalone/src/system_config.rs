@@ -138,7 +138,10 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
 impl pallet_asset_tx_payment::Config for Runtime {
     type Fungibles = Assets;
     type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
-        pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+        pallet_xdns::XdnsAssetBalance<
+            Runtime,
+            pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+        >,
         CreditToBlockAuthor,
     >;
     type RuntimeEvent = RuntimeEvent;
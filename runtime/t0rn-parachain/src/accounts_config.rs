@@ -1,20 +1,46 @@
 use crate::{
-    hooks::GlobalOnInitQueues, treasuries_config::EscrowTreasuryId, AssetId, Assets, Balance,
-    Balances, Clock, Imbalance, OnUnbalanced, Runtime, RuntimeCall, RuntimeEvent, ThreeVm,
-    Timestamp,
+    hooks::GlobalOnInitQueues, treasuries_config::EscrowTreasuryId, AccountManager, AssetId,
+    Assets, Attesters, Balance, Balances, Clock, Imbalance, OnUnbalanced, Rewards, Runtime,
+    RuntimeCall, RuntimeEvent, ThreeVm, Timestamp, XDNS,
 };
-use frame_support::parameter_types;
+use frame_support::{parameter_types, weights::Weight};
 use sp_core::{crypto::AccountId32, ConstU32};
 use sp_runtime::traits::{AccountIdConversion, ConvertInto};
+use sp_std::{vec, vec::Vec};
+use t3rn_primitives::clock::RoundHandler;
 
 parameter_types! {
     pub EscrowAccount: AccountId32 = EscrowTreasuryId::get().into_account_truncating();
 }
 
+parameter_types! {
+    pub RoundHandlers: Vec<RoundHandler<Runtime>> = vec![
+        RoundHandler::new::<XDNS>(
+            pallet_xdns::VERIFIER_OVERVIEW_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Rewards>(
+            pallet_rewards::SETTLEMENTS_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<Attesters>(
+            pallet_attesters::SHUFFLING_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+        RoundHandler::new::<AccountManager>(
+            pallet_account_manager::RECONCILIATION_ROUND_HANDLER,
+            Weight::from_parts(10_000_000_000, 0),
+        ),
+    ];
+    pub const RoundHandlersWeightLimit: Weight = Weight::from_parts(50_000_000_000, 0);
+}
+
 impl pallet_clock::Config for Runtime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
     type RoundDuration = ConstU32<300u32>;
+    type RoundHandlers = RoundHandlers;
+    type RoundHandlersWeightLimit = RoundHandlersWeightLimit;
     type RuntimeEvent = RuntimeEvent;
 }
 
@@ -24,11 +50,14 @@ impl pallet_account_manager::Config for Runtime {
     type Assets = Assets;
     type Clock = Clock;
     type Currency = Balances;
+    type DisputeOrigin = frame_system::EnsureRoot<Self::AccountId>;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type ReconciliationReportsToKeep = ConstU32<64>;
     type RuntimeEvent = RuntimeEvent;
+    type StuckChargeRounds = ConstU32<10>;
     type Time = Timestamp;
-    type WeightInfo = ();
+    type WeightInfo = pallet_account_manager::weights::SubstrateWeight<Runtime>;
 }
 
 pallet_account_manager::setup_currency_adapter!();
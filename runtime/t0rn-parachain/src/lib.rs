@@ -144,7 +144,10 @@ impl pallet_identity::Config for Runtime {
 impl pallet_asset_tx_payment::Config for Runtime {
     type Fungibles = Assets;
     type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
-        pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+        pallet_xdns::XdnsAssetBalance<
+            Runtime,
+            pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+        >,
         CreditToBlockAuthor,
     >;
     type RuntimeEvent = RuntimeEvent;
@@ -264,6 +267,7 @@ construct_runtime!(
         EthereumBridge: pallet_eth2_finality_verifier = 132,
         SepoliaBridge: pallet_sepolia_finality_verifier = 133,
         CelestiaLightClient: pallet_celestia_light_client = 134,
+        StandaloneBridge: pallet_standalone_finality_verifier = 135,
 
         // Handy utilities
         Maintenance: pallet_maintenance_mode = 140,
@@ -292,6 +296,10 @@ mod benches {
         // [pallet_account_manager, AccountManager]
         [pallet_eth2_finality_verifier, EthereumBridge]
         [pallet_vacuum, Vacuum]
+        [pallet_portal, Portal]
+        [pallet_rewards, Rewards]
+        [pallet_standalone_finality_verifier, StandaloneBridge]
+        [pallet_xdns, XDNS]
     );
 }
 
@@ -491,7 +499,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId> for Runtime {
+    impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId, BlockNumber> for Runtime {
         fn fetch_records() -> Vec<GatewayRecord<AccountId>> {
              <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::fetch_gateways()
         }
@@ -500,14 +508,112 @@ impl_runtime_apis! {
              <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::fetch_full_gateway_records()
         }
 
-        fn fetch_abi(_chain_id: ChainId) -> Option<GatewayABIConfig> {
-            // deprecated
-            None
+        fn fetch_abi(chain_id: ChainId) -> Option<GatewayABIConfig> {
+             <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::get_target_abi(&chain_id).ok()
         }
 
         fn retreive_treasury_address(treasury_account: t3rn_primitives::TreasuryAccount) -> AccountId {
             Runtime::get_treasury_account(treasury_account)
         }
+
+        fn list_available_mint_assets(
+            gateway_id: pallet_xdns_rpc_runtime_api::TargetId,
+        ) -> Vec<pallet_xdns_rpc_runtime_api::TokenRecord> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::list_available_mint_assets(gateway_id)
+        }
+
+        fn fetch_all_sfx_abi(
+            gateway_id: ChainId,
+        ) -> Vec<(pallet_xdns_rpc_runtime_api::Sfx4bId, pallet_xdns_rpc_runtime_api::SFXAbi)> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::get_all_sfx_abi(&gateway_id)
+        }
+
+        fn fetch_remote_order_contract_address(
+            gateway_id: pallet_xdns_rpc_runtime_api::TargetId,
+        ) -> Option<pallet_xdns_rpc_runtime_api::H256> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::get_remote_order_contract_address(gateway_id).ok()
+        }
+
+        fn fetch_remote_bidding_contract_address(
+            gateway_id: pallet_xdns_rpc_runtime_api::TargetId,
+        ) -> Option<pallet_xdns_rpc_runtime_api::H256> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::get_remote_bidding_contract_address(gateway_id).ok()
+        }
+
+        fn read_last_activity_overview() -> Vec<pallet_xdns_rpc_runtime_api::GatewayActivity<BlockNumber>> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::read_last_activity_overview()
+        }
+
+        fn fetch_epoch_history(
+            verifier: pallet_xdns_rpc_runtime_api::GatewayVendor,
+        ) -> Option<Vec<pallet_xdns_rpc_runtime_api::EpochEstimate<BlockNumber>>> {
+            XDNS::epoch_history(verifier)
+        }
+
+        fn estimate_adaptive_timeout_on_slowest_target(
+            target_ids: Vec<pallet_xdns_rpc_runtime_api::TargetId>,
+            speed_mode: pallet_xdns_rpc_runtime_api::SpeedMode,
+            emergency_offset: BlockNumber,
+        ) -> pallet_xdns_rpc_runtime_api::AdaptiveTimeout<BlockNumber, pallet_xdns_rpc_runtime_api::TargetId> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::estimate_adaptive_timeout_on_slowest_target(
+                target_ids,
+                &speed_mode,
+                emergency_offset,
+            )
+        }
+    }
+
+     impl pallet_account_manager_rpc_runtime_api::AccountManagerRuntimeApi<Block, AccountId, Balance, Hash, AssetId, BlockNumber> for Runtime {
+        fn fetch_deposit(
+            charge_id: Hash,
+        ) -> Option<pallet_account_manager_rpc_runtime_api::DepositInfo<AccountId, Balance, AssetId, BlockNumber>> {
+            AccountManager::deposit_info(charge_id)
+        }
+
+        fn fetch_held_deposits(
+        ) -> Vec<(Hash, pallet_account_manager_rpc_runtime_api::DepositInfo<AccountId, Balance, AssetId, BlockNumber>)> {
+            AccountManager::held_deposits()
+        }
+
+        fn fetch_reconciliation_report(
+            round: pallet_account_manager_rpc_runtime_api::RoundIndex,
+        ) -> Option<pallet_account_manager_rpc_runtime_api::ReconciliationReport<AssetId, Balance>> {
+            AccountManager::reconciliation_report(round)
+        }
+    }
+
+     impl pallet_clock_rpc_runtime_api::ClockRuntimeApi<Block, BlockNumber> for Runtime {
+        fn fetch_round_timings() -> pallet_clock_rpc_runtime_api::RoundTimings<BlockNumber> {
+            Clock::round_timings(System::block_number())
+        }
+
+        fn fetch_round_handler_statuses(
+        ) -> Vec<(pallet_clock_rpc_runtime_api::RoundHandlerId, pallet_clock_rpc_runtime_api::RoundHandlerStatus<BlockNumber>)> {
+            Clock::round_handler_statuses()
+        }
+    }
+
+     impl pallet_rewards_rpc_runtime_api::RewardsRuntimeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn fetch_distribution_history(
+            from: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_rewards_rpc_runtime_api::DistributionRecord<BlockNumber, Balance>)> {
+            Rewards::distribution_history(from, limit)
+        }
+
+        fn fetch_earnings(
+            account: AccountId,
+            from_round: pallet_rewards_rpc_runtime_api::RoundIndex,
+            limit: u32,
+        ) -> Vec<(pallet_rewards_rpc_runtime_api::RoundIndex, Vec<pallet_rewards_rpc_runtime_api::EarningsEntry<Balance>>)> {
+            Rewards::earnings(&account, from_round, limit)
+        }
+
+        fn fetch_vesting_balances(
+            account: AccountId,
+        ) -> Vec<(pallet_rewards_rpc_runtime_api::CircuitRole, Balance, Balance)> {
+            Rewards::vesting_balances(&account)
+        }
     }
 
      impl pallet_portal_rpc_runtime_api::PortalRuntimeApi<Block, AccountId, Balance, Hash> for Runtime {
@@ -527,6 +633,23 @@ impl_runtime_apis! {
         )> {
             Circuit::get_pending_xtx_for(for_executor)
         }
+
+        fn fetch_heartbeat(chain_id: ChainId) -> Option<pallet_portal_rpc_runtime_api::GatewayHeartbeat> {
+            Portal::fetch_heartbeat(chain_id)
+        }
+
+        fn fetch_heights(chain_id: ChainId) -> Option<pallet_portal_rpc_runtime_api::GatewayHeights> {
+            Portal::fetch_heights(chain_id)
+        }
+
+        fn verify_event_inclusion(
+            chain_id: ChainId,
+            speed_mode: t3rn_primitives::SpeedMode,
+            source: Option<t3rn_primitives::ExecutionSource>,
+            message: Vec<u8>,
+        ) -> Result<pallet_portal_rpc_runtime_api::InclusionReceipt<u128>, Vec<u8>> {
+            Portal::dry_run_event_inclusion(chain_id, speed_mode, source, message)
+        }
     }
 
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -153,20 +153,8 @@ impl t3rn_primitives::clock::OnHookQueues<Runtime> for GlobalOnInitQueues {
     fn process_hourly(n: BlockNumber, hook_weight_limit: Weight) -> Weight {
         let mut total_consumed: Weight = Zero::zero();
 
-        let weight = XDNS::check_for_manual_verifier_overview_process(n);
-        log::debug!(
-            "XDNS::check_for_manual_verifier_overview_process consumed: {:?}",
-            weight
-        );
-        total_consumed = total_consumed.saturating_add(weight);
-
-        let weight = Rewards::process_accumulated_settlements();
-        log::debug!(
-            "Rewards::process_accumulated_settlements consumed: {:?}",
-            weight
-        );
-        total_consumed = total_consumed.saturating_add(weight);
-
+        // The verifier overview, settlements accumulation and committee shuffling run
+        // as round handlers once the round is bumped, see `RoundHandlers`.
         let weight = Clock::check_bump_round(n);
         log::debug!("Clock::check_bump_round consumed: {:?}", weight);
         total_consumed = total_consumed.saturating_add(weight);
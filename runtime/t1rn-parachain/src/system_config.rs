@@ -252,6 +252,7 @@ impl Contains<RuntimeCall> for MaintenanceFilter {
             RuntimeCall::PolkadotBridge(_) => true,
             RuntimeCall::EthereumBridge(_) => true,
             RuntimeCall::SepoliaBridge(_) => true,
+            RuntimeCall::StandaloneBridge(_) => true,
             #[allow(unreachable_patterns)] // We need this as an accidental catchall
             _ => false,
         }
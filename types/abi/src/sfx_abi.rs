@@ -100,6 +100,26 @@ impl SFXAbi {
         received_payload: Data,
         ordered_args_codec: &Codec,
         payload_codec: &Codec,
+    ) -> Result<(), DispatchError> {
+        self.validate_normalized_arguments_against_received(
+            ordered_args,
+            received_payload,
+            ordered_args_codec,
+            payload_codec,
+            |_arg_name, recoded_arg| Ok(recoded_arg),
+        )
+    }
+
+    /// Same as `validate_arguments_against_received`, but each received argument is passed through
+    /// `normalize` after recoding to `ordered_args_codec`, e.g. to scale amounts emitted in the decimals
+    /// of the asset on the target to the decimals the ordered arguments are expressed in.
+    pub fn validate_normalized_arguments_against_received(
+        &self,
+        ordered_args: &Vec<Data>,
+        received_payload: Data,
+        ordered_args_codec: &Codec,
+        payload_codec: &Codec,
+        normalize: impl Fn(&Name, Data) -> Result<Data, DispatchError>,
     ) -> Result<(), DispatchError> {
         self.ensure_arguments_order(ordered_args)?;
         let abi: Abi = self
@@ -149,8 +169,10 @@ impl SFXAbi {
                 })?;
 
             // Check if arguments are equal after recoding to ordered_args_codec
-            let recoded_payload: Data =
-                filled_abi_matched_by_name.recode_as(payload_codec, ordered_args_codec, true)?;
+            let recoded_payload: Data = normalize(
+                current_arg_name,
+                filled_abi_matched_by_name.recode_as(payload_codec, ordered_args_codec, true)?,
+            )?;
 
             match recoded_payload == *ordered_arg {
                 true => continue,
@@ -1,4 +1,4 @@
-use crate::types::Data;
+use crate::types::{Data, Name};
 pub use crate::{
     bid::SFXBid,
     fsx::{FullSideEffect, SideEffectId},
@@ -143,6 +143,23 @@ where
             ingress_codec,
         )
     }
+
+    pub fn confirm_normalized(
+        &self,
+        sfx_abi: SFXAbi,
+        ingress_payload: Data,
+        egress_codec: &Codec,
+        ingress_codec: &Codec,
+        normalize: impl Fn(&Name, Data) -> Result<Data, DispatchError>,
+    ) -> Result<(), DispatchError> {
+        sfx_abi.validate_normalized_arguments_against_received(
+            &self.encoded_args,
+            ingress_payload,
+            egress_codec,
+            ingress_codec,
+            normalize,
+        )
+    }
 }
 
 #[cfg(feature = "runtime")]